@@ -83,3 +83,34 @@ impl ToNative for MetadataModuleConfig {
         })
     }
 }
+
+impl FromNativeWithNetworkContext for MetadataModuleConfig {
+    type Native = engine::ModuleConfig<engine::MetadataInit>;
+
+    fn from_native(native: Self::Native, network_id: u8) -> Self {
+        Self {
+            init: native
+                .init
+                .data
+                .into_iter()
+                .map(|(key, entry)| {
+                    let entry = MetadataInitEntry {
+                        value: entry.value.map(|value| {
+                            MetadataValue::from_native(&value, network_id)
+                        }),
+                        lock: entry.lock,
+                    };
+                    (key, entry)
+                })
+                .collect(),
+            roles: native
+                .roles
+                .data
+                .into_iter()
+                .map(|(role_key, rule)| {
+                    (role_key.key, rule.map(|rule| Arc::new(AccessRule(rule))))
+                })
+                .collect(),
+        }
+    }
+}
@@ -27,12 +27,54 @@ pub struct FungibleResourceRoles {
     pub deposit_roles: Option<ResourceManagerRole>,
 }
 
+#[derive(Debug, Clone, Record)]
+pub struct NonFungibleResourceRoles {
+    pub mint_roles: Option<ResourceManagerRole>,
+    pub burn_roles: Option<ResourceManagerRole>,
+    pub freeze_roles: Option<ResourceManagerRole>,
+    pub recall_roles: Option<ResourceManagerRole>,
+    pub withdraw_roles: Option<ResourceManagerRole>,
+    pub deposit_roles: Option<ResourceManagerRole>,
+    pub non_fungible_data_update_roles: Option<ResourceManagerRole>,
+}
+
+#[derive(Debug, Clone, Record)]
+pub struct NonFungibleDataSchema {
+    pub schema: Schema,
+    pub mutable_fields: Vec<String>,
+}
+
 #[derive(Debug, Clone, Record)]
 pub struct ResourceManagerRole {
     pub role: Option<Arc<AccessRule>>,
     pub role_updater: Option<Arc<AccessRule>>,
 }
 
+#[derive(Debug, Clone, Enum)]
+pub enum ResourceManagerRoleKey {
+    Minter,
+    MinterUpdater,
+    Burner,
+    BurnerUpdater,
+    Withdrawer,
+    WithdrawerUpdater,
+    Depositor,
+    DepositorUpdater,
+    Recaller,
+    RecallerUpdater,
+    Freezer,
+    FreezerUpdater,
+    NonFungibleDataUpdater,
+    NonFungibleDataUpdaterUpdater,
+}
+
+#[derive(Debug, Clone, Record)]
+pub struct VaultFreezeFlags {
+    pub withdraw: bool,
+    pub deposit: bool,
+    pub burn: bool,
+}
+
 impl ResourceManagerRole {
     resource_manager_role_conversion! {MintRoles, minter}
     resource_manager_role_conversion! {BurnRoles, burner}
@@ -40,6 +82,7 @@ impl ResourceManagerRole {
     resource_manager_role_conversion! {RecallRoles, recaller}
     resource_manager_role_conversion! {WithdrawRoles, withdrawer}
     resource_manager_role_conversion! {DepositRoles, depositor}
+    resource_manager_role_conversion! {NonFungibleDataUpdateRoles, non_fungible_data_updater}
 }
 
 impl ToNative for FungibleResourceRoles {
@@ -69,6 +112,160 @@ impl ToNative for FungibleResourceRoles {
     }
 }
 
+impl FromNative for FungibleResourceRoles {
+    type Native = engine::FungibleResourceRoles;
+
+    fn from_native(native: Self::Native) -> Self {
+        Self {
+            mint_roles: native
+                .mint_roles
+                .map(ResourceManagerRole::from_native_mint_roles),
+            burn_roles: native
+                .burn_roles
+                .map(ResourceManagerRole::from_native_burn_roles),
+            freeze_roles: native
+                .freeze_roles
+                .map(ResourceManagerRole::from_native_freeze_roles),
+            recall_roles: native
+                .recall_roles
+                .map(ResourceManagerRole::from_native_recall_roles),
+            withdraw_roles: native
+                .withdraw_roles
+                .map(ResourceManagerRole::from_native_withdraw_roles),
+            deposit_roles: native
+                .deposit_roles
+                .map(ResourceManagerRole::from_native_deposit_roles),
+        }
+    }
+}
+
+impl ToNative for NonFungibleResourceRoles {
+    type Native = engine::NonFungibleResourceRoles;
+
+    fn to_native(self) -> Result<Self::Native> {
+        Ok(engine::NonFungibleResourceRoles {
+            mint_roles: self
+                .mint_roles
+                .map(|value| value.to_native_mint_roles()),
+            burn_roles: self
+                .burn_roles
+                .map(|value| value.to_native_burn_roles()),
+            freeze_roles: self
+                .freeze_roles
+                .map(|value| value.to_native_freeze_roles()),
+            recall_roles: self
+                .recall_roles
+                .map(|value| value.to_native_recall_roles()),
+            withdraw_roles: self
+                .withdraw_roles
+                .map(|value| value.to_native_withdraw_roles()),
+            deposit_roles: self
+                .deposit_roles
+                .map(|value| value.to_native_deposit_roles()),
+            non_fungible_data_update_roles: self
+                .non_fungible_data_update_roles
+                .map(|value| value.to_native_non_fungible_data_update_roles()),
+        })
+    }
+}
+
+impl FromNative for NonFungibleResourceRoles {
+    type Native = engine::NonFungibleResourceRoles;
+
+    fn from_native(native: Self::Native) -> Self {
+        Self {
+            mint_roles: native
+                .mint_roles
+                .map(ResourceManagerRole::from_native_mint_roles),
+            burn_roles: native
+                .burn_roles
+                .map(ResourceManagerRole::from_native_burn_roles),
+            freeze_roles: native
+                .freeze_roles
+                .map(ResourceManagerRole::from_native_freeze_roles),
+            recall_roles: native
+                .recall_roles
+                .map(ResourceManagerRole::from_native_recall_roles),
+            withdraw_roles: native
+                .withdraw_roles
+                .map(ResourceManagerRole::from_native_withdraw_roles),
+            deposit_roles: native
+                .deposit_roles
+                .map(ResourceManagerRole::from_native_deposit_roles),
+            non_fungible_data_update_roles: native
+                .non_fungible_data_update_roles
+                .map(
+                    ResourceManagerRole::from_native_non_fungible_data_update_roles,
+                ),
+        }
+    }
+}
+
+impl From<ResourceManagerRoleKey> for engine::RoleKey {
+    fn from(value: ResourceManagerRoleKey) -> Self {
+        let role_key = match value {
+            ResourceManagerRoleKey::Minter => engine::MINTER_ROLE,
+            ResourceManagerRoleKey::MinterUpdater => {
+                engine::MINTER_UPDATER_ROLE
+            }
+            ResourceManagerRoleKey::Burner => engine::BURNER_ROLE,
+            ResourceManagerRoleKey::BurnerUpdater => {
+                engine::BURNER_UPDATER_ROLE
+            }
+            ResourceManagerRoleKey::Withdrawer => engine::WITHDRAWER_ROLE,
+            ResourceManagerRoleKey::WithdrawerUpdater => {
+                engine::WITHDRAWER_UPDATER_ROLE
+            }
+            ResourceManagerRoleKey::Depositor => engine::DEPOSITOR_ROLE,
+            ResourceManagerRoleKey::DepositorUpdater => {
+                engine::DEPOSITOR_UPDATER_ROLE
+            }
+            ResourceManagerRoleKey::Recaller => engine::RECALLER_ROLE,
+            ResourceManagerRoleKey::RecallerUpdater => {
+                engine::RECALLER_UPDATER_ROLE
+            }
+            ResourceManagerRoleKey::Freezer => engine::FREEZER_ROLE,
+            ResourceManagerRoleKey::FreezerUpdater => {
+                engine::FREEZER_UPDATER_ROLE
+            }
+            ResourceManagerRoleKey::NonFungibleDataUpdater => {
+                engine::NON_FUNGIBLE_DATA_UPDATER_ROLE
+            }
+            ResourceManagerRoleKey::NonFungibleDataUpdaterUpdater => {
+                engine::NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE
+            }
+        };
+        engine::RoleKey::new(role_key)
+    }
+}
+
+impl ToNative for VaultFreezeFlags {
+    type Native = engine::VaultFreezeFlags;
+
+    fn to_native(self) -> Result<Self::Native> {
+        let mut flags = engine::VaultFreezeFlags::empty();
+        flags.set(engine::VaultFreezeFlags::WITHDRAW, self.withdraw);
+        flags.set(engine::VaultFreezeFlags::DEPOSIT, self.deposit);
+        flags.set(engine::VaultFreezeFlags::BURN, self.burn);
+        Ok(flags)
+    }
+}
+
+impl ToNative for NonFungibleDataSchema {
+    type Native = engine::NonFungibleDataSchema;
+
+    fn to_native(self) -> Result<Self::Native> {
+        let (type_id, schema) = self.schema.try_into()?;
+        Ok(engine::NonFungibleDataSchema::Local(
+            engine::LocalNonFungibleDataSchema {
+                schema: engine::VersionedScryptoSchema::from(schema),
+                type_id,
+                mutable_fields: self.mutable_fields.into_iter().collect(),
+            },
+        ))
+    }
+}
+
 macro_rules! resource_manager_role_conversion {
     ($ty: ident, $name: ident) => {
         paste::paste! {
@@ -78,6 +275,13 @@ macro_rules! resource_manager_role_conversion {
                     [< $name _updater >]: self.role_updater.as_ref().map(|value| value.0.clone()),
                 }
             }
+
+            pub fn [< from_native_ $ty: snake >](native: engine::$ty<engine::RoleDefinition>) -> Self {
+                Self {
+                    role: native.$name.map(|value| Arc::new(AccessRule(value))),
+                    role_updater: native.[< $name _updater >].map(|value| Arc::new(AccessRule(value))),
+                }
+            }
         }
     };
 }
@@ -1036,19 +1036,17 @@ impl ManifestV1Builder {
 
     pub fn mint_fungible(
         self: Arc<Self>,
-        resource_address: Arc<Address>,
+        resource_address: ManifestBuilderAddress,
         amount: Arc<Decimal>,
     ) -> Result<Arc<Self>> {
         builder_arc_map(self, |builder| {
-            let resource_address =
-                engine::ResourceAddress::try_from(*resource_address)?;
+            let resource_address = resource_address
+                .to_native_resource_address(&builder.name_record)?;
             let amount = amount.0;
 
             let instruction =
                 engine::InstructionV1::CallMethod(engine::CallMethod {
-                    address: engine::DynamicGlobalAddress::Static(
-                        resource_address.into(),
-                    ),
+                    address: resource_address,
                     method_name: engine::FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT
                         .to_owned(),
                     args: engine::to_manifest_value_and_unwrap!(
@@ -1062,6 +1060,177 @@ impl ManifestV1Builder {
         })
     }
 
+    pub fn create_non_fungible_resource_manager(
+        self: Arc<Self>,
+        owner_role: OwnerRole,
+        id_type: NonFungibleIdType,
+        track_total_supply: bool,
+        non_fungible_schema: NonFungibleDataSchema,
+        initial_supply: Option<Vec<ManifestBuilderNonFungibleEntry>>,
+        resource_roles: NonFungibleResourceRoles,
+        metadata: MetadataModuleConfig,
+        address_reservation: Option<ManifestBuilderAddressReservation>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let owner_role = owner_role.to_native()?;
+            let id_type = id_type.to_native()?;
+            let non_fungible_schema = non_fungible_schema.to_native()?;
+            let resource_roles = resource_roles.to_native()?;
+            let metadata = metadata.to_native()?;
+            let address_reservation = if let Some(value) = address_reservation {
+                Some(value.to_native(&builder.name_record)?)
+            } else {
+                None
+            };
+
+            let (function_name, args) = if let Some(initial_supply) =
+                initial_supply
+            {
+                let entries = non_fungible_entries_to_native(
+                    initial_supply,
+                    &builder.name_record,
+                )?;
+                (
+                    engine::NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
+                    engine::to_manifest_value_and_unwrap!(
+                        &engine::NonFungibleResourceManagerCreateWithInitialSupplyManifestInput {
+                            owner_role: owner_role.into(),
+                            id_type,
+                            track_total_supply,
+                            non_fungible_schema,
+                            entries,
+                            resource_roles: resource_roles.into(),
+                            metadata: metadata.into(),
+                            address_reservation
+                        }
+                    ),
+                )
+            } else {
+                (
+                    engine::NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
+                    engine::to_manifest_value_and_unwrap!(
+                        &engine::NonFungibleResourceManagerCreateManifestInput {
+                            owner_role: owner_role.into(),
+                            id_type,
+                            track_total_supply,
+                            non_fungible_schema,
+                            resource_roles: resource_roles.into(),
+                            metadata: metadata.into(),
+                            address_reservation
+                        }
+                    ),
+                )
+            };
+
+            let instruction =
+                engine::InstructionV1::CallFunction(engine::CallFunction {
+                    package_address: engine::DynamicPackageAddress::Static(
+                        engine::RESOURCE_PACKAGE,
+                    ),
+                    blueprint_name:
+                        engine::NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT
+                            .to_owned(),
+                    function_name: function_name.to_owned(),
+                    args,
+                });
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn mint_non_fungible(
+        self: Arc<Self>,
+        resource_address: ManifestBuilderAddress,
+        entries: Vec<ManifestBuilderNonFungibleEntry>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let resource_address = resource_address
+                .to_native_resource_address(&builder.name_record)?;
+            let entries =
+                non_fungible_entries_to_native(entries, &builder.name_record)?;
+
+            let instruction =
+                engine::InstructionV1::CallMethod(engine::CallMethod {
+                    address: resource_address,
+                    method_name:
+                        engine::NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT
+                            .to_owned(),
+                    args: engine::to_manifest_value_and_unwrap!(
+                        &engine::NonFungibleResourceManagerMintManifestInput {
+                            entries
+                        }
+                    ),
+                });
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn mint_ruid_non_fungible(
+        self: Arc<Self>,
+        resource_address: ManifestBuilderAddress,
+        entries: Vec<ManifestBuilderNonFungibleData>,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let resource_address = resource_address
+                .to_native_resource_address(&builder.name_record)?;
+            let entries = entries
+                .into_iter()
+                .map(|data| {
+                    data.to_native(&builder.name_record).map(|data| (data,))
+                })
+                .collect::<Result<_>>()?;
+
+            let instruction =
+                engine::InstructionV1::CallMethod(engine::CallMethod {
+                    address: resource_address,
+                    method_name:
+                        engine::NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT
+                            .to_owned(),
+                    args: engine::to_manifest_value_and_unwrap!(
+                        &engine::NonFungibleResourceManagerMintRuidManifestInput {
+                            entries
+                        }
+                    ),
+                });
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
+    pub fn update_non_fungible_data(
+        self: Arc<Self>,
+        resource_address: ManifestBuilderAddress,
+        non_fungible_local_id: NonFungibleLocalId,
+        field_name: String,
+        data: ManifestBuilderNonFungibleData,
+    ) -> Result<Arc<Self>> {
+        builder_arc_map(self, |builder| {
+            let resource_address = resource_address
+                .to_native_resource_address(&builder.name_record)?;
+            let id =
+                engine::NonFungibleLocalId::try_from(non_fungible_local_id)?;
+            let data = data.to_native(&builder.name_record)?;
+
+            let instruction =
+                engine::InstructionV1::CallMethod(engine::CallMethod {
+                    address: resource_address,
+                    method_name:
+                        engine::NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT
+                            .to_owned(),
+                    args: engine::to_manifest_value_and_unwrap!(
+                        &engine::NonFungibleResourceManagerUpdateDataManifestInput {
+                            id,
+                            field_name,
+                            data
+                        }
+                    ),
+                });
+            builder.instructions.push(instruction);
+            Ok(())
+        })
+    }
+
     //=================
     // Builder Methods
     //=================
@@ -1078,6 +1247,113 @@ impl ManifestV1Builder {
             blobs: self.blobs.clone(),
         })
     }
+
+    pub fn build_with_options(
+        self: Arc<Self>,
+        network_id: u8,
+        options: ManifestBuildOptions,
+    ) -> Result<Arc<TransactionManifestV1>> {
+        let ManifestBuildOptions {
+            lock_fee,
+            worktop_sweep,
+            forbid_unconsumed_buckets_and_proofs,
+        } = options;
+        let mut builder = self;
+
+        if let Some(ManifestBuilderWorktopSweep {
+            account_address,
+            deposit_mode,
+        }) = worktop_sweep
+        {
+            // Returning all of the unconsumed buckets to the worktop so that
+            // they're deposited with the rest of the worktop.
+            builder = builder_arc_map(builder, |builder| {
+                let (buckets, _) =
+                    builder.name_record.unconsumed_buckets_and_proofs(
+                        builder
+                            .instructions
+                            .iter()
+                            .map(|instruction| instruction.effect()),
+                    );
+                for bucket in buckets {
+                    builder.instructions.push(
+                        engine::InstructionV1::ReturnToWorktop(
+                            engine::ReturnToWorktop { bucket_id: bucket },
+                        ),
+                    );
+                }
+                Ok(())
+            })?;
+            builder = match deposit_mode {
+                ManifestBuilderDepositMode::Deposit => {
+                    builder.account_deposit_entire_worktop(account_address)?
+                }
+                ManifestBuilderDepositMode::TryDepositOrRefund {
+                    authorized_depositor_badge,
+                } => builder.account_try_deposit_entire_worktop_or_refund(
+                    account_address,
+                    authorized_depositor_badge,
+                )?,
+                ManifestBuilderDepositMode::TryDepositOrAbort {
+                    authorized_depositor_badge,
+                } => builder.account_try_deposit_entire_worktop_or_abort(
+                    account_address,
+                    authorized_depositor_badge,
+                )?,
+            };
+        }
+
+        if let Some(ManifestBuilderLockFee {
+            account_address,
+            amount,
+        }) = lock_fee
+        {
+            builder = builder_arc_map(builder, |builder| {
+                let address =
+                    engine::GlobalAddress::try_from(*account_address)?;
+                let instruction =
+                    engine::InstructionV1::CallMethod(engine::CallMethod {
+                        address: engine::DynamicGlobalAddress::Static(address),
+                        method_name: engine::ACCOUNT_LOCK_FEE_IDENT.to_owned(),
+                        args: engine::to_manifest_value_and_unwrap!(
+                            &engine::AccountLockFeeManifestInput {
+                                amount: amount.0
+                            }
+                        ),
+                    });
+                builder.instructions.insert(0, instruction);
+                Ok(())
+            })?;
+        }
+
+        if forbid_unconsumed_buckets_and_proofs {
+            let (buckets, proofs) =
+                builder.name_record.unconsumed_buckets_and_proofs(
+                    builder
+                        .instructions
+                        .iter()
+                        .map(|instruction| instruction.effect()),
+                );
+            if !buckets.is_empty() || !proofs.is_empty() {
+                return Err(
+                    RadixEngineToolkitError::UnconsumedBucketsOrProofs {
+                        buckets: buckets
+                            .iter()
+                            .map(|bucket| {
+                                builder.name_record.bucket_name(bucket)
+                            })
+                            .collect(),
+                        proofs: proofs
+                            .iter()
+                            .map(|proof| builder.name_record.proof_name(proof))
+                            .collect(),
+                    },
+                );
+            }
+        }
+
+        Ok(builder.build(network_id))
+    }
 }
 
 #[derive(Debug, Clone, Record)]
@@ -1189,7 +1465,36 @@ macro_rules! builder_alias {
                         input_arg_type: $underlying_arg_type,
                     }
                 ),*
-            ]
+            ],
+            fixed_args: []
+        }
+    };
+    (
+        builder_method: $builder_method: ident,
+        method_ident: $method_ident: expr,
+        instruction: $instruction: ident,
+        args: $input_type: ident {
+            $(
+                $arg_name: ident as $arg_name_alias: ident : ( $interface_arg_type: ty => $underlying_arg_type: ty )
+            ),* $(,)?
+        } $(,)?
+    ) => {
+        builder_alias_internal! {
+            builder_method: $builder_method,
+            method_ident: $method_ident,
+            instruction: $instruction,
+            input_type: $input_type,
+            args: [
+                $(
+                    {
+                        interface_arg_name: $arg_name_alias,
+                        interface_arg_type: $interface_arg_type,
+                        input_arg_name: $arg_name,
+                        input_arg_type: $underlying_arg_type,
+                    }
+                ),*
+            ],
+            fixed_args: []
         }
     };
     (
@@ -1200,6 +1505,11 @@ macro_rules! builder_alias {
             $(
                 $arg_name: ident as $arg_name_alias: ident : ( $interface_arg_type: ty => $underlying_arg_type: ty )
             ),* $(,)?
+        },
+        fixed_args: {
+            $(
+                $fixed_arg_name: ident: $fixed_arg_value: expr
+            ),* $(,)?
         } $(,)?
     ) => {
         builder_alias_internal! {
@@ -1216,6 +1526,11 @@ macro_rules! builder_alias {
                         input_arg_type: $underlying_arg_type,
                     }
                 ),*
+            ],
+            fixed_args: [
+                $(
+                    $fixed_arg_name: $fixed_arg_value
+                ),*
             ]
         }
     };
@@ -1280,6 +1595,62 @@ macro_rules! builder_alias {
 }
 
 macro_rules! builder_alias_internal {
+    (
+        builder_method: $builder_method: ident,
+        method_ident: $method_ident: expr,
+        instruction: CallDirectVaultMethod,
+        input_type: $input_type: ident,
+        args: [
+            $(
+                {
+                    /* Interface */
+                    interface_arg_name: $interface_arg_name: ident,
+                    interface_arg_type: $interface_arg_type: ty,
+                    /* Input */
+                    input_arg_name: $input_arg_name: ident,
+                    input_arg_type: $input_arg_type: ty $(,)?
+                }
+            ),* $(,)?
+        ],
+        fixed_args: [
+            $(
+                $fixed_arg_name: ident: $fixed_arg_value: expr
+            ),* $(,)?
+        ] $(,)?
+    ) => {
+        #[uniffi::export]
+        impl ManifestV1Builder {
+            pub fn $builder_method(
+                self: $crate::prelude::Arc<Self>,
+                address: $crate::prelude::Arc<$crate::prelude::Address>,
+                $(
+                    $interface_arg_name: $interface_arg_type
+                ),*
+            ) -> $crate::prelude::Result<Arc<Self>> {
+                $crate::builder::manifest_builder::utils::builder_arc_map(self, |builder| {
+                    let instruction = $crate::prelude::engine::InstructionV1::CallDirectVaultMethod(engine::CallDirectVaultMethod {
+                        address: $crate::prelude::engine::InternalAddress::try_from(*address)?,
+                        method_name: $method_ident.to_owned(),
+                        args: $crate::prelude::engine::to_manifest_value_and_unwrap! {
+                            &engine::$input_type {
+                                $(
+                                    $fixed_arg_name: $fixed_arg_value,
+                                )*
+                                $(
+                                    $input_arg_name: <
+                                        $input_arg_type
+                                        as $crate::builder::manifest_builder::traits::FromWithNameRecordContext<$interface_arg_type>
+                                    >::from($interface_arg_name, &builder.name_record)?.into()
+                                ),*
+                            }
+                        }
+                    });
+                    builder.instructions.push(instruction);
+                    Ok(())
+                })
+            }
+        }
+    };
     (
         builder_method: $builder_method: ident,
         method_ident: $method_ident: expr,
@@ -1296,6 +1667,11 @@ macro_rules! builder_alias_internal {
                     input_arg_type: $input_arg_type: ty $(,)?
                 }
             ),* $(,)?
+        ],
+        fixed_args: [
+            $(
+                $fixed_arg_name: ident: $fixed_arg_value: expr
+            ),* $(,)?
         ] $(,)?
     ) => {
         paste::paste! {
@@ -1314,6 +1690,9 @@ macro_rules! builder_alias_internal {
                             method_name: $method_ident.to_owned(),
                             args: $crate::prelude::engine::to_manifest_value_and_unwrap! {
                                 &engine::$input_type {
+                                    $(
+                                        $fixed_arg_name: $fixed_arg_value,
+                                    )*
                                     $(
                                         $input_arg_name: <
                                             $input_arg_type
@@ -2092,6 +2471,79 @@ builder_alias! {
         instruction: CallMethod,
         args: MultiResourcePoolGetVaultAmountsManifestInput {}
     },
+    // =================
+    // Resource Manager
+    // =================
+    {
+        builder_method: resource_manager_burn,
+        method_ident: engine::RESOURCE_MANAGER_BURN_IDENT,
+        instruction: CallMethod,
+        args: ResourceManagerBurnManifestInput {
+            bucket: (ManifestBuilderBucket => engine::ManifestBucket),
+        }
+    },
+    {
+        builder_method: resource_manager_set_role,
+        method_ident: engine::ROLE_ASSIGNMENT_SET_IDENT,
+        instruction: CallRoleAssignmentMethod,
+        args: RoleAssignmentSetManifestInput {
+            role_key as role: (ResourceManagerRoleKey => engine::RoleKey),
+            rule as rule: (Arc<AccessRule> => engine::AccessRule),
+        },
+        fixed_args: {
+            module: engine::ObjectModuleId::Main.into(),
+        }
+    },
+    // Locks a role of the resource manager by setting its updater role to
+    // deny all. The role given is the updater role, e.g. `MinterUpdater` to
+    // lock the minter role.
+    {
+        builder_method: resource_manager_lock_role,
+        method_ident: engine::ROLE_ASSIGNMENT_SET_IDENT,
+        instruction: CallRoleAssignmentMethod,
+        args: RoleAssignmentSetManifestInput {
+            role_key as updater_role: (ResourceManagerRoleKey => engine::RoleKey),
+        },
+        fixed_args: {
+            module: engine::ObjectModuleId::Main.into(),
+            rule: engine::AccessRule::DenyAll.into(),
+        }
+    },
+    // ======
+    // Vault
+    // ======
+    {
+        builder_method: vault_recall,
+        method_ident: engine::VAULT_RECALL_IDENT,
+        instruction: CallDirectVaultMethod,
+        args: VaultRecallManifestInput {
+            amount: (Arc<Decimal> => engine::Decimal),
+        }
+    },
+    {
+        builder_method: vault_freeze,
+        method_ident: engine::VAULT_FREEZE_IDENT,
+        instruction: CallDirectVaultMethod,
+        args: VaultFreezeManifestInput {
+            to_freeze: (VaultFreezeFlags => engine::VaultFreezeFlags),
+        }
+    },
+    {
+        builder_method: vault_unfreeze,
+        method_ident: engine::VAULT_UNFREEZE_IDENT,
+        instruction: CallDirectVaultMethod,
+        args: VaultUnfreezeManifestInput {
+            to_unfreeze: (VaultFreezeFlags => engine::VaultFreezeFlags),
+        }
+    },
+    {
+        builder_method: vault_recall_non_fungibles,
+        method_ident: engine::NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
+        instruction: CallDirectVaultMethod,
+        args: NonFungibleVaultRecallNonFungiblesManifestInput {
+            non_fungible_local_ids: (Vec<NonFungibleLocalId> => engine::IndexSet<engine::NonFungibleLocalId>),
+        }
+    },
     // ================
     // Metadata Module
     // ================
@@ -94,6 +94,89 @@ define_name_record! {
     },
 }
 
+impl NameRecord {
+    /// Replays the effects of the given instructions and returns the buckets
+    /// and proofs that were created but never consumed by any of them.
+    pub fn unconsumed_buckets_and_proofs<'a>(
+        &self,
+        effects: impl IntoIterator<Item = engine::ManifestInstructionEffect<'a>>,
+    ) -> (
+        engine::IndexSet<engine::ManifestBucket>,
+        engine::IndexSet<engine::ManifestProof>,
+    ) {
+        let mut id_allocator = engine::ManifestIdAllocator::new();
+        let mut buckets = engine::IndexSet::new();
+        let mut proofs = engine::IndexSet::new();
+
+        for effect in effects {
+            match effect {
+                engine::ManifestInstructionEffect::CreateBucket { .. } => {
+                    buckets.insert(id_allocator.new_bucket_id());
+                }
+                engine::ManifestInstructionEffect::CreateProof { .. }
+                | engine::ManifestInstructionEffect::CloneProof { .. } => {
+                    proofs.insert(id_allocator.new_proof_id());
+                }
+                engine::ManifestInstructionEffect::ConsumeBucket {
+                    consumed_bucket,
+                    ..
+                } => {
+                    buckets.swap_remove(&consumed_bucket);
+                }
+                engine::ManifestInstructionEffect::ConsumeProof {
+                    consumed_proof,
+                    ..
+                } => {
+                    proofs.swap_remove(&consumed_proof);
+                }
+                engine::ManifestInstructionEffect::DropManyProofs {
+                    drop_all_named_proofs,
+                    ..
+                } => {
+                    if drop_all_named_proofs {
+                        proofs.clear();
+                    }
+                }
+                engine::ManifestInstructionEffect::Invocation {
+                    args, ..
+                } => {
+                    let args =
+                        toolkit::IndexedManifestValue::from_manifest_value(args);
+                    for bucket in args.buckets() {
+                        buckets.swap_remove(bucket);
+                    }
+                    for proof in args.proofs() {
+                        proofs.swap_remove(proof);
+                    }
+                }
+                engine::ManifestInstructionEffect::CreateAddressAndReservation { .. }
+                | engine::ManifestInstructionEffect::ResourceAssertion { .. }
+                | engine::ManifestInstructionEffect::Verification { .. } => {}
+            }
+        }
+
+        (buckets, proofs)
+    }
+
+    /// The name that the bucket was created with in the builder or its id if
+    /// it was not created through the name record.
+    pub fn bucket_name(&self, bucket: &engine::ManifestBucket) -> String {
+        self.bucket
+            .iter()
+            .find_map(|(name, value)| (value == bucket).then(|| name.clone()))
+            .unwrap_or_else(|| format!("bucket{}", bucket.0))
+    }
+
+    /// The name that the proof was created with in the builder or its id if
+    /// it was not created through the name record.
+    pub fn proof_name(&self, proof: &engine::ManifestProof) -> String {
+        self.proof
+            .iter()
+            .find_map(|(name, value)| (value == proof).then(|| name.clone()))
+            .unwrap_or_else(|| format!("proof{}", proof.0))
+    }
+}
+
 #[derive(Clone, Debug, Enum)]
 pub enum NameRecordError {
     ObjectNameIsAlreadyTaken { object: String, name: String },
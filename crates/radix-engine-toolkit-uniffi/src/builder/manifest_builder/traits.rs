@@ -411,12 +411,24 @@ impl FromWithNameRecordContext<String> for engine::RoleKey {
     }
 }
 
+impl FromWithNameRecordContext<ResourceManagerRoleKey> for engine::RoleKey {
+    fn from(item: ResourceManagerRoleKey, _: &NameRecord) -> Result<Self> {
+        Ok(item.into())
+    }
+}
+
 impl FromWithNameRecordContext<ModuleId> for engine::ObjectModuleId {
     fn from(item: ModuleId, _: &NameRecord) -> Result<Self> {
         Ok(item.into())
     }
 }
 
+impl FromWithNameRecordContext<VaultFreezeFlags> for engine::VaultFreezeFlags {
+    fn from(item: VaultFreezeFlags, _: &NameRecord) -> Result<Self> {
+        item.to_native()
+    }
+}
+
 impl FromWithNameRecordContext<RoyaltyAmount> for engine::RoyaltyAmount {
     fn from(item: RoyaltyAmount, _: &NameRecord) -> Result<Self> {
         Ok(item.into())
@@ -48,6 +48,55 @@ pub enum ManifestBuilderAddress {
     Static { value: Arc<Address> },
 }
 
+/// The data of a non-fungible given either as a manifest value or as the
+/// programmatic JSON representation of the Scrypto value.
+#[derive(Clone, Debug, Enum)]
+pub enum ManifestBuilderNonFungibleData {
+    Value { value: ManifestBuilderValue },
+    ProgrammaticJson { value: String },
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ManifestBuilderNonFungibleEntry {
+    pub id: NonFungibleLocalId,
+    pub data: ManifestBuilderNonFungibleData,
+}
+
+/// Options applied by the builder when finalizing the manifest.
+#[derive(Clone, Debug, Record)]
+pub struct ManifestBuildOptions {
+    /// A fee lock that is prepended to the manifest.
+    pub lock_fee: Option<ManifestBuilderLockFee>,
+    /// A deposit of all of the remaining buckets and worktop contents into
+    /// an account that is appended to the manifest.
+    pub worktop_sweep: Option<ManifestBuilderWorktopSweep>,
+    /// Fails the build if any buckets or proofs remain unconsumed.
+    pub forbid_unconsumed_buckets_and_proofs: bool,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ManifestBuilderLockFee {
+    pub account_address: Arc<Address>,
+    pub amount: Arc<Decimal>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ManifestBuilderWorktopSweep {
+    pub account_address: Arc<Address>,
+    pub deposit_mode: ManifestBuilderDepositMode,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ManifestBuilderDepositMode {
+    Deposit,
+    TryDepositOrRefund {
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+    },
+    TryDepositOrAbort {
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+    },
+}
+
 impl<'a> From<&'a str> for ManifestBuilderIntent {
     fn from(value: &'a str) -> Self {
         Self {
@@ -144,3 +193,74 @@ impl NameRecordConvertible for ManifestBuilderAddress {
         }
     }
 }
+
+impl ManifestBuilderAddress {
+    /// Converts the address into the global address of a resource manager. A
+    /// static address must be a resource address while named addresses are
+    /// accepted as is since their entity type is only known once allocated.
+    pub fn to_native_resource_address(
+        &self,
+        name_record: &NameRecord,
+    ) -> Result<engine::DynamicGlobalAddress> {
+        match self.to_native(name_record)? {
+            engine::ManifestAddress::Static(value) => {
+                engine::ResourceAddress::try_from(value.0)
+                    .map(|address| {
+                        engine::DynamicGlobalAddress::Static(address.into())
+                    })
+                    .map_err(Into::into)
+            }
+            engine::ManifestAddress::Named(value) => {
+                Ok(engine::DynamicGlobalAddress::Named(value))
+            }
+        }
+    }
+}
+
+impl NameRecordConvertible for ManifestBuilderNonFungibleData {
+    type Native = engine::ManifestValue;
+
+    fn to_native(&self, name_record: &NameRecord) -> Result<Self::Native> {
+        match self {
+            Self::Value { value } => value.to_native(name_record),
+            Self::ProgrammaticJson { value } => {
+                toolkit::functions::scrypto_sbor::string_representation_to_manifest_value(
+                    toolkit::ScryptoSborStringRepresentation::ProgrammaticJson(
+                        value.clone(),
+                    ),
+                )
+                .map_err(Into::into)
+            }
+        }
+    }
+}
+
+impl NameRecordConvertible for ManifestBuilderNonFungibleEntry {
+    type Native = (engine::NonFungibleLocalId, (engine::ManifestValue,));
+
+    fn to_native(&self, name_record: &NameRecord) -> Result<Self::Native> {
+        let id = engine::NonFungibleLocalId::try_from(self.id.clone())?;
+        let data = self.data.to_native(name_record)?;
+        Ok((id, (data,)))
+    }
+}
+
+/// Converts the non-fungible entries into the map expected by the resource
+/// manager, failing if the same non-fungible local id is given more than once
+/// rather than silently keeping one of the entries.
+pub fn non_fungible_entries_to_native(
+    entries: Vec<ManifestBuilderNonFungibleEntry>,
+    name_record: &NameRecord,
+) -> Result<IndexMap<engine::NonFungibleLocalId, (engine::ManifestValue,)>> {
+    let mut native_entries = IndexMap::new();
+    for entry in entries {
+        let (id, data) = entry.to_native(name_record)?;
+        if native_entries.contains_key(&id) {
+            return Err(RadixEngineToolkitError::DuplicateNonFungibleLocalId {
+                id: id.to_string(),
+            });
+        }
+        native_entries.insert(id, data);
+    }
+    Ok(native_entries)
+}
@@ -24,3 +24,5 @@ pub mod transaction_v2_builder;
 pub mod partial_transaction_v2_builder;
 pub mod preview_partial_transaction_v2_builder;
 pub mod signed_partial_transaction_v2_builder;
+
+pub mod signing_session;
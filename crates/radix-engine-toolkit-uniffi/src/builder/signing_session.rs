@@ -0,0 +1,595 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::builder::partial_transaction_v2_builder::PartialTransactionV2Builder;
+use crate::prelude::*;
+
+const TRANSACTION_INTENT_DISCRIMINATOR: u8 = 0;
+const PARTIAL_TRANSACTION_DISCRIMINATOR: u8 = 1;
+
+/// A session for collecting the signatures of multiple parties on a transaction
+/// intent or a partial transaction and on all of the subintents beneath it.
+///
+/// Each intent in the tree is identified by its hash: the transaction intent
+/// hash or the subintent hash. The signers required on each of the intents are
+/// derived from its manifest: every preallocated account and identity that the
+/// manifest requires auth from must be signed for by the key that its address
+/// is derived from. The keys of securified entities can't be derived from the
+/// manifest and must be added to the required signers of the intent through
+/// [`SigningSession::require_signers`]. The session only accepts signatures of
+/// required signers that are valid over the hash of the intent that they're
+/// added to and is complete once all of the required signers have signed. The
+/// state of the session can be encoded to bytes and decoded again such that it
+/// can be passed along between the devices of the different parties.
+#[derive(Clone, Debug, Object)]
+pub struct SigningSession {
+    transaction: SigningSessionTransaction,
+    intents: Vec<SigningSessionIntentState>,
+}
+
+#[derive(Clone, Debug)]
+enum SigningSessionTransaction {
+    TransactionIntent(SignedTransactionIntentV2),
+    PartialTransaction(SignedPartialTransactionV2),
+}
+
+/// The signing state of one of the intents of the session.
+#[derive(Clone, Debug)]
+struct SigningSessionIntentState {
+    intent_hash: engine::Hash,
+    network_id: u8,
+    /// The preallocated accounts and identities that the manifest of the
+    /// intent requires auth from.
+    required_entities: engine::IndexSet<engine::GlobalAddress>,
+    /// The signers that were required on the intent in addition to the ones
+    /// of the required entities.
+    required_signers: engine::IndexSet<engine::PublicKey>,
+    /// The signers of the signatures on the intent which are verified once
+    /// when they're added to the session.
+    signers: engine::IndexSet<engine::PublicKey>,
+}
+
+#[uniffi::export]
+impl SigningSession {
+    #[uniffi::constructor]
+    pub fn from_transaction_v2_builder(
+        builder: Arc<TransactionV2Builder>,
+    ) -> Result<Arc<Self>> {
+        let signature_step = builder.prepare_for_signing()?;
+        Self::new(SigningSessionTransaction::TransactionIntent(
+            signature_step.signed_transaction_intent.clone(),
+        ))
+        .map(Arc::new)
+    }
+
+    #[uniffi::constructor]
+    pub fn from_partial_transaction_v2_builder(
+        builder: Arc<PartialTransactionV2Builder>,
+    ) -> Result<Arc<Self>> {
+        let partial_transaction = builder.build()?;
+        let non_root_subintent_signatures =
+            vec![Vec::new(); partial_transaction.non_root_subintents.len()];
+        Self::new(SigningSessionTransaction::PartialTransaction(
+            SignedPartialTransactionV2 {
+                partial_transaction,
+                root_subintent_signatures: Default::default(),
+                non_root_subintent_signatures,
+            },
+        ))
+        .map(Arc::new)
+    }
+
+    #[uniffi::constructor]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Arc<Self>> {
+        let (discriminator, payload, required_signers) =
+            engine::manifest_decode::<(
+                u8,
+                Vec<u8>,
+                Vec<Vec<engine::PublicKey>>,
+            )>(&bytes)
+            .map_err(|_| SigningSessionError::InvalidSessionBytes)?;
+        let transaction = match discriminator {
+            TRANSACTION_INTENT_DISCRIMINATOR => {
+                SigningSessionTransaction::TransactionIntent(
+                    SignedTransactionIntentV2::from_payload_bytes(payload)?
+                        .as_ref()
+                        .clone(),
+                )
+            }
+            PARTIAL_TRANSACTION_DISCRIMINATOR => {
+                SigningSessionTransaction::PartialTransaction(
+                    SignedPartialTransactionV2::from_payload_bytes(payload)?
+                        .as_ref()
+                        .clone(),
+                )
+            }
+            _ => return Err(SigningSessionError::InvalidSessionBytes.into()),
+        };
+
+        let mut session = Self::new(transaction)?;
+        if session.intents.len() != required_signers.len() {
+            return Err(SigningSessionError::InvalidSessionBytes.into());
+        }
+        for (intent, required_signers) in
+            session.intents.iter_mut().zip(required_signers)
+        {
+            intent.required_signers = required_signers.into_iter().collect();
+        }
+        Ok(Arc::new(session))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let (discriminator, payload) = match &self.transaction {
+            SigningSessionTransaction::TransactionIntent(signed_intent) => (
+                TRANSACTION_INTENT_DISCRIMINATOR,
+                signed_intent.to_payload_bytes()?,
+            ),
+            SigningSessionTransaction::PartialTransaction(
+                signed_partial_transaction,
+            ) => (
+                PARTIAL_TRANSACTION_DISCRIMINATOR,
+                signed_partial_transaction.to_payload_bytes()?,
+            ),
+        };
+        let required_signers = self
+            .intents
+            .iter()
+            .map(|intent| {
+                intent.required_signers.iter().cloned().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        Ok(engine::manifest_encode(&(
+            discriminator,
+            payload,
+            required_signers,
+        ))?)
+    }
+
+    pub fn intents(&self) -> Vec<SigningSessionIntent> {
+        self.intents
+            .iter()
+            .map(|intent| {
+                let address = |address: &engine::GlobalAddress| {
+                    Arc::new(Address::from_node_id(*address, intent.network_id))
+                };
+                let public_key = |public_key: &engine::PublicKey| {
+                    PublicKey::from(*public_key)
+                };
+                SigningSessionIntent {
+                    intent_hash: Arc::new(Hash(intent.intent_hash)),
+                    required_entities: intent
+                        .required_entities
+                        .iter()
+                        .map(address)
+                        .collect(),
+                    required_signers: intent
+                        .required_signers
+                        .iter()
+                        .map(public_key)
+                        .collect(),
+                    signers: intent.signers.iter().map(public_key).collect(),
+                    missing_entities: intent
+                        .missing_entities()
+                        .map(address)
+                        .collect(),
+                    missing_signers: intent
+                        .missing_signers()
+                        .map(public_key)
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn require_signers(
+        self: Arc<Self>,
+        intent_hash: Arc<Hash>,
+        signers: Vec<PublicKey>,
+    ) -> Result<Arc<Self>> {
+        self.with_session(|session| {
+            let index = session.intent_index(&intent_hash.0)?;
+            for signer in signers {
+                session.intents[index]
+                    .required_signers
+                    .insert(engine::PublicKey::try_from(signer)?);
+            }
+            Ok(())
+        })
+    }
+
+    pub fn add_signature(
+        self: Arc<Self>,
+        intent_hash: Arc<Hash>,
+        signature: SignatureWithPublicKeyV1,
+    ) -> Result<Arc<Self>> {
+        self.with_session(|session| {
+            session.add_signature_to_intent(&intent_hash.0, signature)
+        })
+    }
+
+    pub fn add_signatures(
+        self: Arc<Self>,
+        signatures: Vec<SigningSessionSignature>,
+    ) -> Result<Arc<Self>> {
+        self.with_session(|session| {
+            signatures.into_iter().try_for_each(
+                |SigningSessionSignature {
+                     intent_hash,
+                     signature,
+                 }| {
+                    session.add_signature_to_intent(&intent_hash.0, signature)
+                },
+            )
+        })
+    }
+
+    pub fn sign_with_private_key(
+        self: Arc<Self>,
+        private_key: Arc<PrivateKey>,
+    ) -> Result<Arc<Self>> {
+        self.with_session(|session| session.sign(private_key.as_ref()))
+    }
+
+    pub fn sign_with_signer(
+        self: Arc<Self>,
+        signer: Box<dyn Signer>,
+    ) -> Result<Arc<Self>> {
+        self.with_session(|session| session.sign(signer.as_ref()))
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.intents.iter().all(|intent| {
+            intent.missing_entities().next().is_none()
+                && intent.missing_signers().next().is_none()
+        })
+    }
+
+    pub fn transaction_v2_signature_step(
+        &self,
+    ) -> Result<Arc<TransactionV2BuilderSignatureStep>> {
+        let SigningSessionTransaction::TransactionIntent(
+            signed_transaction_intent,
+        ) = &self.transaction
+        else {
+            return Err(SigningSessionError::NotATransactionIntent.into());
+        };
+        self.ensure_complete()?;
+        Ok(Arc::new(TransactionV2BuilderSignatureStep {
+            intent_hash: self.intents[0].intent_hash,
+            signed_transaction_intent: signed_transaction_intent.clone(),
+        }))
+    }
+
+    pub fn signed_partial_transaction(
+        &self,
+    ) -> Result<Arc<SignedPartialTransactionV2>> {
+        let SigningSessionTransaction::PartialTransaction(
+            signed_partial_transaction,
+        ) = &self.transaction
+        else {
+            return Err(SigningSessionError::NotAPartialTransaction.into());
+        };
+        self.ensure_complete()?;
+        Ok(Arc::new(signed_partial_transaction.clone()))
+    }
+}
+
+impl SigningSession {
+    fn new(transaction: SigningSessionTransaction) -> Result<Self> {
+        let (root_intent_hash, root_intent_core, non_root_subintents) =
+            match &transaction {
+                SigningSessionTransaction::TransactionIntent(signed_intent) => {
+                    let transaction_intent = &signed_intent.transaction_intent;
+                    (
+                        signed_intent.intent_hash()?.0,
+                        &transaction_intent.root_intent_core,
+                        &transaction_intent.non_root_subintents,
+                    )
+                }
+                SigningSessionTransaction::PartialTransaction(
+                    signed_partial_transaction,
+                ) => {
+                    let partial_transaction =
+                        &signed_partial_transaction.partial_transaction;
+                    (
+                        signed_partial_transaction.root_subintent_hash()?.0,
+                        &partial_transaction.root_subintent.intent_core,
+                        &partial_transaction.non_root_subintents,
+                    )
+                }
+            };
+        if transaction.non_root_subintent_signatures().len()
+            != non_root_subintents.len()
+        {
+            return Err(
+                SigningSessionError::SubintentSignaturesCountMismatch.into()
+            );
+        }
+
+        let root_intent = (
+            root_intent_hash,
+            root_intent_core,
+            matches!(
+                transaction,
+                SigningSessionTransaction::TransactionIntent(..)
+            ),
+        );
+        let non_root_subintents = non_root_subintents.iter().map(|subintent| {
+            subintent
+                .subintent_hash()
+                .map(|hash| (hash.0, &subintent.intent_core, false))
+        });
+        let intents = std::iter::once(Ok(root_intent))
+            .chain(non_root_subintents)
+            .enumerate()
+            .map(|(index, intent)| {
+                let (intent_hash, intent_core, is_transaction_intent) = intent?;
+                let signers = transaction
+                    .signatures(index)
+                    .iter()
+                    .map(|signature| verify(&intent_hash, signature))
+                    .collect::<Result<_>>()?;
+                Ok(SigningSessionIntentState {
+                    intent_hash,
+                    network_id: intent_core.header.network_id,
+                    required_entities: entities_requiring_auth(
+                        intent_core,
+                        is_transaction_intent,
+                    )?,
+                    required_signers: Default::default(),
+                    signers,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            transaction,
+            intents,
+        })
+    }
+
+    fn intent_index(&self, intent_hash: &engine::Hash) -> Result<usize> {
+        self.intents
+            .iter()
+            .position(|intent| intent.intent_hash == *intent_hash)
+            .ok_or(SigningSessionError::IntentNotInSession.into())
+    }
+
+    fn add_signature_to_intent(
+        &mut self,
+        intent_hash: &engine::Hash,
+        signature: SignatureWithPublicKeyV1,
+    ) -> Result<()> {
+        let index = self.intent_index(intent_hash)?;
+        let signer = verify(intent_hash, &signature)?;
+
+        let intent = &mut self.intents[index];
+        if !intent.is_required_signer(&signer) {
+            return Err(SigningSessionError::SignerNotRequired.into());
+        }
+        if intent.signers.insert(signer) {
+            self.transaction.signatures_mut(index).push(signature);
+        }
+        Ok(())
+    }
+
+    /// Signs all of the intents that require the signer and that haven't yet
+    /// been signed by it.
+    fn sign(&mut self, signer: &dyn Signer) -> Result<()> {
+        let public_key = engine::PublicKey::try_from(signer.public_key())?;
+        for index in 0..self.intents.len() {
+            let intent = &self.intents[index];
+            if !intent.is_required_signer(&public_key)
+                || intent.signers.contains(&public_key)
+            {
+                continue;
+            }
+            let intent_hash = intent.intent_hash;
+            let signature = signer
+                .sign_to_signature_with_public_key(Arc::new(Hash(intent_hash)));
+            self.add_signature_to_intent(&intent_hash, signature)?;
+        }
+        Ok(())
+    }
+
+    fn ensure_complete(&self) -> Result<()> {
+        if self.is_complete() {
+            Ok(())
+        } else {
+            Err(SigningSessionError::SessionIsIncomplete.into())
+        }
+    }
+
+    fn with_session(
+        self: Arc<Self>,
+        callback: impl FnOnce(&mut Self) -> Result<()>,
+    ) -> Result<Arc<Self>> {
+        let mut this = Arc::try_unwrap(self).unwrap_or_else(|x| (*x).clone());
+        callback(&mut this)?;
+        Ok(Arc::new(this))
+    }
+}
+
+impl SigningSessionTransaction {
+    fn non_root_subintent_signatures(
+        &self,
+    ) -> &Vec<Vec<SignatureWithPublicKeyV1>> {
+        match self {
+            Self::TransactionIntent(signed_intent) => {
+                &signed_intent.non_root_subintent_signatures
+            }
+            Self::PartialTransaction(signed_partial_transaction) => {
+                &signed_partial_transaction.non_root_subintent_signatures
+            }
+        }
+    }
+
+    fn signatures(&self, index: usize) -> &Vec<SignatureWithPublicKeyV1> {
+        match (self, index) {
+            (Self::TransactionIntent(signed_intent), 0) => {
+                &signed_intent.transaction_intent_signatures
+            }
+            (Self::PartialTransaction(signed_partial_transaction), 0) => {
+                &signed_partial_transaction.root_subintent_signatures
+            }
+            (_, _) => &self.non_root_subintent_signatures()[index - 1],
+        }
+    }
+
+    fn signatures_mut(
+        &mut self,
+        index: usize,
+    ) -> &mut Vec<SignatureWithPublicKeyV1> {
+        match (self, index) {
+            (Self::TransactionIntent(signed_intent), 0) => {
+                &mut signed_intent.transaction_intent_signatures
+            }
+            (Self::TransactionIntent(signed_intent), _) => {
+                &mut signed_intent.non_root_subintent_signatures[index - 1]
+            }
+            (Self::PartialTransaction(signed_partial_transaction), 0) => {
+                &mut signed_partial_transaction.root_subintent_signatures
+            }
+            (Self::PartialTransaction(signed_partial_transaction), _) => {
+                &mut signed_partial_transaction.non_root_subintent_signatures
+                    [index - 1]
+            }
+        }
+    }
+}
+
+impl SigningSessionIntentState {
+    /// Checks if the public key is one of the required signers or the key that
+    /// one of the required entities is derived from.
+    fn is_required_signer(&self, public_key: &engine::PublicKey) -> bool {
+        self.required_signers.contains(public_key)
+            || derived_entities(public_key)
+                .iter()
+                .any(|entity| self.required_entities.contains(entity))
+    }
+
+    fn missing_entities(
+        &self,
+    ) -> impl Iterator<Item = &engine::GlobalAddress> + '_ {
+        self.required_entities.iter().filter(|entity| {
+            !self
+                .signers
+                .iter()
+                .any(|signer| derived_entities(signer).contains(*entity))
+        })
+    }
+
+    fn missing_signers(&self) -> impl Iterator<Item = &engine::PublicKey> + '_ {
+        self.required_signers
+            .iter()
+            .filter(|signer| !self.signers.contains(*signer))
+    }
+}
+
+/// Verifies the signature over the intent hash and returns its signer.
+fn verify(
+    intent_hash: &engine::Hash,
+    signature: &SignatureWithPublicKeyV1,
+) -> Result<engine::PublicKey> {
+    engine::SignatureWithPublicKeyV1::try_from(signature.clone())
+        .ok()
+        .and_then(|signature| {
+            engine::verify_and_recover(intent_hash, &signature)
+        })
+        .ok_or(SigningSessionError::InvalidSignature.into())
+}
+
+/// The preallocated account and identity addresses derived from the public key.
+fn derived_entities(
+    public_key: &engine::PublicKey,
+) -> [engine::GlobalAddress; 2] {
+    [
+        engine::ComponentAddress::preallocated_account_from_public_key(
+            public_key,
+        )
+        .into(),
+        engine::ComponentAddress::preallocated_identity_from_public_key(
+            public_key,
+        )
+        .into(),
+    ]
+}
+
+/// The preallocated accounts and identities that the manifest of the intent
+/// requires auth from. The addresses of allocated entities don't tell who their
+/// signers are and so they're not included.
+fn entities_requiring_auth(
+    intent_core: &IntentCoreV2,
+    is_transaction_intent: bool,
+) -> Result<engine::IndexSet<engine::GlobalAddress>> {
+    let intent_core = engine::IntentCoreV2::try_from(intent_core.clone())?;
+    let static_analysis = if is_transaction_intent {
+        toolkit::functions::transaction_v2::transaction_manifest::statically_analyze(
+            &engine::TransactionManifestV2::from_intent_core(&intent_core),
+        )?
+    } else {
+        toolkit::functions::transaction_v2::subintent_manifest::statically_analyze(
+            &engine::SubintentManifestV2::from_intent_core(&intent_core),
+        )?
+    };
+    let entities = static_analysis.entities_requiring_auth_summary;
+    Ok(entities
+        .accounts
+        .into_iter()
+        .chain(entities.identities)
+        .filter_map(|address| match address {
+            engine::ManifestGlobalAddress::Static(address)
+                if address.as_node_id().is_global_preallocated() =>
+            {
+                Some(address)
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SigningSessionIntent {
+    pub intent_hash: Arc<Hash>,
+    /// The preallocated accounts and identities that the manifest of the
+    /// intent requires auth from.
+    pub required_entities: Vec<Arc<Address>>,
+    /// The signers required on the intent through
+    /// [`SigningSession::require_signers`].
+    pub required_signers: Vec<PublicKey>,
+    pub signers: Vec<PublicKey>,
+    pub missing_entities: Vec<Arc<Address>>,
+    pub missing_signers: Vec<PublicKey>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SigningSessionSignature {
+    pub intent_hash: Arc<Hash>,
+    pub signature: SignatureWithPublicKeyV1,
+}
+
+#[derive(Clone, Debug)]
+pub enum SigningSessionError {
+    IntentNotInSession,
+    InvalidSignature,
+    SignerNotRequired,
+    SessionIsIncomplete,
+    NotATransactionIntent,
+    NotAPartialTransaction,
+    InvalidSessionBytes,
+    SubintentSignaturesCountMismatch,
+}
@@ -172,8 +172,8 @@ impl TransactionV2Builder {
 
 #[derive(Clone, Debug, Object)]
 pub struct TransactionV2BuilderSignatureStep {
-    intent_hash: engine::Hash,
-    signed_transaction_intent: SignedTransactionIntentV2,
+    pub(crate) intent_hash: engine::Hash,
+    pub(crate) signed_transaction_intent: SignedTransactionIntentV2,
 }
 
 #[uniffi::export]
@@ -239,6 +239,22 @@ impl FromNativeWithNetworkContext for ResourceOrNonFungible {
     }
 }
 
+impl FromNative for OwnerRole {
+    type Native = engine::OwnerRole;
+
+    fn from_native(native: Self::Native) -> Self {
+        match native {
+            Self::Native::None => Self::None,
+            Self::Native::Fixed(value) => Self::Fixed {
+                value: Arc::new(AccessRule(value)),
+            },
+            Self::Native::Updatable(value) => Self::Updatable {
+                value: Arc::new(AccessRule(value)),
+            },
+        }
+    }
+}
+
 impl ToNative for OwnerRole {
     type Native = engine::OwnerRole;
 
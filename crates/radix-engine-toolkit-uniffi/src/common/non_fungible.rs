@@ -276,3 +276,37 @@ pub fn non_fungible_local_id_from_str(
         .map_err(Into::into)
         .map(Into::into)
 }
+
+#[derive(Clone, Debug, Enum)]
+pub enum NonFungibleIdType {
+    String,
+    Integer,
+    Bytes,
+    Ruid,
+}
+
+impl FromNative for NonFungibleIdType {
+    type Native = engine::NonFungibleIdType;
+
+    fn from_native(native: Self::Native) -> Self {
+        match native {
+            Self::Native::String => Self::String,
+            Self::Native::Integer => Self::Integer,
+            Self::Native::Bytes => Self::Bytes,
+            Self::Native::RUID => Self::Ruid,
+        }
+    }
+}
+
+impl ToNative for NonFungibleIdType {
+    type Native = engine::NonFungibleIdType;
+
+    fn to_native(self) -> Result<Self::Native> {
+        Ok(match self {
+            Self::String => Self::Native::String,
+            Self::Integer => Self::Native::Integer,
+            Self::Bytes => Self::Native::Bytes,
+            Self::Ruid => Self::Native::RUID,
+        })
+    }
+}
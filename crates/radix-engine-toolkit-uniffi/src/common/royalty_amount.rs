@@ -47,3 +47,27 @@ impl From<RoyaltyAmount> for engine::RoyaltyAmount {
         }
     }
 }
+
+#[derive(Clone, Debug, Enum)]
+pub enum PackageRoyaltyConfig {
+    Disabled,
+    Enabled {
+        value: HashMap<String, RoyaltyAmount>,
+    },
+}
+
+impl From<engine::PackageRoyaltyConfig> for PackageRoyaltyConfig {
+    fn from(value: engine::PackageRoyaltyConfig) -> Self {
+        match value {
+            engine::PackageRoyaltyConfig::Disabled => Self::Disabled,
+            engine::PackageRoyaltyConfig::Enabled(royalties) => Self::Enabled {
+                value: royalties
+                    .into_iter()
+                    .map(|(function_name, amount)| {
+                        (function_name, amount.into())
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
@@ -22,3 +22,12 @@ pub enum Curve {
     Secp256k1,
     Ed25519,
 }
+
+impl From<Curve> for engine::CurveType {
+    fn from(value: Curve) -> Self {
+        match value {
+            Curve::Secp256k1 => Self::Secp256k1,
+            Curve::Ed25519 => Self::Ed25519,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[uniffi::export]
+pub fn mnemonic_to_seed(
+    mnemonic: String,
+    passphrase: String,
+) -> Result<Vec<u8>> {
+    toolkit::functions::key_derivation::mnemonic_to_seed(&mnemonic, &passphrase)
+        .map(|seed| seed.to_vec())
+        .map_err(Into::into)
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum DerivationPath {
+    Cap26 { path: Cap26Path },
+    Custom { path: String },
+}
+
+impl ToNative for DerivationPath {
+    type Native = toolkit::functions::key_derivation::DerivationPath;
+
+    fn to_native(self) -> Result<Self::Native> {
+        match self {
+            Self::Cap26 { path } => {
+                path.into_native().try_into().map_err(Into::into)
+            }
+            Self::Custom { path } => path.parse().map_err(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct Cap26Path {
+    pub network_id: u8,
+    pub entity_kind: Cap26EntityKind,
+    pub key_kind: Cap26KeyKind,
+    pub index: u32,
+}
+
+impl Cap26Path {
+    fn into_native(self) -> toolkit::functions::key_derivation::Cap26Path {
+        toolkit::functions::key_derivation::Cap26Path {
+            network_id: self.network_id,
+            entity_kind: self.entity_kind.into(),
+            key_kind: self.key_kind.into(),
+            index: self.index,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum Cap26EntityKind {
+    Account,
+    Identity,
+}
+
+impl From<Cap26EntityKind>
+    for toolkit::functions::key_derivation::Cap26EntityKind
+{
+    fn from(value: Cap26EntityKind) -> Self {
+        match value {
+            Cap26EntityKind::Account => Self::Account,
+            Cap26EntityKind::Identity => Self::Identity,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum Cap26KeyKind {
+    TransactionSigning,
+    AuthenticationSigning,
+    MessageEncryption,
+}
+
+impl From<Cap26KeyKind> for toolkit::functions::key_derivation::Cap26KeyKind {
+    fn from(value: Cap26KeyKind) -> Self {
+        match value {
+            Cap26KeyKind::TransactionSigning => Self::TransactionSigning,
+            Cap26KeyKind::AuthenticationSigning => Self::AuthenticationSigning,
+            Cap26KeyKind::MessageEncryption => Self::MessageEncryption,
+        }
+    }
+}
@@ -17,6 +17,7 @@
 
 pub mod curve;
 pub mod hash;
+pub mod key_derivation;
 pub mod private_key;
 pub mod public_key;
 pub mod public_key_hash;
@@ -57,6 +57,32 @@ impl PrivateKey {
             .map(|value| Arc::new(Self(engine::PrivateKey::Ed25519(value))))
     }
 
+    #[uniffi::constructor]
+    pub fn derive_from_seed(
+        seed: Vec<u8>,
+        curve: Curve,
+        derivation_path: DerivationPath,
+    ) -> Result<Arc<Self>> {
+        toolkit::functions::key_derivation::derive_private_key(
+            &seed,
+            curve.into(),
+            &derivation_path.to_native()?,
+        )
+        .map(|value| Arc::new(Self(value)))
+        .map_err(Into::into)
+    }
+
+    #[uniffi::constructor]
+    pub fn derive_from_mnemonic(
+        mnemonic: String,
+        passphrase: String,
+        curve: Curve,
+        derivation_path: DerivationPath,
+    ) -> Result<Arc<Self>> {
+        let seed = mnemonic_to_seed(mnemonic, passphrase)?;
+        Self::derive_from_seed(seed, curve, derivation_path)
+    }
+
     pub fn raw(&self) -> Vec<u8> {
         match &self.0 {
             engine::PrivateKey::Ed25519(private_key) => private_key.to_bytes(),
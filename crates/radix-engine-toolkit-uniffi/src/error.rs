@@ -74,8 +74,8 @@ pub enum RadixEngineToolkitError {
     #[error("Failed to SBOR decode some payload: {error}")]
     DecodeError { error: String },
 
-    #[error("Static validation of transaction part has failed: {error}")]
-    TransactionValidationFailed { error: String },
+    #[error("Static validation of transaction part has failed: {error:?}")]
+    TransactionValidationFailed { error: TransactionValidationError },
 
     #[error("Execution analysis failed: {error}")]
     ExecutionModuleError { error: String },
@@ -123,11 +123,52 @@ pub enum RadixEngineToolkitError {
     )]
     NotAllBuilderItemsWereSpecified,
 
-    #[error("Validation of manifest failed: {error}")]
-    ManifestValidationError { error: String },
+    #[error("Validation of manifest failed at instruction {instruction_index:?}: {error:?}")]
+    ManifestValidationError {
+        instruction_index: Option<u64>,
+        error: ManifestValidationError,
+    },
+
+    #[error("Manifest analysis failed: {error:?}")]
+    ManifestAnalysisError { error: ManifestAnalysisError },
+
+    #[error(
+        "The manifest has unconsumed buckets {buckets:?} or proofs {proofs:?}"
+    )]
+    UnconsumedBucketsOrProofs {
+        buckets: Vec<String>,
+        proofs: Vec<String>,
+    },
+
+    #[error("Failed to encrypt the message: {error}")]
+    MessageEncryptionError { error: String },
+
+    #[error("Failed to decrypt the message: {error}")]
+    MessageDecryptionError { error: String },
+
+    #[error("Failed to estimate the fee of the transaction: {error}")]
+    FeeEstimationError { error: String },
+
+    #[error("The signing session operation failed: {error}")]
+    SigningSessionError { error: String },
 
-    #[error("Manifest analysis failed: {error}")]
-    ManifestAnalysisError { error: String },
+    #[error("Failed to derive the key: {error}")]
+    KeyDerivationError { error: String },
+
+    #[error("The intent header is invalid: {error:?}")]
+    InvalidIntentHeader {
+        subintent_index: Option<u64>,
+        error: IntentHeaderValidationError,
+    },
+
+    #[error("Failed to decode the event: {error}")]
+    EventDecodingError { error: String },
+
+    #[error("Failed to verify the signatures: {error}")]
+    SignatureVerificationError { error: String },
+
+    #[error("The non-fungible local id {id} was specified more than once")]
+    DuplicateNonFungibleLocalId { id: String },
 }
 
 macro_rules! dbg_str {
@@ -184,18 +225,100 @@ impl_dbg_str_from! { engine::PrepareError, PrepareError }
 impl_dbg_str_from! { engine::EncodeError, EncodeError }
 impl_dbg_str_from! { engine::DecodeError, DecodeError }
 impl_dbg_str_from! { engine::TransactionHashBech32DecodeError, FailedToDecodeTransactionHash }
-impl_dbg_str_from! { engine::TransactionValidationError, TransactionValidationFailed }
-impl_dbg_str_from! { toolkit::functions::transaction_v1::instructions::InstructionValidationError, TransactionValidationFailed }
 impl_dbg_str_from! { toolkit::functions::manifest_sbor::ManifestSborError, ManifestSborError }
 impl_dbg_str_from! { toolkit::functions::scrypto_sbor::ScryptoSborError, ScryptoSborError }
 impl_dbg_str_from! { engine::TypedNativeEventError, TypedNativeEventError }
 impl_dbg_str_from! { toolkit::InvalidEntityTypeIdError, InvalidEntityTypeIdError }
-impl_dbg_str_from! { engine::ManifestValidationError, ManifestValidationError }
 impl_dbg_str_from! { engine::StaticResourceMovementsError, StaticAnalysisFailed }
-impl_dbg_str_from! { toolkit::ManifestAnalysisError, ManifestAnalysisError }
+impl_dbg_str_from! { toolkit::functions::message::MessageEncryptionError, MessageEncryptionError }
+impl_dbg_str_from! { toolkit::functions::message::MessageDecryptionError, MessageDecryptionError }
+impl_dbg_str_from! { toolkit::functions::fee_estimation::FeeEstimationError, FeeEstimationError }
+impl_dbg_str_from! { SigningSessionError, SigningSessionError }
+impl_dbg_str_from! { toolkit::functions::key_derivation::KeyDerivationError, KeyDerivationError }
+impl_dbg_str_from! { toolkit::functions::events::EventDecodingError, EventDecodingError }
+impl_dbg_str_from! { toolkit::SignatureVerificationError, SignatureVerificationError }
+
+impl From<engine::TransactionValidationError> for RadixEngineToolkitError {
+    fn from(value: engine::TransactionValidationError) -> Self {
+        Self::TransactionValidationFailed {
+            error: value.into(),
+        }
+    }
+}
+
+impl From<toolkit::functions::transaction_v1::instructions::InstructionValidationError>
+    for RadixEngineToolkitError
+{
+    fn from(
+        value: toolkit::functions::transaction_v1::instructions::InstructionValidationError,
+    ) -> Self {
+        match value {
+            toolkit::functions::transaction_v1::instructions::InstructionValidationError::LocatedManifestValidationError(
+                error,
+            ) => error.into(),
+            error => Self::TransactionValidationFailed {
+                error: error.into(),
+            },
+        }
+    }
+}
+
+impl From<engine::ManifestValidationError> for RadixEngineToolkitError {
+    fn from(value: engine::ManifestValidationError) -> Self {
+        Self::ManifestValidationError {
+            instruction_index: None,
+            error: value.into(),
+        }
+    }
+}
+
+impl From<toolkit::LocatedManifestValidationError> for RadixEngineToolkitError {
+    fn from(
+        toolkit::LocatedManifestValidationError {
+            instruction_index,
+            error,
+        }: toolkit::LocatedManifestValidationError,
+    ) -> Self {
+        Self::ManifestValidationError {
+            instruction_index: instruction_index
+                .map(|instruction_index| *instruction_index.value() as u64),
+            error: error.into(),
+        }
+    }
+}
+
+impl From<toolkit::ManifestAnalysisError> for RadixEngineToolkitError {
+    fn from(value: toolkit::ManifestAnalysisError) -> Self {
+        Self::ManifestAnalysisError {
+            error: value.into(),
+        }
+    }
+}
 
 impl From<NameRecordError> for RadixEngineToolkitError {
     fn from(value: NameRecordError) -> Self {
         Self::ManifestBuilderNameRecordError { error: value }
     }
 }
+
+impl From<toolkit::functions::transaction_v2::intent_header::IntentHeaderValidationError>
+    for RadixEngineToolkitError
+{
+    fn from(
+        value: toolkit::functions::transaction_v2::intent_header::IntentHeaderValidationError,
+    ) -> Self {
+        match value {
+            toolkit::functions::transaction_v2::intent_header::IntentHeaderValidationError::InvalidSubintentHeader {
+                subintent_index,
+                error,
+            } => Self::InvalidIntentHeader {
+                subintent_index: Some(subintent_index as u64),
+                error: (*error).into(),
+            },
+            error => Self::InvalidIntentHeader {
+                subintent_index: None,
+                error: error.into(),
+            },
+        }
+    }
+}
@@ -33,6 +33,52 @@ pub fn scrypto_sbor_decode_to_native_event(
     .map_err(Into::into)
 }
 
+/// Decodes an event emitted by any blueprint of a package through the schema
+/// of the event in the package definition, given as its Manifest SBOR encoded
+/// bytes. The name of the blueprint of the emitter is needed to decode events
+/// emitted by methods when more than one blueprint defines the event.
+#[uniffi::export]
+pub fn scrypto_sbor_decode_event(
+    event_type_identifier: EventTypeIdentifier,
+    event_data: Vec<u8>,
+    package_address: Arc<Address>,
+    package_definition: Vec<u8>,
+    emitter_blueprint_name: Option<String>,
+    representation: SerializationMode,
+    network_id: u8,
+) -> Result<DecodedEvent> {
+    let network_definition =
+        engine::NetworkDefinition::from_network_id(network_id);
+    let bech32_encoder = engine::AddressBech32Encoder::new(&network_definition);
+    let package_address = engine::PackageAddress::try_from(*package_address)?;
+    let package_definition = engine::manifest_decode::<
+        engine::PackageDefinition,
+    >(&package_definition)?;
+
+    toolkit::functions::events::scrypto_sbor_decode_event(
+        &event_type_identifier.try_into()?,
+        &event_data,
+        &package_address,
+        &package_definition,
+        emitter_blueprint_name.as_deref(),
+        representation.into(),
+        &bech32_encoder,
+    )
+    .map(|decoded_event| DecodedEvent {
+        blueprint_name: decoded_event.blueprint_name,
+        event_name: decoded_event.event_name,
+        event: decoded_event.event,
+    })
+    .map_err(Into::into)
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct DecodedEvent {
+    pub blueprint_name: String,
+    pub event_name: String,
+    pub event: String,
+}
+
 #[derive(Clone, Debug, Record)]
 pub struct EventTypeIdentifier {
     pub emitter: Emitter,
@@ -80,7 +80,8 @@ pub mod toolkit {
 pub use engine::{
     FromPublicKey as _, HasNotarizedTransactionHash as _,
     HasSignedTransactionIntentHash as _, HasSubintentHash as _,
-    HasTransactionIntentHash as _, Signer as _, TransactionPayload as _,
+    HasTransactionIntentHash as _, ManifestInstructionSet as _, Signer as _,
+    TransactionPayload as _,
 };
 pub use toolkit::extensions::*;
 
@@ -62,6 +62,7 @@ pub mod prelude {
     pub use crate::builder::manifest_builder::utils::*;
     pub use crate::builder::manifest_builder::value::*;
     pub use crate::builder::signed_partial_transaction_v2_builder::*;
+    pub use crate::builder::signing_session::*;
     pub use crate::builder::transaction_v1_builder::*;
     pub use crate::builder::transaction_v2_builder::*;
 
@@ -75,6 +76,7 @@ pub mod prelude {
     /* Cryptography */
     pub use crate::cryptography::curve::*;
     pub use crate::cryptography::hash::*;
+    pub use crate::cryptography::key_derivation::*;
     pub use crate::cryptography::private_key::*;
     pub use crate::cryptography::public_key::*;
     pub use crate::cryptography::public_key_hash::*;
@@ -92,8 +94,13 @@ pub mod prelude {
     pub use crate::manifest::value::*;
 
     /* Transaction */
+    pub use crate::transaction_common::fee_estimation::*;
     pub use crate::transaction_common::hash::*;
     pub use crate::transaction_common::manifest_analysis::*;
+    pub use crate::transaction_common::manifest_classifier::*;
+    pub use crate::transaction_common::signature_verification::*;
+    pub use crate::transaction_common::transaction_summary::*;
+    pub use crate::transaction_common::validation_error::*;
 
     pub use crate::transaction_v1::header::*;
     pub use crate::transaction_v1::instruction::*;
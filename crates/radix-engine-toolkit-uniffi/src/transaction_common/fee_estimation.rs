@@ -0,0 +1,89 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Record)]
+pub struct FeeEstimationParameters {
+    pub signer_count: u32,
+    pub subintent_signer_counts: Vec<u32>,
+    pub notary_is_signatory: bool,
+    pub message_size: u32,
+    pub execution_cost_unit_price: Option<Arc<Decimal>>,
+    pub tip_proportion: Arc<Decimal>,
+    pub margin: Arc<Decimal>,
+}
+
+impl ToNative for FeeEstimationParameters {
+    type Native = toolkit::functions::fee_estimation::FeeEstimationParameters;
+
+    fn to_native(self) -> Result<Self::Native> {
+        let default = Self::Native::default();
+        Ok(Self::Native {
+            signer_count: self.signer_count as usize,
+            subintent_signer_counts: self
+                .subintent_signer_counts
+                .into_iter()
+                .map(|count| count as usize)
+                .collect(),
+            notary_is_signatory: self.notary_is_signatory,
+            message_size: self.message_size as usize,
+            execution_cost_unit_price: self
+                .execution_cost_unit_price
+                .map(|price| price.0)
+                .unwrap_or(default.execution_cost_unit_price),
+            tip_proportion: self.tip_proportion.0,
+            margin: self.margin.0,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct FeeEstimate {
+    pub fee_summary: FeeSummary,
+    pub signature_verification_cost: Arc<Decimal>,
+    pub payload_validation_cost: Arc<Decimal>,
+    pub tip_cost: Arc<Decimal>,
+    pub margin: Arc<Decimal>,
+    pub lock_fee: Arc<Decimal>,
+}
+
+impl FromNative for FeeEstimate {
+    type Native = toolkit::functions::fee_estimation::FeeEstimate;
+
+    fn from_native(
+        Self::Native {
+            fee_summary,
+            signature_verification_cost,
+            payload_validation_cost,
+            tip_cost,
+            margin,
+            lock_fee,
+        }: Self::Native,
+    ) -> Self {
+        Self {
+            fee_summary: FeeSummary::from_native(fee_summary),
+            signature_verification_cost: Arc::new(Decimal(
+                signature_verification_cost,
+            )),
+            payload_validation_cost: Arc::new(Decimal(payload_validation_cost)),
+            tip_cost: Arc::new(Decimal(tip_cost)),
+            margin: Arc::new(Decimal(margin)),
+            lock_fee: Arc::new(Decimal(lock_fee)),
+        }
+    }
+}
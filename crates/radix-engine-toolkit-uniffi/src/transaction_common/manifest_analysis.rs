@@ -27,7 +27,9 @@ pub struct StaticAnalysis {
     pub entities_encountered_summary: EncounteredEntitiesOutput,
     pub entities_requiring_auth_summary: EntitiesRequiringAuthOutput,
     pub reserved_instructions_summary: ReservedInstructionsOutput,
+    pub manifest_warnings_summary: Vec<ManifestWarning>,
     pub manifest_classification: Vec<ManifestClassification>,
+    pub registered_classifications: Vec<RegisteredClassification>,
 }
 
 impl FromNativeWithNetworkContext for StaticAnalysis {
@@ -41,7 +43,9 @@ impl FromNativeWithNetworkContext for StaticAnalysis {
             entities_encountered_summary,
             entities_requiring_auth_summary,
             reserved_instructions_summary,
+            manifest_warnings_summary,
             manifest_classification,
+            registered_classifications,
         }: Self::Native,
         network_id: u8,
     ) -> Self {
@@ -75,12 +79,23 @@ impl FromNativeWithNetworkContext for StaticAnalysis {
                     reserved_instructions_summary,
                     network_id,
                 ),
+            manifest_warnings_summary: manifest_warnings_summary
+                .warnings
+                .into_iter()
+                .filter_map(|warning| {
+                    ManifestWarning::from_native(warning, network_id)
+                })
+                .collect(),
             manifest_classification: manifest_classification
                 .into_iter()
                 .map(|value| {
                     FromNativeWithNetworkContext::from_native(value, network_id)
                 })
                 .collect(),
+            registered_classifications: registered_classifications
+                .iter()
+                .filter_map(RegisteredClassification::from_native)
+                .collect(),
         }
     }
 }
@@ -97,16 +112,23 @@ pub struct DynamicAnalysis {
     pub entities_encountered_summary: EncounteredEntitiesOutput,
     pub entities_requiring_auth_summary: EntitiesRequiringAuthOutput,
     pub reserved_instructions_summary: ReservedInstructionsOutput,
+    pub manifest_warnings_summary: Vec<ManifestWarning>,
     pub fee_locks_summary: FeeLocks,
     pub fee_consumption_summary: FeeSummary,
     pub detailed_manifest_classification: Vec<DetailedManifestClassification>,
+    pub registered_detailed_classifications: Vec<RegisteredClassification>,
+    pub transaction_summary: TransactionSummary,
 }
 
 impl FromNativeWithNetworkContext for DynamicAnalysis {
     type Native = toolkit::DynamicAnalysis;
 
-    fn from_native(
-        Self::Native {
+    fn from_native(native: Self::Native, network_id: u8) -> Self {
+        let transaction_summary = FromNativeWithNetworkContext::from_native(
+            toolkit::TransactionSummary::from_dynamic_analysis(&native),
+            network_id,
+        );
+        let Self::Native {
             account_interactions_summary,
             account_static_resource_movements_summary,
             account_dynamic_resource_movements_summary,
@@ -115,12 +137,12 @@ impl FromNativeWithNetworkContext for DynamicAnalysis {
             entities_encountered_summary,
             entities_requiring_auth_summary,
             reserved_instructions_summary,
+            manifest_warnings_summary,
             fee_locks_summary,
             fee_consumption_summary,
             detailed_manifest_classification,
-        }: Self::Native,
-        network_id: u8,
-    ) -> Self {
+            registered_detailed_classifications,
+        } = native;
         Self {
             account_interactions_summary:
                 FromNativeWithNetworkContext::from_native(
@@ -161,6 +183,13 @@ impl FromNativeWithNetworkContext for DynamicAnalysis {
                     reserved_instructions_summary,
                     network_id,
                 ),
+            manifest_warnings_summary: manifest_warnings_summary
+                .warnings
+                .into_iter()
+                .filter_map(|warning| {
+                    ManifestWarning::from_native(warning, network_id)
+                })
+                .collect(),
             fee_locks_summary: FromNativeWithNetworkContext::from_native(
                 fee_locks_summary,
                 network_id,
@@ -175,10 +204,169 @@ impl FromNativeWithNetworkContext for DynamicAnalysis {
                     FromNativeWithNetworkContext::from_native(value, network_id)
                 })
                 .collect(),
+            registered_detailed_classifications:
+                registered_detailed_classifications
+                    .iter()
+                    .filter_map(RegisteredClassification::from_native_detailed)
+                    .collect(),
+            transaction_summary,
         }
     }
 }
 
+/// A warning about a potentially risky operation performed by a manifest.
+/// Warnings about entities that are referenced through named addresses have no
+/// address to report and are therefore not converted.
+#[derive(Clone, Debug, Enum)]
+pub enum ManifestWarning {
+    WithdrawnResourcesNotDepositedIntoAccounts {
+        accounts_withdrawn_from: Vec<Arc<Address>>,
+    },
+    ResourcesBurnedFromAccount {
+        account: Arc<Address>,
+    },
+    DepositIntoThirdPartyAccount {
+        account: Arc<Address>,
+    },
+    EntitySecurified {
+        entity: Arc<Address>,
+    },
+    OwnerKeysUpdated {
+        entity: Arc<Address>,
+    },
+    OwnerKeysLocked {
+        entity: Arc<Address>,
+    },
+    OwnerRoleUpdated {
+        entity: Arc<Address>,
+    },
+    OwnerRoleLocked {
+        entity: Arc<Address>,
+    },
+    RoleUpdated {
+        entity: Arc<Address>,
+    },
+    RoleLocked {
+        entity: Arc<Address>,
+        role_key: String,
+    },
+    MetadataUpdated {
+        entity: Arc<Address>,
+        key: String,
+    },
+    MetadataLocked {
+        entity: Arc<Address>,
+        key: String,
+    },
+    MetadataRemoved {
+        entity: Arc<Address>,
+        key: String,
+    },
+    DirectVaultMethodInvoked {
+        vault_address: Arc<Address>,
+        method_name: String,
+    },
+}
+
+impl ManifestWarning {
+    pub(crate) fn from_native(
+        native: toolkit::ManifestWarning,
+        network_id: u8,
+    ) -> Option<Self> {
+        let address = |address: engine::ManifestGlobalAddress| {
+            address.into_static().map(|address| {
+                Arc::new(Address::from_node_id(address, network_id))
+            })
+        };
+
+        let warning = match native {
+            toolkit::ManifestWarning::WithdrawnResourcesNotDepositedIntoAccounts {
+                accounts_withdrawn_from,
+            } => Self::WithdrawnResourcesNotDepositedIntoAccounts {
+                accounts_withdrawn_from: accounts_withdrawn_from
+                    .into_iter()
+                    .filter_map(address)
+                    .collect(),
+            },
+            toolkit::ManifestWarning::ResourcesBurnedFromAccount { account } => {
+                Self::ResourcesBurnedFromAccount {
+                    account: address(account)?,
+                }
+            }
+            toolkit::ManifestWarning::DepositIntoThirdPartyAccount {
+                account,
+            } => Self::DepositIntoThirdPartyAccount {
+                account: address(account)?,
+            },
+            toolkit::ManifestWarning::EntitySecurified { entity } => {
+                Self::EntitySecurified {
+                    entity: address(entity)?,
+                }
+            }
+            toolkit::ManifestWarning::OwnerKeysUpdated { entity } => {
+                Self::OwnerKeysUpdated {
+                    entity: address(entity)?,
+                }
+            }
+            toolkit::ManifestWarning::OwnerKeysLocked { entity } => {
+                Self::OwnerKeysLocked {
+                    entity: address(entity)?,
+                }
+            }
+            toolkit::ManifestWarning::OwnerRoleUpdated { entity } => {
+                Self::OwnerRoleUpdated {
+                    entity: address(entity)?,
+                }
+            }
+            toolkit::ManifestWarning::OwnerRoleLocked { entity } => {
+                Self::OwnerRoleLocked {
+                    entity: address(entity)?,
+                }
+            }
+            toolkit::ManifestWarning::RoleUpdated { entity } => {
+                Self::RoleUpdated {
+                    entity: address(entity)?,
+                }
+            }
+            toolkit::ManifestWarning::RoleLocked { entity, role_key } => {
+                Self::RoleLocked {
+                    entity: address(entity)?,
+                    role_key,
+                }
+            }
+            toolkit::ManifestWarning::MetadataUpdated { entity, key } => {
+                Self::MetadataUpdated {
+                    entity: address(entity)?,
+                    key,
+                }
+            }
+            toolkit::ManifestWarning::MetadataLocked { entity, key } => {
+                Self::MetadataLocked {
+                    entity: address(entity)?,
+                    key,
+                }
+            }
+            toolkit::ManifestWarning::MetadataRemoved { entity, key } => {
+                Self::MetadataRemoved {
+                    entity: address(entity)?,
+                    key,
+                }
+            }
+            toolkit::ManifestWarning::DirectVaultMethodInvoked {
+                vault_address,
+                method_name,
+            } => Self::DirectVaultMethodInvoked {
+                vault_address: Arc::new(Address::from_node_id(
+                    vault_address,
+                    network_id,
+                )),
+                method_name,
+            },
+        };
+        Some(warning)
+    }
+}
+
 #[derive(Clone, Debug, Record)]
 pub struct AccountInteractionsOutput {
     pub accounts_securified: Vec<Arc<Address>>,
@@ -715,6 +903,13 @@ pub enum ManifestClassification {
     PoolContribution,
     PoolRedemption,
     AccountDepositSettingsUpdate,
+    Swap,
+    AccountLockerClaim,
+    AccountLockerStore,
+    SecurityShieldUpdate,
+    ResourceCreation,
+    PackagePublish,
+    ValidatorManagement,
 }
 
 impl FromNative for ManifestClassification {
@@ -733,6 +928,13 @@ impl FromNative for ManifestClassification {
             Self::Native::AccountDepositSettingsUpdate => {
                 Self::AccountDepositSettingsUpdate
             }
+            Self::Native::Swap => Self::Swap,
+            Self::Native::AccountLockerClaim => Self::AccountLockerClaim,
+            Self::Native::AccountLockerStore => Self::AccountLockerStore,
+            Self::Native::SecurityShieldUpdate => Self::SecurityShieldUpdate,
+            Self::Native::ResourceCreation => Self::ResourceCreation,
+            Self::Native::PackagePublish => Self::PackagePublish,
+            Self::Native::ValidatorManagement => Self::ValidatorManagement,
         }
     }
 }
@@ -748,6 +950,13 @@ pub enum DetailedManifestClassification {
     PoolContribution { value: PoolContributionOutput },
     PoolRedemption { value: PoolRedemptionOutput },
     AccountDepositSettingsUpdate { value: AccountSettingsUpdateOutput },
+    Swap { value: SwapOutput },
+    AccountLockerClaim { value: AccountLockerClaimOutput },
+    AccountLockerStore { value: AccountLockerStoreOutput },
+    SecurityShieldUpdate { value: SecurityShieldUpdateOutput },
+    ResourceCreation { value: ResourceCreationOutput },
+    PackagePublish { value: PackagePublishOutput },
+    ValidatorManagement { value: ValidatorManagementOutput },
 }
 
 impl FromNativeWithNetworkContext for DetailedManifestClassification {
@@ -796,6 +1005,49 @@ impl FromNativeWithNetworkContext for DetailedManifestClassification {
                     ),
                 }
             }
+            Self::Native::Swap(output) => Self::Swap {
+                value: FromNativeWithNetworkContext::from_native(
+                    output, network_id,
+                ),
+            },
+            Self::Native::AccountLockerClaim(output) => {
+                Self::AccountLockerClaim {
+                    value: FromNativeWithNetworkContext::from_native(
+                        output, network_id,
+                    ),
+                }
+            }
+            Self::Native::AccountLockerStore(output) => {
+                Self::AccountLockerStore {
+                    value: FromNativeWithNetworkContext::from_native(
+                        output, network_id,
+                    ),
+                }
+            }
+            Self::Native::SecurityShieldUpdate(output) => {
+                Self::SecurityShieldUpdate {
+                    value: FromNativeWithNetworkContext::from_native(
+                        output, network_id,
+                    ),
+                }
+            }
+            Self::Native::ResourceCreation(output) => Self::ResourceCreation {
+                value: FromNativeWithNetworkContext::from_native(
+                    output, network_id,
+                ),
+            },
+            Self::Native::PackagePublish(output) => Self::PackagePublish {
+                value: FromNativeWithNetworkContext::from_native(
+                    output, network_id,
+                ),
+            },
+            Self::Native::ValidatorManagement(output) => {
+                Self::ValidatorManagement {
+                    value: FromNativeWithNetworkContext::from_native(
+                        output, network_id,
+                    ),
+                }
+            }
         }
     }
 }
@@ -1651,6 +1903,691 @@ impl FromNativeWithNetworkContext for PoolRedemptionOperation {
     }
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct SwapOutput {
+    pub swap_operations: Vec<SwapOperation>,
+}
+
+impl FromNativeWithNetworkContext for SwapOutput {
+    type Native = toolkit::SwapOutput;
+
+    fn from_native(
+        Self::Native { swap_operations }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            swap_operations: swap_operations
+                .into_iter()
+                .map(|native| {
+                    FromNativeWithNetworkContext::from_native(
+                        native, network_id,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SwapOperation {
+    pub account_address: Arc<Address>,
+    pub component_address: Arc<Address>,
+    pub input_resources: Vec<InvocationIoItem>,
+    pub output_resources: Vec<InvocationIoItem>,
+}
+
+impl FromNativeWithNetworkContext for SwapOperation {
+    type Native = toolkit::SwapOperation;
+
+    fn from_native(
+        Self::Native {
+            account_address,
+            component_address,
+            input_resources,
+            output_resources,
+        }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            account_address: Arc::new(Address::from_node_id(
+                account_address,
+                network_id,
+            )),
+            component_address: Arc::new(Address::from_node_id(
+                component_address,
+                network_id,
+            )),
+            input_resources: input_resources
+                .into_iter()
+                .map(|item| {
+                    FromNativeWithNetworkContext::from_native(item, network_id)
+                })
+                .collect(),
+            output_resources: output_resources
+                .into_iter()
+                .map(|item| {
+                    FromNativeWithNetworkContext::from_native(item, network_id)
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AccountLockerClaimOutput {
+    pub claim_operations: Vec<AccountLockerClaimOperation>,
+}
+
+impl FromNativeWithNetworkContext for AccountLockerClaimOutput {
+    type Native = toolkit::AccountLockerClaimOutput;
+
+    fn from_native(
+        Self::Native { claim_operations }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            claim_operations: claim_operations
+                .into_iter()
+                .map(|native| {
+                    FromNativeWithNetworkContext::from_native(
+                        native, network_id,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AccountLockerClaimOperation {
+    pub locker_address: Arc<Address>,
+    pub claimant_address: Arc<Address>,
+    pub claimed_resources: ResourceSpecifier,
+}
+
+impl FromNativeWithNetworkContext for AccountLockerClaimOperation {
+    type Native = toolkit::AccountLockerClaimOperation;
+
+    fn from_native(
+        Self::Native {
+            locker_address,
+            claimant_address,
+            claimed_resources,
+        }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            locker_address: Arc::new(Address::from_node_id(
+                locker_address,
+                network_id,
+            )),
+            claimant_address: Arc::new(Address::from_node_id(
+                claimant_address,
+                network_id,
+            )),
+            claimed_resources: FromNativeWithNetworkContext::from_native(
+                claimed_resources,
+                network_id,
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AccountLockerStoreOutput {
+    pub store_operations: Vec<AccountLockerStoreOperation>,
+}
+
+impl FromNativeWithNetworkContext for AccountLockerStoreOutput {
+    type Native = toolkit::AccountLockerStoreOutput;
+
+    fn from_native(
+        Self::Native { store_operations }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            store_operations: store_operations
+                .into_iter()
+                .map(|native| {
+                    FromNativeWithNetworkContext::from_native(
+                        native, network_id,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AccountLockerStoreOperation {
+    pub locker_address: Arc<Address>,
+    pub claimant_address: Arc<Address>,
+    pub stored_resources: ResourceSpecifier,
+    pub try_direct_send: bool,
+}
+
+impl FromNativeWithNetworkContext for AccountLockerStoreOperation {
+    type Native = toolkit::AccountLockerStoreOperation;
+
+    fn from_native(
+        Self::Native {
+            locker_address,
+            claimant_address,
+            stored_resources,
+            try_direct_send,
+        }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            locker_address: Arc::new(Address::from_node_id(
+                locker_address,
+                network_id,
+            )),
+            claimant_address: Arc::new(Address::from_node_id(
+                claimant_address,
+                network_id,
+            )),
+            stored_resources: FromNativeWithNetworkContext::from_native(
+                stored_resources,
+                network_id,
+            ),
+            try_direct_send,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SecurityShieldUpdateOutput {
+    pub operations: Vec<SecurityShieldUpdateOperation>,
+}
+
+impl FromNativeWithNetworkContext for SecurityShieldUpdateOutput {
+    type Native = toolkit::SecurityShieldUpdateOutput;
+
+    fn from_native(
+        Self::Native { operations }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            operations: operations
+                .into_iter()
+                .map(|native| {
+                    SecurityShieldUpdateOperation::from_native(
+                        native, network_id,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum AccessControllerProposer {
+    Primary,
+    Recovery,
+}
+
+impl FromNative for AccessControllerProposer {
+    type Native = toolkit::AccessControllerProposer;
+
+    fn from_native(native: Self::Native) -> Self {
+        match native {
+            Self::Native::Primary => Self::Primary,
+            Self::Native::Recovery => Self::Recovery,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SecurityShieldUpdateOperation {
+    CreateAccessController {
+        rule_set: ManifestValue,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    InitiateRecovery {
+        access_controller_address: ManifestAddress,
+        proposer: AccessControllerProposer,
+        rule_set: ManifestValue,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    QuickConfirmRecovery {
+        access_controller_address: ManifestAddress,
+        proposer: AccessControllerProposer,
+        rule_set: ManifestValue,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    TimedConfirmRecovery {
+        access_controller_address: ManifestAddress,
+        rule_set: ManifestValue,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    LockPrimaryRole {
+        access_controller_address: ManifestAddress,
+    },
+    UnlockPrimaryRole {
+        access_controller_address: ManifestAddress,
+    },
+    InitiateBadgeWithdrawAttempt {
+        access_controller_address: ManifestAddress,
+        proposer: AccessControllerProposer,
+    },
+    QuickConfirmBadgeWithdrawAttempt {
+        access_controller_address: ManifestAddress,
+        proposer: AccessControllerProposer,
+    },
+}
+
+impl SecurityShieldUpdateOperation {
+    /// Converts the native operation into its uniffi counterpart. Access
+    /// controllers that are referenced through named addresses are reported
+    /// through their named address. Rule sets are reported as manifest values
+    /// since they may reference named addresses.
+    pub(crate) fn from_native(
+        native: toolkit::SecurityShieldUpdateOperation,
+        network_id: u8,
+    ) -> Self {
+        let address = |address: engine::ManifestGlobalAddress| {
+            ManifestAddress::from_dynamic_global_address(&address, network_id)
+        };
+        let rule_set_value = |rule_set: engine::ManifestRuleSet| {
+            ManifestValue::from_native(
+                &engine::to_manifest_value_and_unwrap!(&rule_set),
+                network_id,
+            )
+        };
+
+        match native {
+            toolkit::SecurityShieldUpdateOperation::CreateAccessController {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } => Self::CreateAccessController {
+                rule_set: rule_set_value(rule_set),
+                timed_recovery_delay_in_minutes,
+            },
+            toolkit::SecurityShieldUpdateOperation::InitiateRecovery {
+                access_controller_address,
+                proposer,
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } => Self::InitiateRecovery {
+                access_controller_address: address(access_controller_address),
+                proposer: FromNative::from_native(proposer),
+                rule_set: rule_set_value(rule_set),
+                timed_recovery_delay_in_minutes,
+            },
+            toolkit::SecurityShieldUpdateOperation::QuickConfirmRecovery {
+                access_controller_address,
+                proposer,
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } => Self::QuickConfirmRecovery {
+                access_controller_address: address(access_controller_address),
+                proposer: FromNative::from_native(proposer),
+                rule_set: rule_set_value(rule_set),
+                timed_recovery_delay_in_minutes,
+            },
+            toolkit::SecurityShieldUpdateOperation::TimedConfirmRecovery {
+                access_controller_address,
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } => Self::TimedConfirmRecovery {
+                access_controller_address: address(access_controller_address),
+                rule_set: rule_set_value(rule_set),
+                timed_recovery_delay_in_minutes,
+            },
+            toolkit::SecurityShieldUpdateOperation::LockPrimaryRole {
+                access_controller_address,
+            } => Self::LockPrimaryRole {
+                access_controller_address: address(access_controller_address),
+            },
+            toolkit::SecurityShieldUpdateOperation::UnlockPrimaryRole {
+                access_controller_address,
+            } => Self::UnlockPrimaryRole {
+                access_controller_address: address(access_controller_address),
+            },
+            toolkit::SecurityShieldUpdateOperation::InitiateBadgeWithdrawAttempt {
+                access_controller_address,
+                proposer,
+            } => Self::InitiateBadgeWithdrawAttempt {
+                access_controller_address: address(access_controller_address),
+                proposer: FromNative::from_native(proposer),
+            },
+            toolkit::SecurityShieldUpdateOperation::QuickConfirmBadgeWithdrawAttempt {
+                access_controller_address,
+                proposer,
+            } => Self::QuickConfirmBadgeWithdrawAttempt {
+                access_controller_address: address(access_controller_address),
+                proposer: FromNative::from_native(proposer),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ResourceCreationOutput {
+    pub created_resources: Vec<CreatedResource>,
+}
+
+impl FromNativeWithNetworkContext for ResourceCreationOutput {
+    type Native = toolkit::ResourceCreationOutput;
+
+    fn from_native(
+        Self::Native { created_resources }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            created_resources: created_resources
+                .into_iter()
+                .map(|native| {
+                    FromNativeWithNetworkContext::from_native(
+                        native, network_id,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct CreatedResource {
+    pub resource_address: Arc<Address>,
+    pub definition: NewResourceDefinition,
+}
+
+impl FromNativeWithNetworkContext for CreatedResource {
+    type Native = toolkit::CreatedResource;
+
+    fn from_native(
+        Self::Native {
+            resource_address,
+            definition,
+        }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            resource_address: Arc::new(Address::from_node_id(
+                resource_address,
+                network_id,
+            )),
+            definition: FromNativeWithNetworkContext::from_native(
+                definition, network_id,
+            ),
+        }
+    }
+}
+
+/// The definition of a newly created resource. The owner role, resource roles
+/// and metadata are reported as manifest values since they may reference named
+/// addresses, buckets, or proofs in the manifest.
+#[derive(Clone, Debug, Enum)]
+pub enum NewResourceDefinition {
+    Fungible {
+        owner_role: ManifestValue,
+        track_total_supply: bool,
+        divisibility: u8,
+        initial_supply: Option<Arc<Decimal>>,
+        resource_roles: ManifestValue,
+        metadata: ManifestValue,
+    },
+    NonFungible {
+        owner_role: ManifestValue,
+        id_type: NonFungibleIdType,
+        track_total_supply: bool,
+        initial_supply: Option<Vec<NonFungibleLocalId>>,
+        resource_roles: ManifestValue,
+        metadata: ManifestValue,
+    },
+}
+
+impl FromNativeWithNetworkContext for NewResourceDefinition {
+    type Native = toolkit::NewResourceDefinition;
+
+    fn from_native(native: Self::Native, network_id: u8) -> Self {
+        let value = |value: engine::ManifestValue| {
+            ManifestValue::from_native(&value, network_id)
+        };
+
+        match native {
+            Self::Native::Fungible {
+                owner_role,
+                track_total_supply,
+                divisibility,
+                initial_supply,
+                resource_roles,
+                metadata,
+            } => Self::Fungible {
+                owner_role: value(engine::to_manifest_value_and_unwrap!(
+                    &owner_role
+                )),
+                track_total_supply,
+                divisibility,
+                initial_supply: initial_supply
+                    .map(|value| Arc::new(Decimal(value))),
+                resource_roles: value(engine::to_manifest_value_and_unwrap!(
+                    &resource_roles
+                )),
+                metadata: value(engine::to_manifest_value_and_unwrap!(
+                    &metadata
+                )),
+            },
+            Self::Native::NonFungible {
+                owner_role,
+                id_type,
+                track_total_supply,
+                initial_supply,
+                resource_roles,
+                metadata,
+            } => Self::NonFungible {
+                owner_role: value(engine::to_manifest_value_and_unwrap!(
+                    &owner_role
+                )),
+                id_type: FromNative::from_native(id_type),
+                track_total_supply,
+                initial_supply: initial_supply.map(|ids| {
+                    ids.into_iter().map(FromNative::from_native).collect()
+                }),
+                resource_roles: value(engine::to_manifest_value_and_unwrap!(
+                    &resource_roles
+                )),
+                metadata: value(engine::to_manifest_value_and_unwrap!(
+                    &metadata
+                )),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct PackagePublishOutput {
+    pub published_packages: Vec<PublishedPackage>,
+}
+
+impl FromNativeWithNetworkContext for PackagePublishOutput {
+    type Native = toolkit::PackagePublishOutput;
+
+    fn from_native(
+        Self::Native { published_packages }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            published_packages: published_packages
+                .into_iter()
+                .map(|native| {
+                    FromNativeWithNetworkContext::from_native(
+                        native, network_id,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct PublishedPackage {
+    pub package_address: Arc<Address>,
+    pub code_hash: Arc<Hash>,
+    pub blueprints: Vec<String>,
+    pub royalty_config: HashMap<String, PackageRoyaltyConfig>,
+    pub owner_role: Option<ManifestValue>,
+}
+
+impl FromNativeWithNetworkContext for PublishedPackage {
+    type Native = toolkit::PublishedPackage;
+
+    fn from_native(
+        Self::Native {
+            package_address,
+            code_hash,
+            blueprints,
+            royalty_config,
+            owner_role,
+        }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            package_address: Arc::new(Address::from_node_id(
+                package_address,
+                network_id,
+            )),
+            code_hash: Arc::new(Hash(code_hash)),
+            blueprints: blueprints.into_iter().collect(),
+            royalty_config: royalty_config
+                .into_iter()
+                .map(|(blueprint_name, royalty_config)| {
+                    (blueprint_name, royalty_config.into())
+                })
+                .collect(),
+            owner_role: owner_role.map(|owner_role| {
+                ManifestValue::from_native(
+                    &engine::to_manifest_value_and_unwrap!(&owner_role),
+                    network_id,
+                )
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ValidatorManagementOutput {
+    pub operations: Vec<ValidatorManagementOperation>,
+}
+
+impl FromNativeWithNetworkContext for ValidatorManagementOutput {
+    type Native = toolkit::ValidatorManagementOutput;
+
+    fn from_native(
+        Self::Native { operations }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            operations: operations
+                .into_iter()
+                .map(|native| {
+                    FromNativeWithNetworkContext::from_native(
+                        native, network_id,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ValidatorManagementOperation {
+    Register {
+        validator_address: Arc<Address>,
+    },
+    Unregister {
+        validator_address: Arc<Address>,
+    },
+    UpdateFee {
+        validator_address: Arc<Address>,
+        new_fee_factor: Arc<Decimal>,
+    },
+    UpdateAcceptDelegatedStake {
+        validator_address: Arc<Address>,
+        accept_delegated_stake: bool,
+    },
+    LockOwnerStakeUnits {
+        validator_address: Arc<Address>,
+        stake_unit_amount: Arc<Decimal>,
+    },
+    StartUnlockOwnerStakeUnits {
+        validator_address: Arc<Address>,
+        requested_stake_unit_amount: Arc<Decimal>,
+    },
+    FinishUnlockOwnerStakeUnits {
+        validator_address: Arc<Address>,
+    },
+    SignalProtocolUpdateReadiness {
+        validator_address: Arc<Address>,
+        protocol_version_name: String,
+    },
+}
+
+impl FromNativeWithNetworkContext for ValidatorManagementOperation {
+    type Native = toolkit::ValidatorManagementOperation;
+
+    fn from_native(native: Self::Native, network_id: u8) -> Self {
+        let address = |validator_address: engine::ComponentAddress| {
+            Arc::new(Address::from_node_id(validator_address, network_id))
+        };
+
+        match native {
+            Self::Native::Register { validator_address } => Self::Register {
+                validator_address: address(validator_address),
+            },
+            Self::Native::Unregister { validator_address } => {
+                Self::Unregister {
+                    validator_address: address(validator_address),
+                }
+            }
+            Self::Native::UpdateFee {
+                validator_address,
+                new_fee_factor,
+            } => Self::UpdateFee {
+                validator_address: address(validator_address),
+                new_fee_factor: Arc::new(Decimal(new_fee_factor)),
+            },
+            Self::Native::UpdateAcceptDelegatedStake {
+                validator_address,
+                accept_delegated_stake,
+            } => Self::UpdateAcceptDelegatedStake {
+                validator_address: address(validator_address),
+                accept_delegated_stake,
+            },
+            Self::Native::LockOwnerStakeUnits {
+                validator_address,
+                stake_unit_amount,
+            } => Self::LockOwnerStakeUnits {
+                validator_address: address(validator_address),
+                stake_unit_amount: Arc::new(Decimal(stake_unit_amount)),
+            },
+            Self::Native::StartUnlockOwnerStakeUnits {
+                validator_address,
+                requested_stake_unit_amount,
+            } => Self::StartUnlockOwnerStakeUnits {
+                validator_address: address(validator_address),
+                requested_stake_unit_amount: Arc::new(Decimal(
+                    requested_stake_unit_amount,
+                )),
+            },
+            Self::Native::FinishUnlockOwnerStakeUnits { validator_address } => {
+                Self::FinishUnlockOwnerStakeUnits {
+                    validator_address: address(validator_address),
+                }
+            }
+            Self::Native::SignalProtocolUpdateReadiness {
+                validator_address,
+                protocol_version_name,
+            } => Self::SignalProtocolUpdateReadiness {
+                validator_address: address(validator_address),
+                protocol_version_name,
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug, Record)]
 pub struct AccountSettingsUpdateOutput {
     pub resource_preferences_updates:
@@ -0,0 +1,208 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+/// A manifest classifier implemented outside of the toolkit that's registered
+/// at runtime and that's ran alongside the classifiers of the toolkit when a
+/// manifest is analyzed.
+///
+/// The toolkit keeps track of the state of the classifier: a manifest matches
+/// the classifier if all of its instructions are permitted by the classifier
+/// and if at least one of its instructions fulfills the requirement of the
+/// classifier. When a manifest matches, the output of the classifier is then
+/// computed from all of the instructions of the manifest.
+#[uniffi::export(callback_interface)]
+pub trait ManifestClassifier: Send + Sync {
+    /// The name of the classification that the classifier produces.
+    fn name(&self) -> String;
+
+    /// Checks if the instruction is permitted in manifests of this class.
+    fn is_instruction_permitted(&self, instruction: InstructionV2) -> bool;
+
+    /// Checks if the instruction fulfills the requirement of this class.
+    fn is_instruction_required(&self, instruction: InstructionV2) -> bool;
+
+    /// Computes the output of the classifier from the instructions of the
+    /// manifest. The output is opaque to the toolkit and is commonly a JSON
+    /// string that the classifier knows how to interpret.
+    fn output(&self, instructions: Vec<InstructionV2>) -> String;
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct RegisteredClassification {
+    pub name: String,
+    pub output: String,
+}
+
+impl RegisteredClassification {
+    /// Converts the native registered classification into this type. This only
+    /// succeeds for the classifications produced by a [`ManifestClassifier`]
+    /// which is the only type of classifier that can be registered from the
+    /// bindings.
+    pub fn from_native(
+        native: &toolkit::RegisteredClassification,
+    ) -> Option<Self> {
+        native.static_output::<String>().map(|output| Self {
+            name: native.name.clone(),
+            output: output.clone(),
+        })
+    }
+
+    pub fn from_native_detailed(
+        native: &toolkit::RegisteredDetailedClassification,
+    ) -> Option<Self> {
+        native.static_output::<String>().map(|output| Self {
+            name: native.name.clone(),
+            output: output.clone(),
+        })
+    }
+}
+
+/// Creates a registry of analyzers from the passed classifiers.
+pub(crate) fn manifest_analyzer_registry(
+    classifiers: Vec<Box<dyn ManifestClassifier>>,
+    network_id: u8,
+) -> toolkit::ManifestAnalyzerRegistry {
+    classifiers.into_iter().fold(
+        toolkit::ManifestAnalyzerRegistry::new(),
+        |mut registry, classifier| {
+            let classifier = Arc::<dyn ManifestClassifier>::from(classifier);
+            registry.register_static_analyzer::<ManifestClassifierAnalyzer>(
+                classifier.name(),
+                ManifestClassifierInitializer {
+                    classifier,
+                    network_id,
+                },
+            );
+            registry
+        },
+    )
+}
+
+#[derive(Clone)]
+struct ManifestClassifierInitializer {
+    classifier: Arc<dyn ManifestClassifier>,
+    network_id: u8,
+}
+
+impl ManifestClassifierInitializer {
+    fn instruction(
+        &self,
+        context: toolkit::InstructionContext<'_>,
+    ) -> InstructionV2 {
+        let instruction =
+            engine::AnyInstruction::from(context.instruction().clone());
+        InstructionV2::from_native(&instruction, self.network_id)
+    }
+}
+
+/// An analyzer that adapts a [`ManifestClassifier`] to the analyzer traits of
+/// the toolkit so that it can be registered in a registry.
+struct ManifestClassifierAnalyzer {
+    initializer: ManifestClassifierInitializer,
+    instructions: Vec<InstructionV2>,
+}
+
+struct ManifestClassifierPermissionState {
+    initializer: ManifestClassifierInitializer,
+    all_instructions_permitted: bool,
+}
+
+struct ManifestClassifierRequirementState {
+    initializer: ManifestClassifierInitializer,
+    is_required_instruction_seen: bool,
+}
+
+impl toolkit::ManifestStaticAnalyzer for ManifestClassifierAnalyzer {
+    type Initializer = ManifestClassifierInitializer;
+    type Output = String;
+    type PermissionState = ManifestClassifierPermissionState;
+    type RequirementState = ManifestClassifierRequirementState;
+
+    fn new(
+        initializer: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Self {
+                initializer: initializer.clone(),
+                instructions: Default::default(),
+            },
+            ManifestClassifierPermissionState {
+                initializer: initializer.clone(),
+                all_instructions_permitted: true,
+            },
+            ManifestClassifierRequirementState {
+                initializer,
+                is_required_instruction_seen: false,
+            },
+        )
+    }
+
+    fn output(self) -> Self::Output {
+        self.initializer.classifier.output(self.instructions)
+    }
+
+    fn process_instruction(
+        &mut self,
+        context: toolkit::InstructionContext<'_>,
+    ) {
+        self.instructions
+            .push(self.initializer.instruction(context));
+    }
+}
+
+impl toolkit::ManifestAnalyzerPermissionState
+    for ManifestClassifierPermissionState
+{
+    fn all_instructions_permitted(&self) -> bool {
+        self.all_instructions_permitted
+    }
+
+    fn process_instruction(
+        &mut self,
+        context: toolkit::InstructionContext<'_>,
+    ) {
+        self.all_instructions_permitted &= self
+            .initializer
+            .classifier
+            .is_instruction_permitted(self.initializer.instruction(context));
+    }
+}
+
+impl toolkit::ManifestAnalyzerRequirementState
+    for ManifestClassifierRequirementState
+{
+    fn requirement_state(&self) -> toolkit::RequirementState {
+        match self.is_required_instruction_seen {
+            true => toolkit::RequirementState::Fulfilled,
+            false => toolkit::RequirementState::CurrentlyUnfulfilled,
+        }
+    }
+
+    fn process_instruction(
+        &mut self,
+        context: toolkit::InstructionContext<'_>,
+    ) {
+        if !self.is_required_instruction_seen {
+            self.is_required_instruction_seen = self
+                .initializer
+                .classifier
+                .is_instruction_required(self.initializer.instruction(context));
+        }
+    }
+}
@@ -15,5 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod fee_estimation;
 pub mod hash;
 pub mod manifest_analysis;
+pub mod manifest_classifier;
+pub mod signature_verification;
+pub mod transaction_summary;
+pub mod validation_error;
@@ -0,0 +1,71 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Record)]
+pub struct SignatureVerification {
+    pub kind: SignatureKind,
+    pub signer: Option<PublicKey>,
+    pub signed_hash: Arc<Hash>,
+    pub is_valid_for_signer: bool,
+}
+
+impl FromNative for SignatureVerification {
+    type Native = toolkit::SignatureVerification;
+
+    fn from_native(
+        Self::Native {
+            kind,
+            signer,
+            signed_hash,
+            is_valid_for_signer,
+        }: Self::Native,
+    ) -> Self {
+        Self {
+            kind: SignatureKind::from_native(kind),
+            signer: signer.map(PublicKey::from),
+            signed_hash: Arc::new(Hash(signed_hash)),
+            is_valid_for_signer,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SignatureKind {
+    TransactionIntent,
+    RootSubintent,
+    NonRootSubintent { index: u64 },
+    Notary,
+}
+
+impl FromNative for SignatureKind {
+    type Native = toolkit::SignatureKind;
+
+    fn from_native(native: Self::Native) -> Self {
+        match native {
+            Self::Native::TransactionIntent => Self::TransactionIntent,
+            Self::Native::RootSubintent => Self::RootSubintent,
+            Self::Native::NonRootSubintent { index } => {
+                Self::NonRootSubintent {
+                    index: index as u64,
+                }
+            }
+            Self::Native::Notary => Self::Notary,
+        }
+    }
+}
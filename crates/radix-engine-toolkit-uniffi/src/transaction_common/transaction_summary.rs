@@ -0,0 +1,464 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Record)]
+pub struct TransactionSummary {
+    pub lines: Vec<TransactionSummaryLine>,
+}
+
+impl FromNativeWithNetworkContext for TransactionSummary {
+    type Native = toolkit::TransactionSummary;
+
+    fn from_native(native: Self::Native, network_id: u8) -> Self {
+        let renderer = toolkit::EnglishTransactionSummaryRenderer::new(
+            &engine::NetworkDefinition::from_network_id(network_id),
+        );
+        Self {
+            lines: native
+                .lines
+                .into_iter()
+                .map(|line| TransactionSummaryLine {
+                    message_key: line.message_key().to_owned(),
+                    english_text:
+                        toolkit::TransactionSummaryRenderer::render_line(
+                            &renderer, &line,
+                        ),
+                    details: FromNativeWithNetworkContext::from_native(
+                        line, network_id,
+                    ),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A line of the transaction summary. The [`message_key`] and [`details`] can
+/// be used to render the line in any language while the [`english_text`] is
+/// the line rendered in English by the toolkit.
+///
+/// [`message_key`]: TransactionSummaryLine::message_key
+/// [`details`]: TransactionSummaryLine::details
+/// [`english_text`]: TransactionSummaryLine::english_text
+#[derive(Clone, Debug, Record)]
+pub struct TransactionSummaryLine {
+    pub message_key: String,
+    pub details: TransactionSummaryLineDetails,
+    pub english_text: String,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum TransactionSummaryLineDetails {
+    Withdraw {
+        account: SummaryEntity,
+        resources: Vec<SummaryResource>,
+    },
+    Deposit {
+        account: SummaryEntity,
+        resources: Vec<SummaryResource>,
+    },
+    Stake {
+        validator: SummaryEntity,
+        staked: SummaryResource,
+        received: SummaryResource,
+    },
+    Unstake {
+        validator: SummaryEntity,
+        unstaked: SummaryResource,
+        received: SummaryResource,
+    },
+    ClaimXrd {
+        validator: SummaryEntity,
+        claimed: SummaryResource,
+        received: SummaryResource,
+    },
+    PoolContribution {
+        pool: SummaryEntity,
+        contributed: Vec<SummaryResource>,
+        received: SummaryResource,
+    },
+    PoolRedemption {
+        pool: SummaryEntity,
+        redeemed: SummaryResource,
+        received: Vec<SummaryResource>,
+    },
+    Swap {
+        component: SummaryEntity,
+        input: Vec<SummaryResource>,
+        output: Vec<SummaryResource>,
+    },
+    Fee {
+        amount: Arc<Decimal>,
+    },
+    General {
+        is_subintent: bool,
+    },
+    Transfer {
+        is_one_to_one_transfer: bool,
+    },
+    /// The resource preference and authorized depositor updates only include
+    /// the resources and badges that are referenced through static addresses.
+    AccountDepositSettingsUpdate {
+        account: ManifestAddress,
+        default_deposit_rule: Option<AccountDefaultDepositRule>,
+        resource_preference_updates: HashMap<String, ResourcePreferenceUpdate>,
+        authorized_depositor_updates:
+            HashMap<Operation, Vec<ResourceOrNonFungible>>,
+    },
+    AccountLockerClaim {
+        locker: SummaryEntity,
+        claimant: SummaryEntity,
+        claimed: SummaryResource,
+    },
+    AccountLockerStore {
+        locker: SummaryEntity,
+        claimant: SummaryEntity,
+        stored: SummaryResource,
+        try_direct_send: bool,
+    },
+    SecurityShieldUpdate {
+        operation: SecurityShieldUpdateOperation,
+    },
+    ResourceCreation {
+        resource: SummaryEntity,
+        initial_supply: Option<SummaryResource>,
+    },
+    PackagePublish {
+        package: SummaryEntity,
+        blueprints: Vec<String>,
+    },
+    ValidatorManagement {
+        operation: ValidatorManagementOperation,
+    },
+    /// The warning is [`None`] if it's about an entity that is referenced
+    /// through a named address, the line's English text still describes it.
+    Warning {
+        warning: Option<ManifestWarning>,
+    },
+}
+
+impl FromNativeWithNetworkContext for TransactionSummaryLineDetails {
+    type Native = toolkit::TransactionSummaryLine;
+
+    fn from_native(native: Self::Native, network_id: u8) -> Self {
+        let entity = |entity: toolkit::SummaryEntity| {
+            SummaryEntity::from_native(entity, network_id)
+        };
+        let resource = |resource: toolkit::SummaryResource| {
+            SummaryResource::from_native(resource, network_id)
+        };
+        let resources = |resources: Vec<toolkit::SummaryResource>| {
+            resources.into_iter().map(resource).collect::<Vec<_>>()
+        };
+        match native {
+            Self::Native::Withdraw {
+                account,
+                resources: withdrawn,
+            } => Self::Withdraw {
+                account: entity(account),
+                resources: resources(withdrawn),
+            },
+            Self::Native::Deposit {
+                account,
+                resources: deposited,
+            } => Self::Deposit {
+                account: entity(account),
+                resources: resources(deposited),
+            },
+            Self::Native::Stake {
+                validator,
+                staked,
+                received,
+            } => Self::Stake {
+                validator: entity(validator),
+                staked: resource(staked),
+                received: resource(received),
+            },
+            Self::Native::Unstake {
+                validator,
+                unstaked,
+                received,
+            } => Self::Unstake {
+                validator: entity(validator),
+                unstaked: resource(unstaked),
+                received: resource(received),
+            },
+            Self::Native::ClaimXrd {
+                validator,
+                claimed,
+                received,
+            } => Self::ClaimXrd {
+                validator: entity(validator),
+                claimed: resource(claimed),
+                received: resource(received),
+            },
+            Self::Native::PoolContribution {
+                pool,
+                contributed,
+                received,
+            } => Self::PoolContribution {
+                pool: entity(pool),
+                contributed: resources(contributed),
+                received: resource(received),
+            },
+            Self::Native::PoolRedemption {
+                pool,
+                redeemed,
+                received,
+            } => Self::PoolRedemption {
+                pool: entity(pool),
+                redeemed: resource(redeemed),
+                received: resources(received),
+            },
+            Self::Native::Swap {
+                component,
+                input,
+                output,
+            } => Self::Swap {
+                component: entity(component),
+                input: resources(input),
+                output: resources(output),
+            },
+            Self::Native::Fee { amount } => Self::Fee {
+                amount: Arc::new(Decimal(amount)),
+            },
+            Self::Native::General { is_subintent } => {
+                Self::General { is_subintent }
+            }
+            Self::Native::Transfer {
+                is_one_to_one_transfer,
+            } => Self::Transfer {
+                is_one_to_one_transfer,
+            },
+            Self::Native::AccountDepositSettingsUpdate {
+                account,
+                default_deposit_rule,
+                resource_preference_updates,
+                authorized_depositor_updates,
+            } => {
+                Self::AccountDepositSettingsUpdate {
+                    account: ManifestAddress::from_dynamic_global_address(
+                        &account, network_id,
+                    ),
+                    default_deposit_rule: default_deposit_rule
+                        .map(FromNative::from_native),
+                    resource_preference_updates: resource_preference_updates
+                        .into_iter()
+                        .filter_map(|(resource_address, update)| {
+                            resource_address.into_static().map(|address| {
+                                (
+                                    Address::from_node_id(
+                                        address.into_node_id(),
+                                        network_id,
+                                    )
+                                    .as_str(),
+                                    FromNative::from_native(update),
+                                )
+                            })
+                        })
+                        .collect(),
+                    authorized_depositor_updates: authorized_depositor_updates
+                        .into_iter()
+                        .filter_map(|(badge, operation)| {
+                            let badge = match badge {
+                            engine::ManifestResourceOrNonFungible::Resource(
+                                engine::ManifestResourceAddress::Static(
+                                    resource_address,
+                                ),
+                            ) => engine::ResourceOrNonFungible::Resource(
+                                resource_address,
+                            ),
+                            engine::ManifestResourceOrNonFungible::NonFungible(
+                                non_fungible_global_id,
+                            ) => engine::ResourceOrNonFungible::NonFungible(
+                                non_fungible_global_id,
+                            ),
+                            engine::ManifestResourceOrNonFungible::Resource(
+                                engine::ManifestResourceAddress::Named(..),
+                            ) => return None,
+                        };
+                            Some((badge, operation))
+                        })
+                        .fold(
+                            HashMap::<_, Vec<_>>::new(),
+                            |mut map, (badge, operation)| {
+                                map.entry(FromNative::from_native(operation))
+                                .or_default()
+                                .push(FromNativeWithNetworkContext::from_native(
+                                    badge, network_id,
+                                ));
+                                map
+                            },
+                        ),
+                }
+            }
+            Self::Native::AccountLockerClaim {
+                locker,
+                claimant,
+                claimed,
+            } => Self::AccountLockerClaim {
+                locker: entity(locker),
+                claimant: entity(claimant),
+                claimed: resource(claimed),
+            },
+            Self::Native::AccountLockerStore {
+                locker,
+                claimant,
+                stored,
+                try_direct_send,
+            } => Self::AccountLockerStore {
+                locker: entity(locker),
+                claimant: entity(claimant),
+                stored: resource(stored),
+                try_direct_send,
+            },
+            Self::Native::SecurityShieldUpdate { operation } => {
+                Self::SecurityShieldUpdate {
+                    operation: SecurityShieldUpdateOperation::from_native(
+                        operation, network_id,
+                    ),
+                }
+            }
+            Self::Native::ResourceCreation {
+                resource: created,
+                initial_supply,
+            } => Self::ResourceCreation {
+                resource: entity(created),
+                initial_supply: initial_supply.map(resource),
+            },
+            Self::Native::PackagePublish {
+                package,
+                blueprints,
+            } => Self::PackagePublish {
+                package: entity(package),
+                blueprints: blueprints.into_iter().collect(),
+            },
+            Self::Native::ValidatorManagement { operation } => {
+                Self::ValidatorManagement {
+                    operation: FromNativeWithNetworkContext::from_native(
+                        operation, network_id,
+                    ),
+                }
+            }
+            Self::Native::Warning { warning } => Self::Warning {
+                warning: ManifestWarning::from_native(warning, network_id),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SummaryEntity {
+    pub kind: SummaryEntityKind,
+    pub address: Arc<Address>,
+}
+
+impl FromNativeWithNetworkContext for SummaryEntity {
+    type Native = toolkit::SummaryEntity;
+
+    fn from_native(
+        Self::Native { kind, address }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            kind: FromNative::from_native(kind),
+            address: Arc::new(Address::from_node_id(address, network_id)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SummaryEntityKind {
+    Account,
+    Identity,
+    Validator,
+    Pool,
+    Resource,
+    Package,
+    AccessController,
+    AccountLocker,
+    Component,
+}
+
+impl FromNative for SummaryEntityKind {
+    type Native = toolkit::SummaryEntityKind;
+
+    fn from_native(native: Self::Native) -> Self {
+        match native {
+            Self::Native::Account => Self::Account,
+            Self::Native::Identity => Self::Identity,
+            Self::Native::Validator => Self::Validator,
+            Self::Native::Pool => Self::Pool,
+            Self::Native::Resource => Self::Resource,
+            Self::Native::Package => Self::Package,
+            Self::Native::AccessController => Self::AccessController,
+            Self::Native::AccountLocker => Self::AccountLocker,
+            Self::Native::Component => Self::Component,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SummaryResource {
+    pub resource_address: Arc<Address>,
+    pub amount: SummaryResourceAmount,
+    pub is_guaranteed: bool,
+}
+
+impl FromNativeWithNetworkContext for SummaryResource {
+    type Native = toolkit::SummaryResource;
+
+    fn from_native(
+        Self::Native {
+            resource_address,
+            amount,
+            is_guaranteed,
+        }: Self::Native,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            resource_address: Arc::new(Address::from_node_id(
+                resource_address,
+                network_id,
+            )),
+            amount: FromNative::from_native(amount),
+            is_guaranteed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SummaryResourceAmount {
+    Fungible { value: Arc<Decimal> },
+    NonFungible { ids: Vec<NonFungibleLocalId> },
+}
+
+impl FromNative for SummaryResourceAmount {
+    type Native = toolkit::SummaryResourceAmount;
+
+    fn from_native(native: Self::Native) -> Self {
+        match native {
+            Self::Native::Fungible(value) => Self::Fungible {
+                value: Arc::new(Decimal(value)),
+            },
+            Self::Native::NonFungible(ids) => Self::NonFungible {
+                ids: ids.into_iter().map(FromNative::from_native).collect(),
+            },
+        }
+    }
+}
@@ -0,0 +1,944 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Enum)]
+pub enum TransactionValidationError {
+    TransactionVersionNotPermitted {
+        version: u64,
+    },
+    TransactionTooLarge,
+    EncodeError {
+        error: EncodeError,
+    },
+    PrepareError {
+        error: PrepareError,
+    },
+    SubintentStructureError {
+        location: TransactionValidationErrorLocation,
+        error: SubintentStructureError,
+    },
+    IntentValidationError {
+        location: TransactionValidationErrorLocation,
+        error: IntentValidationError,
+    },
+    SignatureValidationError {
+        location: TransactionValidationErrorLocation,
+        error: SignatureValidationError,
+    },
+    InstructionSchemaValidationError {
+        instruction_index: u64,
+        error: String,
+    },
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum TransactionValidationErrorLocation {
+    RootTransactionIntent { hash: Arc<Hash> },
+    RootSubintent { hash: Arc<Hash> },
+    NonRootSubintent { index: u64, hash: Arc<Hash> },
+    AcrossTransaction,
+    Unlocatable,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum IntentValidationError {
+    ManifestBasicValidatorError {
+        error: ManifestBasicValidatorError,
+    },
+    ManifestValidationError {
+        instruction_index: Option<u64>,
+        error: ManifestValidationError,
+    },
+    InvalidMessage {
+        error: InvalidMessageError,
+    },
+    HeaderValidationError {
+        error: HeaderValidationError,
+    },
+    TooManyReferences {
+        total: u64,
+        limit: u64,
+    },
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SignatureValidationError {
+    TooManySignatures { total: u64, limit: u64 },
+    InvalidIntentSignature,
+    InvalidNotarySignature,
+    DuplicateSigner,
+    NotaryIsSignatorySoShouldNotAlsoBeASigner,
+    SerializationError { error: EncodeError },
+    IncorrectNumberOfSubintentSignatureBatches,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum InvalidMessageError {
+    PlaintextMessageTooLong {
+        actual: u64,
+        permitted: u64,
+    },
+    MimeTypeTooLong {
+        actual: u64,
+        permitted: u64,
+    },
+    EncryptedMessageTooLong {
+        actual: u64,
+        permitted: u64,
+    },
+    NoDecryptors,
+    MismatchingDecryptorCurves {
+        actual: CurveTypeV2,
+        expected: CurveTypeV2,
+    },
+    TooManyDecryptors {
+        actual: u64,
+        permitted: u64,
+    },
+    NoDecryptorsForCurveType {
+        curve_type: CurveTypeV2,
+    },
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SubintentStructureError {
+    DuplicateSubintent,
+    SubintentHasMultipleParents,
+    ChildSubintentNotIncludedInTransaction { hash: Arc<Hash> },
+    SubintentExceedsMaxDepth,
+    SubintentIsNotReachableFromTheTransactionIntent,
+    MismatchingYieldChildAndYieldParentCountsForSubintent,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ManifestBasicValidatorError {
+    ManifestIdValidationError { error: ManifestIdValidationError },
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ManifestIdValidationError {
+    BucketNotFound {
+        bucket: ManifestBucket,
+    },
+    ProofNotFound {
+        proof: ManifestProof,
+    },
+    BucketLocked {
+        bucket: ManifestBucket,
+    },
+    AddressReservationNotFound {
+        address_reservation: ManifestAddressReservation,
+    },
+    AddressNotFound {
+        named_address_id: u32,
+    },
+    IntentNotFound {
+        named_intent_id: u32,
+    },
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum EncodeError {
+    MaxDepthExceeded {
+        max: u64,
+    },
+    SizeTooLarge {
+        actual: u64,
+        max_allowed: u64,
+    },
+    MismatchingArrayElementValueKind {
+        element_value_kind: u8,
+        actual_value_kind: u8,
+    },
+    MismatchingMapKeyValueKind {
+        key_value_kind: u8,
+        actual_value_kind: u8,
+    },
+    MismatchingMapValueValueKind {
+        value_value_kind: u8,
+        actual_value_kind: u8,
+    },
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum DecodeError {
+    ExtraTrailingBytes { count: u64 },
+    BufferUnderflow { required: u64, remaining: u64 },
+    UnexpectedPayloadPrefix { expected: u8, actual: u8 },
+    UnexpectedValueKind { expected: u8, actual: u8 },
+    UnexpectedCustomValueKind { actual: u8 },
+    UnexpectedSize { expected: u64, actual: u64 },
+    UnexpectedDiscriminator { expected: u8, actual: u8 },
+    UnknownValueKind { value_kind: u8 },
+    UnknownDiscriminator { discriminator: u8 },
+    InvalidBool { value: u8 },
+    InvalidUtf8,
+    InvalidSize,
+    MaxDepthExceeded { max: u64 },
+    DuplicateKey,
+    InvalidCustomValue,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum PrepareError {
+    TransactionTypeNotSupported,
+    TransactionTooLarge,
+    DecodeError {
+        error: DecodeError,
+    },
+    EncodeError {
+        error: EncodeError,
+    },
+    TooManyValues {
+        value_type: PreparationValueType,
+        actual: u64,
+        max: u64,
+    },
+    LengthOverflow,
+    UnexpectedTransactionDiscriminator {
+        actual: Option<u8>,
+    },
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum PreparationValueType {
+    Blob,
+    Subintent,
+    ChildSubintentSpecifier,
+    SubintentSignatureBatches,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum HeaderValidationError {
+    UnknownVersion { version: u8 },
+    InvalidEpochRange,
+    InvalidTimestampRange,
+    InvalidNetwork,
+    InvalidCostUnitLimit,
+    InvalidTip,
+    NoValidEpochRangeAcrossAllIntents,
+    NoValidTimestampRangeAcrossAllIntents,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ManifestValidationError {
+    DuplicateBlob {
+        blob: ManifestBlobRef,
+    },
+    BlobNotRegistered {
+        blob: ManifestBlobRef,
+    },
+    BucketNotYetCreated {
+        bucket: ManifestBucket,
+    },
+    BucketAlreadyUsed {
+        bucket: ManifestBucket,
+        used_by: String,
+    },
+    BucketConsumedWhilstLockedByProof {
+        bucket: ManifestBucket,
+        locked_by: String,
+    },
+    ProofNotYetCreated {
+        proof: ManifestProof,
+    },
+    ProofAlreadyUsed {
+        proof: ManifestProof,
+        used_by: String,
+    },
+    AddressReservationNotYetCreated {
+        address_reservation: ManifestAddressReservation,
+    },
+    AddressReservationAlreadyUsed {
+        address_reservation: ManifestAddressReservation,
+        used_by: String,
+    },
+    NamedAddressNotYetCreated {
+        named_address_id: u32,
+    },
+    ChildIntentNotRegistered {
+        named_intent_id: u32,
+    },
+    DanglingBucket {
+        bucket: ManifestBucket,
+        created_by: String,
+    },
+    DanglingAddressReservation {
+        address_reservation: ManifestAddressReservation,
+        created_by: String,
+    },
+    ArgsEncodeError {
+        error: String,
+    },
+    ArgsDecodeError {
+        error: String,
+    },
+    InstructionNotSupportedInTransactionIntent,
+    SubintentDoesNotEndWithYieldToParent,
+    ProofCannotBePassedToAnotherIntent,
+    TooManyInstructions,
+    InvalidResourceConstraint,
+    InstructionFollowingNextCallAssertionWasNotInvocation,
+    ManifestEndedWhilstExpectingNextCallAssertion,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ManifestAnalysisError {
+    InvalidNamedAddress,
+    ManifestValidationError { error: ManifestValidationError },
+    StaticResourceMovementsError { error: String },
+    TypedManifestNativeInvocationError { error: String },
+    NotACommitSuccessReceipt,
+}
+
+impl From<engine::TransactionValidationError> for TransactionValidationError {
+    fn from(value: engine::TransactionValidationError) -> Self {
+        match value {
+            engine::TransactionValidationError::TransactionVersionNotPermitted(
+                version,
+            ) => Self::TransactionVersionNotPermitted {
+                version: version as u64,
+            },
+            engine::TransactionValidationError::TransactionTooLarge => {
+                Self::TransactionTooLarge
+            }
+            engine::TransactionValidationError::EncodeError(error) => {
+                Self::EncodeError {
+                    error: error.into(),
+                }
+            }
+            engine::TransactionValidationError::PrepareError(error) => {
+                Self::PrepareError {
+                    error: error.into(),
+                }
+            }
+            engine::TransactionValidationError::SubintentStructureError(
+                location,
+                error,
+            ) => Self::SubintentStructureError {
+                location: location.into(),
+                error: error.into(),
+            },
+            engine::TransactionValidationError::IntentValidationError(
+                location,
+                error,
+            ) => Self::IntentValidationError {
+                location: location.into(),
+                error: error.into(),
+            },
+            engine::TransactionValidationError::SignatureValidationError(
+                location,
+                error,
+            ) => Self::SignatureValidationError {
+                location: location.into(),
+                error: error.into(),
+            },
+        }
+    }
+}
+
+impl From<toolkit::functions::transaction_v1::instructions::InstructionValidationError>
+    for TransactionValidationError
+{
+    fn from(
+        value: toolkit::functions::transaction_v1::instructions::InstructionValidationError,
+    ) -> Self {
+        use toolkit::functions::transaction_v1::instructions::InstructionValidationError as Native;
+        match value {
+            Native::IntentValidationError(error) => {
+                Self::IntentValidationError {
+                    location: TransactionValidationErrorLocation::Unlocatable,
+                    error: error.into(),
+                }
+            }
+            Native::TransactionValidationError(error) => error.into(),
+            Native::LocatedInstructionSchemaValidationError(
+                engine::LocatedInstructionSchemaValidationError {
+                    instruction_index,
+                    cause,
+                },
+            ) => Self::InstructionSchemaValidationError {
+                instruction_index: instruction_index as u64,
+                error: format!("{cause:?}"),
+            },
+            Native::LocatedManifestValidationError(
+                toolkit::LocatedManifestValidationError {
+                    instruction_index,
+                    error,
+                },
+            ) => Self::IntentValidationError {
+                location: TransactionValidationErrorLocation::Unlocatable,
+                error: IntentValidationError::ManifestValidationError {
+                    instruction_index: instruction_index.map(
+                        |instruction_index| *instruction_index.value() as u64,
+                    ),
+                    error: error.into(),
+                },
+            },
+        }
+    }
+}
+
+impl From<engine::TransactionValidationErrorLocation>
+    for TransactionValidationErrorLocation
+{
+    fn from(value: engine::TransactionValidationErrorLocation) -> Self {
+        match value {
+            engine::TransactionValidationErrorLocation::RootTransactionIntent(
+                hash,
+            ) => Self::RootTransactionIntent {
+                hash: Arc::new(hash.into_hash().into()),
+            },
+            engine::TransactionValidationErrorLocation::RootSubintent(hash) => {
+                Self::RootSubintent {
+                    hash: Arc::new(hash.into_hash().into()),
+                }
+            }
+            engine::TransactionValidationErrorLocation::NonRootSubintent(
+                index,
+                hash,
+            ) => Self::NonRootSubintent {
+                index: index.0 as u64,
+                hash: Arc::new(hash.into_hash().into()),
+            },
+            engine::TransactionValidationErrorLocation::AcrossTransaction => {
+                Self::AcrossTransaction
+            }
+            engine::TransactionValidationErrorLocation::Unlocatable => {
+                Self::Unlocatable
+            }
+        }
+    }
+}
+
+impl From<engine::IntentValidationError> for IntentValidationError {
+    fn from(value: engine::IntentValidationError) -> Self {
+        match value {
+            engine::IntentValidationError::ManifestBasicValidatorError(
+                error,
+            ) => Self::ManifestBasicValidatorError {
+                error: error.into(),
+            },
+            engine::IntentValidationError::ManifestValidationError(error) => {
+                Self::ManifestValidationError {
+                    instruction_index: None,
+                    error: error.into(),
+                }
+            }
+            engine::IntentValidationError::InvalidMessage(error) => {
+                Self::InvalidMessage {
+                    error: error.into(),
+                }
+            }
+            engine::IntentValidationError::HeaderValidationError(error) => {
+                Self::HeaderValidationError {
+                    error: error.into(),
+                }
+            }
+            engine::IntentValidationError::TooManyReferences {
+                total,
+                limit,
+            } => Self::TooManyReferences {
+                total: total as u64,
+                limit: limit as u64,
+            },
+        }
+    }
+}
+
+impl From<engine::SignatureValidationError> for SignatureValidationError {
+    fn from(value: engine::SignatureValidationError) -> Self {
+        match value {
+            engine::SignatureValidationError::TooManySignatures {
+                total,
+                limit,
+            } => Self::TooManySignatures {
+                total: total as u64,
+                limit: limit as u64,
+            },
+            engine::SignatureValidationError::InvalidIntentSignature => {
+                Self::InvalidIntentSignature
+            }
+            engine::SignatureValidationError::InvalidNotarySignature => {
+                Self::InvalidNotarySignature
+            }
+            engine::SignatureValidationError::DuplicateSigner => {
+                Self::DuplicateSigner
+            }
+            engine::SignatureValidationError::NotaryIsSignatorySoShouldNotAlsoBeASigner => {
+                Self::NotaryIsSignatorySoShouldNotAlsoBeASigner
+            }
+            engine::SignatureValidationError::SerializationError(error) => {
+                Self::SerializationError {
+                    error: error.into(),
+                }
+            }
+            engine::SignatureValidationError::IncorrectNumberOfSubintentSignatureBatches => {
+                Self::IncorrectNumberOfSubintentSignatureBatches
+            }
+        }
+    }
+}
+
+impl From<engine::InvalidMessageError> for InvalidMessageError {
+    fn from(value: engine::InvalidMessageError) -> Self {
+        match value {
+            engine::InvalidMessageError::PlaintextMessageTooLong {
+                actual,
+                permitted,
+            } => Self::PlaintextMessageTooLong {
+                actual: actual as u64,
+                permitted: permitted as u64,
+            },
+            engine::InvalidMessageError::MimeTypeTooLong {
+                actual,
+                permitted,
+            } => Self::MimeTypeTooLong {
+                actual: actual as u64,
+                permitted: permitted as u64,
+            },
+            engine::InvalidMessageError::EncryptedMessageTooLong {
+                actual,
+                permitted,
+            } => Self::EncryptedMessageTooLong {
+                actual: actual as u64,
+                permitted: permitted as u64,
+            },
+            engine::InvalidMessageError::NoDecryptors => Self::NoDecryptors,
+            engine::InvalidMessageError::MismatchingDecryptorCurves {
+                actual,
+                expected,
+            } => Self::MismatchingDecryptorCurves {
+                actual: actual.into(),
+                expected: expected.into(),
+            },
+            engine::InvalidMessageError::TooManyDecryptors {
+                actual,
+                permitted,
+            } => Self::TooManyDecryptors {
+                actual: actual as u64,
+                permitted: permitted as u64,
+            },
+            engine::InvalidMessageError::NoDecryptorsForCurveType {
+                curve_type,
+            } => Self::NoDecryptorsForCurveType {
+                curve_type: curve_type.into(),
+            },
+        }
+    }
+}
+
+impl From<engine::SubintentStructureError> for SubintentStructureError {
+    fn from(value: engine::SubintentStructureError) -> Self {
+        match value {
+            engine::SubintentStructureError::DuplicateSubintent => {
+                Self::DuplicateSubintent
+            }
+            engine::SubintentStructureError::SubintentHasMultipleParents => {
+                Self::SubintentHasMultipleParents
+            }
+            engine::SubintentStructureError::ChildSubintentNotIncludedInTransaction(
+                hash,
+            ) => Self::ChildSubintentNotIncludedInTransaction {
+                hash: Arc::new(hash.into_hash().into()),
+            },
+            engine::SubintentStructureError::SubintentExceedsMaxDepth => {
+                Self::SubintentExceedsMaxDepth
+            }
+            engine::SubintentStructureError::SubintentIsNotReachableFromTheTransactionIntent => {
+                Self::SubintentIsNotReachableFromTheTransactionIntent
+            }
+            engine::SubintentStructureError::MismatchingYieldChildAndYieldParentCountsForSubintent => {
+                Self::MismatchingYieldChildAndYieldParentCountsForSubintent
+            }
+        }
+    }
+}
+
+impl From<engine::ManifestBasicValidatorError> for ManifestBasicValidatorError {
+    fn from(value: engine::ManifestBasicValidatorError) -> Self {
+        match value {
+            engine::ManifestBasicValidatorError::ManifestIdValidationError(
+                error,
+            ) => Self::ManifestIdValidationError {
+                error: error.into(),
+            },
+        }
+    }
+}
+
+impl From<engine::ManifestIdValidationError> for ManifestIdValidationError {
+    fn from(value: engine::ManifestIdValidationError) -> Self {
+        match value {
+            engine::ManifestIdValidationError::BucketNotFound(bucket) => {
+                Self::BucketNotFound {
+                    bucket: bucket.into(),
+                }
+            }
+            engine::ManifestIdValidationError::ProofNotFound(proof) => {
+                Self::ProofNotFound {
+                    proof: proof.into(),
+                }
+            }
+            engine::ManifestIdValidationError::BucketLocked(bucket) => {
+                Self::BucketLocked {
+                    bucket: bucket.into(),
+                }
+            }
+            engine::ManifestIdValidationError::AddressReservationNotFound(
+                address_reservation,
+            ) => Self::AddressReservationNotFound {
+                address_reservation: address_reservation.into(),
+            },
+            engine::ManifestIdValidationError::AddressNotFound(
+                named_address,
+            ) => Self::AddressNotFound {
+                named_address_id: named_address.0,
+            },
+            engine::ManifestIdValidationError::IntentNotFound(named_intent) => {
+                Self::IntentNotFound {
+                    named_intent_id: named_intent.0,
+                }
+            }
+        }
+    }
+}
+
+impl From<engine::EncodeError> for EncodeError {
+    fn from(value: engine::EncodeError) -> Self {
+        match value {
+            engine::EncodeError::MaxDepthExceeded(max) => {
+                Self::MaxDepthExceeded { max: max as u64 }
+            }
+            engine::EncodeError::SizeTooLarge {
+                actual,
+                max_allowed,
+            } => Self::SizeTooLarge {
+                actual: actual as u64,
+                max_allowed: max_allowed as u64,
+            },
+            engine::EncodeError::MismatchingArrayElementValueKind {
+                element_value_kind,
+                actual_value_kind,
+            } => Self::MismatchingArrayElementValueKind {
+                element_value_kind,
+                actual_value_kind,
+            },
+            engine::EncodeError::MismatchingMapKeyValueKind {
+                key_value_kind,
+                actual_value_kind,
+            } => Self::MismatchingMapKeyValueKind {
+                key_value_kind,
+                actual_value_kind,
+            },
+            engine::EncodeError::MismatchingMapValueValueKind {
+                value_value_kind,
+                actual_value_kind,
+            } => Self::MismatchingMapValueValueKind {
+                value_value_kind,
+                actual_value_kind,
+            },
+        }
+    }
+}
+
+impl From<engine::DecodeError> for DecodeError {
+    fn from(value: engine::DecodeError) -> Self {
+        match value {
+            engine::DecodeError::ExtraTrailingBytes(count) => {
+                Self::ExtraTrailingBytes {
+                    count: count as u64,
+                }
+            }
+            engine::DecodeError::BufferUnderflow {
+                required,
+                remaining,
+            } => Self::BufferUnderflow {
+                required: required as u64,
+                remaining: remaining as u64,
+            },
+            engine::DecodeError::UnexpectedPayloadPrefix {
+                expected,
+                actual,
+            } => Self::UnexpectedPayloadPrefix { expected, actual },
+            engine::DecodeError::UnexpectedValueKind { expected, actual } => {
+                Self::UnexpectedValueKind { expected, actual }
+            }
+            engine::DecodeError::UnexpectedCustomValueKind { actual } => {
+                Self::UnexpectedCustomValueKind { actual }
+            }
+            engine::DecodeError::UnexpectedSize { expected, actual } => {
+                Self::UnexpectedSize {
+                    expected: expected as u64,
+                    actual: actual as u64,
+                }
+            }
+            engine::DecodeError::UnexpectedDiscriminator {
+                expected,
+                actual,
+            } => Self::UnexpectedDiscriminator { expected, actual },
+            engine::DecodeError::UnknownValueKind(value_kind) => {
+                Self::UnknownValueKind { value_kind }
+            }
+            engine::DecodeError::UnknownDiscriminator(discriminator) => {
+                Self::UnknownDiscriminator { discriminator }
+            }
+            engine::DecodeError::InvalidBool(value) => {
+                Self::InvalidBool { value }
+            }
+            engine::DecodeError::InvalidUtf8 => Self::InvalidUtf8,
+            engine::DecodeError::InvalidSize => Self::InvalidSize,
+            engine::DecodeError::MaxDepthExceeded(max) => {
+                Self::MaxDepthExceeded { max: max as u64 }
+            }
+            engine::DecodeError::DuplicateKey => Self::DuplicateKey,
+            engine::DecodeError::InvalidCustomValue => Self::InvalidCustomValue,
+        }
+    }
+}
+
+impl From<engine::PrepareError> for PrepareError {
+    fn from(value: engine::PrepareError) -> Self {
+        match value {
+            engine::PrepareError::TransactionTypeNotSupported => {
+                Self::TransactionTypeNotSupported
+            }
+            engine::PrepareError::TransactionTooLarge => {
+                Self::TransactionTooLarge
+            }
+            engine::PrepareError::DecodeError(error) => Self::DecodeError {
+                error: error.into(),
+            },
+            engine::PrepareError::EncodeError(error) => Self::EncodeError {
+                error: error.into(),
+            },
+            engine::PrepareError::TooManyValues {
+                value_type,
+                actual,
+                max,
+            } => Self::TooManyValues {
+                value_type: value_type.into(),
+                actual: actual as u64,
+                max: max as u64,
+            },
+            engine::PrepareError::LengthOverflow => Self::LengthOverflow,
+            engine::PrepareError::UnexpectedTransactionDiscriminator {
+                actual,
+            } => Self::UnexpectedTransactionDiscriminator { actual },
+        }
+    }
+}
+
+impl From<engine::ValueType> for PreparationValueType {
+    fn from(value: engine::ValueType) -> Self {
+        match value {
+            engine::ValueType::Blob => Self::Blob,
+            engine::ValueType::Subintent => Self::Subintent,
+            engine::ValueType::ChildSubintentSpecifier => {
+                Self::ChildSubintentSpecifier
+            }
+            engine::ValueType::SubintentSignatureBatches => {
+                Self::SubintentSignatureBatches
+            }
+        }
+    }
+}
+
+impl From<engine::HeaderValidationError> for HeaderValidationError {
+    fn from(value: engine::HeaderValidationError) -> Self {
+        match value {
+            engine::HeaderValidationError::UnknownVersion(version) => {
+                Self::UnknownVersion { version }
+            }
+            engine::HeaderValidationError::InvalidEpochRange => {
+                Self::InvalidEpochRange
+            }
+            engine::HeaderValidationError::InvalidTimestampRange => {
+                Self::InvalidTimestampRange
+            }
+            engine::HeaderValidationError::InvalidNetwork => {
+                Self::InvalidNetwork
+            }
+            engine::HeaderValidationError::InvalidCostUnitLimit => {
+                Self::InvalidCostUnitLimit
+            }
+            engine::HeaderValidationError::InvalidTip => Self::InvalidTip,
+            engine::HeaderValidationError::NoValidEpochRangeAcrossAllIntents => {
+                Self::NoValidEpochRangeAcrossAllIntents
+            }
+            engine::HeaderValidationError::NoValidTimestampRangeAcrossAllIntents => {
+                Self::NoValidTimestampRangeAcrossAllIntents
+            }
+        }
+    }
+}
+
+impl From<engine::ManifestValidationError> for ManifestValidationError {
+    fn from(value: engine::ManifestValidationError) -> Self {
+        match value {
+            engine::ManifestValidationError::DuplicateBlob(blob) => {
+                Self::DuplicateBlob { blob: blob.into() }
+            }
+            engine::ManifestValidationError::BlobNotRegistered(blob) => {
+                Self::BlobNotRegistered { blob: blob.into() }
+            }
+            engine::ManifestValidationError::BucketNotYetCreated(bucket) => {
+                Self::BucketNotYetCreated {
+                    bucket: bucket.into(),
+                }
+            }
+            engine::ManifestValidationError::BucketAlreadyUsed(
+                bucket,
+                used_by,
+            ) => Self::BucketAlreadyUsed {
+                bucket: bucket.into(),
+                used_by,
+            },
+            engine::ManifestValidationError::BucketConsumedWhilstLockedByProof(
+                bucket,
+                locked_by,
+            ) => Self::BucketConsumedWhilstLockedByProof {
+                bucket: bucket.into(),
+                locked_by,
+            },
+            engine::ManifestValidationError::ProofNotYetCreated(proof) => {
+                Self::ProofNotYetCreated {
+                    proof: proof.into(),
+                }
+            }
+            engine::ManifestValidationError::ProofAlreadyUsed(
+                proof,
+                used_by,
+            ) => Self::ProofAlreadyUsed {
+                proof: proof.into(),
+                used_by,
+            },
+            engine::ManifestValidationError::AddressReservationNotYetCreated(
+                address_reservation,
+            ) => Self::AddressReservationNotYetCreated {
+                address_reservation: address_reservation.into(),
+            },
+            engine::ManifestValidationError::AddressReservationAlreadyUsed(
+                address_reservation,
+                used_by,
+            ) => Self::AddressReservationAlreadyUsed {
+                address_reservation: address_reservation.into(),
+                used_by,
+            },
+            engine::ManifestValidationError::NamedAddressNotYetCreated(
+                named_address,
+            ) => Self::NamedAddressNotYetCreated {
+                named_address_id: named_address.0,
+            },
+            engine::ManifestValidationError::ChildIntentNotRegistered(
+                named_intent,
+            ) => Self::ChildIntentNotRegistered {
+                named_intent_id: named_intent.0,
+            },
+            engine::ManifestValidationError::DanglingBucket(
+                bucket,
+                created_by,
+            ) => Self::DanglingBucket {
+                bucket: bucket.into(),
+                created_by,
+            },
+            engine::ManifestValidationError::DanglingAddressReservation(
+                address_reservation,
+                created_by,
+            ) => Self::DanglingAddressReservation {
+                address_reservation: address_reservation.into(),
+                created_by,
+            },
+            engine::ManifestValidationError::ArgsEncodeError(error) => {
+                Self::ArgsEncodeError {
+                    error: format!("{error:?}"),
+                }
+            }
+            engine::ManifestValidationError::ArgsDecodeError(error) => {
+                Self::ArgsDecodeError {
+                    error: format!("{error:?}"),
+                }
+            }
+            engine::ManifestValidationError::InstructionNotSupportedInTransactionIntent => {
+                Self::InstructionNotSupportedInTransactionIntent
+            }
+            engine::ManifestValidationError::SubintentDoesNotEndWithYieldToParent => {
+                Self::SubintentDoesNotEndWithYieldToParent
+            }
+            engine::ManifestValidationError::ProofCannotBePassedToAnotherIntent => {
+                Self::ProofCannotBePassedToAnotherIntent
+            }
+            engine::ManifestValidationError::TooManyInstructions => {
+                Self::TooManyInstructions
+            }
+            engine::ManifestValidationError::InvalidResourceConstraint => {
+                Self::InvalidResourceConstraint
+            }
+            engine::ManifestValidationError::InstructionFollowingNextCallAssertionWasNotInvocation => {
+                Self::InstructionFollowingNextCallAssertionWasNotInvocation
+            }
+            engine::ManifestValidationError::ManifestEndedWhilstExpectingNextCallAssertion => {
+                Self::ManifestEndedWhilstExpectingNextCallAssertion
+            }
+        }
+    }
+}
+
+impl From<toolkit::ManifestAnalysisError> for ManifestAnalysisError {
+    fn from(value: toolkit::ManifestAnalysisError) -> Self {
+        match value {
+            toolkit::ManifestAnalysisError::TraverserError(error) => {
+                match *error {
+                    toolkit::TraverserError::InvalidNamedAddress => {
+                        Self::InvalidNamedAddress
+                    }
+                    toolkit::TraverserError::StaticResourceMovementsError(
+                        error,
+                    ) => (*error).into(),
+                    toolkit::TraverserError::TypedManifestNativeInvocationError(
+                        error,
+                    ) => Self::TypedManifestNativeInvocationError {
+                        error: format!("{error:?}"),
+                    },
+                }
+            }
+            toolkit::ManifestAnalysisError::StaticResourceMovementsError(
+                error,
+            ) => (*error).into(),
+            toolkit::ManifestAnalysisError::NotACommitSuccessReceipt => {
+                Self::NotACommitSuccessReceipt
+            }
+        }
+    }
+}
+
+impl From<engine::StaticResourceMovementsError> for ManifestAnalysisError {
+    fn from(value: engine::StaticResourceMovementsError) -> Self {
+        match value {
+            engine::StaticResourceMovementsError::ManifestValidationError(
+                error,
+            ) => Self::ManifestValidationError {
+                error: error.into(),
+            },
+            error => Self::StaticResourceMovementsError {
+                error: format!("{error:?}"),
+            },
+        }
+    }
+}
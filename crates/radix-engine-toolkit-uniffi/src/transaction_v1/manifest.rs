@@ -115,10 +115,19 @@ impl TransactionManifestV1 {
     }
 
     pub fn statically_analyze(&self, network_id: u8) -> Result<StaticAnalysis> {
+        self.statically_analyze_with_classifiers(network_id, Vec::new())
+    }
+
+    pub fn statically_analyze_with_classifiers(
+        &self,
+        network_id: u8,
+        classifiers: Vec<Box<dyn ManifestClassifier>>,
+    ) -> Result<StaticAnalysis> {
         let native = self.clone().to_native();
+        let registry = manifest_analyzer_registry(classifiers, network_id);
         let static_analysis =
-            toolkit::functions::transaction_v1::manifest::statically_analyze(
-                &native,
+            toolkit::functions::transaction_v1::manifest::statically_analyze_with_registry(
+                &native, &registry,
             )?;
         Ok(StaticAnalysis::from_native(static_analysis, network_id))
     }
@@ -127,8 +136,22 @@ impl TransactionManifestV1 {
         &self,
         network_id: u8,
         toolkit_receipt: String,
+    ) -> Result<DynamicAnalysis> {
+        self.dynamically_analyze_with_classifiers(
+            network_id,
+            toolkit_receipt,
+            Vec::new(),
+        )
+    }
+
+    pub fn dynamically_analyze_with_classifiers(
+        &self,
+        network_id: u8,
+        toolkit_receipt: String,
+        classifiers: Vec<Box<dyn ManifestClassifier>>,
     ) -> Result<DynamicAnalysis> {
         let native = self.clone().to_native();
+        let registry = manifest_analyzer_registry(classifiers, network_id);
         let network_definition =
             engine::NetworkDefinition::from_network_id(network_id);
         let receipt = serde_json::from_str::<
@@ -143,12 +166,42 @@ impl TransactionManifestV1 {
                 .ok()
         })
         .ok_or(RadixEngineToolkitError::InvalidReceipt)?;
-        toolkit::functions::transaction_v1::manifest::dynamically_analyze(
-            &native, receipt,
+        toolkit::functions::transaction_v1::manifest::dynamically_analyze_with_registry(
+            &native, receipt, &registry,
         )
         .map_err(|_| RadixEngineToolkitError::InvalidReceipt)
         .map(|summary| DynamicAnalysis::from_native(summary, network_id))
     }
+
+    pub fn estimate_fee(
+        &self,
+        network_id: u8,
+        toolkit_receipt: String,
+        parameters: FeeEstimationParameters,
+    ) -> Result<FeeEstimate> {
+        let native = self.clone().to_native();
+        let network_definition =
+            engine::NetworkDefinition::from_network_id(network_id);
+        let receipt = serde_json::from_str::<
+            SerializableToolkitTransactionReceipt,
+        >(&toolkit_receipt)
+        .ok()
+        .and_then(|receipt| {
+            receipt
+                .into_runtime_receipt(&engine::AddressBech32Decoder::new(
+                    &network_definition,
+                ))
+                .ok()
+        })
+        .ok_or(RadixEngineToolkitError::InvalidReceipt)?;
+        toolkit::functions::transaction_v1::manifest::estimate_fee(
+            &native,
+            receipt,
+            &parameters.to_native()?,
+        )
+        .map_err(RadixEngineToolkitError::from)
+        .map(FeeEstimate::from_native)
+    }
 }
 
 impl TransactionManifestV1 {
@@ -88,6 +88,36 @@ pub fn public_key_fingerprint_v1_to_vec(
     value.bytes.0
 }
 
+#[uniffi::export]
+pub fn message_v1_encrypt(
+    plaintext: PlainTextMessageV1,
+    recipients: Vec<PublicKey>,
+) -> Result<MessageV1> {
+    let plaintext = engine::PlaintextMessageV1::from(plaintext);
+    let recipients = recipients
+        .into_iter()
+        .map(engine::PublicKey::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    let encrypted = toolkit::functions::message::encrypt_message_v1(
+        &plaintext,
+        &recipients,
+    )?;
+    Ok(MessageV1::Encrypted {
+        value: encrypted.into(),
+    })
+}
+
+#[uniffi::export]
+pub fn message_v1_decrypt(
+    message: EncryptedMessageV1,
+    private_key: Arc<PrivateKey>,
+) -> Result<PlainTextMessageV1> {
+    let message = engine::EncryptedMessageV1::try_from(message)?;
+    toolkit::functions::message::decrypt_message_v1(&message, &private_key.0)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
 //==================
 // From Trait Impls
 //==================
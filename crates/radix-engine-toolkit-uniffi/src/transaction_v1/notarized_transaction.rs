@@ -110,6 +110,19 @@ impl NotarizedTransactionV1 {
         .map_err(Into::into)
         .map(|value| value.into_iter().map(Into::into).collect())
     }
+
+    pub fn verify_signatures(&self) -> Result<Vec<SignatureVerification>> {
+        toolkit::functions::transaction_v1::notarized_transaction::verify_signatures(
+            &engine::NotarizedTransactionV1::try_from(self.clone())?,
+        )
+        .map_err(Into::into)
+        .map(|value| {
+            value
+                .into_iter()
+                .map(SignatureVerification::from_native)
+                .collect()
+        })
+    }
 }
 
 impl From<engine::NotarizedTransactionV1> for NotarizedTransactionV1 {
@@ -98,6 +98,19 @@ impl SignedTransactionIntentV1 {
         )
         .map_err(Into::into)
     }
+
+    pub fn verify_signatures(&self) -> Result<Vec<SignatureVerification>> {
+        toolkit::functions::transaction_v1::signed_intent::verify_signatures(
+            &engine::SignedIntentV1::try_from(self.clone())?,
+        )
+        .map_err(Into::into)
+        .map(|value| {
+            value
+                .into_iter()
+                .map(SignatureVerification::from_native)
+                .collect()
+        })
+    }
 }
 
 impl From<engine::SignedIntentV1> for SignedTransactionIntentV1 {
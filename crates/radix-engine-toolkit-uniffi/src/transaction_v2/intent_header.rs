@@ -68,3 +68,142 @@ impl From<engine::IntentHeaderV2> for IntentHeaderV2 {
         }
     }
 }
+
+#[derive(Clone, Debug, Enum)]
+pub enum IntentHeaderValidationError {
+    EpochRangeOverflows {
+        start_epoch_inclusive: u64,
+        epoch_count: u64,
+    },
+    EpochRangeIsEmpty {
+        start_epoch_inclusive: u64,
+        end_epoch_exclusive: u64,
+    },
+    EpochRangeTooLong {
+        epoch_range: u64,
+        max_epoch_range: u64,
+    },
+    TimestampRangeIsEmpty {
+        min_inclusive: i64,
+        max_exclusive: i64,
+    },
+    NotYetValid {
+        current_epoch: u64,
+        start_epoch_inclusive: u64,
+    },
+    Expired {
+        current_epoch: u64,
+        end_epoch_exclusive: u64,
+    },
+    SubintentEpochRangeDoesNotOverlap {
+        subintent_index: u64,
+    },
+    SubintentTimestampRangeDoesNotOverlap {
+        subintent_index: u64,
+    },
+}
+
+impl From<toolkit::functions::transaction_v2::intent_header::IntentHeaderValidationError>
+    for IntentHeaderValidationError
+{
+    fn from(
+        value: toolkit::functions::transaction_v2::intent_header::IntentHeaderValidationError,
+    ) -> Self {
+        use toolkit::functions::transaction_v2::intent_header::IntentHeaderValidationError as Native;
+        match value {
+            Native::EpochRangeOverflows {
+                start_epoch_inclusive,
+                epoch_count,
+            } => Self::EpochRangeOverflows {
+                start_epoch_inclusive: start_epoch_inclusive.number(),
+                epoch_count,
+            },
+            Native::EpochRangeIsEmpty {
+                start_epoch_inclusive,
+                end_epoch_exclusive,
+            } => Self::EpochRangeIsEmpty {
+                start_epoch_inclusive: start_epoch_inclusive.number(),
+                end_epoch_exclusive: end_epoch_exclusive.number(),
+            },
+            Native::EpochRangeTooLong {
+                epoch_range,
+                max_epoch_range,
+            } => Self::EpochRangeTooLong {
+                epoch_range,
+                max_epoch_range,
+            },
+            Native::TimestampRangeIsEmpty {
+                min_inclusive,
+                max_exclusive,
+            } => Self::TimestampRangeIsEmpty {
+                min_inclusive: min_inclusive.seconds_since_unix_epoch,
+                max_exclusive: max_exclusive.seconds_since_unix_epoch,
+            },
+            Native::NotYetValid {
+                current_epoch,
+                start_epoch_inclusive,
+            } => Self::NotYetValid {
+                current_epoch: current_epoch.number(),
+                start_epoch_inclusive: start_epoch_inclusive.number(),
+            },
+            Native::Expired {
+                current_epoch,
+                end_epoch_exclusive,
+            } => Self::Expired {
+                current_epoch: current_epoch.number(),
+                end_epoch_exclusive: end_epoch_exclusive.number(),
+            },
+            // The index of the subintent is surfaced by the toolkit error.
+            Native::InvalidSubintentHeader { error, .. } => (*error).into(),
+            Native::SubintentEpochRangeDoesNotOverlap { subintent_index } => {
+                Self::SubintentEpochRangeDoesNotOverlap {
+                    subintent_index: subintent_index as u64,
+                }
+            }
+            Native::SubintentTimestampRangeDoesNotOverlap {
+                subintent_index,
+            } => Self::SubintentTimestampRangeDoesNotOverlap {
+                subintent_index: subintent_index as u64,
+            },
+        }
+    }
+}
+
+// ==================
+// Exposed "Methods"
+// ==================
+
+#[uniffi::export]
+pub fn intent_header_v2_new(
+    network_id: u8,
+    current_epoch: u64,
+    epoch_count: u64,
+    min_proposer_timestamp_inclusive: Option<i64>,
+    max_proposer_timestamp_exclusive: Option<i64>,
+    intent_discriminator: u64,
+) -> Result<IntentHeaderV2> {
+    toolkit::functions::transaction_v2::intent_header::new(
+        network_id,
+        engine::Epoch::of(current_epoch),
+        epoch_count,
+        min_proposer_timestamp_inclusive.map(engine::Instant::new),
+        max_proposer_timestamp_exclusive.map(engine::Instant::new),
+        intent_discriminator,
+        &engine::TransactionValidationConfigV1::cuttlefish(),
+    )
+    .map(Into::into)
+    .map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn intent_header_v2_validate(
+    header: IntentHeaderV2,
+    current_epoch: Option<u64>,
+) -> Result<()> {
+    toolkit::functions::transaction_v2::intent_header::validate(
+        &header.try_into()?,
+        current_epoch.map(engine::Epoch::of),
+        &engine::TransactionValidationConfigV1::cuttlefish(),
+    )
+    .map_err(Into::into)
+}
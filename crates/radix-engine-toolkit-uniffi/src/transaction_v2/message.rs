@@ -93,6 +93,36 @@ pub fn public_key_fingerprint_v2_to_vec(
     value.bytes.0
 }
 
+#[uniffi::export]
+pub fn message_v2_encrypt(
+    plaintext: PlainTextMessageV2,
+    recipients: Vec<PublicKey>,
+) -> Result<MessageV2> {
+    let plaintext = engine::PlaintextMessageV1::from(plaintext);
+    let recipients = recipients
+        .into_iter()
+        .map(engine::PublicKey::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    let encrypted = toolkit::functions::message::encrypt_message_v2(
+        &plaintext,
+        &recipients,
+    )?;
+    Ok(MessageV2::Encrypted {
+        value: encrypted.into(),
+    })
+}
+
+#[uniffi::export]
+pub fn message_v2_decrypt(
+    message: EncryptedMessageV2,
+    private_key: Arc<PrivateKey>,
+) -> Result<PlainTextMessageV2> {
+    let message = engine::EncryptedMessageV2::try_from(message)?;
+    toolkit::functions::message::decrypt_message_v2(&message, &private_key.0)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
 //==================
 // From Trait Impls
 //==================
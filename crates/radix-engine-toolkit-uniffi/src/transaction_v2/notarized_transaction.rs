@@ -116,6 +116,19 @@ impl NotarizedTransactionV2 {
         .map_err(Into::into)
         .map(|value| value.into_iter().map(Into::into).collect())
     }
+
+    pub fn verify_signatures(&self) -> Result<Vec<SignatureVerification>> {
+        toolkit::functions::transaction_v2::notarized_transaction::verify_signatures(
+            &engine::NotarizedTransactionV2::try_from(self.clone())?,
+        )
+        .map_err(Into::into)
+        .map(|value| {
+            value
+                .into_iter()
+                .map(SignatureVerification::from_native)
+                .collect()
+        })
+    }
 }
 
 impl TryFrom<engine::NotarizedTransactionV2> for NotarizedTransactionV2 {
@@ -65,6 +65,20 @@ impl PartialTransactionV2 {
             .map_err(Into::into)
         })
     }
+
+    pub fn validate_intent_headers(
+        &self,
+        current_epoch: Option<u64>,
+    ) -> Result<()> {
+        engine::PartialTransactionV2::try_from(self.clone()).and_then(|intent| {
+            toolkit::functions::transaction_v2::partial_transaction::validate_intent_headers(
+                &intent,
+                current_epoch.map(engine::Epoch::of),
+                &engine::TransactionValidationConfigV1::cuttlefish(),
+            )
+            .map_err(Into::into)
+        })
+    }
 }
 
 impl TryFrom<engine::PartialTransactionV2> for PartialTransactionV2 {
@@ -95,6 +95,19 @@ impl SignedTransactionIntentV2 {
             .map_err(Into::into)
         })
     }
+
+    pub fn verify_signatures(&self) -> Result<Vec<SignatureVerification>> {
+        toolkit::functions::transaction_v2::signed_transaction_intent::verify_signatures(
+            &engine::SignedTransactionIntentV2::try_from(self.clone())?,
+        )
+        .map_err(Into::into)
+        .map(|value| {
+            value
+                .into_iter()
+                .map(SignatureVerification::from_native)
+                .collect()
+        })
+    }
 }
 
 impl TryFrom<engine::SignedTransactionIntentV2> for SignedTransactionIntentV2 {
@@ -92,6 +92,19 @@ impl SignedPartialTransactionV2 {
         )
         .map_err(Into::into)
     }
+
+    pub fn verify_signatures(&self) -> Result<Vec<SignatureVerification>> {
+        toolkit::functions::transaction_v2::signed_partial_transaction::verify_signatures(
+            &engine::SignedPartialTransactionV2::try_from(self.clone())?,
+        )
+        .map_err(Into::into)
+        .map(|value| {
+            value
+                .into_iter()
+                .map(SignatureVerification::from_native)
+                .collect()
+        })
+    }
 }
 
 impl TryFrom<engine::SignedPartialTransactionV2>
@@ -83,6 +83,20 @@ impl TransactionIntentV2 {
             .map_err(Into::into)
         })
     }
+
+    pub fn validate_intent_headers(
+        &self,
+        current_epoch: Option<u64>,
+    ) -> Result<()> {
+        engine::TransactionIntentV2::try_from(self.clone()).and_then(|intent| {
+            toolkit::functions::transaction_v2::transaction_intent::validate_intent_headers(
+                &intent,
+                current_epoch.map(engine::Epoch::of),
+                &engine::TransactionValidationConfigV1::cuttlefish(),
+            )
+            .map_err(Into::into)
+        })
+    }
 }
 
 impl TryFrom<engine::TransactionIntentV2> for TransactionIntentV2 {
@@ -0,0 +1,613 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::*;
+
+use radix_engine_toolkit_uniffi::prelude::*;
+use sbor::generate_full_schema_from_single_type;
+use scrypto::prelude::ScryptoSbor;
+
+#[test]
+fn non_fungibles_can_be_minted_and_updated_through_a_named_resource_address(
+) -> Result<()> {
+    // Arrange
+    let builder = ManifestV2Builder::new(1)
+        .allocate_global_address(
+            Arc::new(Address::from_node_id(engine::RESOURCE_PACKAGE, 1)),
+            engine::NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_owned(),
+            ManifestBuilderAddressReservation {
+                name: "reservation".to_owned(),
+            },
+            ManifestBuilderNamedAddress {
+                name: "resource".to_owned(),
+            },
+        )?
+        .create_non_fungible_resource_manager(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            true,
+            non_fungible_data_schema(),
+            None,
+            non_fungible_resource_roles(),
+            metadata_module_config(),
+            Some(ManifestBuilderAddressReservation {
+                name: "reservation".to_owned(),
+            }),
+        )?;
+
+    // Act
+    let manifest = builder
+        .mint_non_fungible(
+            named_address("resource"),
+            vec![ManifestBuilderNonFungibleEntry {
+                id: NonFungibleLocalId::Integer { value: 1 },
+                data: non_fungible_data("Radix"),
+            }],
+        )?
+        .update_non_fungible_data(
+            named_address("resource"),
+            NonFungibleLocalId::Integer { value: 1 },
+            "name".to_owned(),
+            ManifestBuilderNonFungibleData::Value {
+                value: ManifestBuilderValue::StringValue {
+                    value: "Radix DLT".to_owned(),
+                },
+            },
+        )?
+        .build_with_options(ManifestBuildOptions {
+            lock_fee: None,
+            worktop_sweep: Some(ManifestBuilderWorktopSweep {
+                account_address: account_address(),
+                deposit_mode: ManifestBuilderDepositMode::Deposit,
+            }),
+            forbid_unconsumed_buckets_and_proofs: true,
+        })?;
+
+    // Assert
+    manifest.statically_validate()?;
+    let instructions = manifest.instructions().instructions_list();
+    let named_address_methods = instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            InstructionV2::CallMethod {
+                address: ManifestAddress::Named { .. },
+                method_name,
+                ..
+            } => Some(method_name.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        named_address_methods,
+        vec![
+            engine::NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT,
+            engine::NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn ruid_non_fungibles_can_be_minted_from_programmatic_json_data() -> Result<()>
+{
+    // Arrange
+    let builder = ManifestV2Builder::new(1)
+        .allocate_global_address(
+            Arc::new(Address::from_node_id(engine::RESOURCE_PACKAGE, 1)),
+            engine::NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_owned(),
+            ManifestBuilderAddressReservation {
+                name: "reservation".to_owned(),
+            },
+            ManifestBuilderNamedAddress {
+                name: "resource".to_owned(),
+            },
+        )?
+        .create_non_fungible_resource_manager(
+            OwnerRole::None,
+            NonFungibleIdType::Ruid,
+            true,
+            non_fungible_data_schema(),
+            None,
+            non_fungible_resource_roles(),
+            metadata_module_config(),
+            Some(ManifestBuilderAddressReservation {
+                name: "reservation".to_owned(),
+            }),
+        )?;
+
+    // Act
+    let manifest = builder
+        .mint_ruid_non_fungible(
+            named_address("resource"),
+            vec![ManifestBuilderNonFungibleData::ProgrammaticJson {
+                value: r#"
+                {
+                    "kind": "Tuple",
+                    "fields": [{ "kind": "String", "value": "Radix" }]
+                }
+                "#
+                .to_owned(),
+            }],
+        )?
+        .build_with_options(ManifestBuildOptions {
+            lock_fee: None,
+            worktop_sweep: Some(ManifestBuilderWorktopSweep {
+                account_address: account_address(),
+                deposit_mode: ManifestBuilderDepositMode::Deposit,
+            }),
+            forbid_unconsumed_buckets_and_proofs: true,
+        })?;
+
+    // Assert
+    manifest.statically_validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn v1_builder_creates_and_mints_non_fungibles_of_static_resources() -> Result<()>
+{
+    // Arrange
+    let resource_address =
+        Arc::new(Address::from_node_id(engine::ACCOUNT_OWNER_BADGE, 1));
+    let builder = ManifestV1Builder::new()
+        .create_non_fungible_resource_manager(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            true,
+            non_fungible_data_schema(),
+            Some(vec![ManifestBuilderNonFungibleEntry {
+                id: NonFungibleLocalId::Integer { value: 1 },
+                data: non_fungible_data("Radix"),
+            }]),
+            non_fungible_resource_roles(),
+            metadata_module_config(),
+            None,
+        )?;
+
+    // Act
+    let manifest = builder
+        .mint_non_fungible(
+            ManifestBuilderAddress::Static {
+                value: resource_address.clone(),
+            },
+            vec![ManifestBuilderNonFungibleEntry {
+                id: NonFungibleLocalId::Integer { value: 2 },
+                data: non_fungible_data("Radix"),
+            }],
+        )?
+        .update_non_fungible_data(
+            ManifestBuilderAddress::Static {
+                value: resource_address,
+            },
+            NonFungibleLocalId::Integer { value: 2 },
+            "name".to_owned(),
+            ManifestBuilderNonFungibleData::Value {
+                value: ManifestBuilderValue::StringValue {
+                    value: "Radix DLT".to_owned(),
+                },
+            },
+        )?
+        .build_with_options(
+            1,
+            ManifestBuildOptions {
+                lock_fee: None,
+                worktop_sweep: Some(ManifestBuilderWorktopSweep {
+                    account_address: account_address(),
+                    deposit_mode: ManifestBuilderDepositMode::Deposit,
+                }),
+                forbid_unconsumed_buckets_and_proofs: true,
+            },
+        )?;
+
+    // Assert
+    manifest.statically_validate(1)?;
+
+    Ok(())
+}
+
+#[test]
+fn minting_rejects_static_addresses_that_are_not_resource_addresses(
+) -> Result<()> {
+    // Arrange
+    let builder = ManifestV2Builder::new(1);
+
+    // Act
+    let result = builder.mint_fungible(
+        ManifestBuilderAddress::Static {
+            value: account_address(),
+        },
+        Decimal::new("1".to_owned())?,
+    );
+
+    // Assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn minting_rejects_duplicate_non_fungible_local_ids() -> Result<()> {
+    // Arrange
+    let resource_address =
+        Arc::new(Address::from_node_id(engine::ACCOUNT_OWNER_BADGE, 1));
+    let entries = vec![
+        ManifestBuilderNonFungibleEntry {
+            id: NonFungibleLocalId::Integer { value: 1 },
+            data: non_fungible_data("Radix"),
+        },
+        ManifestBuilderNonFungibleEntry {
+            id: NonFungibleLocalId::Integer { value: 1 },
+            data: non_fungible_data("Radix DLT"),
+        },
+    ];
+
+    // Act
+    let mint_result = ManifestV2Builder::new(1).mint_non_fungible(
+        ManifestBuilderAddress::Static {
+            value: resource_address,
+        },
+        entries.clone(),
+    );
+    let create_result = ManifestV1Builder::new()
+        .create_non_fungible_resource_manager(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            true,
+            non_fungible_data_schema(),
+            Some(entries),
+            non_fungible_resource_roles(),
+            metadata_module_config(),
+            None,
+        );
+
+    // Assert
+    assert!(matches!(
+        mint_result,
+        Err(RadixEngineToolkitError::DuplicateNonFungibleLocalId { .. })
+    ));
+    assert!(matches!(
+        create_result,
+        Err(RadixEngineToolkitError::DuplicateNonFungibleLocalId { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn vault_administration_aliases_produce_valid_manifests() -> Result<()> {
+    // Arrange
+    let vault_address = vault_address();
+    let builder = ManifestV2Builder::new(1);
+
+    // Act
+    let manifest = builder
+        .vault_freeze(
+            vault_address.clone(),
+            VaultFreezeFlags {
+                withdraw: true,
+                deposit: false,
+                burn: true,
+            },
+        )?
+        .vault_unfreeze(
+            vault_address.clone(),
+            VaultFreezeFlags {
+                withdraw: false,
+                deposit: true,
+                burn: false,
+            },
+        )?
+        .vault_recall(vault_address, Decimal::new("10".to_owned())?)?
+        .build_with_options(ManifestBuildOptions {
+            lock_fee: None,
+            worktop_sweep: Some(ManifestBuilderWorktopSweep {
+                account_address: account_address(),
+                deposit_mode: ManifestBuilderDepositMode::Deposit,
+            }),
+            forbid_unconsumed_buckets_and_proofs: true,
+        })?;
+
+    // Assert
+    manifest.statically_validate()?;
+    let instructions = manifest.instructions().instructions_list();
+    let vault_method_args = instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            InstructionV2::CallDirectVaultMethod {
+                method_name, args, ..
+            } => Some((method_name.as_str(), args.to_native())),
+            _ => None,
+        })
+        .map(|(method_name, args)| args.map(|args| (method_name, args)))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        vault_method_args,
+        vec![
+            (
+                engine::VAULT_FREEZE_IDENT,
+                engine::to_manifest_value_and_unwrap!(
+                    &engine::VaultFreezeManifestInput {
+                        to_freeze: engine::VaultFreezeFlags::WITHDRAW
+                            | engine::VaultFreezeFlags::BURN
+                    }
+                )
+            ),
+            (
+                engine::VAULT_UNFREEZE_IDENT,
+                engine::to_manifest_value_and_unwrap!(
+                    &engine::VaultUnfreezeManifestInput {
+                        to_unfreeze: engine::VaultFreezeFlags::DEPOSIT
+                    }
+                )
+            ),
+            (
+                engine::VAULT_RECALL_IDENT,
+                engine::to_manifest_value_and_unwrap!(
+                    &engine::VaultRecallManifestInput {
+                        amount: engine::Decimal::from(10)
+                    }
+                )
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn non_fungible_vault_recall_alias_produces_a_valid_manifest() -> Result<()> {
+    // Arrange
+    let builder = ManifestV2Builder::new(1);
+
+    // Act
+    let manifest = builder
+        .vault_recall_non_fungibles(
+            non_fungible_vault_address(),
+            vec![NonFungibleLocalId::Integer { value: 1 }],
+        )?
+        .build_with_options(ManifestBuildOptions {
+            lock_fee: None,
+            worktop_sweep: Some(ManifestBuilderWorktopSweep {
+                account_address: account_address(),
+                deposit_mode: ManifestBuilderDepositMode::Deposit,
+            }),
+            forbid_unconsumed_buckets_and_proofs: true,
+        })?;
+
+    // Assert
+    manifest.statically_validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn resource_manager_burn_alias_consumes_the_bucket() -> Result<()> {
+    // Arrange
+    let xrd = Arc::new(Address::from_node_id(engine::XRD, 1));
+    let builder = ManifestV2Builder::new(1)
+        .account_withdraw(
+            account_address(),
+            xrd.clone(),
+            Decimal::new("10".to_owned())?,
+        )?
+        .take_all_from_worktop(xrd.clone(), "bucket".into())?;
+
+    // Act
+    let manifest = builder
+        .resource_manager_burn(xrd, "bucket".into())?
+        .build_with_options(ManifestBuildOptions {
+            lock_fee: None,
+            worktop_sweep: None,
+            forbid_unconsumed_buckets_and_proofs: true,
+        })?;
+
+    // Assert
+    manifest.statically_validate()?;
+    assert!(matches!(
+        manifest.instructions().instructions_list().last(),
+        Some(InstructionV2::CallMethod { method_name, .. })
+            if method_name == engine::RESOURCE_MANAGER_BURN_IDENT
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn resource_manager_set_role_calls_the_role_assignment_module() -> Result<()> {
+    // Arrange
+    let builder = ManifestV2Builder::new(1);
+
+    // Act
+    let manifest = builder
+        .resource_manager_set_role(
+            Arc::new(Address::from_node_id(engine::XRD, 1)),
+            ResourceManagerRoleKey::MinterUpdater,
+            AccessRule::deny_all(),
+        )?
+        .build();
+
+    // Assert
+    manifest.statically_validate()?;
+    let [InstructionV2::CallRoleAssignmentMethod {
+        address: ManifestAddress::Static { static_address },
+        method_name,
+        args,
+    }] = manifest
+        .instructions()
+        .instructions_list()
+        .try_into()
+        .unwrap()
+    else {
+        panic!("Expected a single role assignment method call")
+    };
+    assert_eq!(
+        static_address.as_str(),
+        Address::from_node_id(engine::XRD, 1).as_str()
+    );
+    assert_eq!(method_name, engine::ROLE_ASSIGNMENT_SET_IDENT);
+    assert_eq!(
+        args.to_native()?,
+        engine::to_manifest_value_and_unwrap!(
+            &engine::RoleAssignmentSetManifestInput {
+                module: engine::ObjectModuleId::Main.into(),
+                role_key: engine::RoleKey::new(engine::MINTER_UPDATER_ROLE),
+                rule: engine::AccessRule::DenyAll,
+            }
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn resource_manager_lock_role_denies_all_updates_of_the_role() -> Result<()> {
+    // Arrange
+    let builder = ManifestV1Builder::new();
+
+    // Act
+    let manifest = builder
+        .resource_manager_lock_role(
+            Arc::new(Address::from_node_id(engine::XRD, 1)),
+            ResourceManagerRoleKey::BurnerUpdater,
+        )?
+        .build(1);
+
+    // Assert
+    manifest.statically_validate(1)?;
+    let [InstructionV1::CallRoleAssignmentMethod {
+        method_name, args, ..
+    }] = manifest
+        .instructions()
+        .instructions_list()
+        .try_into()
+        .unwrap()
+    else {
+        panic!("Expected a single role assignment method call")
+    };
+    assert_eq!(method_name, engine::ROLE_ASSIGNMENT_SET_IDENT);
+    assert_eq!(
+        args.to_native()?,
+        engine::to_manifest_value_and_unwrap!(
+            &engine::RoleAssignmentSetManifestInput {
+                module: engine::ObjectModuleId::Main.into(),
+                role_key: engine::RoleKey::new(engine::BURNER_UPDATER_ROLE),
+                rule: engine::AccessRule::DenyAll,
+            }
+        )
+    );
+
+    Ok(())
+}
+
+#[derive(ScryptoSbor)]
+struct NonFungibleData {
+    name: String,
+}
+
+fn non_fungible_data_schema() -> NonFungibleDataSchema {
+    let (local_type_id, schema) = generate_full_schema_from_single_type::<
+        NonFungibleData,
+        engine::ScryptoCustomSchema,
+    >();
+    let engine::LocalTypeId::SchemaLocalIndex(index) = local_type_id else {
+        panic!("Expected the data to be described by the generated schema")
+    };
+    NonFungibleDataSchema {
+        schema: Schema {
+            local_type_id: LocalTypeId::SchemaLocalIndex {
+                value: index as u64,
+            },
+            schema: engine::scrypto_encode(schema.v1()).unwrap(),
+        },
+        mutable_fields: vec!["name".to_owned()],
+    }
+}
+
+fn non_fungible_data(name: &str) -> ManifestBuilderNonFungibleData {
+    ManifestBuilderNonFungibleData::Value {
+        value: ManifestBuilderValue::TupleValue {
+            fields: vec![ManifestBuilderValue::StringValue {
+                value: name.to_owned(),
+            }],
+        },
+    }
+}
+
+fn non_fungible_resource_roles() -> NonFungibleResourceRoles {
+    let allow_all = Some(ResourceManagerRole {
+        role: Some(AccessRule::allow_all()),
+        role_updater: None,
+    });
+    NonFungibleResourceRoles {
+        mint_roles: allow_all.clone(),
+        burn_roles: None,
+        freeze_roles: None,
+        recall_roles: None,
+        withdraw_roles: None,
+        deposit_roles: None,
+        non_fungible_data_update_roles: allow_all,
+    }
+}
+
+fn metadata_module_config() -> MetadataModuleConfig {
+    MetadataModuleConfig {
+        init: HashMap::new(),
+        roles: HashMap::new(),
+    }
+}
+
+fn named_address(name: &str) -> ManifestBuilderAddress {
+    ManifestBuilderAddress::Named {
+        value: ManifestBuilderNamedAddress {
+            name: name.to_owned(),
+        },
+    }
+}
+
+fn account_address() -> Arc<Address> {
+    let public_key =
+        engine::Ed25519PrivateKey::from_u64(1).unwrap().public_key();
+    Arc::new(Address::from_node_id(
+        engine::ComponentAddress::preallocated_account_from_public_key(
+            &public_key,
+        ),
+        1,
+    ))
+}
+
+fn vault_address() -> Arc<Address> {
+    Arc::new(Address::from_node_id(
+        engine::NodeId::new(
+            engine::EntityType::InternalFungibleVault as u8,
+            &[1; engine::NodeId::RID_LENGTH],
+        ),
+        1,
+    ))
+}
+
+fn non_fungible_vault_address() -> Arc<Address> {
+    Arc::new(Address::from_node_id(
+        engine::NodeId::new(
+            engine::EntityType::InternalNonFungibleVault as u8,
+            &[1; engine::NodeId::RID_LENGTH],
+        ),
+        1,
+    ))
+}
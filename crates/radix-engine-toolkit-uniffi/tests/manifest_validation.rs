@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_uniffi::prelude::*;
+
+#[test]
+fn manifest_validation_error_is_located_at_the_failing_instruction(
+) -> Result<()> {
+    // Arrange
+    let manifest = TransactionManifestV2::new(
+        InstructionsV2::from_instructions(
+            vec![
+                InstructionV2::DropAllProofs,
+                InstructionV2::ReturnToWorktop {
+                    bucket_id: ManifestBucket { value: 0 },
+                },
+            ],
+            0x01,
+        )?,
+        vec![],
+        vec![],
+    );
+
+    // Act
+    let result = manifest.statically_validate();
+
+    // Assert
+    let Err(RadixEngineToolkitError::ManifestValidationError {
+        instruction_index,
+        error,
+    }) = result
+    else {
+        panic!("Expected a manifest validation error: {result:?}")
+    };
+    assert_eq!(instruction_index, Some(1));
+    assert!(matches!(
+        error,
+        ManifestValidationError::BucketNotYetCreated {
+            bucket: ManifestBucket { value: 0 }
+        }
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn manifest_v1_validation_error_is_located_at_the_failing_instruction(
+) -> Result<()> {
+    // Arrange
+    let manifest = TransactionManifestV1::new(
+        InstructionsV1::from_instructions(
+            vec![
+                InstructionV1::DropAllProofs,
+                InstructionV1::ReturnToWorktop {
+                    bucket_id: ManifestBucket { value: 0 },
+                },
+            ],
+            0x01,
+        )?,
+        vec![],
+    );
+
+    // Act
+    let result = manifest.statically_validate(0x01);
+
+    // Assert
+    let Err(RadixEngineToolkitError::ManifestValidationError {
+        instruction_index,
+        error,
+    }) = result
+    else {
+        panic!("Expected a manifest validation error: {result:?}")
+    };
+    assert_eq!(instruction_index, Some(1));
+    assert!(matches!(
+        error,
+        ManifestValidationError::BucketNotYetCreated {
+            bucket: ManifestBucket { value: 0 }
+        }
+    ));
+
+    Ok(())
+}
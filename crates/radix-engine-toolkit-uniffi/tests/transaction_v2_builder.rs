@@ -17,6 +17,7 @@
 
 use std::sync::*;
 
+use radix_engine_toolkit_uniffi::builder::partial_transaction_v2_builder::PartialTransactionV2Builder;
 use radix_engine_toolkit_uniffi::builder::preview_transaction_v2_builder::PreviewTransactionV2Builder;
 use radix_engine_toolkit_uniffi::prelude::*;
 use scrypto_test::prelude::RawPreviewTransaction;
@@ -457,6 +458,502 @@ fn preview_v2_builder_produces_valid_preview_transactions() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn build_options_prepend_a_fee_lock_and_append_a_worktop_sweep() -> Result<()> {
+    // Arrange
+    let account = account_address();
+    let xrd = Arc::new(Address::from_node_id(engine::XRD, 1));
+    let builder = ManifestV2Builder::new(1)
+        .account_withdraw(
+            account.clone(),
+            xrd.clone(),
+            Decimal::new("10".to_owned())?,
+        )?
+        .take_all_from_worktop(xrd, "bucket".into())?;
+
+    // Act
+    let manifest = builder.build_with_options(ManifestBuildOptions {
+        lock_fee: Some(ManifestBuilderLockFee {
+            account_address: account.clone(),
+            amount: Decimal::new("1".to_owned())?,
+        }),
+        worktop_sweep: Some(ManifestBuilderWorktopSweep {
+            account_address: account,
+            deposit_mode: ManifestBuilderDepositMode::Deposit,
+        }),
+        forbid_unconsumed_buckets_and_proofs: true,
+    })?;
+
+    // Assert
+    let instructions = manifest.instructions().instructions_list();
+    assert_eq!(instructions.len(), 5);
+    assert!(matches!(
+        instructions.first(),
+        Some(InstructionV2::CallMethod { method_name, .. })
+            if method_name == engine::ACCOUNT_LOCK_FEE_IDENT
+    ));
+    assert!(matches!(
+        instructions.get(3),
+        Some(InstructionV2::ReturnToWorktop { .. })
+    ));
+    assert!(matches!(
+        instructions.last(),
+        Some(InstructionV2::CallMethod { method_name, .. })
+            if method_name == engine::ACCOUNT_DEPOSIT_BATCH_IDENT
+    ));
+    manifest.statically_validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn build_options_fee_lock_produces_valid_transactions_with_child_subintents(
+) -> Result<()> {
+    // Arrange
+    let [notary_private_key, signer_private_key] = private_keys();
+    let child_partial_transaction = SignedPartialTransactionV2Builder::new()
+        .intent_header(IntentHeaderV2 {
+            network_id: 0x01,
+            start_epoch_inclusive: 1,
+            end_epoch_exclusive: 10,
+            min_proposer_timestamp_inclusive: None,
+            max_proposer_timestamp_exclusive: None,
+            intent_discriminator: 100,
+        })
+        .manifest(ManifestV2Builder::new(1).yield_to_parent(vec![])?.build())
+        .prepare_for_signing()?
+        .sign_with_private_key(signer_private_key.clone())
+        .build();
+    let manifest = ManifestV2Builder::new(1)
+        .use_child(
+            child_partial_transaction.root_subintent_hash()?,
+            "subintent".into(),
+        )?
+        .yield_to_child("subintent".into(), vec![])?
+        .build_with_options(ManifestBuildOptions {
+            lock_fee: Some(ManifestBuilderLockFee {
+                account_address: account_address(),
+                amount: Decimal::new("1".to_owned())?,
+            }),
+            worktop_sweep: None,
+            forbid_unconsumed_buckets_and_proofs: true,
+        })?;
+
+    // Act
+    let transaction = TransactionV2Builder::new()
+        .transaction_header(TransactionHeaderV2 {
+            notary_public_key: notary_private_key.public_key(),
+            notary_is_signatory: true,
+            tip_basis_points: 0,
+        })
+        .intent_header(IntentHeaderV2 {
+            network_id: 0x01,
+            start_epoch_inclusive: 1,
+            end_epoch_exclusive: 10,
+            min_proposer_timestamp_inclusive: None,
+            max_proposer_timestamp_exclusive: None,
+            intent_discriminator: 100,
+        })
+        .add_child(child_partial_transaction.clone())
+        .manifest(manifest.clone())
+        .prepare_for_signing()?
+        .sign_with_private_key(signer_private_key.clone())
+        .notarize_with_private_key(notary_private_key)?;
+
+    // Assert
+    assert!(matches!(
+        manifest.instructions().instructions_list().first(),
+        Some(InstructionV2::CallMethod { method_name, .. })
+            if method_name == engine::ACCOUNT_LOCK_FEE_IDENT
+    ));
+    let notarized_transaction =
+        engine::NotarizedTransactionV2::try_from(transaction.as_ref().clone())?;
+    let validator = engine::TransactionValidator::new_with_latest_config(
+        &engine::NetworkDefinition::mainnet(),
+    );
+    validator
+        .validate_notarized_v2(
+            notarized_transaction
+                .prepare(&engine::PreparationSettings::latest())?,
+        )
+        .expect("Validation failed");
+
+    Ok(())
+}
+
+#[test]
+fn build_options_worktop_sweep_uses_the_requested_deposit_mode() -> Result<()> {
+    // Arrange
+    let account = account_address();
+    let xrd = Arc::new(Address::from_node_id(engine::XRD, 1));
+    let builder = ManifestV2Builder::new(1)
+        .account_withdraw(
+            account.clone(),
+            xrd.clone(),
+            Decimal::new("10".to_owned())?,
+        )?
+        .take_from_worktop(
+            xrd.clone(),
+            Decimal::new("5".to_owned())?,
+            "first_bucket".into(),
+        )?
+        .take_all_from_worktop(xrd, "second_bucket".into())?;
+
+    // Act
+    let manifest = builder.build_with_options(ManifestBuildOptions {
+        lock_fee: None,
+        worktop_sweep: Some(ManifestBuilderWorktopSweep {
+            account_address: account,
+            deposit_mode: ManifestBuilderDepositMode::TryDepositOrAbort {
+                authorized_depositor_badge: None,
+            },
+        }),
+        forbid_unconsumed_buckets_and_proofs: true,
+    })?;
+
+    // Assert
+    let instructions = manifest.instructions().instructions_list();
+    assert_eq!(
+        instructions
+            .iter()
+            .filter(|instruction| matches!(
+                instruction,
+                InstructionV2::ReturnToWorktop { .. }
+            ))
+            .count(),
+        2
+    );
+    assert!(matches!(
+        instructions.last(),
+        Some(InstructionV2::CallMethod { method_name, .. })
+            if method_name == engine::ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+    ));
+    manifest.statically_validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn build_options_reject_manifests_with_unconsumed_buckets() -> Result<()> {
+    // Arrange
+    let account = account_address();
+    let xrd = Arc::new(Address::from_node_id(engine::XRD, 1));
+    let builder = ManifestV2Builder::new(1)
+        .account_withdraw(account, xrd.clone(), Decimal::new("10".to_owned())?)?
+        .take_all_from_worktop(xrd, "bucket".into())?;
+
+    // Act
+    let result = builder.build_with_options(ManifestBuildOptions {
+        lock_fee: None,
+        worktop_sweep: None,
+        forbid_unconsumed_buckets_and_proofs: true,
+    });
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(RadixEngineToolkitError::UnconsumedBucketsOrProofs { buckets, proofs })
+            if buckets == vec!["bucket".to_owned()] && proofs.is_empty()
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn build_options_reject_manifests_with_unconsumed_proofs() -> Result<()> {
+    // Arrange
+    let xrd = Arc::new(Address::from_node_id(engine::XRD, 1));
+    let builder = ManifestV2Builder::new(1)
+        .create_proof_from_auth_zone_of_all(xrd, "proof".into())?;
+
+    // Act
+    let result = builder.build_with_options(ManifestBuildOptions {
+        lock_fee: None,
+        worktop_sweep: None,
+        forbid_unconsumed_buckets_and_proofs: true,
+    });
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(RadixEngineToolkitError::UnconsumedBucketsOrProofs { buckets, proofs })
+            if buckets.is_empty() && proofs == vec!["proof".to_owned()]
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn build_options_accept_manifests_once_proofs_are_dropped() -> Result<()> {
+    // Arrange
+    let xrd = Arc::new(Address::from_node_id(engine::XRD, 1));
+    let builder = ManifestV2Builder::new(1)
+        .create_proof_from_auth_zone_of_all(xrd, "proof".into())?
+        .drop_proof("proof".into())?;
+
+    // Act
+    let result = builder.build_with_options(ManifestBuildOptions {
+        lock_fee: None,
+        worktop_sweep: None,
+        forbid_unconsumed_buckets_and_proofs: true,
+    });
+
+    // Assert
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn signing_session_is_complete_once_all_required_signers_sign() -> Result<()> {
+    // Arrange
+    let [first_signer, second_signer] = private_keys();
+    let session = SigningSession::from_partial_transaction_v2_builder(
+        partial_transaction_builder()?,
+    )?;
+    let [intent] = session.intents().try_into().unwrap();
+    let session = session.require_signers(
+        intent.intent_hash.clone(),
+        vec![first_signer.public_key(), second_signer.public_key()],
+    )?;
+
+    // Act
+    let session = session.sign_with_private_key(first_signer)?;
+    let session = SigningSession::from_bytes(session.to_bytes()?)?;
+    let is_complete_before_second_signature = session.is_complete();
+    let session = session.add_signature(
+        intent.intent_hash.clone(),
+        second_signer.sign_to_signature_with_public_key(intent.intent_hash),
+    )?;
+
+    // Assert
+    assert!(!is_complete_before_second_signature);
+    assert!(session.is_complete());
+    let signed_partial_transaction = session.signed_partial_transaction()?;
+    assert_eq!(
+        signed_partial_transaction.root_subintent_signatures.len(),
+        2
+    );
+    signed_partial_transaction.statically_validate(1)?;
+
+    Ok(())
+}
+
+#[test]
+fn signing_session_requires_the_signers_of_the_accounts_the_manifest_needs_auth_from(
+) -> Result<()> {
+    // Arrange
+    let [signer] = private_keys();
+    let account = account_address();
+    let xrd = Arc::new(Address::from_node_id(engine::XRD, 1));
+    let session = SigningSession::from_partial_transaction_v2_builder(
+        PartialTransactionV2Builder::new()
+            .intent_header(intent_header())
+            .manifest(
+                ManifestV2Builder::new(1)
+                    .account_create_proof_of_amount(
+                        account.clone(),
+                        xrd,
+                        Decimal::new("10".to_owned())?,
+                    )?
+                    .drop_all_proofs()?
+                    .yield_to_parent(vec![])?
+                    .build(),
+            ),
+    )?;
+    let [intent] = session.intents().try_into().unwrap();
+    let is_complete_before_signing = session.is_complete();
+
+    // Act
+    let session = session.sign_with_private_key(signer.clone())?;
+
+    // Assert
+    assert!(!is_complete_before_signing);
+    assert_eq!(
+        intent
+            .required_entities
+            .iter()
+            .map(|address| address.address_string())
+            .collect::<Vec<_>>(),
+        vec![account.address_string()]
+    );
+    assert!(intent.required_signers.is_empty());
+    assert_eq!(intent.missing_entities.len(), 1);
+    let [intent] = session.intents().try_into().unwrap();
+    assert_eq!(intent.signers, vec![signer.public_key()]);
+    assert!(intent.missing_entities.is_empty());
+    assert!(session.is_complete());
+
+    Ok(())
+}
+
+#[test]
+fn signing_session_without_signers_required_by_the_manifest_is_complete(
+) -> Result<()> {
+    // Arrange
+    let session = SigningSession::from_partial_transaction_v2_builder(
+        partial_transaction_builder()?,
+    )?;
+
+    // Act
+    let signed_partial_transaction = session.signed_partial_transaction();
+
+    // Assert
+    assert!(session.is_complete());
+    assert!(signed_partial_transaction.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn signing_session_rejects_bytes_with_signatures_of_unknown_subintents(
+) -> Result<()> {
+    // Arrange
+    let partial_transaction = partial_transaction_builder()?.build()?;
+    let payload = SignedPartialTransactionV2::new(
+        partial_transaction,
+        vec![],
+        vec![vec![]],
+    )
+    .to_payload_bytes()?;
+    let bytes = engine::manifest_encode(&(
+        1u8,
+        payload,
+        vec![Vec::<engine::PublicKey>::new()],
+    ))
+    .unwrap();
+
+    // Act
+    let result = SigningSession::from_bytes(bytes);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(RadixEngineToolkitError::SigningSessionError { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn signing_session_without_required_signers_rejects_signatures() -> Result<()> {
+    // Arrange
+    let [signer] = private_keys();
+    let session = SigningSession::from_partial_transaction_v2_builder(
+        partial_transaction_builder()?,
+    )?;
+    let [intent] = session.intents().try_into().unwrap();
+
+    // Act
+    let result = session.add_signature(
+        intent.intent_hash.clone(),
+        signer.sign_to_signature_with_public_key(intent.intent_hash),
+    );
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(RadixEngineToolkitError::SigningSessionError { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn partly_signed_signing_session_round_trips_through_bytes() -> Result<()> {
+    // Arrange
+    let [first_signer, second_signer] = private_keys();
+    let session = SigningSession::from_partial_transaction_v2_builder(
+        partial_transaction_builder()?,
+    )?;
+    let [intent] = session.intents().try_into().unwrap();
+    let session = session
+        .require_signers(
+            intent.intent_hash.clone(),
+            vec![first_signer.public_key(), second_signer.public_key()],
+        )?
+        .sign_with_private_key(first_signer.clone())?;
+
+    // Act
+    let decoded_session = SigningSession::from_bytes(session.to_bytes()?)?;
+
+    // Assert
+    let [intent] = session.intents().try_into().unwrap();
+    let [decoded_intent] = decoded_session.intents().try_into().unwrap();
+    assert_eq!(
+        decoded_intent.intent_hash.as_str(),
+        intent.intent_hash.as_str()
+    );
+    assert_eq!(
+        decoded_intent.required_signers,
+        vec![first_signer.public_key(), second_signer.public_key()]
+    );
+    assert_eq!(decoded_intent.signers, vec![first_signer.public_key()]);
+    assert_eq!(
+        decoded_intent.missing_signers,
+        vec![second_signer.public_key()]
+    );
+    assert!(!decoded_session.is_complete());
+    assert_eq!(decoded_session.to_bytes()?, session.to_bytes()?);
+
+    Ok(())
+}
+
+#[test]
+fn signing_session_rejects_signatures_over_other_hashes() -> Result<()> {
+    // Arrange
+    let [signer] = private_keys();
+    let session = SigningSession::from_partial_transaction_v2_builder(
+        partial_transaction_builder()?,
+    )?;
+    let [intent] = session.intents().try_into().unwrap();
+    let session = session.require_signers(
+        intent.intent_hash.clone(),
+        vec![signer.public_key()],
+    )?;
+    let signature = signer
+        .sign_to_signature_with_public_key(Hash::from_unhashed_bytes(vec![0]));
+
+    // Act
+    let result = session.add_signature(intent.intent_hash, signature);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(RadixEngineToolkitError::SigningSessionError { .. })
+    ));
+
+    Ok(())
+}
+
+fn partial_transaction_builder() -> Result<Arc<PartialTransactionV2Builder>> {
+    Ok(PartialTransactionV2Builder::new()
+        .intent_header(intent_header())
+        .manifest(ManifestV2Builder::new(1).yield_to_parent(vec![])?.build()))
+}
+
+fn intent_header() -> IntentHeaderV2 {
+    IntentHeaderV2 {
+        network_id: 0x01,
+        start_epoch_inclusive: 1,
+        end_epoch_exclusive: 10,
+        min_proposer_timestamp_inclusive: None,
+        max_proposer_timestamp_exclusive: None,
+        intent_discriminator: 100,
+    }
+}
+
+fn account_address() -> Arc<Address> {
+    let public_key =
+        engine::Ed25519PrivateKey::from_u64(1).unwrap().public_key();
+    Arc::new(Address::from_node_id(
+        engine::ComponentAddress::preallocated_account_from_public_key(
+            &public_key,
+        ),
+        1,
+    ))
+}
+
 fn private_keys<const N: usize>() -> [Arc<PrivateKey>; N] {
     std::array::from_fn(|i| i + 1)
         .map(|value| value as u64)
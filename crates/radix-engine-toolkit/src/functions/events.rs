@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::functions::scrypto_sbor::{self, ScryptoSborError};
 use crate::internal_prelude::*;
+use radix_engine::object_modules::metadata::MetadataNativePackage;
+use radix_engine::object_modules::role_assignment::RoleAssignmentNativePackage;
+use radix_engine::object_modules::royalty::RoyaltyNativePackage;
 
 /// Attempts to decode the passed in event into a structured
 /// [`TypedNativeEvent`] if the event is emitted from a native blueprint of a
@@ -26,3 +30,164 @@ pub fn scrypto_sbor_decode_to_native_event(
 ) -> Result<TypedNativeEvent, TypedNativeEventError> {
     to_typed_native_event(event_type_identifier, event_data)
 }
+
+/// Decodes an event emitted by any blueprint of a package using the schema of
+/// the event in the package's definition and returns it along with the names
+/// of the blueprint and the event. The [`SerializationMode::Model`] produces
+/// annotated JSON while [`SerializationMode::Natural`] produces a tree of the
+/// values keyed by the field names in the schema.
+///
+/// Events emitted by functions name the package of their blueprint which must
+/// be the given package. The blueprint of events emitted by methods is not part
+/// of the event type identifier, and is taken from `emitter_blueprint_name`
+/// when it's known, such as from the blueprint of the emitting component.
+/// Otherwise, the event is decoded against every blueprint in the package that
+/// defines an event of that name and an error is returned if it's valid against
+/// more than one of them.
+///
+/// Events emitted by the metadata, royalty and role assignment modules of a
+/// component are defined by the native module packages rather than by the
+/// package of the component, and are decoded with the schemas of the native
+/// module blueprints regardless of the given package.
+pub fn scrypto_sbor_decode_event(
+    event_type_identifier: &EventTypeIdentifier,
+    event_data: &[u8],
+    package_address: &PackageAddress,
+    package_definition: &PackageDefinition,
+    emitter_blueprint_name: Option<&str>,
+    representation: SerializationMode,
+    bech32_encoder: &AddressBech32Encoder,
+) -> Result<DecodedEvent, EventDecodingError> {
+    let EventTypeIdentifier(emitter, event_name) = event_type_identifier;
+
+    let module_package_definition;
+    let (package_definition, blueprint_name) = match emitter {
+        Emitter::Function(BlueprintId {
+            package_address: emitter_package_address,
+            blueprint_name,
+        }) => {
+            if emitter_package_address != package_address {
+                return Err(EventDecodingError::PackageAddressMismatch {
+                    expected: *package_address,
+                    actual: *emitter_package_address,
+                });
+            }
+            (package_definition, Some(blueprint_name.as_str()))
+        }
+        Emitter::Method(_, ModuleId::Main) => {
+            (package_definition, emitter_blueprint_name)
+        }
+        Emitter::Method(_, ModuleId::Metadata) => {
+            module_package_definition = MetadataNativePackage::definition();
+            (&module_package_definition, Some(METADATA_BLUEPRINT))
+        }
+        Emitter::Method(_, ModuleId::Royalty) => {
+            module_package_definition = RoyaltyNativePackage::definition();
+            (
+                &module_package_definition,
+                Some(COMPONENT_ROYALTY_BLUEPRINT),
+            )
+        }
+        Emitter::Method(_, ModuleId::RoleAssignment) => {
+            module_package_definition =
+                RoleAssignmentNativePackage::definition();
+            (&module_package_definition, Some(ROLE_ASSIGNMENT_BLUEPRINT))
+        }
+    };
+    let candidate_blueprints = match blueprint_name {
+        Some(blueprint_name) => {
+            let blueprint_definition = package_definition
+                .blueprints
+                .get(blueprint_name)
+                .ok_or_else(|| {
+                    EventDecodingError::BlueprintNotFound(
+                        blueprint_name.to_owned(),
+                    )
+                })?;
+            vec![(blueprint_name, blueprint_definition)]
+        }
+        None => package_definition
+            .blueprints
+            .iter()
+            .map(|(blueprint_name, blueprint_definition)| {
+                (blueprint_name.as_str(), blueprint_definition)
+            })
+            .collect(),
+    };
+
+    let mut event_schemas = candidate_blueprints
+        .into_iter()
+        .filter_map(|(blueprint_name, blueprint_definition)| {
+            blueprint_definition
+                .schema
+                .events
+                .event_schema
+                .get(event_name)
+                .map(|type_ref| {
+                    (blueprint_name, type_ref, &blueprint_definition.schema)
+                })
+        })
+        .peekable();
+    if event_schemas.peek().is_none() {
+        return Err(EventDecodingError::EventNotFound(event_name.clone()));
+    }
+
+    let mut decoded_events = vec![];
+    let mut last_error = None;
+    for (blueprint_name, type_ref, blueprint_schema) in event_schemas {
+        let TypeRef::Static(local_type_id) = type_ref else {
+            last_error = Some(EventDecodingError::GenericEventTypeNotSupported);
+            continue;
+        };
+        match scrypto_sbor::decode_to_string_representation(
+            event_data,
+            representation,
+            bech32_encoder,
+            Some((*local_type_id, blueprint_schema.schema.v1().clone())),
+        ) {
+            Ok(event) => decoded_events.push(DecodedEvent {
+                blueprint_name: blueprint_name.to_owned(),
+                event_name: event_name.clone(),
+                event,
+            }),
+            Err(error) => {
+                last_error = Some(EventDecodingError::ScryptoSborError(error))
+            }
+        }
+    }
+
+    match decoded_events.len() {
+        0 => Err(last_error.expect("At least one event schema was checked")),
+        1 => Ok(decoded_events.remove(0)),
+        _ => Err(EventDecodingError::AmbiguousEmitterBlueprint(
+            decoded_events
+                .into_iter()
+                .map(|decoded_event| decoded_event.blueprint_name)
+                .collect(),
+        )),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedEvent {
+    pub blueprint_name: String,
+    pub event_name: String,
+    /// The event in the requested string representation.
+    pub event: String,
+}
+
+#[derive(Debug)]
+pub enum EventDecodingError {
+    BlueprintNotFound(String),
+    EventNotFound(String),
+    /// The event was emitted by a function of a blueprint of another package.
+    PackageAddressMismatch {
+        expected: PackageAddress,
+        actual: PackageAddress,
+    },
+    /// The event of a method was valid against the schemas of the events of
+    /// more than one blueprint and the blueprint of the emitter wasn't given.
+    AmbiguousEmitterBlueprint(Vec<String>),
+    GenericEventTypeNotSupported,
+    ScryptoSborError(ScryptoSborError),
+}
@@ -0,0 +1,227 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Estimation of the fee to lock for a transaction from the receipt of its
+//! preview.
+//!
+//! The receipt of a preview already includes the cost of validating the payload
+//! of the previewed intent. What it doesn't include is the part of the payload
+//! that only exists in the notarized transaction: the intent signatures, the
+//! notary signature and public key and the message if the preview was done
+//! without it. The estimate adds the cost of validating that part of the
+//! payload, priced by the engine's [`FeeTable`].
+//!
+//! The cost of verifying the signatures depends on the version of the preview:
+//!
+//! * A V1 preview doesn't verify any signatures, so the estimate adds the cost
+//!   of verifying the intent signatures and the notary signature.
+//! * A V2 preview is given the public keys of the signers of every intent and
+//!   charges for their verification as well as that of the notary signature,
+//!   so the estimate doesn't add it again.
+//!
+//! Finally, the tip of the final transaction is added and a safety margin is
+//! applied on the total. Fees locked but not consumed by a transaction are
+//! refunded, so the estimate errs on the side of being too high where the
+//! final transaction can't be known precisely.
+
+use crate::internal_prelude::*;
+
+use radix_engine::system::system_modules::costing::FeeTable;
+use radix_engine::transaction::CostingParameters;
+
+/// The parameters of the estimation that describe the final transaction and how
+/// conservative the estimate should be.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeEstimationParameters {
+    /// The number of signers of the transaction intent. If the notary is a
+    /// signatory then it's expected to be counted as one of the signers.
+    pub signer_count: usize,
+    /// The number of signers of each of the non-root subintents of a V2
+    /// transaction in the order in which the subintents are given. This must be
+    /// empty for V1 transactions.
+    pub subintent_signer_counts: Vec<usize>,
+    /// Whether the notary is a signatory of the transaction. When it is then
+    /// its signature stands in for its intent signature.
+    pub notary_is_signatory: bool,
+    /// The size in bytes of the message of the transaction if it wasn't a part
+    /// of the previewed intent.
+    pub message_size: usize,
+    /// The price of an execution cost unit in XRD.
+    pub execution_cost_unit_price: Decimal,
+    /// The tip of the transaction as a proportion of its execution and
+    /// finalization costs. As an example, a tip percentage of `5` or a tip of
+    /// `500` basis points are both a proportion of `0.05`.
+    pub tip_proportion: Decimal,
+    /// The margin applied to the estimate as a fraction of it. As an example,
+    /// a margin of `0.1` increases the estimate by 10%.
+    pub margin: Decimal,
+}
+
+impl Default for FeeEstimationParameters {
+    fn default() -> Self {
+        Self {
+            signer_count: 0,
+            subintent_signer_counts: vec![],
+            notary_is_signatory: false,
+            message_size: 0,
+            execution_cost_unit_price: CostingParameters::babylon_genesis()
+                .execution_cost_unit_price,
+            tip_proportion: Decimal::ZERO,
+            margin: dec!("0.1"),
+        }
+    }
+}
+
+/// The estimated fee of a transaction and its breakdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// The fees paid by the preview of the transaction.
+    pub fee_summary: FeeSummary,
+    /// The cost of verifying the signatures that isn't already included in the
+    /// fees of the preview.
+    pub signature_verification_cost: Decimal,
+    /// The cost of validating the part of the notarized payload that isn't
+    /// already included in the fees of the preview.
+    pub payload_validation_cost: Decimal,
+    /// The tip paid on the execution and finalization costs.
+    pub tip_cost: Decimal,
+    /// The amount added to the estimate as a safety margin.
+    pub margin: Decimal,
+    /// The amount of XRD recommended to be locked for the transaction fees.
+    pub lock_fee: Decimal,
+}
+
+/// The sizes of the payloads of a transaction that the estimation is based on.
+pub(crate) struct PayloadSizes {
+    /// The size of the notarized transaction with the notary signature but
+    /// without any intent signatures or message.
+    pub unsigned_notarized_transaction: usize,
+    /// The size of the intent whose validation is charged by the preview.
+    pub previewed_intent: usize,
+}
+
+/// Estimates the fee of a transaction from the receipt of its preview and the
+/// sizes of its payloads. The intent signatures and message are added to the
+/// payload from the parameters. The signature verification is only added when
+/// the preview doesn't already charge for it.
+pub(crate) fn estimate_fee(
+    receipt: RuntimeToolkitTransactionReceipt,
+    payload_sizes: PayloadSizes,
+    preview_verifies_signatures: bool,
+    parameters: &FeeEstimationParameters,
+) -> Result<FeeEstimate, FeeEstimationError> {
+    let fee_summary = AnalysisTransactionReceipt::new(receipt)
+        .ok_or(FeeEstimationError::ReceiptIsNotCommitSuccess)?
+        .fee_summary();
+    let FeeEstimationParameters {
+        signer_count,
+        ref subintent_signer_counts,
+        notary_is_signatory,
+        message_size,
+        execution_cost_unit_price,
+        tip_proportion,
+        margin,
+    } = *parameters;
+    let fee_table = FeeTable::latest();
+
+    // When the notary is a signatory then its notary signature is the only one
+    // it provides. The notary signature is always verified.
+    let transaction_intent_signature_count = if notary_is_signatory {
+        signer_count.saturating_sub(1)
+    } else {
+        signer_count
+    };
+    let intent_signature_count = transaction_intent_signature_count
+        + subintent_signer_counts.iter().sum::<usize>();
+    let signature_verification_cost = if preview_verifies_signatures {
+        Decimal::ZERO
+    } else {
+        Decimal::from(
+            fee_table.verify_tx_signatures_cost(intent_signature_count + 1),
+        ) * execution_cost_unit_price
+    };
+
+    let payload_size = payload_sizes.unsigned_notarized_transaction
+        + message_size
+        + intent_signature_count * intent_signature_size()?;
+    let payload_validation_cost =
+        Decimal::from(fee_table.validate_tx_payload_cost(
+            payload_size.saturating_sub(payload_sizes.previewed_intent),
+        )) * execution_cost_unit_price;
+
+    let tip_cost = (fee_summary.execution_cost
+        + fee_summary.finalization_cost
+        + signature_verification_cost
+        + payload_validation_cost)
+        * tip_proportion;
+
+    let subtotal = fee_summary.execution_cost
+        + fee_summary.finalization_cost
+        + fee_summary.storage_expansion_cost
+        + fee_summary.royalty_cost
+        + signature_verification_cost
+        + payload_validation_cost
+        + tip_cost;
+    let margin = subtotal * margin;
+
+    Ok(FeeEstimate {
+        fee_summary,
+        signature_verification_cost,
+        payload_validation_cost,
+        tip_cost,
+        margin,
+        lock_fee: subtotal + margin,
+    })
+}
+
+/// The notary public key and signature used in place of the real ones when
+/// encoding a transaction to measure its size. Secp256k1 keys and signatures are
+/// used as they're the larger of the supported curves once encoded: 33 and 65
+/// bytes against the 32 and 64 bytes of Ed25519.
+pub(crate) fn placeholder_notary() -> (PublicKey, SignatureV1) {
+    (
+        PublicKey::Secp256k1(Secp256k1PublicKey(
+            [0; Secp256k1PublicKey::LENGTH],
+        )),
+        SignatureV1::Secp256k1(Secp256k1Signature(
+            [0; Secp256k1Signature::LENGTH],
+        )),
+    )
+}
+
+/// An upper bound on the size that a single intent signature adds to the
+/// payload of a notarized transaction. Unlike the notary, an intent signature
+/// is larger with Ed25519 as it carries the public key along with the signature
+/// while a Secp256k1 public key is recovered from the signature.
+fn intent_signature_size() -> Result<usize, FeeEstimationError> {
+    manifest_encode(&IntentSignatureV1(SignatureWithPublicKeyV1::Ed25519 {
+        public_key: Ed25519PublicKey([0; Ed25519PublicKey::LENGTH]),
+        signature: Ed25519Signature([0; Ed25519Signature::LENGTH]),
+    }))
+    .map(|encoded| encoded.len())
+    .map_err(FeeEstimationError::EncodeError)
+}
+
+#[derive(Debug)]
+pub enum FeeEstimationError {
+    ReceiptIsNotCommitSuccess,
+    EncodeError(EncodeError),
+    SubintentSignerCountMismatch {
+        subintents: usize,
+        subintent_signer_counts: usize,
+    },
+}
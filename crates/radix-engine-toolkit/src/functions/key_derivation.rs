@@ -0,0 +1,292 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Hierarchical deterministic derivation of private keys.
+//!
+//! A seed is obtained from a BIP-39 mnemonic and an optional passphrase. Keys
+//! are then derived from the seed through SLIP-10 along a [`DerivationPath`].
+//! SLIP-10 only defines hardened derivation for Ed25519 while both hardened and
+//! non-hardened derivation are supported for Secp256k1.
+//!
+//! The Babylon wallet derives its keys along CAP-26 paths that are modeled by
+//! the [`Cap26Path`] type. These are of the following form where all of the
+//! components are hardened:
+//!
+//! `m/44'/1022'/<network id>'/<entity kind>'/<key kind>'/<index>'`
+
+use crate::internal_prelude::*;
+
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+/// The offset added to the index of hardened path components.
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// The BIP-44 purpose used in the CAP-26 derivation paths.
+pub const CAP26_PURPOSE: u32 = 44;
+
+/// The SLIP-44 coin type registered for Radix.
+pub const RADIX_COIN_TYPE: u32 = 1022;
+
+const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+const SECP256K1_SEED_KEY: &[u8] = b"Bitcoin seed";
+
+/// Converts a BIP-39 mnemonic and a passphrase into the 64 byte seed that keys
+/// are derived from. The passphrase is empty when none is used.
+pub fn mnemonic_to_seed(
+    mnemonic: &str,
+    passphrase: &str,
+) -> Result<[u8; 64], KeyDerivationError> {
+    bip39::Mnemonic::parse(mnemonic)
+        .map(|mnemonic| mnemonic.to_seed(passphrase))
+        .map_err(|_| KeyDerivationError::InvalidMnemonic)
+}
+
+/// Derives the private key of the curve at the path from the seed through
+/// SLIP-10.
+pub fn derive_private_key(
+    seed: &[u8],
+    curve: CurveType,
+    path: &DerivationPath,
+) -> Result<PrivateKey, KeyDerivationError> {
+    match curve {
+        CurveType::Ed25519 => {
+            let (mut key, mut chain_code) =
+                split(hmac_sha512(ED25519_SEED_KEY, &[seed]));
+            for component in path.0.iter().copied() {
+                if !is_hardened(component) {
+                    return Err(
+                        KeyDerivationError::NonHardenedEd25519Derivation,
+                    );
+                }
+                (key, chain_code) = split(hmac_sha512(
+                    &chain_code,
+                    &[&[0u8], &key, &component.to_be_bytes()],
+                ));
+            }
+            Ed25519PrivateKey::from_bytes(&key)
+                .map(PrivateKey::Ed25519)
+                .map_err(|_| KeyDerivationError::InvalidDerivedKey)
+        }
+        CurveType::Secp256k1 => {
+            let secp = secp256k1::Secp256k1::signing_only();
+
+            // The master key is rederived from the output of the HMAC until it
+            // is a valid Secp256k1 private key as defined by SLIP-10.
+            let mut output = hmac_sha512(SECP256K1_SEED_KEY, &[seed]);
+            let (mut key, mut chain_code) = loop {
+                let (key, chain_code) = split(output);
+                match secp256k1::SecretKey::from_slice(&key) {
+                    Ok(key) => break (key, chain_code),
+                    Err(_) => {
+                        output = hmac_sha512(SECP256K1_SEED_KEY, &[&output])
+                    }
+                }
+            };
+
+            for component in path.0.iter().copied() {
+                let mut output = if is_hardened(component) {
+                    hmac_sha512(
+                        &chain_code,
+                        &[
+                            &[0u8],
+                            &key.secret_bytes(),
+                            &component.to_be_bytes(),
+                        ],
+                    )
+                } else {
+                    let public_key =
+                        secp256k1::PublicKey::from_secret_key(&secp, &key);
+                    hmac_sha512(
+                        &chain_code,
+                        &[&public_key.serialize(), &component.to_be_bytes()],
+                    )
+                };
+
+                // A child key is skipped over as defined by SLIP-10 when the
+                // tweak is not a valid scalar or the child key is invalid.
+                (key, chain_code) = loop {
+                    let (tweak, child_chain_code) = split(output);
+                    let child_key = secp256k1::Scalar::from_be_bytes(tweak)
+                        .ok()
+                        .and_then(|tweak| key.add_tweak(&tweak).ok());
+                    match child_key {
+                        Some(child_key) => break (child_key, child_chain_code),
+                        None => {
+                            output = hmac_sha512(
+                                &chain_code,
+                                &[
+                                    &[1u8],
+                                    &child_chain_code,
+                                    &component.to_be_bytes(),
+                                ],
+                            )
+                        }
+                    }
+                };
+            }
+
+            Secp256k1PrivateKey::from_bytes(&key.secret_bytes())
+                .map(PrivateKey::Secp256k1)
+                .map_err(|_| KeyDerivationError::InvalidDerivedKey)
+        }
+    }
+}
+
+/// A derivation path made up of the indices of its components where the index
+/// of hardened components includes the [`HARDENED_OFFSET`].
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DerivationPath(pub Vec<u32>);
+
+impl FromStr for DerivationPath {
+    type Err = KeyDerivationError;
+
+    /// Parses paths such as `m/44'/1022'/1'/525'/1460'/0'`. Hardened components
+    /// may be marked with either `'` or `H`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split('/');
+        if components.next() != Some("m") {
+            return Err(KeyDerivationError::InvalidDerivationPath);
+        }
+        components
+            .map(|component| {
+                let (index, hardened) = match component
+                    .strip_suffix('\'')
+                    .or_else(|| component.strip_suffix('H'))
+                {
+                    Some(index) => (index, true),
+                    None => (component, false),
+                };
+                let index = index
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|index| *index < HARDENED_OFFSET)
+                    .ok_or(KeyDerivationError::InvalidDerivationPath)?;
+                Ok(if hardened {
+                    index + HARDENED_OFFSET
+                } else {
+                    index
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "m")?;
+        for component in self.0.iter().copied() {
+            if is_hardened(component) {
+                write!(f, "/{}H", component - HARDENED_OFFSET)?;
+            } else {
+                write!(f, "/{component}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A CAP-26 derivation path used by the Babylon wallet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cap26Path {
+    pub network_id: u8,
+    pub entity_kind: Cap26EntityKind,
+    pub key_kind: Cap26KeyKind,
+    pub index: u32,
+}
+
+impl TryFrom<Cap26Path> for DerivationPath {
+    type Error = KeyDerivationError;
+
+    /// Converts the path into a derivation path where all of the components
+    /// are hardened. Fails if the index is already at or beyond the
+    /// [`HARDENED_OFFSET`] since it can't be hardened without overflowing.
+    fn try_from(
+        Cap26Path {
+            network_id,
+            entity_kind,
+            key_kind,
+            index,
+        }: Cap26Path,
+    ) -> Result<Self, Self::Error> {
+        if index >= HARDENED_OFFSET {
+            return Err(KeyDerivationError::InvalidDerivationPath);
+        }
+        Ok(Self(
+            [
+                CAP26_PURPOSE,
+                RADIX_COIN_TYPE,
+                network_id as u32,
+                entity_kind as u32,
+                key_kind as u32,
+                index,
+            ]
+            .into_iter()
+            .map(|component| component | HARDENED_OFFSET)
+            .collect(),
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Cap26EntityKind {
+    Account = 525,
+    Identity = 618,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Cap26KeyKind {
+    TransactionSigning = 1460,
+    AuthenticationSigning = 1678,
+    MessageEncryption = 1391,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyDerivationError {
+    InvalidMnemonic,
+    InvalidDerivationPath,
+    NonHardenedEd25519Derivation,
+    InvalidDerivedKey,
+}
+
+fn is_hardened(component: u32) -> bool {
+    component >= HARDENED_OFFSET
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key)
+        .expect("HMAC accepts keys of any length");
+    for data in data {
+        mac.update(data);
+    }
+    let mut output = [0u8; 64];
+    output.copy_from_slice(&mac.finalize().into_bytes());
+    output
+}
+
+fn split(output: [u8; 64]) -> ([u8; 32], [u8; 32]) {
+    let (left, right) = output.split_at(32);
+    (
+        left.try_into().expect("Length is fixed"),
+        right.try_into().expect("Length is fixed"),
+    )
+}
@@ -15,7 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::functions::sbor_diff::diff_values;
+use crate::functions::schema_json::{self, SchemaJsonError};
 use crate::internal_prelude::*;
+use radix_transactions::data::{
+    to_decimal, to_non_fungible_local_id, to_precise_decimal,
+};
 
 pub fn encode(value: &ManifestValue) -> Result<Vec<u8>, EncodeError> {
     manifest_encode(value)
@@ -96,14 +101,265 @@ where
             .map_err(ManifestSborError::FmtError)?;
             string
         }
+        ManifestSborStringRepresentation::ProgrammaticJson { network_id } => {
+            let value = ProgrammaticManifestValue::from_manifest_value(
+                &decode(value).map_err(ManifestSborError::DecodeError)?,
+                network_id,
+            );
+            serde_json::to_string(&value).expect("Impossible Case!")
+        }
     };
 
     Ok(string)
 }
 
-#[derive(Debug, Clone)]
+/// Encodes the programmatic JSON representation of a [`ManifestValue`] into
+/// Manifest SBOR. This is the inverse of decoding with the
+/// [`ManifestSborStringRepresentation::ProgrammaticJson`] representation.
+pub fn encode_string_representation(
+    value: &str,
+) -> Result<Vec<u8>, ManifestSborError> {
+    let value = serde_json::from_str::<ProgrammaticManifestValue>(value)
+        .map_err(ManifestSborError::SerdeDeserializationFailed)?;
+    if manifest_value_contains_network_mismatch(&value) {
+        return Err(ManifestSborError::ValueContainsNetworkMismatch);
+    }
+
+    encode(&value.to_manifest_value()).map_err(ManifestSborError::EncodeError)
+}
+
+/// Encodes natural or annotated JSON into Manifest SBOR of the type with the
+/// given id in the schema. See [`schema_json`] for the accepted forms of JSON.
+/// The encoded payload is validated against the schema before it's returned.
+pub fn encode_json_with_schema(
+    value: &str,
+    local_type_id: LocalTypeId,
+    schema: &Schema<ScryptoCustomSchema>,
+) -> Result<Vec<u8>, ManifestSborError> {
+    let json = serde_json::from_str::<serde_json::Value>(value)
+        .map_err(ManifestSborError::SerdeDeserializationFailed)?;
+    let value = schema_json::to_manifest_value(&json, local_type_id, schema)
+        .map_err(ManifestSborError::SchemaJsonError)?;
+    let encoded = encode(&value).map_err(ManifestSborError::EncodeError)?;
+
+    validate_payload_against_schema::<ManifestCustomExtension, _>(
+        &encoded,
+        schema,
+        local_type_id,
+        &(),
+        MANIFEST_SBOR_V1_MAX_DEPTH,
+    )
+    .map_err(|_| ManifestSborError::SchemaValidationError)?;
+
+    Ok(encoded)
+}
+
+/// Compares two Manifest SBOR payloads and returns the changes between them with
+/// the changed values in their [`ProgrammaticScryptoValue`] representation. If a
+/// schema is given both payloads are validated against it and the fields of
+/// tuples and enum variants are identified by their names in the schema. The
+/// diff fails if a changed value has no Scrypto representation, such as a
+/// bucket, a proof or a named address.
+pub fn diff<T>(
+    old_value: T,
+    new_value: T,
+    network_id: u8,
+    schema: Option<(LocalTypeId, Schema<ScryptoCustomSchema>)>,
+) -> Result<Vec<SborChange<ProgrammaticScryptoValue>>, ManifestSborError>
+where
+    T: AsRef<[u8]>,
+{
+    let [old_value, new_value] = [old_value.as_ref(), new_value.as_ref()].map(
+        |value| -> Result<_, ManifestSborError> {
+            if let Some((ref local_type_id, ref schema)) = schema {
+                validate_payload_against_schema::<ManifestCustomExtension, _>(
+                    value,
+                    schema,
+                    *local_type_id,
+                    &(),
+                    MANIFEST_SBOR_V1_MAX_DEPTH,
+                )
+                .map_err(|_| ManifestSborError::SchemaValidationError)?;
+            }
+            decode(value).map_err(ManifestSborError::DecodeError)
+        },
+    );
+
+    diff_values(
+        &old_value?,
+        &new_value?,
+        schema
+            .as_ref()
+            .map(|(local_type_id, schema)| (*local_type_id, schema)),
+        |value| {
+            manifest_value_to_scrypto_value(value).map(|value| {
+                ProgrammaticScryptoValue::from_scrypto_value(&value, network_id)
+            })
+        },
+    )
+}
+
+/// Converts a [`ManifestValue`] into a [`ScryptoValue`]. Static addresses become
+/// references while buckets, proofs, expressions, blobs, address reservations
+/// and named addresses only exist in manifests and result in an error.
+pub fn manifest_value_to_scrypto_value(
+    value: &ManifestValue,
+) -> Result<ScryptoValue, ManifestSborError> {
+    let value = match value {
+        ManifestValue::Bool { value } => ScryptoValue::Bool { value: *value },
+        ManifestValue::I8 { value } => ScryptoValue::I8 { value: *value },
+        ManifestValue::I16 { value } => ScryptoValue::I16 { value: *value },
+        ManifestValue::I32 { value } => ScryptoValue::I32 { value: *value },
+        ManifestValue::I64 { value } => ScryptoValue::I64 { value: *value },
+        ManifestValue::I128 { value } => ScryptoValue::I128 { value: *value },
+        ManifestValue::U8 { value } => ScryptoValue::U8 { value: *value },
+        ManifestValue::U16 { value } => ScryptoValue::U16 { value: *value },
+        ManifestValue::U32 { value } => ScryptoValue::U32 { value: *value },
+        ManifestValue::U64 { value } => ScryptoValue::U64 { value: *value },
+        ManifestValue::U128 { value } => ScryptoValue::U128 { value: *value },
+        ManifestValue::String { value } => ScryptoValue::String {
+            value: value.clone(),
+        },
+        ManifestValue::Enum {
+            discriminator,
+            fields,
+        } => ScryptoValue::Enum {
+            discriminator: *discriminator,
+            fields: fields
+                .iter()
+                .map(manifest_value_to_scrypto_value)
+                .collect::<Result<_, _>>()?,
+        },
+        ManifestValue::Array {
+            element_value_kind,
+            elements,
+        } => ScryptoValue::Array {
+            element_value_kind: manifest_value_kind_to_scrypto_value_kind(
+                *element_value_kind,
+            )?,
+            elements: elements
+                .iter()
+                .map(manifest_value_to_scrypto_value)
+                .collect::<Result<_, _>>()?,
+        },
+        ManifestValue::Tuple { fields } => ScryptoValue::Tuple {
+            fields: fields
+                .iter()
+                .map(manifest_value_to_scrypto_value)
+                .collect::<Result<_, _>>()?,
+        },
+        ManifestValue::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        } => ScryptoValue::Map {
+            key_value_kind: manifest_value_kind_to_scrypto_value_kind(
+                *key_value_kind,
+            )?,
+            value_value_kind: manifest_value_kind_to_scrypto_value_kind(
+                *value_value_kind,
+            )?,
+            entries: entries
+                .iter()
+                .map(|(key, value)| {
+                    Ok((
+                        manifest_value_to_scrypto_value(key)?,
+                        manifest_value_to_scrypto_value(value)?,
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+        },
+        ManifestValue::Custom { value } => ScryptoValue::Custom {
+            value: match value {
+                ManifestCustomValue::Address(ManifestAddress::Static(
+                    node_id,
+                )) => ScryptoCustomValue::Reference(Reference(*node_id)),
+                ManifestCustomValue::Decimal(value) => {
+                    ScryptoCustomValue::Decimal(to_decimal(value.clone()))
+                }
+                ManifestCustomValue::PreciseDecimal(value) => {
+                    ScryptoCustomValue::PreciseDecimal(to_precise_decimal(
+                        value.clone(),
+                    ))
+                }
+                ManifestCustomValue::NonFungibleLocalId(value) => {
+                    ScryptoCustomValue::NonFungibleLocalId(
+                        to_non_fungible_local_id(value.clone()),
+                    )
+                }
+                ManifestCustomValue::Address(ManifestAddress::Named(..))
+                | ManifestCustomValue::Bucket(..)
+                | ManifestCustomValue::Proof(..)
+                | ManifestCustomValue::Expression(..)
+                | ManifestCustomValue::Blob(..)
+                | ManifestCustomValue::AddressReservation(..) => {
+                    return Err(
+                        ManifestSborError::ValueNotRepresentableInScrypto,
+                    )
+                }
+            },
+        },
+    };
+    Ok(value)
+}
+
+fn manifest_value_kind_to_scrypto_value_kind(
+    value_kind: ManifestValueKind,
+) -> Result<ScryptoValueKind, ManifestSborError> {
+    let value_kind = match value_kind {
+        ManifestValueKind::Bool => ScryptoValueKind::Bool,
+        ManifestValueKind::I8 => ScryptoValueKind::I8,
+        ManifestValueKind::I16 => ScryptoValueKind::I16,
+        ManifestValueKind::I32 => ScryptoValueKind::I32,
+        ManifestValueKind::I64 => ScryptoValueKind::I64,
+        ManifestValueKind::I128 => ScryptoValueKind::I128,
+        ManifestValueKind::U8 => ScryptoValueKind::U8,
+        ManifestValueKind::U16 => ScryptoValueKind::U16,
+        ManifestValueKind::U32 => ScryptoValueKind::U32,
+        ManifestValueKind::U64 => ScryptoValueKind::U64,
+        ManifestValueKind::U128 => ScryptoValueKind::U128,
+        ManifestValueKind::String => ScryptoValueKind::String,
+        ManifestValueKind::Enum => ScryptoValueKind::Enum,
+        ManifestValueKind::Array => ScryptoValueKind::Array,
+        ManifestValueKind::Tuple => ScryptoValueKind::Tuple,
+        ManifestValueKind::Map => ScryptoValueKind::Map,
+        ManifestValueKind::Custom(custom_value_kind) => {
+            ScryptoValueKind::Custom(match custom_value_kind {
+                ManifestCustomValueKind::Address => {
+                    ScryptoCustomValueKind::Reference
+                }
+                ManifestCustomValueKind::Decimal => {
+                    ScryptoCustomValueKind::Decimal
+                }
+                ManifestCustomValueKind::PreciseDecimal => {
+                    ScryptoCustomValueKind::PreciseDecimal
+                }
+                ManifestCustomValueKind::NonFungibleLocalId => {
+                    ScryptoCustomValueKind::NonFungibleLocalId
+                }
+                ManifestCustomValueKind::Bucket
+                | ManifestCustomValueKind::Proof
+                | ManifestCustomValueKind::Expression
+                | ManifestCustomValueKind::Blob
+                | ManifestCustomValueKind::AddressReservation => {
+                    return Err(
+                        ManifestSborError::ValueNotRepresentableInScrypto,
+                    )
+                }
+            })
+        }
+    };
+    Ok(value_kind)
+}
+
+#[derive(Debug)]
 pub enum ManifestSborError {
     SchemaValidationError,
     DecodeError(DecodeError),
+    EncodeError(EncodeError),
     FmtError(std::fmt::Error),
+    SerdeDeserializationFailed(serde_json::Error),
+    ValueContainsNetworkMismatch,
+    ValueNotRepresentableInScrypto,
+    SchemaJsonError(SchemaJsonError),
 }
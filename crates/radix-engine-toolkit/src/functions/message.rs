@@ -0,0 +1,471 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encryption and decryption of transaction messages.
+//!
+//! This implements the scheme described on [`EncryptedMessageV1`]. A message is
+//! encrypted once with a randomly generated message key through AES-GCM. The
+//! message key is then made available to every recipient by wrapping it with
+//! AES-256-KeyWrap using a 256-bit key-encryption key that is unique to that
+//! recipient. The key-encryption key is derived through HKDF with Blake2b-256,
+//! no salt and no info from the Diffie-Hellman shared secret of the recipient's
+//! public key and an ephemeral key generated for the recipient's curve:
+//!
+//! * Secp256k1: ECDH where the shared secret is the x-coordinate of the shared
+//!   point.
+//! * Ed25519: X25519 over the birationally equivalent Curve25519 keys.
+//!
+//! The AES-GCM payload is the 12 byte nonce followed by the ciphertext and the
+//! 16 byte tag and the plaintext is the Manifest SBOR encoding of the
+//! [`PlaintextMessageV1`].
+//!
+//! The message key size follows the wrapped keys of the envelope: version one
+//! messages carry [`AesWrapped128BitKey`]s and therefore use a 128-bit message
+//! key and AES-128-GCM. Version two messages carry [`AesWrapped256BitKey`]s and
+//! use a 256-bit message key and AES-256-GCM.
+
+use crate::internal_prelude::*;
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes128Gcm, Aes256Gcm, Nonce};
+use aes_kw::KekAes256;
+use blake2::digest::consts::U32;
+use blake2::Blake2b;
+use hkdf::SimpleHkdf;
+
+const AES_GCM_NONCE_LENGTH: usize = 12;
+const AES_KW_OVERHEAD: usize = 8;
+const KEY_ENCRYPTION_KEY_LENGTH: usize = 32;
+const MESSAGE_KEY_V1_LENGTH: usize = 16;
+const MESSAGE_KEY_V2_LENGTH: usize = 32;
+
+pub fn encrypt_message_v1(
+    plaintext: &PlaintextMessageV1,
+    recipients: &[PublicKey],
+) -> Result<EncryptedMessageV1, MessageEncryptionError> {
+    let (encrypted, decryptors) =
+        encrypt::<MESSAGE_KEY_V1_LENGTH>(plaintext, recipients)?;
+    let decryptors_by_curve = decryptors
+        .into_iter()
+        .map(|(curve_type, (dh_ephemeral_public_key, wrapped_keys))| {
+            let decryptors = wrapped_keys
+                .into_iter()
+                .map(|(fingerprint, wrapped_key)| {
+                    let wrapped_key = wrapped_key
+                        .try_into()
+                        .map(AesWrapped128BitKey)
+                        .expect("Wrapped key length is fixed by the key");
+                    (fingerprint, wrapped_key)
+                })
+                .collect();
+            let decryptors = match dh_ephemeral_public_key {
+                PublicKey::Secp256k1(dh_ephemeral_public_key) => {
+                    DecryptorsByCurve::Secp256k1 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    }
+                }
+                PublicKey::Ed25519(dh_ephemeral_public_key) => {
+                    DecryptorsByCurve::Ed25519 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    }
+                }
+            };
+            (curve_type, decryptors)
+        })
+        .collect();
+
+    Ok(EncryptedMessageV1 {
+        encrypted,
+        decryptors_by_curve,
+    })
+}
+
+pub fn encrypt_message_v2(
+    plaintext: &PlaintextMessageV1,
+    recipients: &[PublicKey],
+) -> Result<EncryptedMessageV2, MessageEncryptionError> {
+    let (encrypted, decryptors) =
+        encrypt::<MESSAGE_KEY_V2_LENGTH>(plaintext, recipients)?;
+    let decryptors_by_curve = decryptors
+        .into_iter()
+        .map(|(curve_type, (dh_ephemeral_public_key, wrapped_keys))| {
+            let decryptors = wrapped_keys
+                .into_iter()
+                .map(|(fingerprint, wrapped_key)| {
+                    let wrapped_key = wrapped_key
+                        .try_into()
+                        .map(AesWrapped256BitKey)
+                        .expect("Wrapped key length is fixed by the key");
+                    (fingerprint, wrapped_key)
+                })
+                .collect();
+            let decryptors = match dh_ephemeral_public_key {
+                PublicKey::Secp256k1(dh_ephemeral_public_key) => {
+                    DecryptorsByCurveV2::Secp256k1 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    }
+                }
+                PublicKey::Ed25519(dh_ephemeral_public_key) => {
+                    DecryptorsByCurveV2::Ed25519 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    }
+                }
+            };
+            (curve_type, decryptors)
+        })
+        .collect();
+
+    Ok(EncryptedMessageV2 {
+        encrypted,
+        decryptors_by_curve,
+    })
+}
+
+pub fn decrypt_message_v1(
+    message: &EncryptedMessageV1,
+    private_key: &PrivateKey,
+) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+    let (dh_ephemeral_public_key, wrapped_key) = match (
+        private_key,
+        message.decryptors_by_curve.get(&curve_type(private_key)),
+    ) {
+        (
+            PrivateKey::Secp256k1(private_key),
+            Some(DecryptorsByCurve::Secp256k1 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            PublicKey::Secp256k1(*dh_ephemeral_public_key),
+            decryptors
+                .get(&public_key_fingerprint(&private_key.public_key().into()))
+                .map(|wrapped_key| wrapped_key.0.as_slice()),
+        ),
+        (
+            PrivateKey::Ed25519(private_key),
+            Some(DecryptorsByCurve::Ed25519 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            PublicKey::Ed25519(*dh_ephemeral_public_key),
+            decryptors
+                .get(&public_key_fingerprint(&private_key.public_key().into()))
+                .map(|wrapped_key| wrapped_key.0.as_slice()),
+        ),
+        _ => return Err(MessageDecryptionError::NotARecipient),
+    };
+    let wrapped_key =
+        wrapped_key.ok_or(MessageDecryptionError::NotARecipient)?;
+
+    decrypt::<MESSAGE_KEY_V1_LENGTH>(
+        &message.encrypted,
+        private_key,
+        &dh_ephemeral_public_key,
+        wrapped_key,
+    )
+}
+
+pub fn decrypt_message_v2(
+    message: &EncryptedMessageV2,
+    private_key: &PrivateKey,
+) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+    let (dh_ephemeral_public_key, wrapped_key) = match (
+        private_key,
+        message.decryptors_by_curve.get(&curve_type(private_key)),
+    ) {
+        (
+            PrivateKey::Secp256k1(private_key),
+            Some(DecryptorsByCurveV2::Secp256k1 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            PublicKey::Secp256k1(*dh_ephemeral_public_key),
+            decryptors
+                .get(&public_key_fingerprint(&private_key.public_key().into()))
+                .map(|wrapped_key| wrapped_key.0.as_slice()),
+        ),
+        (
+            PrivateKey::Ed25519(private_key),
+            Some(DecryptorsByCurveV2::Ed25519 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            PublicKey::Ed25519(*dh_ephemeral_public_key),
+            decryptors
+                .get(&public_key_fingerprint(&private_key.public_key().into()))
+                .map(|wrapped_key| wrapped_key.0.as_slice()),
+        ),
+        _ => return Err(MessageDecryptionError::NotARecipient),
+    };
+    let wrapped_key =
+        wrapped_key.ok_or(MessageDecryptionError::NotARecipient)?;
+
+    decrypt::<MESSAGE_KEY_V2_LENGTH>(
+        &message.encrypted,
+        private_key,
+        &dh_ephemeral_public_key,
+        wrapped_key,
+    )
+}
+
+/// The fingerprint of a public key is the last 8 bytes of the Blake2b hash of
+/// the public key bytes.
+pub fn public_key_fingerprint(public_key: &PublicKey) -> PublicKeyFingerprint {
+    PublicKeyFingerprint::from(*public_key)
+}
+
+/// The ephemeral public key and the wrapped message keys of the recipients on
+/// a single curve.
+type CurveDecryptors = (PublicKey, IndexMap<PublicKeyFingerprint, Vec<u8>>);
+
+fn encrypt<const KEY_LENGTH: usize>(
+    plaintext: &PlaintextMessageV1,
+    recipients: &[PublicKey],
+) -> Result<
+    (AesGcmPayload, IndexMap<CurveType, CurveDecryptors>),
+    MessageEncryptionError,
+> {
+    if recipients.is_empty() {
+        return Err(MessageEncryptionError::NoRecipients);
+    }
+
+    // Encrypting the message with a random message key.
+    let mut message_key = [0u8; KEY_LENGTH];
+    OsRng.fill_bytes(&mut message_key);
+    let plaintext = manifest_encode(plaintext)
+        .map_err(MessageEncryptionError::EncodeError)?;
+    let encrypted = aes_gcm_encrypt(&message_key, &plaintext)
+        .ok_or(MessageEncryptionError::EncryptionFailed)?;
+
+    // Wrapping the message key for each of the recipients. A single ephemeral
+    // key is used for all of the recipients on the same curve.
+    let mut decryptors =
+        IndexMap::<CurveType, (PrivateKey, CurveDecryptors)>::new();
+    for recipient in recipients {
+        let (ephemeral_private_key, (_, wrapped_keys)) = decryptors
+            .entry(curve_type_of_public_key(recipient))
+            .or_insert_with_key(|curve_type| {
+                let private_key = new_ephemeral_private_key(*curve_type);
+                let public_key = private_key.public_key();
+                (private_key, (public_key, IndexMap::new()))
+            });
+
+        let key_encryption_key =
+            key_encryption_key(ephemeral_private_key, recipient)
+                .ok_or(MessageEncryptionError::InvalidPublicKey(*recipient))?;
+        let wrapped_key = aes_kw_wrap(&key_encryption_key, &message_key)
+            .ok_or(MessageEncryptionError::EncryptionFailed)?;
+        wrapped_keys.insert(public_key_fingerprint(recipient), wrapped_key);
+    }
+
+    let decryptors = decryptors
+        .into_iter()
+        .map(|(curve_type, (_, decryptors))| (curve_type, decryptors))
+        .collect();
+    Ok((encrypted, decryptors))
+}
+
+fn decrypt<const KEY_LENGTH: usize>(
+    encrypted: &AesGcmPayload,
+    private_key: &PrivateKey,
+    dh_ephemeral_public_key: &PublicKey,
+    wrapped_key: &[u8],
+) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+    let key_encryption_key =
+        key_encryption_key(private_key, dh_ephemeral_public_key)
+            .ok_or(MessageDecryptionError::InvalidEphemeralPublicKey)?;
+    let message_key = aes_kw_unwrap(&key_encryption_key, wrapped_key)
+        .ok_or(MessageDecryptionError::KeyUnwrappingFailed)?;
+
+    let plaintext = aes_gcm_decrypt(&message_key, &encrypted.0)
+        .ok_or(MessageDecryptionError::DecryptionFailed)?;
+    manifest_decode(&plaintext).map_err(MessageDecryptionError::DecodeError)
+}
+
+/// Derives the 256-bit key-encryption key from the Diffie-Hellman shared
+/// secret of the given private and public keys through HKDF with Blake2b-256,
+/// no salt and no info. The same key-encryption key is used regardless of the
+/// length of the message key that it wraps.
+fn key_encryption_key(
+    private_key: &PrivateKey,
+    public_key: &PublicKey,
+) -> Option<[u8; KEY_ENCRYPTION_KEY_LENGTH]> {
+    let shared_secret = diffie_hellman(private_key, public_key)?;
+
+    let mut key_encryption_key = [0u8; KEY_ENCRYPTION_KEY_LENGTH];
+    SimpleHkdf::<Blake2b<U32>>::new(None, &shared_secret)
+        .expand(&[], &mut key_encryption_key)
+        .ok()?;
+    Some(key_encryption_key)
+}
+
+fn aes_kw_wrap(
+    key_encryption_key: &[u8; KEY_ENCRYPTION_KEY_LENGTH],
+    key: &[u8],
+) -> Option<Vec<u8>> {
+    let mut wrapped_key = vec![0u8; key.len() + AES_KW_OVERHEAD];
+    KekAes256::from(*key_encryption_key)
+        .wrap(key, &mut wrapped_key)
+        .ok()?;
+    Some(wrapped_key)
+}
+
+fn aes_kw_unwrap(
+    key_encryption_key: &[u8; KEY_ENCRYPTION_KEY_LENGTH],
+    wrapped_key: &[u8],
+) -> Option<Vec<u8>> {
+    let mut key = vec![0u8; wrapped_key.len().checked_sub(AES_KW_OVERHEAD)?];
+    KekAes256::from(*key_encryption_key)
+        .unwrap(wrapped_key, &mut key)
+        .ok()?;
+    Some(key)
+}
+
+fn diffie_hellman(
+    private_key: &PrivateKey,
+    public_key: &PublicKey,
+) -> Option<[u8; 32]> {
+    match (private_key, public_key) {
+        (
+            PrivateKey::Secp256k1(private_key),
+            PublicKey::Secp256k1(public_key),
+        ) => {
+            let private_key =
+                secp256k1::SecretKey::from_slice(&private_key.to_bytes())
+                    .ok()?;
+            let public_key =
+                secp256k1::PublicKey::from_slice(&public_key.0).ok()?;
+            let shared_point =
+                secp256k1::ecdh::shared_secret_point(&public_key, &private_key);
+
+            let mut shared_secret = [0u8; 32];
+            shared_secret.copy_from_slice(&shared_point[..32]);
+            Some(shared_secret)
+        }
+        (PrivateKey::Ed25519(private_key), PublicKey::Ed25519(public_key)) => {
+            let private_key = ed25519_dalek::SigningKey::from_bytes(
+                &private_key.to_bytes().try_into().ok()?,
+            );
+            let public_key =
+                ed25519_dalek::VerifyingKey::from_bytes(&public_key.0).ok()?;
+            Some(x25519_dalek::x25519(
+                private_key.to_scalar_bytes(),
+                public_key.to_montgomery().to_bytes(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn new_ephemeral_private_key(curve_type: CurveType) -> PrivateKey {
+    loop {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        let private_key = match curve_type {
+            CurveType::Secp256k1 => Secp256k1PrivateKey::from_bytes(&bytes)
+                .ok()
+                .map(PrivateKey::Secp256k1),
+            CurveType::Ed25519 => Ed25519PrivateKey::from_bytes(&bytes)
+                .ok()
+                .map(PrivateKey::Ed25519),
+        };
+        // Secp256k1 private keys are rejected when they're not below the order
+        // of the curve which is extremely unlikely. We just try again.
+        if let Some(private_key) = private_key {
+            return private_key;
+        }
+    }
+}
+
+fn aes_gcm_encrypt(key: &[u8], plaintext: &[u8]) -> Option<AesGcmPayload> {
+    let mut nonce = [0u8; AES_GCM_NONCE_LENGTH];
+    OsRng.fill_bytes(&mut nonce);
+    let nonce = Nonce::from_slice(&nonce);
+
+    let ciphertext = match key.len() {
+        MESSAGE_KEY_V1_LENGTH => Aes128Gcm::new_from_slice(key)
+            .ok()?
+            .encrypt(nonce, plaintext),
+        MESSAGE_KEY_V2_LENGTH => Aes256Gcm::new_from_slice(key)
+            .ok()?
+            .encrypt(nonce, plaintext),
+        _ => return None,
+    }
+    .ok()?;
+
+    Some(AesGcmPayload(
+        nonce.iter().copied().chain(ciphertext).collect(),
+    ))
+}
+
+fn aes_gcm_decrypt(key: &[u8], payload: &[u8]) -> Option<Vec<u8>> {
+    if payload.len() < AES_GCM_NONCE_LENGTH {
+        return None;
+    }
+    let (nonce, ciphertext) = payload.split_at(AES_GCM_NONCE_LENGTH);
+    let nonce = Nonce::from_slice(nonce);
+
+    match key.len() {
+        MESSAGE_KEY_V1_LENGTH => Aes128Gcm::new_from_slice(key)
+            .ok()?
+            .decrypt(nonce, ciphertext),
+        MESSAGE_KEY_V2_LENGTH => Aes256Gcm::new_from_slice(key)
+            .ok()?
+            .decrypt(nonce, ciphertext),
+        _ => return None,
+    }
+    .ok()
+}
+
+fn curve_type(private_key: &PrivateKey) -> CurveType {
+    match private_key {
+        PrivateKey::Secp256k1(..) => CurveType::Secp256k1,
+        PrivateKey::Ed25519(..) => CurveType::Ed25519,
+    }
+}
+
+fn curve_type_of_public_key(public_key: &PublicKey) -> CurveType {
+    match public_key {
+        PublicKey::Secp256k1(..) => CurveType::Secp256k1,
+        PublicKey::Ed25519(..) => CurveType::Ed25519,
+    }
+}
+
+#[derive(Debug)]
+pub enum MessageEncryptionError {
+    NoRecipients,
+    InvalidPublicKey(PublicKey),
+    EncodeError(EncodeError),
+    EncryptionFailed,
+}
+
+#[derive(Debug)]
+pub enum MessageDecryptionError {
+    NotARecipient,
+    InvalidEphemeralPublicKey,
+    KeyUnwrappingFailed,
+    DecryptionFailed,
+    DecodeError(DecodeError),
+}
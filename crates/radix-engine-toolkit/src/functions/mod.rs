@@ -25,10 +25,16 @@ pub mod access_rule;
 pub mod derive;
 
 pub mod manifest_sbor;
+pub mod sbor_diff;
+pub mod schema_codegen;
+pub mod schema_json;
 pub mod scrypto_sbor;
 
 pub mod address;
 pub mod events;
+pub mod fee_estimation;
+pub mod key_derivation;
+pub mod message;
 pub mod utils;
 
 pub mod transaction_v1;
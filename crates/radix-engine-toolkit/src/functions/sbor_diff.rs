@@ -0,0 +1,328 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Structural comparison of Scrypto and Manifest values. The values are walked
+//! together and a change is recorded for every place where they differ. When a
+//! schema is given the fields of tuples and enum variants are identified by the
+//! names of the fields in the schema.
+
+use crate::functions::schema_json::field_names;
+use crate::internal_prelude::*;
+
+/// Compares the two values and returns the changes needed to go from the old
+/// to the new value. Each changed value and map key is converted into the
+/// output representation through the given function, the first conversion
+/// that fails fails the whole diff.
+///
+/// Tuples and enums of the same variant are compared field by field, arrays
+/// element by element and maps entry by entry with entries matched by their
+/// keys. Any other difference, such as an enum changing its variant, is
+/// recorded as a change of the whole value.
+pub fn diff_values<X, Y, V, E>(
+    old_value: &Value<X, Y>,
+    new_value: &Value<X, Y>,
+    schema: Option<(LocalTypeId, &Schema<ScryptoCustomSchema>)>,
+    to_output: impl Fn(&Value<X, Y>) -> Result<V, E>,
+) -> Result<Vec<SborChange<V>>, E>
+where
+    X: CustomValueKind,
+    Y: CustomValue<X> + PartialEq + for<'b> Encode<X, VecEncoder<'b, X>>,
+    V: Clone,
+{
+    let mut differ = Differ {
+        schema: schema.map(|(_, schema)| schema),
+        to_output,
+        changes: vec![],
+    };
+    differ.diff(
+        old_value,
+        new_value,
+        schema.map(|(local_type_id, _)| local_type_id),
+        &mut vec![],
+    )?;
+    Ok(differ.changes)
+}
+
+struct Differ<'s, F, V> {
+    schema: Option<&'s Schema<ScryptoCustomSchema>>,
+    to_output: F,
+    changes: Vec<SborChange<V>>,
+}
+
+impl<'s, F, V> Differ<'s, F, V>
+where
+    V: Clone,
+{
+    fn diff<X, Y, E>(
+        &mut self,
+        old_value: &Value<X, Y>,
+        new_value: &Value<X, Y>,
+        local_type_id: Option<LocalTypeId>,
+        path: &mut Vec<SborPathElement<V>>,
+    ) -> Result<(), E>
+    where
+        X: CustomValueKind,
+        Y: CustomValue<X> + PartialEq + for<'b> Encode<X, VecEncoder<'b, X>>,
+        F: Fn(&Value<X, Y>) -> Result<V, E>,
+    {
+        if old_value == new_value {
+            return Ok(());
+        }
+
+        let (type_kind, type_metadata) = match self.schema.zip(local_type_id) {
+            Some((schema, local_type_id)) => (
+                schema.resolve_type_kind(local_type_id),
+                schema.resolve_type_metadata(local_type_id),
+            ),
+            None => (None, None),
+        };
+
+        match (old_value, new_value) {
+            (
+                Value::Tuple { fields: old_fields },
+                Value::Tuple { fields: new_fields },
+            ) if old_fields.len() == new_fields.len() => {
+                let field_types = match type_kind {
+                    Some(TypeKind::Tuple { field_types }) => {
+                        Some(field_types.as_slice())
+                    }
+                    _ => None,
+                };
+                self.diff_fields(
+                    old_fields,
+                    new_fields,
+                    field_types,
+                    field_names(type_metadata),
+                    path,
+                )?
+            }
+            (
+                Value::Enum {
+                    discriminator: old_discriminator,
+                    fields: old_fields,
+                },
+                Value::Enum {
+                    discriminator: new_discriminator,
+                    fields: new_fields,
+                },
+            ) if old_discriminator == new_discriminator
+                && old_fields.len() == new_fields.len() =>
+            {
+                let field_types = match type_kind {
+                    Some(TypeKind::Enum { variants }) => {
+                        variants.get(old_discriminator).map(Vec::as_slice)
+                    }
+                    _ => None,
+                };
+                let variant_metadata = match type_metadata
+                    .and_then(|metadata| metadata.child_names.as_ref())
+                {
+                    Some(ChildNames::EnumVariants(variants)) => {
+                        variants.get(old_discriminator)
+                    }
+                    _ => None,
+                };
+                self.diff_fields(
+                    old_fields,
+                    new_fields,
+                    field_types,
+                    field_names(variant_metadata),
+                    path,
+                )?
+            }
+            (
+                Value::Array {
+                    elements: old_elements,
+                    ..
+                },
+                Value::Array {
+                    elements: new_elements,
+                    ..
+                },
+            ) => {
+                let element_type = match type_kind {
+                    Some(TypeKind::Array { element_type }) => {
+                        Some(*element_type)
+                    }
+                    _ => None,
+                };
+                for index in 0..old_elements.len().max(new_elements.len()) {
+                    path.push(SborPathElement::ArrayIndex(index));
+                    match (old_elements.get(index), new_elements.get(index)) {
+                        (Some(old_element), Some(new_element)) => self.diff(
+                            old_element,
+                            new_element,
+                            element_type,
+                            path,
+                        )?,
+                        (old_element, new_element) => {
+                            self.record(path, old_element, new_element)?
+                        }
+                    }
+                    path.pop();
+                }
+            }
+            (
+                Value::Map {
+                    entries: old_entries,
+                    ..
+                },
+                Value::Map {
+                    entries: new_entries,
+                    ..
+                },
+            ) => {
+                let value_type = match type_kind {
+                    Some(TypeKind::Map { value_type, .. }) => Some(*value_type),
+                    _ => None,
+                };
+                let old_entries_by_key = MapEntries::new(old_entries);
+                let new_entries_by_key = MapEntries::new(new_entries);
+                for (key, old_entry_value) in old_entries.iter() {
+                    path.push(SborPathElement::MapKey((self.to_output)(key)?));
+                    match new_entries_by_key.get(key) {
+                        Some(new_entry_value) => self.diff(
+                            old_entry_value,
+                            new_entry_value,
+                            value_type,
+                            path,
+                        )?,
+                        None => {
+                            self.record(path, Some(old_entry_value), None)?
+                        }
+                    }
+                    path.pop();
+                }
+                for (key, new_entry_value) in new_entries.iter() {
+                    if old_entries_by_key.get(key).is_some() {
+                        continue;
+                    }
+                    path.push(SborPathElement::MapKey((self.to_output)(key)?));
+                    self.record(path, None, Some(new_entry_value))?;
+                    path.pop();
+                }
+            }
+            (old_value, new_value) => {
+                self.record(path, Some(old_value), Some(new_value))?
+            }
+        }
+        Ok(())
+    }
+
+    fn diff_fields<X, Y, E>(
+        &mut self,
+        old_fields: &[Value<X, Y>],
+        new_fields: &[Value<X, Y>],
+        field_types: Option<&[LocalTypeId]>,
+        field_names: Option<&[Cow<'static, str>]>,
+        path: &mut Vec<SborPathElement<V>>,
+    ) -> Result<(), E>
+    where
+        X: CustomValueKind,
+        Y: CustomValue<X> + PartialEq + for<'b> Encode<X, VecEncoder<'b, X>>,
+        F: Fn(&Value<X, Y>) -> Result<V, E>,
+    {
+        for (index, (old_field, new_field)) in
+            old_fields.iter().zip(new_fields).enumerate()
+        {
+            path.push(match field_names.and_then(|names| names.get(index)) {
+                Some(name) => SborPathElement::FieldName(name.to_string()),
+                None => SborPathElement::FieldIndex(index),
+            });
+            self.diff(
+                old_field,
+                new_field,
+                field_types.and_then(|types| types.get(index)).copied(),
+                path,
+            )?;
+            path.pop();
+        }
+        Ok(())
+    }
+
+    fn record<X, Y, E>(
+        &mut self,
+        path: &[SborPathElement<V>],
+        old_value: Option<&Value<X, Y>>,
+        new_value: Option<&Value<X, Y>>,
+    ) -> Result<(), E>
+    where
+        X: CustomValueKind,
+        Y: CustomValue<X>,
+        F: Fn(&Value<X, Y>) -> Result<V, E>,
+    {
+        self.changes.push(SborChange {
+            path: path.to_vec(),
+            old_value: old_value.map(&self.to_output).transpose()?,
+            new_value: new_value.map(&self.to_output).transpose()?,
+        });
+        Ok(())
+    }
+}
+
+/// The key and value pairs of a map value.
+type Entries<X, Y> = [(Value<X, Y>, Value<X, Y>)];
+
+/// The entries of a map indexed by the encoding of their keys so that the
+/// entries of two maps can be matched without comparing every pair of keys.
+/// Keys that can't be encoded are rare enough to be looked up linearly.
+struct MapEntries<'v, X, Y>
+where
+    X: CustomValueKind,
+    Y: CustomValue<X>,
+{
+    entries: &'v Entries<X, Y>,
+    entries_by_key: IndexMap<Vec<u8>, &'v Value<X, Y>>,
+}
+
+impl<'v, X, Y> MapEntries<'v, X, Y>
+where
+    X: CustomValueKind,
+    Y: CustomValue<X> + PartialEq + for<'b> Encode<X, VecEncoder<'b, X>>,
+{
+    fn new(entries: &'v Entries<X, Y>) -> Self {
+        let mut entries_by_key = IndexMap::new();
+        for (key, value) in entries {
+            if let Some(encoded_key) = Self::encode_key(key) {
+                entries_by_key.entry(encoded_key).or_insert(value);
+            }
+        }
+        Self {
+            entries,
+            entries_by_key,
+        }
+    }
+
+    fn get(&self, key: &Value<X, Y>) -> Option<&'v Value<X, Y>> {
+        match Self::encode_key(key) {
+            Some(encoded_key) => self.entries_by_key.get(&encoded_key).copied(),
+            None => self
+                .entries
+                .iter()
+                .find(|(entry_key, _)| entry_key == key)
+                .map(|(_, value)| value),
+        }
+    }
+
+    fn encode_key(key: &Value<X, Y>) -> Option<Vec<u8>> {
+        let mut encoded_key = vec![];
+        VecEncoder::<X>::new(&mut encoded_key, usize::MAX)
+            .encode(key)
+            .ok()?;
+        Some(encoded_key)
+    }
+}
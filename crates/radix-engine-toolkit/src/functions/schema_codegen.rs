@@ -0,0 +1,1377 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Generation of typed bindings from the schemas of blueprints. For each
+//! blueprint in a package definition the tuples and enums reachable from its
+//! events, state, registered types and functions are turned into Rust structs
+//! and enums, TypeScript types or Kotlin classes.
+//!
+//! Types without a name in the schema are named after where they're found,
+//! such as `TreeBounds` for the unnamed tuple in the `bounds` field of `Tree`.
+//!
+//! The Rust and Kotlin bindings also add typed helpers for every method of the
+//! blueprint which are built on `call_method` of the manifest builders. In
+//! Kotlin the generated classes convert into the values of the Radix Engine
+//! Toolkit's `ManifestV2Builder` which the helpers extend. The TypeScript
+//! bindings only contain the type definitions since there's no manifest
+//! builder in TypeScript to extend.
+
+use crate::functions::schema_json::field_names;
+use crate::internal_prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaCodegenLanguage {
+    Rust,
+    TypeScript,
+    Kotlin,
+}
+
+#[derive(Debug)]
+pub enum SchemaCodegenError {
+    TypeNotFoundInSchema(LocalTypeId),
+}
+
+/// Generates the bindings of all of the blueprints in the package definition
+/// and returns the source of each keyed by the name of the blueprint.
+pub fn generate(
+    package_definition: &PackageDefinition,
+    language: SchemaCodegenLanguage,
+) -> Result<IndexMap<String, String>, SchemaCodegenError> {
+    package_definition
+        .blueprints
+        .iter()
+        .map(|(blueprint_name, blueprint_definition)| {
+            generate_blueprint(blueprint_name, blueprint_definition, language)
+                .map(|source| (blueprint_name.clone(), source))
+        })
+        .collect()
+}
+
+/// Generates the bindings of a single blueprint from its definition.
+pub fn generate_blueprint(
+    blueprint_name: &str,
+    blueprint_definition: &BlueprintDefinitionInit,
+    language: SchemaCodegenLanguage,
+) -> Result<String, SchemaCodegenError> {
+    let BlueprintSchemaInit {
+        schema,
+        state,
+        events,
+        types,
+        functions,
+        ..
+    } = &blueprint_definition.schema;
+    let schema = schema.v1();
+
+    // Each of the roots comes with the name to give to it and the types in it
+    // that aren't named in the schema.
+    let roots = events
+        .event_schema
+        .iter()
+        .map(|(event_name, type_ref)| (type_ref, event_name.clone()))
+        .chain(state.fields.iter().enumerate().map(|(index, field)| {
+            (&field.field, format!("{blueprint_name}_field_{index}"))
+        }))
+        .chain(state.collections.iter().enumerate().flat_map(
+            |(index, collection)| {
+                let key_value_schema = match collection {
+                    BlueprintCollectionSchema::KeyValueStore(
+                        key_value_schema,
+                    )
+                    | BlueprintCollectionSchema::Index(key_value_schema)
+                    | BlueprintCollectionSchema::SortedIndex(
+                        key_value_schema,
+                    ) => key_value_schema,
+                };
+                let name = format!("{blueprint_name}_collection_{index}");
+                [
+                    (&key_value_schema.key, format!("{name}_key")),
+                    (&key_value_schema.value, format!("{name}_value")),
+                ]
+            },
+        ))
+        .chain(functions.functions.iter().flat_map(
+            |(function_name, function)| {
+                [
+                    (&function.input, format!("{function_name}_input")),
+                    (&function.output, format!("{function_name}_output")),
+                ]
+            },
+        ))
+        .filter_map(|(type_ref, name)| match type_ref {
+            TypeRef::Static(local_type_id) => Some((*local_type_id, name)),
+            TypeRef::Generic(..) => None,
+        })
+        .chain(types.type_schema.iter().map(|(type_name, local_type_id)| {
+            (*local_type_id, type_name.clone())
+        }))
+        .collect::<Vec<_>>();
+
+    let mut generator = Generator {
+        language,
+        schema,
+        definitions: IndexMap::new(),
+    };
+    let mut visited = IndexSet::new();
+    for (local_type_id, name) in roots {
+        generator.discover(local_type_id, &pascal_case(&name), &mut visited)?;
+    }
+
+    let mut method_helpers = vec![];
+    for (method_name, function) in functions
+        .functions
+        .iter()
+        .filter(|(_, function)| function.receiver.is_some())
+    {
+        if let Some(method_helper) = generator.method_helper(
+            blueprint_name,
+            method_name,
+            &function.input,
+        )? {
+            method_helpers.push(method_helper);
+        }
+    }
+
+    let mut sections =
+        vec![generator.header(blueprint_name, !method_helpers.is_empty())];
+    for (local_type_id, name) in generator.definitions.iter() {
+        sections.push(generator.definition(*local_type_id, name)?);
+    }
+    if !method_helpers.is_empty() {
+        match language {
+            SchemaCodegenLanguage::Rust => sections
+                .push(rust_method_helpers(blueprint_name, &method_helpers)),
+            SchemaCodegenLanguage::TypeScript => {}
+            SchemaCodegenLanguage::Kotlin => sections.extend(
+                method_helpers
+                    .iter()
+                    .map(|method_helper| method_helper.kotlin_extension()),
+            ),
+        }
+    }
+
+    Ok(sections.join("\n\n") + "\n")
+}
+
+struct Generator<'s> {
+    language: SchemaCodegenLanguage,
+    schema: &'s Schema<ScryptoCustomSchema>,
+    /// The types that get their own definition along with their names.
+    definitions: IndexMap<LocalTypeId, String>,
+}
+
+impl<'s> Generator<'s> {
+    fn header(&self, blueprint_name: &str, has_method_helpers: bool) -> String {
+        let comment = format!(
+            "// Generated from the schema of the {blueprint_name} blueprint."
+        );
+        match self.language {
+            SchemaCodegenLanguage::Rust if has_method_helpers => format!(
+                "{comment}\n\nuse radix_transactions::prelude::{{\n    BuildableManifest, InstructionV1, ManifestBuilder,\n    ReferencedManifestGlobalAddress,\n}};\nuse scrypto::prelude::*;"
+            ),
+            SchemaCodegenLanguage::Rust => {
+                format!("{comment}\n\nuse scrypto::prelude::*;")
+            }
+            SchemaCodegenLanguage::TypeScript => comment,
+            SchemaCodegenLanguage::Kotlin => format!(
+                "{comment}\n\nimport com.radixdlt.ret.*\nimport java.math.BigInteger"
+            ),
+        }
+    }
+
+    /// Walks the type and all of the types it references and records the ones
+    /// that need a definition of their own. The context is the name of the
+    /// place where the type was found and names the type if the schema doesn't.
+    fn discover(
+        &mut self,
+        local_type_id: LocalTypeId,
+        context: &str,
+        visited: &mut IndexSet<LocalTypeId>,
+    ) -> Result<(), SchemaCodegenError> {
+        if !visited.insert(local_type_id) {
+            return Ok(());
+        }
+
+        let context = if self.is_defined(local_type_id)? {
+            let name = self.unique_name(local_type_id, context);
+            self.definitions.insert(local_type_id, name.clone());
+            name
+        } else {
+            context.to_owned()
+        };
+
+        let type_metadata = self.type_metadata(local_type_id);
+        let children = match self.type_kind(local_type_id)? {
+            TypeKind::Array { element_type } => {
+                vec![(*element_type, format!("{context}Element"))]
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => vec![
+                (*key_type, format!("{context}Key")),
+                (*value_type, format!("{context}Value")),
+            ],
+            TypeKind::Tuple { field_types } => self
+                .fields(field_types, field_names(type_metadata))
+                .iter()
+                .map(|field| {
+                    (
+                        field.type_id,
+                        format!("{context}{}", field.context_name()),
+                    )
+                })
+                .collect(),
+            TypeKind::Enum { .. } => {
+                if let Some(inner_type_id) =
+                    self.option_inner_type(local_type_id)?
+                {
+                    vec![(inner_type_id, context)]
+                } else {
+                    self.variants(local_type_id)?
+                        .into_iter()
+                        .flat_map(|variant| {
+                            let context = format!("{context}{}", variant.name);
+                            let is_single_field = variant.fields.len() == 1;
+                            variant.fields.into_iter().map(move |field| {
+                                if is_single_field {
+                                    (field.type_id, context.clone())
+                                } else {
+                                    (
+                                        field.type_id,
+                                        format!(
+                                            "{context}{}",
+                                            field.context_name()
+                                        ),
+                                    )
+                                }
+                            })
+                        })
+                        .collect()
+                }
+            }
+            _ => vec![],
+        };
+        for (child, context) in children {
+            self.discover(child, &context, visited)?;
+        }
+
+        Ok(())
+    }
+
+    /// Tuples and enums get a definition of their own with the exception of
+    /// the unit type and options. Well-known tuples and enums are defined by
+    /// Scrypto and are only referenced by name in Rust.
+    fn is_defined(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Result<bool, SchemaCodegenError> {
+        let is_definable =
+            matches!(local_type_id, LocalTypeId::SchemaLocalIndex(..))
+                || self.language != SchemaCodegenLanguage::Rust;
+        let is_defined = match self.type_kind(local_type_id)? {
+            TypeKind::Tuple { field_types } => {
+                !field_types.is_empty()
+                    || (self.type_name(local_type_id).is_some()
+                        && matches!(
+                            local_type_id,
+                            LocalTypeId::SchemaLocalIndex(..)
+                        ))
+            }
+            TypeKind::Enum { .. } => {
+                self.option_inner_type(local_type_id)?.is_none()
+            }
+            _ => false,
+        };
+        Ok(is_definable && is_defined)
+    }
+
+    fn unique_name(&self, local_type_id: LocalTypeId, context: &str) -> String {
+        let base_name = self
+            .type_name(local_type_id)
+            .map(pascal_case)
+            .unwrap_or_else(|| pascal_case(context));
+        let mut name = base_name.clone();
+        let mut suffix = 2;
+        while self.definitions.values().any(|existing| *existing == name) {
+            name = format!("{base_name}{suffix}");
+            suffix += 1;
+        }
+        name
+    }
+
+    fn definition(
+        &self,
+        local_type_id: LocalTypeId,
+        name: &str,
+    ) -> Result<String, SchemaCodegenError> {
+        let type_metadata = self.type_metadata(local_type_id);
+        let is_manifest_representable = self
+            .is_manifest_representable(local_type_id, &mut IndexSet::new())?;
+        match self.type_kind(local_type_id)? {
+            TypeKind::Tuple { field_types } => self.struct_definition(
+                name,
+                &self.fields(field_types, field_names(type_metadata)),
+                is_manifest_representable,
+            ),
+            TypeKind::Enum { .. } => self.enum_definition(
+                name,
+                &self.variants(local_type_id)?,
+                is_manifest_representable,
+            ),
+            _ => Ok(String::new()),
+        }
+    }
+
+    /// The variants of the enum type with their names and fields.
+    fn variants(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Result<Vec<Variant>, SchemaCodegenError> {
+        let TypeKind::Enum { variants } = self.type_kind(local_type_id)? else {
+            return Ok(vec![]);
+        };
+        let variants_metadata = match self
+            .type_metadata(local_type_id)
+            .and_then(|metadata| metadata.child_names.as_ref())
+        {
+            Some(ChildNames::EnumVariants(variants_metadata)) => {
+                Some(variants_metadata)
+            }
+            _ => None,
+        };
+        Ok(variants
+            .iter()
+            .map(|(discriminator, field_types)| {
+                let variant_metadata = variants_metadata
+                    .and_then(|metadata| metadata.get(discriminator));
+                Variant {
+                    discriminator: *discriminator,
+                    name: variant_metadata
+                        .and_then(|metadata| metadata.type_name.as_deref())
+                        .map(pascal_case)
+                        .unwrap_or_else(|| format!("Variant{discriminator}")),
+                    fields: self
+                        .fields(field_types, field_names(variant_metadata)),
+                }
+            })
+            .collect())
+    }
+
+    fn struct_definition(
+        &self,
+        name: &str,
+        fields: &[Field],
+        is_manifest_representable: bool,
+    ) -> Result<String, SchemaCodegenError> {
+        let is_named = fields.iter().all(|field| field.name.is_some());
+        let definition = match self.language {
+            SchemaCodegenLanguage::Rust => {
+                let derive = rust_derive(is_manifest_representable);
+                if fields.is_empty() {
+                    format!("{derive}\npub struct {name};")
+                } else if is_named {
+                    format!(
+                        "{derive}\npub struct {name} {{\n{}}}",
+                        self.field_lines(fields, "    pub ", ",")?
+                    )
+                } else {
+                    format!(
+                        "{derive}\npub struct {name}({});",
+                        fields
+                            .iter()
+                            .map(|field| self
+                                .render_type(field.type_id)
+                                .map(|type_name| format!("pub {type_name}")))
+                            .collect::<Result<Vec<_>, _>>()?
+                            .join(", ")
+                    )
+                }
+            }
+            SchemaCodegenLanguage::TypeScript => {
+                if is_named && !fields.is_empty() {
+                    format!(
+                        "export interface {name} {{\n{}}}",
+                        self.field_lines(fields, "    ", ";")?
+                    )
+                } else {
+                    format!(
+                        "export type {name} = [{}];",
+                        self.render_types(fields)?.join(", ")
+                    )
+                }
+            }
+            SchemaCodegenLanguage::Kotlin => {
+                let conversion = if is_manifest_representable {
+                    Some(self.kotlin_conversion(
+                        "fun",
+                        &format!(
+                            "ManifestBuilderValue.TupleValue({})",
+                            self.kotlin_field_values(fields)?
+                        ),
+                    ))
+                } else {
+                    None
+                };
+                if fields.is_empty() {
+                    format!(
+                        "object {name} {{\n{}}}",
+                        conversion.unwrap_or_default()
+                    )
+                } else {
+                    format!(
+                        "data class {name}(\n{}){}",
+                        self.field_lines(fields, "    val ", ",")?,
+                        conversion
+                            .map(|conversion| format!(" {{\n{conversion}}}"))
+                            .unwrap_or_default()
+                    )
+                }
+            }
+        };
+        Ok(definition)
+    }
+
+    fn enum_definition(
+        &self,
+        name: &str,
+        variants: &[Variant],
+        is_manifest_representable: bool,
+    ) -> Result<String, SchemaCodegenError> {
+        let definition = match self.language {
+            SchemaCodegenLanguage::Rust => {
+                let mut lines = vec![];
+                for (index, variant) in variants.iter().enumerate() {
+                    if usize::from(variant.discriminator) != index {
+                        lines.push(format!(
+                            "    #[sbor(discriminator({}))]\n",
+                            variant.discriminator
+                        ));
+                    }
+                    let fields = if variant.fields.is_empty() {
+                        String::new()
+                    } else if variant.fields.iter().all(|f| f.name.is_some()) {
+                        format!(
+                            " {{\n{}    }}",
+                            self.field_lines(&variant.fields, "        ", ",")?
+                        )
+                    } else {
+                        format!(
+                            "({})",
+                            self.render_types(&variant.fields)?.join(", ")
+                        )
+                    };
+                    lines.push(format!("    {}{fields},\n", variant.name));
+                }
+                format!(
+                    "{}\npub enum {name} {{\n{}}}",
+                    rust_derive(is_manifest_representable),
+                    lines.concat()
+                )
+            }
+            SchemaCodegenLanguage::TypeScript => {
+                if variants.is_empty() {
+                    format!("export type {name} = never;")
+                } else {
+                    let variants = variants
+                        .iter()
+                        .map(|variant| {
+                            let fields = if variant.fields.is_empty() {
+                                String::new()
+                            } else if variant
+                                .fields
+                                .iter()
+                                .all(|field| field.name.is_some())
+                            {
+                                format!(
+                                    "; fields: {{ {} }}",
+                                    variant
+                                        .fields
+                                        .iter()
+                                        .map(|field| self.field_line(field))
+                                        .collect::<Result<Vec<_>, _>>()?
+                                        .join("; ")
+                                )
+                            } else {
+                                format!(
+                                    "; fields: [{}]",
+                                    self.render_types(&variant.fields)?
+                                        .join(", ")
+                                )
+                            };
+                            Ok(format!(
+                                "\n    | {{ variant: \"{}\"{fields} }}",
+                                variant.name
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, SchemaCodegenError>>()?;
+                    format!("export type {name} ={};", variants.concat())
+                }
+            }
+            SchemaCodegenLanguage::Kotlin => {
+                let mut members = vec![];
+                if is_manifest_representable {
+                    members.push(
+                        "    abstract fun toManifestBuilderValue(): ManifestBuilderValue\n"
+                            .to_owned(),
+                    );
+                }
+                for variant in variants {
+                    let conversion = if is_manifest_representable {
+                        let conversion = self.kotlin_conversion(
+                            "override fun",
+                            &format!(
+                                "ManifestBuilderValue.EnumValue({}u, {})",
+                                variant.discriminator,
+                                self.kotlin_field_values(&variant.fields)?
+                            ),
+                        );
+                        format!(" {{\n{}    }}", indent(&conversion))
+                    } else {
+                        String::new()
+                    };
+                    let variant_name = &variant.name;
+                    members.push(if variant.fields.is_empty() {
+                        format!(
+                            "    object {variant_name} : {name}(){conversion}\n"
+                        )
+                    } else {
+                        format!(
+                            "    data class {variant_name}({}) : {name}(){conversion}\n",
+                            variant
+                                .fields
+                                .iter()
+                                .map(|field| self
+                                    .field_line(field)
+                                    .map(|line| format!("val {line}")))
+                                .collect::<Result<Vec<_>, _>>()?
+                                .join(", ")
+                        )
+                    });
+                }
+                format!("sealed class {name} {{\n{}}}", members.join("\n"))
+            }
+        };
+        Ok(definition)
+    }
+
+    /// Renders the parameters and arguments of a typed helper for calling the
+    /// method. Methods whose arguments can't be passed in a manifest or that
+    /// don't take a tuple of arguments don't get a helper.
+    fn method_helper(
+        &self,
+        blueprint_name: &str,
+        method_name: &str,
+        input: &TypeRef<LocalTypeId>,
+    ) -> Result<Option<MethodHelper>, SchemaCodegenError> {
+        let TypeRef::Static(input) = input else {
+            return Ok(None);
+        };
+        let TypeKind::Tuple { field_types } = self.type_kind(*input)? else {
+            return Ok(None);
+        };
+        if !self.is_manifest_representable(*input, &mut IndexSet::new())? {
+            return Ok(None);
+        }
+
+        let fields =
+            self.fields(field_types, field_names(self.type_metadata(*input)));
+        let identifiers = fields
+            .iter()
+            .map(|field| self.field_identifier(field))
+            .collect::<Vec<_>>();
+        let receiver = match self.language {
+            SchemaCodegenLanguage::Rust => {
+                ["address", "receiver_address", "component_address"]
+            }
+            SchemaCodegenLanguage::TypeScript
+            | SchemaCodegenLanguage::Kotlin => {
+                ["address", "receiverAddress", "componentAddress"]
+            }
+        }
+        .into_iter()
+        .find(|name| !identifiers.iter().any(|other| other == name))
+        .unwrap_or(match self.language {
+            SchemaCodegenLanguage::Rust => "method_receiver_address",
+            SchemaCodegenLanguage::TypeScript
+            | SchemaCodegenLanguage::Kotlin => "methodReceiverAddress",
+        })
+        .to_owned();
+
+        let arguments = match self.language {
+            SchemaCodegenLanguage::Rust => identifiers,
+            SchemaCodegenLanguage::TypeScript => vec![],
+            SchemaCodegenLanguage::Kotlin => fields
+                .iter()
+                .zip(identifiers.iter())
+                .map(|(field, identifier)| {
+                    self.kotlin_value(field.type_id, identifier, 0)
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+
+        Ok(Some(MethodHelper {
+            name: match self.language {
+                SchemaCodegenLanguage::Rust => format!(
+                    "{}_{}",
+                    snake_case(blueprint_name),
+                    snake_case(method_name)
+                ),
+                SchemaCodegenLanguage::TypeScript
+                | SchemaCodegenLanguage::Kotlin => format!(
+                    "{}{}",
+                    camel_case(blueprint_name),
+                    pascal_case(method_name)
+                ),
+            },
+            method_name: method_name.to_owned(),
+            receiver,
+            parameters: self.field_lines(&fields, "    ", ",")?,
+            arguments,
+        }))
+    }
+
+    fn kotlin_conversion(&self, modifiers: &str, value: &str) -> String {
+        format!(
+            "    {modifiers} toManifestBuilderValue(): ManifestBuilderValue =\n        {value}\n"
+        )
+    }
+
+    fn kotlin_field_values(
+        &self,
+        fields: &[Field],
+    ) -> Result<String, SchemaCodegenError> {
+        let values = fields
+            .iter()
+            .map(|field| {
+                self.kotlin_value(
+                    field.type_id,
+                    &self.field_identifier(field),
+                    0,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(format!("listOf({})", values.join(", ")))
+    }
+
+    /// The Kotlin expression converting the given expression of the type into
+    /// a `ManifestBuilderValue`. The type must be representable in manifests.
+    fn kotlin_value(
+        &self,
+        local_type_id: LocalTypeId,
+        expression: &str,
+        depth: usize,
+    ) -> Result<String, SchemaCodegenError> {
+        if self.definitions.contains_key(&local_type_id) {
+            return Ok(format!("{expression}.toManifestBuilderValue()"));
+        }
+        if let Some(inner_type_id) = self.option_inner_type(local_type_id)? {
+            let variable = format!("value{depth}");
+            let inner_value =
+                self.kotlin_value(inner_type_id, &variable, depth + 1)?;
+            return Ok(format!(
+                "{expression}?.let {{ {variable} -> ManifestBuilderValue.EnumValue(1u, listOf({inner_value})) }} ?: ManifestBuilderValue.EnumValue(0u, listOf())"
+            ));
+        }
+
+        let value = match self.type_kind(local_type_id)? {
+            TypeKind::Bool => format!("ManifestBuilderValue.BoolValue({expression})"),
+            TypeKind::I8 => format!("ManifestBuilderValue.I8Value({expression})"),
+            TypeKind::I16 => format!("ManifestBuilderValue.I16Value({expression})"),
+            TypeKind::I32 => format!("ManifestBuilderValue.I32Value({expression})"),
+            TypeKind::I64 => format!("ManifestBuilderValue.I64Value({expression})"),
+            TypeKind::I128 => {
+                format!("ManifestBuilderValue.I128Value({expression}.toString())")
+            }
+            TypeKind::U8 => format!("ManifestBuilderValue.U8Value({expression})"),
+            TypeKind::U16 => format!("ManifestBuilderValue.U16Value({expression})"),
+            TypeKind::U32 => format!("ManifestBuilderValue.U32Value({expression})"),
+            TypeKind::U64 => format!("ManifestBuilderValue.U64Value({expression})"),
+            TypeKind::U128 => {
+                format!("ManifestBuilderValue.U128Value({expression}.toString())")
+            }
+            TypeKind::String => {
+                format!("ManifestBuilderValue.StringValue({expression})")
+            }
+            TypeKind::Array { element_type } => {
+                if let TypeKind::U8 = self.type_kind(*element_type)? {
+                    format!(
+                        "ManifestBuilderValue.ArrayValue(ManifestBuilderValueKind.U8_VALUE, {expression}.map {{ ManifestBuilderValue.U8Value(it.toUByte()) }})"
+                    )
+                } else {
+                    let variable = format!("element{depth}");
+                    format!(
+                        "ManifestBuilderValue.ArrayValue({}, {expression}.map {{ {variable} -> {} }})",
+                        self.kotlin_value_kind(*element_type)?,
+                        self.kotlin_value(*element_type, &variable, depth + 1)?
+                    )
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let key_variable = format!("key{depth}");
+                let value_variable = format!("value{depth}");
+                format!(
+                    "ManifestBuilderValue.MapValue({}, {}, {expression}.map {{ ({key_variable}, {value_variable}) -> ManifestBuilderMapEntry({}, {}) }})",
+                    self.kotlin_value_kind(*key_type)?,
+                    self.kotlin_value_kind(*value_type)?,
+                    self.kotlin_value(*key_type, &key_variable, depth + 1)?,
+                    self.kotlin_value(*value_type, &value_variable, depth + 1)?
+                )
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => format!(
+                "ManifestBuilderValue.AddressValue(ManifestBuilderAddress.Static({expression}))"
+            ),
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+                match self.own_validation(local_type_id) {
+                    Some(OwnValidation::IsProof) => {
+                        format!("ManifestBuilderValue.ProofValue({expression})")
+                    }
+                    Some(OwnValidation::IsGlobalAddressReservation) => format!(
+                        "ManifestBuilderValue.AddressReservationValue({expression})"
+                    ),
+                    _ => format!("ManifestBuilderValue.BucketValue({expression})"),
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => {
+                format!("ManifestBuilderValue.DecimalValue({expression})")
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+                format!("ManifestBuilderValue.PreciseDecimalValue({expression})")
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => format!(
+                "ManifestBuilderValue.NonFungibleLocalIdValue({expression})"
+            ),
+            TypeKind::Any | TypeKind::Tuple { .. } | TypeKind::Enum { .. } => {
+                "ManifestBuilderValue.TupleValue(listOf())".to_owned()
+            }
+        };
+        Ok(value)
+    }
+
+    fn kotlin_value_kind(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Result<String, SchemaCodegenError> {
+        let value_kind = match self.type_kind(local_type_id)? {
+            TypeKind::Bool => "BOOL_VALUE",
+            TypeKind::I8 => "I8_VALUE",
+            TypeKind::I16 => "I16_VALUE",
+            TypeKind::I32 => "I32_VALUE",
+            TypeKind::I64 => "I64_VALUE",
+            TypeKind::I128 => "I128_VALUE",
+            TypeKind::U8 => "U8_VALUE",
+            TypeKind::U16 => "U16_VALUE",
+            TypeKind::U32 => "U32_VALUE",
+            TypeKind::U64 => "U64_VALUE",
+            TypeKind::U128 => "U128_VALUE",
+            TypeKind::String => "STRING_VALUE",
+            TypeKind::Array { .. } => "ARRAY_VALUE",
+            TypeKind::Any | TypeKind::Tuple { .. } => "TUPLE_VALUE",
+            TypeKind::Enum { .. } => "ENUM_VALUE",
+            TypeKind::Map { .. } => "MAP_VALUE",
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+                "ADDRESS_VALUE"
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+                match self.own_validation(local_type_id) {
+                    Some(OwnValidation::IsProof) => "PROOF_VALUE",
+                    Some(OwnValidation::IsGlobalAddressReservation) => {
+                        "ADDRESS_RESERVATION_VALUE"
+                    }
+                    _ => "BUCKET_VALUE",
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => "DECIMAL_VALUE",
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+                "PRECISE_DECIMAL_VALUE"
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+                "NON_FUNGIBLE_LOCAL_ID_VALUE"
+            }
+        };
+        Ok(format!("ManifestBuilderValueKind.{value_kind}"))
+    }
+
+    /// Whether values of the type can be passed in a manifest. In Rust none of
+    /// the owned types can be, while in Kotlin buckets, proofs and address
+    /// reservations map to their `ManifestV2Builder` counterparts.
+    fn is_manifest_representable(
+        &self,
+        local_type_id: LocalTypeId,
+        visited: &mut IndexSet<LocalTypeId>,
+    ) -> Result<bool, SchemaCodegenError> {
+        if !visited.insert(local_type_id) {
+            return Ok(true);
+        }
+
+        let children = match self.type_kind(local_type_id)? {
+            TypeKind::Any => return Ok(false),
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+                return Ok(self.language == SchemaCodegenLanguage::Kotlin
+                    && matches!(
+                        self.own_validation(local_type_id),
+                        Some(
+                            OwnValidation::IsBucket
+                                | OwnValidation::IsProof
+                                | OwnValidation::IsGlobalAddressReservation
+                        )
+                    ))
+            }
+            TypeKind::Array { element_type } => vec![*element_type],
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => vec![*key_type, *value_type],
+            TypeKind::Tuple { field_types } => field_types.clone(),
+            TypeKind::Enum { variants } => {
+                variants.values().flatten().copied().collect()
+            }
+            _ => vec![],
+        };
+        for child in children {
+            if !self.is_manifest_representable(child, visited)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// The name of the type when it's referenced in the generated code.
+    fn render_type(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Result<String, SchemaCodegenError> {
+        if let Some(name) = self.definitions.get(&local_type_id) {
+            return Ok(name.clone());
+        }
+        if let Some(inner_type_id) = self.option_inner_type(local_type_id)? {
+            let inner = self.render_type(inner_type_id)?;
+            return Ok(match self.language {
+                SchemaCodegenLanguage::Rust => format!("Option<{inner}>"),
+                SchemaCodegenLanguage::TypeScript => format!("{inner} | null"),
+                SchemaCodegenLanguage::Kotlin => format!("{inner}?"),
+            });
+        }
+
+        let rendered = match self.type_kind(local_type_id)? {
+            TypeKind::Any => self.pick(["ScryptoValue", "unknown", "Any"]),
+            TypeKind::Bool => self.pick(["bool", "boolean", "Boolean"]),
+            TypeKind::I8 => self.pick(["i8", "number", "Byte"]),
+            TypeKind::I16 => self.pick(["i16", "number", "Short"]),
+            TypeKind::I32 => self.pick(["i32", "number", "Int"]),
+            TypeKind::I64 => self.pick(["i64", "string", "Long"]),
+            TypeKind::I128 => self.pick(["i128", "string", "BigInteger"]),
+            TypeKind::U8 => self.pick(["u8", "number", "UByte"]),
+            TypeKind::U16 => self.pick(["u16", "number", "UShort"]),
+            TypeKind::U32 => self.pick(["u32", "number", "UInt"]),
+            TypeKind::U64 => self.pick(["u64", "string", "ULong"]),
+            TypeKind::U128 => self.pick(["u128", "string", "BigInteger"]),
+            TypeKind::String => self.pick(["String", "string", "String"]),
+            TypeKind::Array { element_type } => {
+                if let TypeKind::U8 = self.type_kind(*element_type)? {
+                    self.pick(["Vec<u8>", "string", "ByteArray"])
+                } else {
+                    let element = self.render_type(*element_type)?;
+                    match self.language {
+                        SchemaCodegenLanguage::Rust => {
+                            format!("Vec<{element}>")
+                        }
+                        SchemaCodegenLanguage::TypeScript
+                            if element.contains('|') =>
+                        {
+                            format!("({element})[]")
+                        }
+                        SchemaCodegenLanguage::TypeScript => {
+                            format!("{element}[]")
+                        }
+                        SchemaCodegenLanguage::Kotlin => {
+                            format!("List<{element}>")
+                        }
+                    }
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let key = self.render_type(*key_type)?;
+                let value = self.render_type(*value_type)?;
+                match self.language {
+                    SchemaCodegenLanguage::Rust => {
+                        format!("IndexMap<{key}, {value}>")
+                    }
+                    SchemaCodegenLanguage::TypeScript
+                    | SchemaCodegenLanguage::Kotlin => {
+                        format!("Map<{key}, {value}>")
+                    }
+                }
+            }
+            TypeKind::Tuple { field_types } if field_types.is_empty() => {
+                self.pick(["()", "[]", "Unit"])
+            }
+            // Only the well-known tuples and enums of Scrypto in Rust remain.
+            TypeKind::Tuple { .. } | TypeKind::Enum { .. } => self
+                .type_name(local_type_id)
+                .map(pascal_case)
+                .unwrap_or_else(|| "ScryptoValue".to_owned()),
+            TypeKind::Custom(custom_type_kind) => {
+                self.render_custom_type(local_type_id, custom_type_kind)
+            }
+        };
+        Ok(rendered)
+    }
+
+    fn render_custom_type(
+        &self,
+        local_type_id: LocalTypeId,
+        custom_type_kind: &ScryptoCustomTypeKind,
+    ) -> String {
+        match custom_type_kind {
+            ScryptoCustomTypeKind::Reference => {
+                let rust_type =
+                    match self.schema.resolve_type_validation(local_type_id) {
+                        Some(TypeValidation::Custom(
+                            ScryptoCustomTypeValidation::Reference(validation),
+                        )) => match validation {
+                            ReferenceValidation::IsGlobalPackage => {
+                                "PackageAddress"
+                            }
+                            ReferenceValidation::IsGlobalComponent => {
+                                "ComponentAddress"
+                            }
+                            ReferenceValidation::IsGlobalResourceManager => {
+                                "ResourceAddress"
+                            }
+                            ReferenceValidation::IsInternal
+                            | ReferenceValidation::IsInternalTyped(..) => {
+                                "InternalAddress"
+                            }
+                            _ => "GlobalAddress",
+                        },
+                        _ => "Reference",
+                    };
+                self.pick([rust_type, "string", "Address"])
+            }
+            ScryptoCustomTypeKind::Own => {
+                match self.own_validation(local_type_id) {
+                    Some(OwnValidation::IsBucket) => {
+                        self.pick(["Bucket", "string", "ManifestBuilderBucket"])
+                    }
+                    Some(OwnValidation::IsProof) => {
+                        self.pick(["Proof", "string", "ManifestBuilderProof"])
+                    }
+                    Some(OwnValidation::IsVault) => {
+                        self.pick(["Vault", "string", "Address"])
+                    }
+                    Some(OwnValidation::IsGlobalAddressReservation) => self
+                        .pick([
+                            "GlobalAddressReservation",
+                            "string",
+                            "ManifestBuilderAddressReservation",
+                        ]),
+                    _ => self.pick(["Own", "string", "Address"]),
+                }
+            }
+            ScryptoCustomTypeKind::Decimal => {
+                self.pick(["Decimal", "string", "Decimal"])
+            }
+            ScryptoCustomTypeKind::PreciseDecimal => {
+                self.pick(["PreciseDecimal", "string", "PreciseDecimal"])
+            }
+            ScryptoCustomTypeKind::NonFungibleLocalId => self.pick([
+                "NonFungibleLocalId",
+                "string",
+                "NonFungibleLocalId",
+            ]),
+        }
+    }
+
+    fn render_types(
+        &self,
+        fields: &[Field],
+    ) -> Result<Vec<String>, SchemaCodegenError> {
+        fields
+            .iter()
+            .map(|field| self.render_type(field.type_id))
+            .collect()
+    }
+
+    fn fields(
+        &self,
+        field_types: &[LocalTypeId],
+        field_names: Option<&[Cow<'static, str>]>,
+    ) -> Vec<Field> {
+        field_types
+            .iter()
+            .enumerate()
+            .map(|(index, type_id)| Field {
+                index,
+                name: field_names
+                    .and_then(|names| names.get(index))
+                    .map(|name| name.to_string()),
+                type_id: *type_id,
+            })
+            .collect()
+    }
+
+    /// A line per field with the given prefix and terminator, each in the form
+    /// of `name: Type`.
+    fn field_lines(
+        &self,
+        fields: &[Field],
+        prefix: &str,
+        terminator: &str,
+    ) -> Result<String, SchemaCodegenError> {
+        fields
+            .iter()
+            .map(|field| {
+                self.field_line(field)
+                    .map(|line| format!("{prefix}{line}{terminator}\n"))
+            })
+            .collect()
+    }
+
+    fn field_line(&self, field: &Field) -> Result<String, SchemaCodegenError> {
+        Ok(format!(
+            "{}: {}",
+            self.field_identifier(field),
+            self.render_type(field.type_id)?
+        ))
+    }
+
+    fn field_identifier(&self, field: &Field) -> String {
+        let identifier = match field.name {
+            Some(ref name) => sanitize_identifier(name),
+            None => format!("field{}", field.index),
+        };
+        match self.language {
+            SchemaCodegenLanguage::Rust
+                if RUST_KEYWORDS.contains(&identifier.as_str()) =>
+            {
+                if ["self", "Self", "super", "crate"]
+                    .contains(&identifier.as_str())
+                {
+                    format!("{identifier}_")
+                } else {
+                    format!("r#{identifier}")
+                }
+            }
+            SchemaCodegenLanguage::Kotlin
+                if KOTLIN_KEYWORDS.contains(&identifier.as_str()) =>
+            {
+                format!("`{identifier}`")
+            }
+            _ => identifier,
+        }
+    }
+
+    fn pick(&self, [rust, typescript, kotlin]: [&str; 3]) -> String {
+        match self.language {
+            SchemaCodegenLanguage::Rust => rust,
+            SchemaCodegenLanguage::TypeScript => typescript,
+            SchemaCodegenLanguage::Kotlin => kotlin,
+        }
+        .to_owned()
+    }
+
+    /// The type of the value of an `Option` if the type is an option.
+    fn option_inner_type(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Result<Option<LocalTypeId>, SchemaCodegenError> {
+        let TypeKind::Enum { variants } = self.type_kind(local_type_id)? else {
+            return Ok(None);
+        };
+        let is_option = self.type_name(local_type_id) == Some("Option")
+            && variants.len() == 2
+            && variants.get(&0).is_some_and(|fields| fields.is_empty());
+        Ok(variants
+            .get(&1)
+            .filter(|fields| is_option && fields.len() == 1)
+            .map(|fields| fields[0]))
+    }
+
+    fn own_validation(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Option<&'s OwnValidation> {
+        match self.schema.resolve_type_validation(local_type_id) {
+            Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                validation,
+            ))) => Some(validation),
+            _ => None,
+        }
+    }
+
+    fn type_kind(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Result<&'s LocalTypeKind<ScryptoCustomSchema>, SchemaCodegenError>
+    {
+        self.schema
+            .resolve_type_kind(local_type_id)
+            .ok_or(SchemaCodegenError::TypeNotFoundInSchema(local_type_id))
+    }
+
+    fn type_metadata(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Option<&'s TypeMetadata> {
+        self.schema.resolve_type_metadata(local_type_id)
+    }
+
+    fn type_name(&self, local_type_id: LocalTypeId) -> Option<&'s str> {
+        self.type_metadata(local_type_id)
+            .and_then(|metadata| metadata.type_name.as_deref())
+    }
+}
+
+struct Field {
+    index: usize,
+    name: Option<String>,
+    type_id: LocalTypeId,
+}
+
+impl Field {
+    /// The name of the field when naming the unnamed types found in it.
+    fn context_name(&self) -> String {
+        match self.name {
+            Some(ref name) => pascal_case(name),
+            None => format!("Field{}", self.index),
+        }
+    }
+}
+
+/// A typed helper for calling a method of the blueprint, rendered in the
+/// language of the bindings.
+struct MethodHelper {
+    name: String,
+    method_name: String,
+    receiver: String,
+    /// A line per parameter of the form `name: Type,`.
+    parameters: String,
+    /// The expressions passed as the arguments of the method.
+    arguments: Vec<String>,
+}
+
+impl MethodHelper {
+    fn rust_signature(&self) -> String {
+        format!(
+            "fn {}(\n        self,\n        {}: impl ReferencedManifestGlobalAddress,\n{}    ) -> Self",
+            self.name,
+            self.receiver,
+            indent(&self.parameters)
+        )
+    }
+
+    fn kotlin_extension(&self) -> String {
+        format!(
+            "fun ManifestV2Builder.{}(\n    {}: ManifestBuilderAddress,\n{}): ManifestV2Builder = callMethod(\n    {},\n    \"{}\",\n    listOf(\n{}    ),\n)",
+            self.name,
+            self.receiver,
+            self.parameters,
+            self.receiver,
+            self.method_name,
+            self.arguments
+                .iter()
+                .map(|argument| format!("        {argument},\n"))
+                .collect::<String>()
+        )
+    }
+}
+
+/// A trait with a typed helper for every method of the blueprint implemented
+/// on the manifest builders of all manifest types.
+fn rust_method_helpers(
+    blueprint_name: &str,
+    method_helpers: &[MethodHelper],
+) -> String {
+    let trait_name =
+        format!("{}ManifestBuilderExt", pascal_case(blueprint_name));
+    let declarations = method_helpers
+        .iter()
+        .map(|method_helper| {
+            format!("    {};\n", method_helper.rust_signature())
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let implementations = method_helpers
+        .iter()
+        .map(|method_helper| {
+            let arguments = match method_helper.arguments.as_slice() {
+                [] => "()".to_owned(),
+                [argument] => format!("({argument},)"),
+                arguments => format!("({})", arguments.join(", ")),
+            };
+            format!(
+                "    {} {{\n        self.call_method({}, \"{}\", {arguments})\n    }}\n",
+                method_helper.rust_signature(),
+                method_helper.receiver,
+                method_helper.method_name,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "pub trait {trait_name}: Sized {{\n{declarations}}}\n\nimpl<M: BuildableManifest> {trait_name} for ManifestBuilder<M>\nwhere\n    M::Instruction: From<InstructionV1>,\n{{\n{implementations}}}"
+    )
+}
+
+struct Variant {
+    discriminator: u8,
+    name: String,
+    fields: Vec<Field>,
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "do",
+    "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut",
+    "override", "priv", "pub", "ref", "return", "self", "Self", "static",
+    "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe",
+    "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+const KOTLIN_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+fn rust_derive(is_manifest_representable: bool) -> &'static str {
+    if is_manifest_representable {
+        "#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]"
+    } else {
+        "#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]"
+    }
+}
+
+fn sanitize_identifier(name: &str) -> String {
+    let identifier = name
+        .chars()
+        .map(|char| {
+            if char.is_ascii_alphanumeric() || char == '_' {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    match identifier.chars().next() {
+        Some(char) if !char.is_ascii_digit() => identifier,
+        _ => format!("_{identifier}"),
+    }
+}
+
+/// Converts names such as `Account_withdraw_Input` or `withdraw` into
+/// `AccountWithdrawInput` and `Withdraw`.
+fn pascal_case(name: &str) -> String {
+    let pascal_case = name
+        .split(|char: char| !char.is_ascii_alphanumeric())
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let mut chars = segment.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+                .collect::<String>()
+        })
+        .collect::<String>();
+    match pascal_case.chars().next() {
+        Some(char) if !char.is_ascii_digit() => pascal_case,
+        _ => format!("Type{pascal_case}"),
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    let mut previous_is_lowercase = false;
+    for char in name.chars() {
+        if !char.is_ascii_alphanumeric() {
+            if !snake_case.is_empty() && !snake_case.ends_with('_') {
+                snake_case.push('_');
+            }
+            previous_is_lowercase = false;
+            continue;
+        }
+        if char.is_ascii_uppercase() && previous_is_lowercase {
+            snake_case.push('_');
+        }
+        snake_case.push(char.to_ascii_lowercase());
+        previous_is_lowercase =
+            char.is_ascii_lowercase() || char.is_ascii_digit();
+    }
+    let snake_case = snake_case.trim_end_matches('_').to_owned();
+    match snake_case.chars().next() {
+        Some(char) if !char.is_ascii_digit() => snake_case,
+        _ => format!("_{snake_case}"),
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    let pascal_case = pascal_case(name);
+    let mut chars = pascal_case.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_lowercase())
+        .into_iter()
+        .chain(chars)
+        .collect()
+}
+
+fn indent(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_owned()
+            } else {
+                format!("    {line}\n")
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,782 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of natural or annotated JSON into Scrypto and Manifest values by
+//! following the type of the value in a [`Schema`].
+//!
+//! The natural form of a value is driven entirely by the schema: tuples can be
+//! given as JSON arrays or, when the schema names their fields, as JSON objects
+//! keyed by the field names. Enums can be given as the name or id of a variant
+//! or as an object with a `variant_name` or `variant_id` and the `fields` of the
+//! variant. Numbers can be given as JSON numbers or strings, and addresses,
+//! decimals and non-fungible local ids as their string representations.
+//!
+//! Any value may also be given in the annotated form produced when decoding
+//! with [`SerializationMode::Model`] or [`SerializationMode::Programmatic`],
+//! that is, an object with a `kind` and the content of the value. The annotated
+//! form is required for values of the `Any` type and for the Manifest values
+//! that can't be derived from the schema such as buckets, proofs, expressions,
+//! blobs, address reservations and named addresses.
+
+use crate::internal_prelude::*;
+use radix_transactions::data::{
+    from_decimal, from_non_fungible_local_id, from_precise_decimal,
+};
+use sbor_json::common::address::SerializableNodeId;
+use serde_json::Value as JsonValue;
+use std::str::FromStr;
+
+/// Converts the JSON into a [`ScryptoValue`] of the type with the given id in
+/// the schema.
+pub fn to_scrypto_value(
+    json: &JsonValue,
+    local_type_id: LocalTypeId,
+    schema: &Schema<ScryptoCustomSchema>,
+) -> Result<ScryptoValue, SchemaJsonError> {
+    Converter::new(schema).convert(json, local_type_id, 0)
+}
+
+/// Converts the JSON into a [`ManifestValue`] of the type with the given id in
+/// the schema.
+pub fn to_manifest_value(
+    json: &JsonValue,
+    local_type_id: LocalTypeId,
+    schema: &Schema<ScryptoCustomSchema>,
+) -> Result<ManifestValue, SchemaJsonError> {
+    Converter::new(schema).convert(json, local_type_id, 0)
+}
+
+#[derive(Debug)]
+pub enum SchemaJsonError {
+    TypeNotFoundInSchema(LocalTypeId),
+    DepthLimitExceeded,
+    InvalidValue {
+        expected: &'static str,
+        found: String,
+    },
+    InvalidFieldCount {
+        expected: usize,
+        found: usize,
+    },
+    MissingField(String),
+    UnknownField(String),
+    UnknownEnumVariant(String),
+    AnyTypeRequiresAnnotatedJson,
+    CannotDetermineValueKind,
+    ValueContainsNetworkMismatch,
+    SerdeDeserializationFailed(serde_json::Error),
+}
+
+/// The kinds of the annotated JSON values, any object with a `kind` of one of
+/// these is treated as an annotated value.
+const ANNOTATED_KINDS: &[&str] = &[
+    "Bool",
+    "I8",
+    "I16",
+    "I32",
+    "I64",
+    "I128",
+    "U8",
+    "U16",
+    "U32",
+    "U64",
+    "U128",
+    "String",
+    "Enum",
+    "Array",
+    "Bytes",
+    "Tuple",
+    "Map",
+    "Reference",
+    "Own",
+    "Decimal",
+    "PreciseDecimal",
+    "NonFungibleLocalId",
+    "Address",
+    "NamedAddress",
+    "Bucket",
+    "Proof",
+    "Expression",
+    "Blob",
+    "AddressReservation",
+];
+
+/// The custom values that JSON can be converted into along with how the custom
+/// type kinds of the schema map to them.
+trait JsonCustomValue<X: CustomValueKind>: CustomValue<X> + Sized {
+    fn custom_value_kind(type_kind: &ScryptoCustomTypeKind) -> Option<X>;
+
+    fn from_json(
+        type_kind: &ScryptoCustomTypeKind,
+        json: &JsonValue,
+        networks: &mut NetworkTracker,
+    ) -> Result<Self, SchemaJsonError>;
+
+    /// Converts the annotated values that can't be derived from the schema.
+    fn from_annotated_json(
+        kind: &str,
+        json: &JsonValue,
+    ) -> Option<Result<Self, SchemaJsonError>>;
+
+    /// Converts values of the `Any` type which must be in the annotated form.
+    fn any_from_json(
+        json: &JsonValue,
+    ) -> Result<Value<X, Self>, SchemaJsonError>;
+}
+
+impl JsonCustomValue<ScryptoCustomValueKind> for ScryptoCustomValue {
+    fn custom_value_kind(
+        type_kind: &ScryptoCustomTypeKind,
+    ) -> Option<ScryptoCustomValueKind> {
+        Some(match type_kind {
+            ScryptoCustomTypeKind::Reference => {
+                ScryptoCustomValueKind::Reference
+            }
+            ScryptoCustomTypeKind::Own => ScryptoCustomValueKind::Own,
+            ScryptoCustomTypeKind::Decimal => ScryptoCustomValueKind::Decimal,
+            ScryptoCustomTypeKind::PreciseDecimal => {
+                ScryptoCustomValueKind::PreciseDecimal
+            }
+            ScryptoCustomTypeKind::NonFungibleLocalId => {
+                ScryptoCustomValueKind::NonFungibleLocalId
+            }
+        })
+    }
+
+    fn from_json(
+        type_kind: &ScryptoCustomTypeKind,
+        json: &JsonValue,
+        networks: &mut NetworkTracker,
+    ) -> Result<Self, SchemaJsonError> {
+        Ok(match type_kind {
+            ScryptoCustomTypeKind::Reference => {
+                Self::Reference(Reference(networks.node_id(json)?))
+            }
+            ScryptoCustomTypeKind::Own => {
+                Self::Own(Own(networks.node_id(json)?))
+            }
+            ScryptoCustomTypeKind::Decimal => {
+                Self::Decimal(parse(json, "a decimal")?)
+            }
+            ScryptoCustomTypeKind::PreciseDecimal => {
+                Self::PreciseDecimal(parse(json, "a precise decimal")?)
+            }
+            ScryptoCustomTypeKind::NonFungibleLocalId => {
+                Self::NonFungibleLocalId(parse(
+                    json,
+                    "a non-fungible local id",
+                )?)
+            }
+        })
+    }
+
+    fn from_annotated_json(
+        _: &str,
+        _: &JsonValue,
+    ) -> Option<Result<Self, SchemaJsonError>> {
+        None
+    }
+
+    fn any_from_json(
+        json: &JsonValue,
+    ) -> Result<ScryptoValue, SchemaJsonError> {
+        let value =
+            serde_json::from_value::<ProgrammaticScryptoValue>(json.clone())
+                .map_err(SchemaJsonError::SerdeDeserializationFailed)?;
+        if value_contains_network_mismatch(&value) {
+            return Err(SchemaJsonError::ValueContainsNetworkMismatch);
+        }
+        Ok(value.to_scrypto_value())
+    }
+}
+
+impl JsonCustomValue<ManifestCustomValueKind> for ManifestCustomValue {
+    fn custom_value_kind(
+        type_kind: &ScryptoCustomTypeKind,
+    ) -> Option<ManifestCustomValueKind> {
+        match type_kind {
+            ScryptoCustomTypeKind::Reference => {
+                Some(ManifestCustomValueKind::Address)
+            }
+            // Owned nodes are passed as buckets, proofs or address reservations
+            // in manifests, the kind can only be determined from the value.
+            ScryptoCustomTypeKind::Own => None,
+            ScryptoCustomTypeKind::Decimal => {
+                Some(ManifestCustomValueKind::Decimal)
+            }
+            ScryptoCustomTypeKind::PreciseDecimal => {
+                Some(ManifestCustomValueKind::PreciseDecimal)
+            }
+            ScryptoCustomTypeKind::NonFungibleLocalId => {
+                Some(ManifestCustomValueKind::NonFungibleLocalId)
+            }
+        }
+    }
+
+    fn from_json(
+        type_kind: &ScryptoCustomTypeKind,
+        json: &JsonValue,
+        networks: &mut NetworkTracker,
+    ) -> Result<Self, SchemaJsonError> {
+        Ok(match type_kind {
+            ScryptoCustomTypeKind::Reference => {
+                Self::Address(ManifestAddress::Static(networks.node_id(json)?))
+            }
+            ScryptoCustomTypeKind::Own => {
+                return Err(SchemaJsonError::InvalidValue {
+                    expected:
+                        "an annotated bucket, proof or address reservation",
+                    found: json.to_string(),
+                })
+            }
+            ScryptoCustomTypeKind::Decimal => Self::Decimal(from_decimal(
+                parse::<Decimal>(json, "a decimal")?,
+            )),
+            ScryptoCustomTypeKind::PreciseDecimal => {
+                Self::PreciseDecimal(from_precise_decimal(parse::<
+                    PreciseDecimal,
+                >(
+                    json,
+                    "a precise decimal",
+                )?))
+            }
+            ScryptoCustomTypeKind::NonFungibleLocalId => {
+                Self::NonFungibleLocalId(from_non_fungible_local_id(parse::<
+                    NonFungibleLocalId,
+                >(
+                    json,
+                    "a non-fungible local id",
+                )?))
+            }
+        })
+    }
+
+    fn from_annotated_json(
+        kind: &str,
+        json: &JsonValue,
+    ) -> Option<Result<Self, SchemaJsonError>> {
+        if ![
+            "NamedAddress",
+            "Bucket",
+            "Proof",
+            "Expression",
+            "Blob",
+            "AddressReservation",
+        ]
+        .contains(&kind)
+        {
+            return None;
+        }
+
+        let value =
+            serde_json::from_value::<ProgrammaticManifestValue>(json.clone())
+                .map_err(SchemaJsonError::SerdeDeserializationFailed);
+        Some(value.and_then(|value| match value.to_manifest_value() {
+            ManifestValue::Custom { value } => Ok(value),
+            _ => Err(SchemaJsonError::InvalidValue {
+                expected: "an annotated manifest value",
+                found: json.to_string(),
+            }),
+        }))
+    }
+
+    fn any_from_json(
+        json: &JsonValue,
+    ) -> Result<ManifestValue, SchemaJsonError> {
+        let value =
+            serde_json::from_value::<ProgrammaticManifestValue>(json.clone())
+                .map_err(SchemaJsonError::SerdeDeserializationFailed)?;
+        if manifest_value_contains_network_mismatch(&value) {
+            return Err(SchemaJsonError::ValueContainsNetworkMismatch);
+        }
+        Ok(value.to_manifest_value())
+    }
+}
+
+/// Keeps track of the network of the addresses encountered in the JSON so that
+/// values with addresses from different networks are rejected.
+#[derive(Default)]
+struct NetworkTracker(Option<u8>);
+
+impl NetworkTracker {
+    fn node_id(&mut self, json: &JsonValue) -> Result<NodeId, SchemaJsonError> {
+        let SerializableNodeId(node_id, network_id) =
+            parse(json, "a Bech32m encoded address")?;
+        match self.0 {
+            Some(existing_network_id) if existing_network_id != network_id => {
+                Err(SchemaJsonError::ValueContainsNetworkMismatch)
+            }
+            _ => {
+                self.0 = Some(network_id);
+                Ok(node_id)
+            }
+        }
+    }
+}
+
+struct Converter<'s> {
+    schema: &'s Schema<ScryptoCustomSchema>,
+    networks: NetworkTracker,
+}
+
+impl<'s> Converter<'s> {
+    fn new(schema: &'s Schema<ScryptoCustomSchema>) -> Self {
+        Self {
+            schema,
+            networks: Default::default(),
+        }
+    }
+
+    fn convert<X: CustomValueKind, Y: JsonCustomValue<X>>(
+        &mut self,
+        json: &JsonValue,
+        local_type_id: LocalTypeId,
+        depth: usize,
+    ) -> Result<Value<X, Y>, SchemaJsonError> {
+        if depth > SCRYPTO_SBOR_V1_MAX_DEPTH {
+            return Err(SchemaJsonError::DepthLimitExceeded);
+        }
+
+        let type_kind = self
+            .schema
+            .resolve_type_kind(local_type_id)
+            .ok_or(SchemaJsonError::TypeNotFoundInSchema(local_type_id))?;
+        let type_metadata = self.schema.resolve_type_metadata(local_type_id);
+
+        // Annotated values are unwrapped into their content which is then
+        // converted in the same way as the natural form.
+        let json = match annotated_kind(json, type_kind, type_metadata) {
+            Some(kind) => {
+                if let Some(value) = Y::from_annotated_json(kind, json) {
+                    return value.map(|value| Value::Custom { value });
+                }
+                if let TypeKind::Any = type_kind {
+                    return Y::any_from_json(json);
+                }
+                annotated_content(kind, json)?
+            }
+            None => json,
+        };
+
+        let value = match type_kind {
+            TypeKind::Any => {
+                return Err(SchemaJsonError::AnyTypeRequiresAnnotatedJson)
+            }
+            TypeKind::Bool => Value::Bool {
+                value: match json {
+                    JsonValue::Bool(value) => *value,
+                    json => parse(json, "a boolean")?,
+                },
+            },
+            TypeKind::I8 => Value::I8 {
+                value: parse(json, "an i8")?,
+            },
+            TypeKind::I16 => Value::I16 {
+                value: parse(json, "an i16")?,
+            },
+            TypeKind::I32 => Value::I32 {
+                value: parse(json, "an i32")?,
+            },
+            TypeKind::I64 => Value::I64 {
+                value: parse(json, "an i64")?,
+            },
+            TypeKind::I128 => Value::I128 {
+                value: parse(json, "an i128")?,
+            },
+            TypeKind::U8 => Value::U8 {
+                value: parse(json, "a u8")?,
+            },
+            TypeKind::U16 => Value::U16 {
+                value: parse(json, "a u16")?,
+            },
+            TypeKind::U32 => Value::U32 {
+                value: parse(json, "a u32")?,
+            },
+            TypeKind::U64 => Value::U64 {
+                value: parse(json, "a u64")?,
+            },
+            TypeKind::U128 => Value::U128 {
+                value: parse(json, "a u128")?,
+            },
+            TypeKind::String => Value::String {
+                value: json
+                    .as_str()
+                    .ok_or_else(|| invalid_value("a string", json))?
+                    .to_owned(),
+            },
+            TypeKind::Array { element_type } => {
+                let elements = match json {
+                    // Bytes given as a hex string.
+                    JsonValue::String(string) => decode_hex(string)?
+                        .into_iter()
+                        .map(JsonValue::from)
+                        .map(|json| {
+                            self.convert(&json, *element_type, depth + 1)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                    JsonValue::Array(elements) => elements
+                        .iter()
+                        .map(|json| {
+                            self.convert(json, *element_type, depth + 1)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                    json => return Err(invalid_value("an array", json)),
+                };
+                Value::Array {
+                    element_value_kind: self
+                        .value_kind::<X, Y>(*element_type, elements.first())?,
+                    elements,
+                }
+            }
+            TypeKind::Tuple { field_types } => Value::Tuple {
+                fields: self.convert_fields::<X, Y>(
+                    Some(json),
+                    field_types,
+                    field_names(type_metadata),
+                    depth,
+                )?,
+            },
+            TypeKind::Enum { variants } => {
+                let (variant, fields) = match json {
+                    JsonValue::Object(object) => (
+                        object
+                            .get("variant_name")
+                            .or_else(|| object.get("variant"))
+                            .or_else(|| object.get("variant_id"))
+                            .ok_or_else(|| {
+                                invalid_value("an enum variant", json)
+                            })?,
+                        object.get("fields"),
+                    ),
+                    json => (json, None),
+                };
+                let (discriminator, variant_metadata) =
+                    resolve_variant(variant, variants, type_metadata)?;
+                Value::Enum {
+                    discriminator,
+                    fields: self.convert_fields::<X, Y>(
+                        fields,
+                        variants
+                            .get(&discriminator)
+                            .map(Vec::as_slice)
+                            .unwrap_or_default(),
+                        field_names(variant_metadata),
+                        depth,
+                    )?,
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let entries = match json {
+                    JsonValue::Object(object) => object
+                        .iter()
+                        .map(|(key, value)| {
+                            Ok((
+                                self.convert(
+                                    &JsonValue::String(key.clone()),
+                                    *key_type,
+                                    depth + 1,
+                                )?,
+                                self.convert(value, *value_type, depth + 1)?,
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, SchemaJsonError>>()?,
+                    JsonValue::Array(entries) => entries
+                        .iter()
+                        .map(|entry| {
+                            let (key, value) = map_entry(entry)?;
+                            Ok((
+                                self.convert(key, *key_type, depth + 1)?,
+                                self.convert(value, *value_type, depth + 1)?,
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, SchemaJsonError>>()?,
+                    json => return Err(invalid_value("a map", json)),
+                };
+                Value::Map {
+                    key_value_kind: self.value_kind::<X, Y>(
+                        *key_type,
+                        entries.first().map(|(key, _)| key),
+                    )?,
+                    value_value_kind: self.value_kind::<X, Y>(
+                        *value_type,
+                        entries.first().map(|(_, value)| value),
+                    )?,
+                    entries,
+                }
+            }
+            TypeKind::Custom(custom_type_kind) => Value::Custom {
+                value: Y::from_json(
+                    custom_type_kind,
+                    json,
+                    &mut self.networks,
+                )?,
+            },
+        };
+
+        Ok(value)
+    }
+
+    fn convert_fields<X: CustomValueKind, Y: JsonCustomValue<X>>(
+        &mut self,
+        json: Option<&JsonValue>,
+        field_types: &[LocalTypeId],
+        field_names: Option<&[Cow<'static, str>]>,
+        depth: usize,
+    ) -> Result<Vec<Value<X, Y>>, SchemaJsonError> {
+        match (json, field_names) {
+            (None, _) if field_types.is_empty() => Ok(vec![]),
+            (Some(JsonValue::Array(fields)), _) => {
+                if fields.len() != field_types.len() {
+                    return Err(SchemaJsonError::InvalidFieldCount {
+                        expected: field_types.len(),
+                        found: fields.len(),
+                    });
+                }
+                fields
+                    .iter()
+                    .zip(field_types)
+                    .map(|(json, type_id)| {
+                        self.convert(json, *type_id, depth + 1)
+                    })
+                    .collect()
+            }
+            (Some(JsonValue::Object(fields)), Some(field_names)) => {
+                if let Some(unknown_field) = fields.keys().find(|key| {
+                    !field_names.iter().any(|name| name.as_ref() == *key)
+                }) {
+                    return Err(SchemaJsonError::UnknownField(
+                        unknown_field.clone(),
+                    ));
+                }
+                field_names
+                    .iter()
+                    .zip(field_types)
+                    .map(|(name, type_id)| {
+                        let json =
+                            fields.get(name.as_ref()).ok_or_else(|| {
+                                SchemaJsonError::MissingField(name.to_string())
+                            })?;
+                        self.convert(json, *type_id, depth + 1)
+                    })
+                    .collect()
+            }
+            (json, _) => Err(invalid_value(
+                "the fields as an array or an object of named fields",
+                json.unwrap_or(&JsonValue::Null),
+            )),
+        }
+    }
+
+    /// The value kind of the given type or, if it can't be determined from the
+    /// type, that of the first converted value.
+    fn value_kind<X: CustomValueKind, Y: JsonCustomValue<X>>(
+        &self,
+        local_type_id: LocalTypeId,
+        first_value: Option<&Value<X, Y>>,
+    ) -> Result<ValueKind<X>, SchemaJsonError> {
+        let value_kind = match self.schema.resolve_type_kind(local_type_id) {
+            Some(TypeKind::Bool) => Some(ValueKind::Bool),
+            Some(TypeKind::I8) => Some(ValueKind::I8),
+            Some(TypeKind::I16) => Some(ValueKind::I16),
+            Some(TypeKind::I32) => Some(ValueKind::I32),
+            Some(TypeKind::I64) => Some(ValueKind::I64),
+            Some(TypeKind::I128) => Some(ValueKind::I128),
+            Some(TypeKind::U8) => Some(ValueKind::U8),
+            Some(TypeKind::U16) => Some(ValueKind::U16),
+            Some(TypeKind::U32) => Some(ValueKind::U32),
+            Some(TypeKind::U64) => Some(ValueKind::U64),
+            Some(TypeKind::U128) => Some(ValueKind::U128),
+            Some(TypeKind::String) => Some(ValueKind::String),
+            Some(TypeKind::Array { .. }) => Some(ValueKind::Array),
+            Some(TypeKind::Tuple { .. }) => Some(ValueKind::Tuple),
+            Some(TypeKind::Enum { .. }) => Some(ValueKind::Enum),
+            Some(TypeKind::Map { .. }) => Some(ValueKind::Map),
+            Some(TypeKind::Custom(custom_type_kind)) => {
+                Y::custom_value_kind(custom_type_kind).map(ValueKind::Custom)
+            }
+            Some(TypeKind::Any) | None => None,
+        };
+        value_kind
+            .or_else(|| first_value.map(value_kind_of))
+            .ok_or(SchemaJsonError::CannotDetermineValueKind)
+    }
+}
+
+/// The kind of the annotated value if the JSON is in the annotated form. An
+/// object with a `kind` field is not considered annotated if the type is a
+/// tuple that itself has a field called `kind`.
+fn annotated_kind<'j>(
+    json: &'j JsonValue,
+    type_kind: &LocalTypeKind<ScryptoCustomSchema>,
+    type_metadata: Option<&TypeMetadata>,
+) -> Option<&'j str> {
+    let kind = json
+        .as_object()
+        .and_then(|object| object.get("kind"))
+        .and_then(JsonValue::as_str)
+        .filter(|kind| ANNOTATED_KINDS.contains(kind))?;
+    let type_has_kind_field = matches!(type_kind, TypeKind::Tuple { .. })
+        && field_names(type_metadata)
+            .is_some_and(|names| names.iter().any(|name| name == "kind"));
+    (!type_has_kind_field).then_some(kind)
+}
+
+/// The content of an annotated value in a form that can be converted in the
+/// same way as the natural form of the value.
+fn annotated_content<'j>(
+    kind: &str,
+    json: &'j JsonValue,
+) -> Result<&'j JsonValue, SchemaJsonError> {
+    let field = match kind {
+        "Enum" => return Ok(json),
+        "Tuple" => "fields",
+        "Array" => "elements",
+        "Bytes" => "hex",
+        "Map" => "entries",
+        _ => "value",
+    };
+    json.get(field)
+        .ok_or_else(|| invalid_value("an annotated value", json))
+}
+
+pub(crate) fn field_names(
+    type_metadata: Option<&TypeMetadata>,
+) -> Option<&[Cow<'static, str>]> {
+    match type_metadata.and_then(|metadata| metadata.child_names.as_ref()) {
+        Some(ChildNames::NamedFields(names)) => Some(names.as_slice()),
+        _ => None,
+    }
+}
+
+/// Resolves the discriminator of the enum variant from its name or id along
+/// with the metadata of the variant.
+fn resolve_variant<'m>(
+    json: &JsonValue,
+    variants: &IndexMap<u8, Vec<LocalTypeId>>,
+    type_metadata: Option<&'m TypeMetadata>,
+) -> Result<(u8, Option<&'m TypeMetadata>), SchemaJsonError> {
+    let variants_metadata = match type_metadata
+        .and_then(|metadata| metadata.child_names.as_ref())
+    {
+        Some(ChildNames::EnumVariants(variants_metadata)) => {
+            Some(variants_metadata)
+        }
+        _ => None,
+    };
+
+    let discriminator = json
+        .as_str()
+        .and_then(|name| {
+            variants_metadata?
+                .iter()
+                .find_map(|(discriminator, metadata)| {
+                    (metadata.type_name.as_deref() == Some(name))
+                        .then_some(*discriminator)
+                })
+        })
+        .or_else(|| parse::<u8>(json, "").ok())
+        .filter(|discriminator| variants.contains_key(discriminator))
+        .ok_or_else(|| SchemaJsonError::UnknownEnumVariant(json.to_string()))?;
+
+    Ok((
+        discriminator,
+        variants_metadata.and_then(|metadata| metadata.get(&discriminator)),
+    ))
+}
+
+/// A map entry given either as a `{ "key": .., "value": .. }` object or as a
+/// `[key, value]` array.
+fn map_entry(
+    json: &JsonValue,
+) -> Result<(&JsonValue, &JsonValue), SchemaJsonError> {
+    match json {
+        JsonValue::Object(object) => object.get("key").zip(object.get("value")),
+        JsonValue::Array(array) if array.len() == 2 => {
+            array.first().zip(array.get(1))
+        }
+        _ => None,
+    }
+    .ok_or_else(|| invalid_value("a map entry", json))
+}
+
+/// Parses a value from a JSON string or from the string representation of any
+/// other JSON value such as a number.
+fn parse<T: FromStr>(
+    json: &JsonValue,
+    expected: &'static str,
+) -> Result<T, SchemaJsonError> {
+    match json {
+        JsonValue::String(string) => T::from_str(string),
+        JsonValue::Object(..) | JsonValue::Array(..) | JsonValue::Null => {
+            return Err(invalid_value(expected, json))
+        }
+        json => T::from_str(&json.to_string()),
+    }
+    .map_err(|_| invalid_value(expected, json))
+}
+
+fn decode_hex(string: &str) -> Result<Vec<u8>, SchemaJsonError> {
+    let invalid_hex = || invalid_value("a hex string", &string.into());
+    if !string.len().is_multiple_of(2) {
+        return Err(invalid_hex());
+    }
+    (0..string.len())
+        .step_by(2)
+        .map(|index| {
+            string
+                .get(index..index + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(invalid_hex)
+        })
+        .collect()
+}
+
+fn invalid_value(expected: &'static str, json: &JsonValue) -> SchemaJsonError {
+    SchemaJsonError::InvalidValue {
+        expected,
+        found: json.to_string(),
+    }
+}
+
+fn value_kind_of<X: CustomValueKind, Y: CustomValue<X>>(
+    value: &Value<X, Y>,
+) -> ValueKind<X> {
+    match value {
+        Value::Bool { .. } => ValueKind::Bool,
+        Value::I8 { .. } => ValueKind::I8,
+        Value::I16 { .. } => ValueKind::I16,
+        Value::I32 { .. } => ValueKind::I32,
+        Value::I64 { .. } => ValueKind::I64,
+        Value::I128 { .. } => ValueKind::I128,
+        Value::U8 { .. } => ValueKind::U8,
+        Value::U16 { .. } => ValueKind::U16,
+        Value::U32 { .. } => ValueKind::U32,
+        Value::U64 { .. } => ValueKind::U64,
+        Value::U128 { .. } => ValueKind::U128,
+        Value::String { .. } => ValueKind::String,
+        Value::Enum { .. } => ValueKind::Enum,
+        Value::Array { .. } => ValueKind::Array,
+        Value::Tuple { .. } => ValueKind::Tuple,
+        Value::Map { .. } => ValueKind::Map,
+        Value::Custom { value } => {
+            ValueKind::Custom(value.get_custom_value_kind())
+        }
+    }
+}
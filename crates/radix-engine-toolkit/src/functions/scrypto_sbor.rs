@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::functions::sbor_diff::diff_values;
+use crate::functions::schema_json::{self, SchemaJsonError};
 use crate::internal_prelude::*;
 
 pub fn encode(value: &ScryptoValue) -> Result<Vec<u8>, EncodeError> {
@@ -99,6 +101,233 @@ pub fn encode_string_representation(
     }
 }
 
+/// Encodes natural or annotated JSON into Scrypto SBOR of the type with the
+/// given id in the schema. See [`schema_json`] for the accepted forms of JSON.
+/// The encoded payload is validated against the schema before it's returned.
+pub fn encode_json_with_schema(
+    value: &str,
+    local_type_id: LocalTypeId,
+    schema: &Schema<ScryptoCustomSchema>,
+) -> Result<Vec<u8>, ScryptoSborError> {
+    let json = serde_json::from_str::<serde_json::Value>(value)
+        .map_err(ScryptoSborError::SerdeDeserializationFailed)?;
+    let value = schema_json::to_scrypto_value(&json, local_type_id, schema)
+        .map_err(ScryptoSborError::SchemaJsonError)?;
+    let encoded = encode(&value).map_err(ScryptoSborError::EncodeError)?;
+
+    validate_payload_against_schema::<ScryptoCustomExtension, _>(
+        &encoded,
+        schema,
+        local_type_id,
+        &(),
+        SCRYPTO_SBOR_V1_MAX_DEPTH,
+    )
+    .map_err(|_| ScryptoSborError::SchemaValidationError)?;
+
+    Ok(encoded)
+}
+
+/// Converts the programmatic JSON representation of a Scrypto value into a
+/// [`ManifestValue`] that can be passed as an argument in a manifest.
+pub fn string_representation_to_manifest_value(
+    representation: ScryptoSborStringRepresentation,
+) -> Result<ManifestValue, ScryptoSborError> {
+    match representation {
+        ScryptoSborStringRepresentation::ProgrammaticJson(value) => {
+            let value =
+                serde_json::from_str::<ProgrammaticScryptoValue>(&value)
+                    .map_err(ScryptoSborError::SerdeDeserializationFailed)?;
+            if value_contains_network_mismatch(&value) {
+                return Err(ScryptoSborError::ValueContainsNetworkMismatch);
+            }
+
+            scrypto_value_to_manifest_value(&value.to_scrypto_value())
+        }
+    }
+}
+
+/// Converts a [`ScryptoValue`] into a [`ManifestValue`]. References become
+/// static addresses while owned nodes can't be represented in a manifest and
+/// result in an error.
+pub fn scrypto_value_to_manifest_value(
+    value: &ScryptoValue,
+) -> Result<ManifestValue, ScryptoSborError> {
+    let value = match value {
+        ScryptoValue::Bool { value } => ManifestValue::Bool { value: *value },
+        ScryptoValue::I8 { value } => ManifestValue::I8 { value: *value },
+        ScryptoValue::I16 { value } => ManifestValue::I16 { value: *value },
+        ScryptoValue::I32 { value } => ManifestValue::I32 { value: *value },
+        ScryptoValue::I64 { value } => ManifestValue::I64 { value: *value },
+        ScryptoValue::I128 { value } => ManifestValue::I128 { value: *value },
+        ScryptoValue::U8 { value } => ManifestValue::U8 { value: *value },
+        ScryptoValue::U16 { value } => ManifestValue::U16 { value: *value },
+        ScryptoValue::U32 { value } => ManifestValue::U32 { value: *value },
+        ScryptoValue::U64 { value } => ManifestValue::U64 { value: *value },
+        ScryptoValue::U128 { value } => ManifestValue::U128 { value: *value },
+        ScryptoValue::String { value } => ManifestValue::String {
+            value: value.clone(),
+        },
+        ScryptoValue::Enum {
+            discriminator,
+            fields,
+        } => ManifestValue::Enum {
+            discriminator: *discriminator,
+            fields: fields
+                .iter()
+                .map(scrypto_value_to_manifest_value)
+                .collect::<Result<_, _>>()?,
+        },
+        ScryptoValue::Array {
+            element_value_kind,
+            elements,
+        } => ManifestValue::Array {
+            element_value_kind: scrypto_value_kind_to_manifest_value_kind(
+                *element_value_kind,
+            )?,
+            elements: elements
+                .iter()
+                .map(scrypto_value_to_manifest_value)
+                .collect::<Result<_, _>>()?,
+        },
+        ScryptoValue::Tuple { fields } => ManifestValue::Tuple {
+            fields: fields
+                .iter()
+                .map(scrypto_value_to_manifest_value)
+                .collect::<Result<_, _>>()?,
+        },
+        ScryptoValue::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        } => ManifestValue::Map {
+            key_value_kind: scrypto_value_kind_to_manifest_value_kind(
+                *key_value_kind,
+            )?,
+            value_value_kind: scrypto_value_kind_to_manifest_value_kind(
+                *value_value_kind,
+            )?,
+            entries: entries
+                .iter()
+                .map(|(key, value)| {
+                    Ok((
+                        scrypto_value_to_manifest_value(key)?,
+                        scrypto_value_to_manifest_value(value)?,
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+        },
+        ScryptoValue::Custom { value } => match value {
+            ScryptoCustomValue::Reference(reference) => ManifestValue::Custom {
+                value: ManifestCustomValue::Address(ManifestAddress::Static(
+                    reference.0,
+                )),
+            },
+            ScryptoCustomValue::Own(..) => {
+                return Err(ScryptoSborError::ValueNotRepresentableInManifest)
+            }
+            ScryptoCustomValue::Decimal(value) => to_manifest_value(value)
+                .expect("Decimals are always manifest encodable"),
+            ScryptoCustomValue::PreciseDecimal(value) => {
+                to_manifest_value(value)
+                    .expect("Precise decimals are always manifest encodable")
+            }
+            ScryptoCustomValue::NonFungibleLocalId(value) => to_manifest_value(
+                value,
+            )
+            .expect("Non-fungible local ids are always manifest encodable"),
+        },
+    };
+    Ok(value)
+}
+
+fn scrypto_value_kind_to_manifest_value_kind(
+    value_kind: ScryptoValueKind,
+) -> Result<ManifestValueKind, ScryptoSborError> {
+    let value_kind = match value_kind {
+        ScryptoValueKind::Bool => ManifestValueKind::Bool,
+        ScryptoValueKind::I8 => ManifestValueKind::I8,
+        ScryptoValueKind::I16 => ManifestValueKind::I16,
+        ScryptoValueKind::I32 => ManifestValueKind::I32,
+        ScryptoValueKind::I64 => ManifestValueKind::I64,
+        ScryptoValueKind::I128 => ManifestValueKind::I128,
+        ScryptoValueKind::U8 => ManifestValueKind::U8,
+        ScryptoValueKind::U16 => ManifestValueKind::U16,
+        ScryptoValueKind::U32 => ManifestValueKind::U32,
+        ScryptoValueKind::U64 => ManifestValueKind::U64,
+        ScryptoValueKind::U128 => ManifestValueKind::U128,
+        ScryptoValueKind::String => ManifestValueKind::String,
+        ScryptoValueKind::Enum => ManifestValueKind::Enum,
+        ScryptoValueKind::Array => ManifestValueKind::Array,
+        ScryptoValueKind::Tuple => ManifestValueKind::Tuple,
+        ScryptoValueKind::Map => ManifestValueKind::Map,
+        ScryptoValueKind::Custom(custom_value_kind) => {
+            ManifestValueKind::Custom(match custom_value_kind {
+                ScryptoCustomValueKind::Reference => {
+                    ManifestCustomValueKind::Address
+                }
+                ScryptoCustomValueKind::Own => {
+                    return Err(
+                        ScryptoSborError::ValueNotRepresentableInManifest,
+                    )
+                }
+                ScryptoCustomValueKind::Decimal => {
+                    ManifestCustomValueKind::Decimal
+                }
+                ScryptoCustomValueKind::PreciseDecimal => {
+                    ManifestCustomValueKind::PreciseDecimal
+                }
+                ScryptoCustomValueKind::NonFungibleLocalId => {
+                    ManifestCustomValueKind::NonFungibleLocalId
+                }
+            })
+        }
+    };
+    Ok(value_kind)
+}
+
+/// Compares two Scrypto SBOR payloads and returns the changes between them with
+/// the changed values in their [`ProgrammaticScryptoValue`] representation. If a
+/// schema is given both payloads are validated against it and the fields of
+/// tuples and enum variants are identified by their names in the schema.
+pub fn diff<T>(
+    old_value: T,
+    new_value: T,
+    network_id: u8,
+    schema: Option<(LocalTypeId, Schema<ScryptoCustomSchema>)>,
+) -> Result<Vec<SborChange<ProgrammaticScryptoValue>>, ScryptoSborError>
+where
+    T: AsRef<[u8]>,
+{
+    let [old_value, new_value] = [old_value.as_ref(), new_value.as_ref()].map(
+        |value| -> Result<_, ScryptoSborError> {
+            if let Some((ref local_type_id, ref schema)) = schema {
+                validate_payload_against_schema::<ScryptoCustomExtension, _>(
+                    value,
+                    schema,
+                    *local_type_id,
+                    &(),
+                    SCRYPTO_SBOR_V1_MAX_DEPTH,
+                )
+                .map_err(|_| ScryptoSborError::SchemaValidationError)?;
+            }
+            decode(value).map_err(ScryptoSborError::DecodeError)
+        },
+    );
+
+    diff_values(
+        &old_value?,
+        &new_value?,
+        schema
+            .as_ref()
+            .map(|(local_type_id, schema)| (*local_type_id, schema)),
+        |value| {
+            Ok(ProgrammaticScryptoValue::from_scrypto_value(
+                value, network_id,
+            ))
+        },
+    )
+}
+
 #[derive(Debug)]
 pub enum ScryptoSborError {
     SchemaValidationError,
@@ -106,4 +335,6 @@ pub enum ScryptoSborError {
     EncodeError(EncodeError),
     SerdeDeserializationFailed(serde_json::Error),
     ValueContainsNetworkMismatch,
+    ValueNotRepresentableInManifest,
+    SchemaJsonError(SchemaJsonError),
 }
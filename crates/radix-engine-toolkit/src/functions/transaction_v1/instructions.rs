@@ -32,10 +32,52 @@ pub fn statically_validate(
     .map_err(
         InstructionValidationError::LocatedInstructionSchemaValidationError,
     )?;
-    TransactionValidator::new_with_latest_config(network_definition)
+    validate_instructions(instructions, blobs, network_definition)
+}
+
+/// Validates the instructions with the validator of the engine. Manifest
+/// validation errors are returned along with the index of the instruction that
+/// caused them where it can be found.
+fn validate_instructions(
+    instructions: &[InstructionV1],
+    blobs: &IndexMap<Hash, Vec<u8>>,
+    network_definition: &NetworkDefinition,
+) -> Result<(), InstructionValidationError> {
+    let validator =
+        TransactionValidator::new_with_latest_config(network_definition);
+    validator
         .validate_instructions_v1(instructions, blobs)
-        .map_err(InstructionValidationError::IntentValidationError)?;
-    Ok(())
+        .map_err(|error| {
+            match (error, validator.config().manifest_validation) {
+                (
+                    IntentValidationError::ManifestValidationError(error),
+                    ManifestValidationRuleset::Interpreter(specifier),
+                ) => {
+                    InstructionValidationError::LocatedManifestValidationError(
+                        LocatedManifestValidationError::locate(
+                            &TransactionManifestV1 {
+                                instructions: instructions.to_vec(),
+                                blobs: blobs.clone(),
+                                object_names: Default::default(),
+                            },
+                            ValidationRuleset::for_specifier(specifier),
+                            error,
+                        ),
+                    )
+                }
+                (IntentValidationError::ManifestValidationError(error), _) => {
+                    InstructionValidationError::LocatedManifestValidationError(
+                        LocatedManifestValidationError {
+                            instruction_index: None,
+                            error,
+                        },
+                    )
+                }
+                (error, _) => {
+                    InstructionValidationError::IntentValidationError(error)
+                }
+            }
+        })
 }
 
 pub fn extract_addresses(
@@ -63,4 +105,5 @@ pub enum InstructionValidationError {
     LocatedInstructionSchemaValidationError(
         LocatedInstructionSchemaValidationError,
     ),
+    LocatedManifestValidationError(LocatedManifestValidationError),
 }
@@ -17,6 +17,11 @@
 
 use crate::internal_prelude::*;
 
+use crate::functions::fee_estimation::{
+    placeholder_notary, FeeEstimate, FeeEstimationError,
+    FeeEstimationParameters, PayloadSizes,
+};
+
 pub fn to_payload_bytes(
     manifest: &TransactionManifestV1,
 ) -> Result<Vec<u8>, EncodeError> {
@@ -46,15 +51,97 @@ pub fn statically_validate(
         })
 }
 
+pub fn estimate_fee(
+    manifest: &TransactionManifestV1,
+    receipt: RuntimeToolkitTransactionReceipt,
+    parameters: &FeeEstimationParameters,
+) -> Result<FeeEstimate, FeeEstimationError> {
+    if !parameters.subintent_signer_counts.is_empty() {
+        return Err(FeeEstimationError::SubintentSignerCountMismatch {
+            subintents: 0,
+            subintent_signer_counts: parameters.subintent_signer_counts.len(),
+        });
+    }
+
+    // The header and notary signature are placeholders of the same size as
+    // the real ones while the intent signatures and message are accounted for
+    // by the estimation. A V1 preview doesn't verify any signatures.
+    let (notary_public_key, notary_signature) = placeholder_notary();
+    let notarized_transaction = NotarizedTransactionV1 {
+        signed_intent: SignedIntentV1 {
+            intent: IntentV1 {
+                header: TransactionHeaderV1 {
+                    network_id: 0,
+                    start_epoch_inclusive: Epoch::of(0),
+                    end_epoch_exclusive: Epoch::of(0),
+                    nonce: 0,
+                    notary_public_key,
+                    notary_is_signatory: parameters.notary_is_signatory,
+                    tip_percentage: 0,
+                },
+                instructions: InstructionsV1(manifest.instructions.clone()),
+                blobs: BlobsV1 {
+                    blobs: manifest
+                        .blobs
+                        .values()
+                        .cloned()
+                        .map(BlobV1)
+                        .collect(),
+                },
+                message: MessageV1::None,
+            },
+            intent_signatures: IntentSignaturesV1 { signatures: vec![] },
+        },
+        notary_signature: NotarySignatureV1(notary_signature),
+    };
+    let payload_sizes = PayloadSizes {
+        unsigned_notarized_transaction: notarized_transaction
+            .to_raw()
+            .map_err(FeeEstimationError::EncodeError)?
+            .len(),
+        previewed_intent: notarized_transaction
+            .signed_intent
+            .intent
+            .to_raw()
+            .map_err(FeeEstimationError::EncodeError)?
+            .len(),
+    };
+    crate::functions::fee_estimation::estimate_fee(
+        receipt,
+        payload_sizes,
+        false,
+        parameters,
+    )
+}
+
 pub fn statically_analyze(
     manifest: &TransactionManifestV1,
 ) -> Result<StaticAnalysis, ManifestAnalysisError> {
     crate::internal_prelude::statically_analyze(manifest)
 }
 
+pub fn statically_analyze_with_registry(
+    manifest: &TransactionManifestV1,
+    registry: &ManifestAnalyzerRegistry,
+) -> Result<StaticAnalysis, ManifestAnalysisError> {
+    crate::internal_prelude::statically_analyze_with_registry(
+        manifest, registry,
+    )
+}
+
 pub fn dynamically_analyze(
     manifest: &TransactionManifestV1,
     receipt: RuntimeToolkitTransactionReceipt,
 ) -> Result<DynamicAnalysis, ManifestAnalysisError> {
     crate::internal_prelude::dynamically_analyze(manifest, receipt)
 }
+
+pub fn dynamically_analyze_with_registry(
+    manifest: &TransactionManifestV1,
+    receipt: RuntimeToolkitTransactionReceipt,
+    registry: &ManifestAnalyzerRegistry,
+) -> Result<DynamicAnalysis, ManifestAnalysisError> {
+    crate::internal_prelude::dynamically_analyze_with_registry(
+        manifest, receipt, registry,
+    )
+}
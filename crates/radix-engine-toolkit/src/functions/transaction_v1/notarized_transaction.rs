@@ -76,3 +76,21 @@ pub fn extract_signer_public_keys(
         .and_then(|prepared| validator.validate_notarized_v1(prepared))
         .map(|value| value.signer_keys)
 }
+
+/// Verifies the intent signatures and the notary signature of a
+/// [`NotarizedTransactionV1`] against the hashes that they're expected to be
+/// over.
+pub fn verify_signatures(
+    notarized_transaction: &NotarizedTransactionV1,
+) -> Result<Vec<SignatureVerification>, PrepareError> {
+    let signed_intent = &notarized_transaction.signed_intent;
+    let signed_intent_hash = super::signed_intent::hash(signed_intent)?.hash;
+    let mut verifications =
+        super::signed_intent::verify_signatures(signed_intent)?;
+    verifications.push(SignatureVerification::of_notary_signature(
+        signed_intent_hash,
+        signed_intent.intent.header.notary_public_key,
+        &notarized_transaction.notary_signature.0,
+    ));
+    Ok(verifications)
+}
@@ -64,3 +64,23 @@ pub fn statically_validate(
         })
         .map(|_| ())
 }
+
+/// Verifies the intent signatures of a [`SignedIntentV1`] against the hash of
+/// its intent.
+pub fn verify_signatures(
+    signed_intent: &SignedIntentV1,
+) -> Result<Vec<SignatureVerification>, PrepareError> {
+    let intent_hash = super::intent::hash(&signed_intent.intent)?.hash;
+    Ok(signed_intent
+        .intent_signatures
+        .signatures
+        .iter()
+        .map(|IntentSignatureV1(signature)| {
+            SignatureVerification::of_intent_signature(
+                SignatureKind::TransactionIntent,
+                intent_hash,
+                signature,
+            )
+        })
+        .collect())
+}
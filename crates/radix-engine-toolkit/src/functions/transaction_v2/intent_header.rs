@@ -0,0 +1,231 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Construction and validation of the validity windows of intent headers.
+//!
+//! An intent is valid within its epoch range and, optionally, within its range
+//! of proposer timestamps. A transaction is only valid where the windows of all
+//! of its intents overlap, which is why a running intersection of the windows
+//! of the root intent and of the non-root subintents is kept and is required
+//! to never become empty.
+
+use crate::internal_prelude::*;
+
+/// Constructs an [`IntentHeaderV2`] that is valid from the current epoch for
+/// the given number of epochs and within the optional proposer timestamp
+/// range. The constructed header is validated before it's returned.
+#[allow(clippy::too_many_arguments)]
+pub fn new(
+    network_id: u8,
+    current_epoch: Epoch,
+    epoch_count: u64,
+    min_proposer_timestamp_inclusive: Option<Instant>,
+    max_proposer_timestamp_exclusive: Option<Instant>,
+    intent_discriminator: u64,
+    validation_config: &TransactionValidationConfigV1,
+) -> Result<IntentHeaderV2, IntentHeaderValidationError> {
+    let end_epoch_exclusive = current_epoch
+        .number()
+        .checked_add(epoch_count)
+        .map(Epoch::of)
+        .ok_or(IntentHeaderValidationError::EpochRangeOverflows {
+            start_epoch_inclusive: current_epoch,
+            epoch_count,
+        })?;
+    let header = IntentHeaderV2 {
+        network_id,
+        start_epoch_inclusive: current_epoch,
+        end_epoch_exclusive,
+        min_proposer_timestamp_inclusive,
+        max_proposer_timestamp_exclusive,
+        intent_discriminator,
+    };
+    validate(&header, Some(current_epoch), validation_config)?;
+    Ok(header)
+}
+
+/// Validates the epoch and timestamp ranges of the header against the limits
+/// of the validation config. If a current epoch is provided then the header is
+/// also checked to be valid at that epoch.
+pub fn validate(
+    header: &IntentHeaderV2,
+    current_epoch: Option<Epoch>,
+    validation_config: &TransactionValidationConfigV1,
+) -> Result<(), IntentHeaderValidationError> {
+    let start_epoch_inclusive = header.start_epoch_inclusive;
+    let end_epoch_exclusive = header.end_epoch_exclusive;
+    if start_epoch_inclusive >= end_epoch_exclusive {
+        return Err(IntentHeaderValidationError::EpochRangeIsEmpty {
+            start_epoch_inclusive,
+            end_epoch_exclusive,
+        });
+    }
+
+    let epoch_range =
+        end_epoch_exclusive.number() - start_epoch_inclusive.number();
+    let max_epoch_range = validation_config.max_epoch_range;
+    if epoch_range > max_epoch_range {
+        return Err(IntentHeaderValidationError::EpochRangeTooLong {
+            epoch_range,
+            max_epoch_range,
+        });
+    }
+
+    if let (Some(min_inclusive), Some(max_exclusive)) = (
+        header.min_proposer_timestamp_inclusive,
+        header.max_proposer_timestamp_exclusive,
+    ) {
+        if min_inclusive.seconds_since_unix_epoch
+            >= max_exclusive.seconds_since_unix_epoch
+        {
+            return Err(IntentHeaderValidationError::TimestampRangeIsEmpty {
+                min_inclusive,
+                max_exclusive,
+            });
+        }
+    }
+
+    match current_epoch {
+        Some(current_epoch) if current_epoch < start_epoch_inclusive => {
+            Err(IntentHeaderValidationError::NotYetValid {
+                current_epoch,
+                start_epoch_inclusive,
+            })
+        }
+        Some(current_epoch) if current_epoch >= end_epoch_exclusive => {
+            Err(IntentHeaderValidationError::Expired {
+                current_epoch,
+                end_epoch_exclusive,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates the headers of the root intent and of the non-root subintents and
+/// checks that the windows of all of the intents have a common overlap. The
+/// epoch and timestamp ranges are intersected one intent after the other and
+/// the subintent that makes the intersection empty is reported.
+pub(crate) fn validate_non_root_subintent_headers(
+    root_header: &IntentHeaderV2,
+    non_root_subintents: &NonRootSubintentsV2,
+    current_epoch: Option<Epoch>,
+    validation_config: &TransactionValidationConfigV1,
+) -> Result<(), IntentHeaderValidationError> {
+    validate(root_header, current_epoch, validation_config)?;
+
+    let mut start_epoch_inclusive = root_header.start_epoch_inclusive;
+    let mut end_epoch_exclusive = root_header.end_epoch_exclusive;
+    let mut min_timestamp_inclusive = root_header
+        .min_proposer_timestamp_inclusive
+        .map(|instant| instant.seconds_since_unix_epoch);
+    let mut max_timestamp_exclusive = root_header
+        .max_proposer_timestamp_exclusive
+        .map(|instant| instant.seconds_since_unix_epoch);
+
+    for (subintent_index, subintent) in non_root_subintents.0.iter().enumerate()
+    {
+        let header = &subintent.intent_core.header;
+        validate(header, current_epoch, validation_config).map_err(
+            |error| IntentHeaderValidationError::InvalidSubintentHeader {
+                subintent_index,
+                error: Box::new(error),
+            },
+        )?;
+
+        start_epoch_inclusive =
+            start_epoch_inclusive.max(header.start_epoch_inclusive);
+        end_epoch_exclusive =
+            end_epoch_exclusive.min(header.end_epoch_exclusive);
+        if start_epoch_inclusive >= end_epoch_exclusive {
+            return Err(
+                IntentHeaderValidationError::SubintentEpochRangeDoesNotOverlap {
+                    subintent_index,
+                },
+            );
+        }
+
+        min_timestamp_inclusive = [
+            min_timestamp_inclusive,
+            header
+                .min_proposer_timestamp_inclusive
+                .map(|instant| instant.seconds_since_unix_epoch),
+        ]
+        .into_iter()
+        .flatten()
+        .max();
+        max_timestamp_exclusive = [
+            max_timestamp_exclusive,
+            header
+                .max_proposer_timestamp_exclusive
+                .map(|instant| instant.seconds_since_unix_epoch),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+        if let (Some(min_timestamp_inclusive), Some(max_timestamp_exclusive)) =
+            (min_timestamp_inclusive, max_timestamp_exclusive)
+        {
+            if min_timestamp_inclusive >= max_timestamp_exclusive {
+                return Err(
+                    IntentHeaderValidationError::SubintentTimestampRangeDoesNotOverlap {
+                        subintent_index,
+                    },
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntentHeaderValidationError {
+    EpochRangeOverflows {
+        start_epoch_inclusive: Epoch,
+        epoch_count: u64,
+    },
+    EpochRangeIsEmpty {
+        start_epoch_inclusive: Epoch,
+        end_epoch_exclusive: Epoch,
+    },
+    EpochRangeTooLong {
+        epoch_range: u64,
+        max_epoch_range: u64,
+    },
+    TimestampRangeIsEmpty {
+        min_inclusive: Instant,
+        max_exclusive: Instant,
+    },
+    NotYetValid {
+        current_epoch: Epoch,
+        start_epoch_inclusive: Epoch,
+    },
+    Expired {
+        current_epoch: Epoch,
+        end_epoch_exclusive: Epoch,
+    },
+    InvalidSubintentHeader {
+        subintent_index: usize,
+        error: Box<IntentHeaderValidationError>,
+    },
+    SubintentEpochRangeDoesNotOverlap {
+        subintent_index: usize,
+    },
+    SubintentTimestampRangeDoesNotOverlap {
+        subintent_index: usize,
+    },
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 pub mod instructions;
+pub mod intent_header;
 pub mod notarized_transaction;
 pub mod partial_transaction;
 pub mod signed_partial_transaction;
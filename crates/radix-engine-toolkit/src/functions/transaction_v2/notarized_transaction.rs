@@ -93,3 +93,28 @@ pub fn extract_signer_public_keys(
                 .collect()
         })
 }
+
+/// Verifies all of the intent and subintent signatures and the notary signature
+/// of a [`NotarizedTransactionV2`] against the hashes that they're expected to
+/// be over.
+pub fn verify_signatures(
+    notarized_transaction: &NotarizedTransactionV2,
+) -> Result<Vec<SignatureVerification>, SignatureVerificationError> {
+    let signed_transaction_intent =
+        &notarized_transaction.signed_transaction_intent;
+    let signed_transaction_intent_hash =
+        super::signed_transaction_intent::hash(signed_transaction_intent)?.hash;
+    let mut verifications =
+        super::signed_transaction_intent::verify_signatures(
+            signed_transaction_intent,
+        )?;
+    verifications.push(SignatureVerification::of_notary_signature(
+        signed_transaction_intent_hash,
+        signed_transaction_intent
+            .transaction_intent
+            .transaction_header
+            .notary_public_key,
+        &notarized_transaction.notary_signature.0,
+    ));
+    Ok(verifications)
+}
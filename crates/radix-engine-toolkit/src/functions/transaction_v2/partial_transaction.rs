@@ -17,6 +17,8 @@
 
 use crate::internal_prelude::*;
 
+use super::intent_header::IntentHeaderValidationError;
+
 pub fn hash(
     partial_transaction: &PartialTransactionV2,
 ) -> Result<TransactionHash, PrepareError> {
@@ -49,3 +51,19 @@ where
 {
     PartialTransactionV2::from_raw(&payload_bytes.as_ref().to_vec().into())
 }
+
+/// Validates the windows of the headers of all of the intents of the
+/// [`PartialTransactionV2`] and checks that the windows of all of the
+/// intents have a common overlap.
+pub fn validate_intent_headers(
+    partial_transaction: &PartialTransactionV2,
+    current_epoch: Option<Epoch>,
+    validation_config: &TransactionValidationConfigV1,
+) -> Result<(), IntentHeaderValidationError> {
+    super::intent_header::validate_non_root_subintent_headers(
+        &partial_transaction.root_subintent.intent_core.header,
+        &partial_transaction.non_root_subintents,
+        current_epoch,
+        validation_config,
+    )
+}
@@ -67,3 +67,33 @@ pub fn statically_validate(
         })
         .map(|_| ())
 }
+
+/// Verifies the signatures of the root subintent and of all of the non-root
+/// subintents of a [`SignedPartialTransactionV2`] against the hashes of the
+/// subintents that they're on.
+pub fn verify_signatures(
+    signed_partial_transaction: &SignedPartialTransactionV2,
+) -> Result<Vec<SignatureVerification>, SignatureVerificationError> {
+    let partial_transaction = &signed_partial_transaction.partial_transaction;
+    let root_subintent_hash =
+        super::subintent::hash(&partial_transaction.root_subintent)?.hash;
+    let mut verifications = signed_partial_transaction
+        .root_subintent_signatures
+        .signatures
+        .iter()
+        .map(|IntentSignatureV1(signature)| {
+            SignatureVerification::of_intent_signature(
+                SignatureKind::RootSubintent,
+                root_subintent_hash,
+                signature,
+            )
+        })
+        .collect::<Vec<_>>();
+    verifications.extend(
+        super::subintent::verify_non_root_subintent_signatures(
+            &partial_transaction.non_root_subintents,
+            &signed_partial_transaction.non_root_subintent_signatures,
+        )?,
+    );
+    Ok(verifications)
+}
@@ -49,3 +49,33 @@ where
 {
     SignedTransactionIntentV2::from_raw(&payload_bytes.as_ref().to_vec().into())
 }
+
+/// Verifies the signatures of the transaction intent and of all of the non-root
+/// subintents of a [`SignedTransactionIntentV2`] against the hashes of the
+/// intents that they're on.
+pub fn verify_signatures(
+    signed_transaction_intent: &SignedTransactionIntentV2,
+) -> Result<Vec<SignatureVerification>, SignatureVerificationError> {
+    let transaction_intent = &signed_transaction_intent.transaction_intent;
+    let transaction_intent_hash =
+        super::transaction_intent::hash(transaction_intent)?.hash;
+    let mut verifications = signed_transaction_intent
+        .transaction_intent_signatures
+        .signatures
+        .iter()
+        .map(|IntentSignatureV1(signature)| {
+            SignatureVerification::of_intent_signature(
+                SignatureKind::TransactionIntent,
+                transaction_intent_hash,
+                signature,
+            )
+        })
+        .collect::<Vec<_>>();
+    verifications.extend(
+        super::subintent::verify_non_root_subintent_signatures(
+            &transaction_intent.non_root_subintents,
+            &signed_transaction_intent.non_root_subintent_signatures,
+        )?,
+    );
+    Ok(verifications)
+}
@@ -40,3 +40,45 @@ where
 {
     SubintentV2::from_raw(&payload_bytes.as_ref().to_vec().into())
 }
+
+/// Verifies the signatures of each of the non-root subintents against the hash
+/// of the subintent. The signatures of a subintent are found at the same index
+/// as the subintent, and thus there must be exactly one list of signatures for
+/// each of the subintents.
+pub(crate) fn verify_non_root_subintent_signatures(
+    non_root_subintents: &NonRootSubintentsV2,
+    non_root_subintent_signatures: &NonRootSubintentSignaturesV2,
+) -> Result<Vec<SignatureVerification>, SignatureVerificationError> {
+    if non_root_subintents.0.len()
+        != non_root_subintent_signatures.by_subintent.len()
+    {
+        return Err(
+            SignatureVerificationError::IncorrectNumberOfSubintentSignatureBatches {
+                non_root_subintents: non_root_subintents.0.len(),
+                signature_batches: non_root_subintent_signatures
+                    .by_subintent
+                    .len(),
+            },
+        );
+    }
+
+    let mut verifications = Vec::new();
+    for (index, (subintent, signatures)) in non_root_subintents
+        .0
+        .iter()
+        .zip(non_root_subintent_signatures.by_subintent.iter())
+        .enumerate()
+    {
+        let subintent_hash = hash(subintent)?.hash;
+        verifications.extend(signatures.signatures.iter().map(
+            |IntentSignatureV1(signature)| {
+                SignatureVerification::of_intent_signature(
+                    SignatureKind::NonRootSubintent { index },
+                    subintent_hash,
+                    signature,
+                )
+            },
+        ));
+    }
+    Ok(verifications)
+}
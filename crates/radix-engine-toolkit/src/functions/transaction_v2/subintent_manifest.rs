@@ -38,6 +38,15 @@ pub fn statically_analyze(
     crate::internal_prelude::statically_analyze(manifest)
 }
 
+pub fn statically_analyze_with_registry(
+    manifest: &SubintentManifestV2,
+    registry: &ManifestAnalyzerRegistry,
+) -> Result<StaticAnalysis, ManifestAnalysisError> {
+    crate::internal_prelude::statically_analyze_with_registry(
+        manifest, registry,
+    )
+}
+
 pub fn as_enclosed(
     SubintentManifestV2 {
         instructions,
@@ -79,29 +88,10 @@ pub fn as_enclosed(
     }
 }
 
+/// Statically validates the manifest. If the validation fails then the error
+/// is returned along with the index of the instruction that caused it.
 pub fn statically_validate(
     manifest: &SubintentManifestV2,
-) -> Result<(), ManifestValidationError> {
-    pub struct Error(ManifestValidationError);
-    impl From<ManifestValidationError> for Error {
-        fn from(value: ManifestValidationError) -> Self {
-            Self(value)
-        }
-    }
-    impl From<Error> for ManifestValidationError {
-        fn from(value: Error) -> Self {
-            value.0
-        }
-    }
-
-    pub struct Visitor;
-    impl ManifestInterpretationVisitor for Visitor {
-        type Output = Error;
-    }
-
-    let interpreter =
-        StaticManifestInterpreter::new(ValidationRuleset::all(), manifest);
-    interpreter.validate_and_apply_visitor(&mut Visitor)?;
-
-    Ok(())
+) -> Result<(), LocatedManifestValidationError> {
+    LocatedManifestValidationError::validate(manifest, ValidationRuleset::all())
 }
@@ -17,6 +17,8 @@
 
 use crate::internal_prelude::*;
 
+use super::intent_header::IntentHeaderValidationError;
+
 pub fn hash(
     transaction_intent: &TransactionIntentV2,
 ) -> Result<TransactionHash, PrepareError> {
@@ -45,3 +47,19 @@ where
 {
     TransactionIntentV2::from_raw(&payload_bytes.as_ref().to_vec().into())
 }
+
+/// Validates the windows of the headers of all of the intents of the
+/// [`TransactionIntentV2`] and checks that the windows of all of the
+/// intents have a common overlap.
+pub fn validate_intent_headers(
+    transaction_intent: &TransactionIntentV2,
+    current_epoch: Option<Epoch>,
+    validation_config: &TransactionValidationConfigV1,
+) -> Result<(), IntentHeaderValidationError> {
+    super::intent_header::validate_non_root_subintent_headers(
+        &transaction_intent.root_intent_core.header,
+        &transaction_intent.non_root_subintents,
+        current_epoch,
+        validation_config,
+    )
+}
@@ -17,6 +17,11 @@
 
 use crate::internal_prelude::*;
 
+use crate::functions::fee_estimation::{
+    placeholder_notary, FeeEstimate, FeeEstimationError,
+    FeeEstimationParameters, PayloadSizes,
+};
+
 pub fn to_payload_bytes(
     manifest: &TransactionManifestV2,
 ) -> Result<Vec<u8>, EncodeError> {
@@ -32,12 +37,109 @@ where
     TransactionManifestV2::from_raw(&payload_bytes.as_ref().to_vec().into())
 }
 
+/// Estimates the fee of the transaction from the receipt of its preview. The
+/// subintents are all of the non-root subintents of the transaction in the
+/// order in which they appear in it, and the signer counts of the parameters
+/// are expected to be given for them in the same order. The preview is expected
+/// to have been given the public keys of the signers of every intent, which is
+/// how the engine charges for the verification of their signatures.
+pub fn estimate_fee(
+    manifest: &TransactionManifestV2,
+    subintents: &[SubintentV2],
+    receipt: RuntimeToolkitTransactionReceipt,
+    parameters: &FeeEstimationParameters,
+) -> Result<FeeEstimate, FeeEstimationError> {
+    if subintents.len() != parameters.subintent_signer_counts.len() {
+        return Err(FeeEstimationError::SubintentSignerCountMismatch {
+            subintents: subintents.len(),
+            subintent_signer_counts: parameters.subintent_signer_counts.len(),
+        });
+    }
+
+    // The headers and notary signature are placeholders of the same size as
+    // the real ones while the intent signatures and message are accounted for
+    // by the estimation.
+    let (notary_public_key, notary_signature) = placeholder_notary();
+    let notarized_transaction = NotarizedTransactionV2 {
+        signed_transaction_intent: SignedTransactionIntentV2 {
+            transaction_intent: TransactionIntentV2 {
+                transaction_header: TransactionHeaderV2 {
+                    notary_public_key,
+                    notary_is_signatory: parameters.notary_is_signatory,
+                    tip_basis_points: 0,
+                },
+                root_intent_core: IntentCoreV2 {
+                    header: IntentHeaderV2 {
+                        network_id: 0,
+                        start_epoch_inclusive: Epoch::of(0),
+                        end_epoch_exclusive: Epoch::of(0),
+                        min_proposer_timestamp_inclusive: Some(Instant::new(0)),
+                        max_proposer_timestamp_exclusive: Some(Instant::new(0)),
+                        intent_discriminator: 0,
+                    },
+                    blobs: BlobsV1 {
+                        blobs: manifest
+                            .blobs
+                            .values()
+                            .cloned()
+                            .map(BlobV1)
+                            .collect(),
+                    },
+                    message: MessageV2::None,
+                    children: ChildSubintentSpecifiersV2 {
+                        children: manifest.children.clone(),
+                    },
+                    instructions: InstructionsV2(manifest.instructions.clone()),
+                },
+                non_root_subintents: NonRootSubintentsV2(subintents.to_vec()),
+            },
+            transaction_intent_signatures: IntentSignaturesV2 {
+                signatures: vec![],
+            },
+            non_root_subintent_signatures: NonRootSubintentSignaturesV2 {
+                by_subintent: vec![
+                    IntentSignaturesV2 { signatures: vec![] };
+                    subintents.len()
+                ],
+            },
+        },
+        notary_signature: NotarySignatureV2(notary_signature),
+    };
+    let payload_sizes = PayloadSizes {
+        unsigned_notarized_transaction: notarized_transaction
+            .to_raw()
+            .map_err(FeeEstimationError::EncodeError)?
+            .len(),
+        previewed_intent: notarized_transaction
+            .signed_transaction_intent
+            .transaction_intent
+            .to_raw()
+            .map_err(FeeEstimationError::EncodeError)?
+            .len(),
+    };
+    crate::functions::fee_estimation::estimate_fee(
+        receipt,
+        payload_sizes,
+        true,
+        parameters,
+    )
+}
+
 pub fn statically_analyze(
     manifest: &TransactionManifestV2,
 ) -> Result<StaticAnalysis, ManifestAnalysisError> {
     crate::internal_prelude::statically_analyze(manifest)
 }
 
+pub fn statically_analyze_with_registry(
+    manifest: &TransactionManifestV2,
+    registry: &ManifestAnalyzerRegistry,
+) -> Result<StaticAnalysis, ManifestAnalysisError> {
+    crate::internal_prelude::statically_analyze_with_registry(
+        manifest, registry,
+    )
+}
+
 pub fn dynamically_analyze(
     manifest: &TransactionManifestV2,
     receipt: RuntimeToolkitTransactionReceipt,
@@ -45,29 +147,20 @@ pub fn dynamically_analyze(
     crate::internal_prelude::dynamically_analyze(manifest, receipt)
 }
 
-pub fn statically_validate(
+pub fn dynamically_analyze_with_registry(
     manifest: &TransactionManifestV2,
-) -> Result<(), ManifestValidationError> {
-    pub struct Error(ManifestValidationError);
-    impl From<ManifestValidationError> for Error {
-        fn from(value: ManifestValidationError) -> Self {
-            Self(value)
-        }
-    }
-    impl From<Error> for ManifestValidationError {
-        fn from(value: Error) -> Self {
-            value.0
-        }
-    }
-
-    pub struct Visitor;
-    impl ManifestInterpretationVisitor for Visitor {
-        type Output = Error;
-    }
-
-    let interpreter =
-        StaticManifestInterpreter::new(ValidationRuleset::all(), manifest);
-    interpreter.validate_and_apply_visitor(&mut Visitor)?;
+    receipt: RuntimeToolkitTransactionReceipt,
+    registry: &ManifestAnalyzerRegistry,
+) -> Result<DynamicAnalysis, ManifestAnalysisError> {
+    crate::internal_prelude::dynamically_analyze_with_registry(
+        manifest, receipt, registry,
+    )
+}
 
-    Ok(())
+/// Statically validates the manifest. If the validation fails then the error
+/// is returned along with the index of the instruction that caused it.
+pub fn statically_validate(
+    manifest: &TransactionManifestV2,
+) -> Result<(), LocatedManifestValidationError> {
+    LocatedManifestValidationError::validate(manifest, ValidationRuleset::all())
 }
@@ -19,6 +19,7 @@ pub mod constants;
 pub mod extensions;
 pub mod functions;
 pub mod manifest_analysis;
+pub mod transaction_summary;
 pub mod types;
 
 pub(crate) mod internal_prelude {
@@ -31,6 +32,7 @@ pub(crate) mod internal_prelude {
     pub use crate::prelude::*;
 
     // Modules from the Radixdlt-Scrypto Crates.
+    pub use radix_blueprint_schema_init::*;
     pub use radix_common::prelude::*;
     pub use radix_engine::blueprints::consensus_manager::*;
     pub use radix_engine::system::system_modules::execution_trace::{
@@ -42,6 +44,7 @@ pub(crate) mod internal_prelude {
     pub use radix_engine_interface::blueprints::account::*;
     pub use radix_engine_interface::blueprints::consensus_manager::*;
     pub use radix_engine_interface::blueprints::locker::*;
+    pub use radix_engine_interface::blueprints::package::*;
     pub use radix_engine_interface::blueprints::pool::*;
     pub use radix_engine_interface::blueprints::resource::*;
     pub use radix_engine_interface::prelude::*;
@@ -59,6 +62,8 @@ pub(crate) mod internal_prelude {
     pub use sbor::traversal::*;
 
     // Modules from our own crates
+    pub use sbor_json::manifest::programmatic::utils::*;
+    pub use sbor_json::manifest::programmatic::value::*;
     pub use sbor_json::scrypto::programmatic::utils::*;
     pub use sbor_json::scrypto::programmatic::value::*;
 
@@ -77,5 +82,6 @@ pub mod prelude {
     pub use crate::extensions::*;
     pub use crate::functions;
     pub use crate::manifest_analysis::*;
+    pub use crate::transaction_summary::*;
     pub use crate::types::*;
 }
@@ -20,6 +20,17 @@ use composite_analyzer::*;
 
 pub fn statically_analyze(
     manifest: &impl ReadableManifest,
+) -> Result<StaticAnalysis, ManifestAnalysisError> {
+    statically_analyze_with_registry(manifest, &Default::default())
+}
+
+/// Statically analyzes the manifest with the analyzers of the toolkit and the
+/// additional analyzers in the passed [`ManifestAnalyzerRegistry`]. The outputs
+/// of the registered analyzers are found in the registered classifications of
+/// the static analysis.
+pub fn statically_analyze_with_registry(
+    manifest: &impl ReadableManifest,
+    registry: &ManifestAnalyzerRegistry,
 ) -> Result<StaticAnalysis, ManifestAnalysisError> {
     // Analyzing the manifest using the composite visitor defined below.
     let StaticAnalyzerState {
@@ -28,7 +39,10 @@ pub fn statically_analyze(
         static_requirement_state,
     } = static_analyzer_traverse::<CompositeAnalyzer>(
         manifest,
-        Default::default(),
+        CompositeInitializer {
+            registered_analyzers: registry.instantiate(),
+            ..Default::default()
+        },
     )?;
     let resolved_composite_output = CompositeResolvedStaticOutput::new(
         ManifestStaticAnalyzer::output(analyzer),
@@ -72,7 +86,10 @@ pub fn statically_analyze(
         entities_requiring_auth: Some(entities_requiring_auth_summary),
         presented_proofs: Some(proofs_created_summary),
         reserved_instructions: Some(reserved_instructions_summary),
+        manifest_warnings: Some(manifest_warnings_summary),
         account_dynamic_resource_movements: Some(..),
+        /* Registered Analyzers */
+        registered_analyzers: Some(registered_classifications),
         /* Manifest Classification */
         general_classification,
         general_subintent_classification,
@@ -84,6 +101,13 @@ pub fn statically_analyze(
         pool_contribution_classification,
         pool_redemption_classification,
         account_settings_update_classification,
+        swap_classification,
+        account_locker_claim_classification,
+        account_locker_store_classification,
+        security_shield_update_classification,
+        resource_creation_classification,
+        package_publish_classification,
+        validator_management_classification,
     } = resolved_composite_output
     else {
         unreachable!()
@@ -96,6 +120,7 @@ pub fn statically_analyze(
         entities_encountered_summary,
         entities_requiring_auth_summary,
         reserved_instructions_summary,
+        manifest_warnings_summary,
         manifest_classification: [
             validator_stake_classification
                 .map(|_| ManifestClassification::ValidatorStake),
@@ -109,6 +134,19 @@ pub fn statically_analyze(
                 .map(|_| ManifestClassification::PoolRedemption),
             account_settings_update_classification
                 .map(|_| ManifestClassification::AccountDepositSettingsUpdate),
+            swap_classification.map(|_| ManifestClassification::Swap),
+            account_locker_claim_classification
+                .map(|_| ManifestClassification::AccountLockerClaim),
+            account_locker_store_classification
+                .map(|_| ManifestClassification::AccountLockerStore),
+            security_shield_update_classification
+                .map(|_| ManifestClassification::SecurityShieldUpdate),
+            resource_creation_classification
+                .map(|_| ManifestClassification::ResourceCreation),
+            package_publish_classification
+                .map(|_| ManifestClassification::PackagePublish),
+            validator_management_classification
+                .map(|_| ManifestClassification::ValidatorManagement),
             transfer_classification.map(|_| ManifestClassification::Transfer),
             general_classification.map(|_| ManifestClassification::General),
             general_subintent_classification
@@ -117,6 +155,7 @@ pub fn statically_analyze(
         .into_iter()
         .flatten()
         .collect(),
+        registered_classifications,
     };
     Ok(static_analysis)
 }
@@ -124,6 +163,18 @@ pub fn statically_analyze(
 pub fn dynamically_analyze(
     manifest: &impl ReadableManifest,
     receipt: RuntimeToolkitTransactionReceipt,
+) -> Result<DynamicAnalysis, ManifestAnalysisError> {
+    dynamically_analyze_with_registry(manifest, receipt, &Default::default())
+}
+
+/// Dynamically analyzes the manifest with the analyzers of the toolkit and the
+/// additional analyzers in the passed [`ManifestAnalyzerRegistry`]. The outputs
+/// of the registered analyzers are found in the registered detailed
+/// classifications of the dynamic analysis.
+pub fn dynamically_analyze_with_registry(
+    manifest: &impl ReadableManifest,
+    receipt: RuntimeToolkitTransactionReceipt,
+    registry: &ManifestAnalyzerRegistry,
 ) -> Result<DynamicAnalysis, ManifestAnalysisError> {
     // Creating an analysis receipt from the runtime receipt passed to this
     // function.
@@ -138,7 +189,10 @@ pub fn dynamically_analyze(
     } = dynamic_analyzer_traverse::<CompositeAnalyzer>(
         manifest,
         &analysis_receipt,
-        Default::default(),
+        CompositeInitializer {
+            registered_analyzers: registry.instantiate(),
+            ..Default::default()
+        },
     )?;
     let resolved_composite_output = CompositeResolvedDynamicOutput::new(
         ManifestDynamicAnalyzer::output(analyzer),
@@ -200,12 +254,23 @@ pub fn dynamically_analyze(
                 static_analyzer_output: reserved_instructions_summary,
                 dynamic_analyzer_output: _,
             }),
+        manifest_warnings:
+            Some(CombinedAnalysisOutput {
+                static_analyzer_output: manifest_warnings_summary,
+                dynamic_analyzer_output: _,
+            }),
         account_dynamic_resource_movements:
             Some(CombinedAnalysisOutput {
                 static_analyzer_output: _,
                 dynamic_analyzer_output:
                     account_dynamic_resource_movements_summary,
             }),
+        /* Registered Analyzers */
+        registered_analyzers:
+            Some(CombinedAnalysisOutput {
+                static_analyzer_output: _,
+                dynamic_analyzer_output: registered_detailed_classifications,
+            }),
         /* Manifest Classification */
         general_classification,
         general_subintent_classification,
@@ -217,6 +282,13 @@ pub fn dynamically_analyze(
         pool_contribution_classification,
         pool_redemption_classification,
         account_settings_update_classification,
+        swap_classification,
+        account_locker_claim_classification,
+        account_locker_store_classification,
+        security_shield_update_classification,
+        resource_creation_classification,
+        package_publish_classification,
+        validator_management_classification,
     } = resolved_composite_output
     else {
         unreachable!()
@@ -231,6 +303,7 @@ pub fn dynamically_analyze(
         entities_encountered_summary,
         entities_requiring_auth_summary,
         reserved_instructions_summary,
+        manifest_warnings_summary,
         fee_locks_summary: analysis_receipt.fee_locks(),
         fee_consumption_summary: analysis_receipt.fee_summary(),
         detailed_manifest_classification: vec![
@@ -252,6 +325,27 @@ pub fn dynamically_analyze(
             account_settings_update_classification
                 .map(CombinedAnalysisOutput::into_static_analyzer_output)
                 .map(DetailedManifestClassification::AccountDepositSettingsUpdate),
+            swap_classification
+                .map(CombinedAnalysisOutput::into_dynamic_analyzer_output)
+                .map(DetailedManifestClassification::Swap),
+            account_locker_claim_classification
+                .map(CombinedAnalysisOutput::into_static_analyzer_output)
+                .map(DetailedManifestClassification::AccountLockerClaim),
+            account_locker_store_classification
+                .map(CombinedAnalysisOutput::into_dynamic_analyzer_output)
+                .map(DetailedManifestClassification::AccountLockerStore),
+            security_shield_update_classification
+                .map(CombinedAnalysisOutput::into_static_analyzer_output)
+                .map(DetailedManifestClassification::SecurityShieldUpdate),
+            resource_creation_classification
+                .map(CombinedAnalysisOutput::into_dynamic_analyzer_output)
+                .map(DetailedManifestClassification::ResourceCreation),
+            package_publish_classification
+                .map(CombinedAnalysisOutput::into_dynamic_analyzer_output)
+                .map(DetailedManifestClassification::PackagePublish),
+            validator_management_classification
+                .map(CombinedAnalysisOutput::into_dynamic_analyzer_output)
+                .map(DetailedManifestClassification::ValidatorManagement),
             transfer_classification.map(|_| DetailedManifestClassification::Transfer {
                 is_one_to_one_transfer: simple_transfer_classification.is_some(),
             }),
@@ -262,6 +356,7 @@ pub fn dynamically_analyze(
         .into_iter()
         .flatten()
         .collect(),
+        registered_detailed_classifications,
     };
     Ok(dynamic_analysis)
 }
@@ -296,10 +391,16 @@ mod composite_analyzer {
                 DynamicAnalyzerWrapper<ReservedInstructionsAnalyzer>,
                 ()
             ),
+            manifest_warnings: (
+                DynamicAnalyzerWrapper<ManifestWarningsAnalyzer>,
+                ()
+            ),
             account_dynamic_resource_movements: (
                 AccountDynamicResourceMovementsAnalyzer,
                 ()
             ),
+            /* Registered Analyzers */
+            registered_analyzers: (RegisteredAnalyzersAnalyzer, Vec::new()),
             /* Manifest Classification */
             general_classification: (
                 DynamicAnalyzerWrapper<GeneralAnalyzer>,
@@ -326,6 +427,25 @@ mod composite_analyzer {
                 DynamicAnalyzerWrapper<AccountSettingsUpdateAnalyzer>,
                 ()
             ),
+            swap_classification: (SwapAnalyzer, ()),
+            account_locker_claim_classification: (
+                DynamicAnalyzerWrapper<AccountLockerClaimAnalyzer>,
+                ()
+            ),
+            account_locker_store_classification: (
+                AccountLockerStoreAnalyzer,
+                ()
+            ),
+            security_shield_update_classification: (
+                DynamicAnalyzerWrapper<SecurityShieldUpdateAnalyzer>,
+                ()
+            ),
+            resource_creation_classification: (ResourceCreationAnalyzer, ()),
+            package_publish_classification: (PackagePublishAnalyzer, ()),
+            validator_management_classification: (
+                ValidatorManagementAnalyzer,
+                ()
+            ),
         }
     }
 }
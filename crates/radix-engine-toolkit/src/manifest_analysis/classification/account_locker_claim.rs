@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AccountLockerClaimAnalyzer(AccountLockerClaimOutput);
+
+impl ManifestStaticAnalyzer for AccountLockerClaimAnalyzer {
+    type Initializer = ();
+    type Output = AccountLockerClaimOutput;
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = AllOfRequirement<(
+        AccountLockerClaimInstructionPresentRequirement,
+        AccountDepositInstructionPresentRequirement,
+        AccountLockerStaticAddressesRequirement,
+    )>;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {
+        self.0
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    receiver:
+                        ManifestInvocationReceiver::GlobalMethod(
+                            ResolvedManifestAddress::Static {
+                                static_address: locker_address,
+                            },
+                        ),
+                    invocation:
+                        TypedManifestNativeInvocation::AccountLockerBlueprintInvocation(
+                            AccountLockerBlueprintInvocation::Method(method),
+                        ),
+                }),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        let (claimant, claimed_resources) = match method {
+            AccountLockerBlueprintMethod::Claim(
+                AccountLockerClaimManifestInput {
+                    claimant,
+                    resource_address,
+                    amount,
+                },
+            ) => (
+                claimant,
+                ManifestResourceSpecifier::Amount(*resource_address, *amount),
+            ),
+            AccountLockerBlueprintMethod::ClaimNonFungibles(
+                AccountLockerClaimNonFungiblesManifestInput {
+                    claimant,
+                    resource_address,
+                    ids,
+                },
+            ) => (
+                claimant,
+                ManifestResourceSpecifier::Ids(*resource_address, ids.clone()),
+            ),
+            AccountLockerBlueprintMethod::Store(..)
+            | AccountLockerBlueprintMethod::Airdrop(..)
+            | AccountLockerBlueprintMethod::Recover(..)
+            | AccountLockerBlueprintMethod::RecoverNonFungibles(..)
+            | AccountLockerBlueprintMethod::GetAmount(..)
+            | AccountLockerBlueprintMethod::GetNonFungibleLocalIds(..) => {
+                return;
+            }
+        };
+
+        // Named lockers and claimants make the requirement fail so they're
+        // never encountered here. Claims of named resources can't be
+        // represented in the output since their addresses are not known ahead
+        // of time.
+        let (
+            Ok(locker_address),
+            ManifestComponentAddress::Static(claimant_address),
+            Ok(claimed_resources),
+        ) = (
+            ComponentAddress::try_from(*locker_address),
+            claimant.0,
+            ResourceSpecifier::try_from(claimed_resources),
+        )
+        else {
+            return;
+        };
+        self.0.claim_operations.push(AccountLockerClaimOperation {
+            locker_address,
+            claimant_address,
+            claimed_resources,
+        });
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AccountLockerClaimOutput {
+    pub claim_operations: Vec<AccountLockerClaimOperation>,
+}
+
+/// This type represents a single claim from an account locker. It contains the
+/// address of the locker that was claimed from, the account that the claim was
+/// made for, and the resources that were claimed from the locker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountLockerClaimOperation {
+    pub locker_address: ComponentAddress,
+    pub claimant_address: ComponentAddress,
+    pub claimed_resources: ResourceSpecifier,
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address,
+                method_name,
+                ..
+            }),
+        ) => {
+            let grouped_entity_type = match address {
+                ManifestGlobalAddress::Static(static_address) => {
+                    static_address.as_node_id().entity_type()
+                }
+                ManifestGlobalAddress::Named(named_address) => context
+                    .named_address_store()
+                    .get(named_address)
+                    .and_then(BlueprintId::entity_type),
+            }
+            .map(GroupedEntityType::from);
+
+            match (grouped_entity_type, method_name.as_str()) {
+                // Selective Permissions
+                (
+                    Some(GroupedEntityType::AccountEntities(..)),
+                    ACCOUNT_DEPOSIT_IDENT
+                    | ACCOUNT_DEPOSIT_BATCH_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_IDENT
+                    | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccessControllerEntities(..)),
+                    ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccountLockerEntities(..)),
+                    ACCOUNT_LOCKER_CLAIM_IDENT
+                    | ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT,
+                ) => true,
+                // Disallowed Invocations
+                (
+                    Some(
+                        GroupedEntityType::IdentityEntities(..)
+                        | GroupedEntityType::PoolEntities(..)
+                        | GroupedEntityType::InternalEntities(..)
+                        | GroupedEntityType::SystemEntities(..)
+                        | GroupedEntityType::ResourceManagerEntities(..)
+                        | GroupedEntityType::PackageEntities(..)
+                        | GroupedEntityType::ValidatorEntities(..)
+                        | GroupedEntityType::AccountEntities(..)
+                        | GroupedEntityType::AccessControllerEntities(..)
+                        | GroupedEntityType::GenericComponentEntities(..)
+                        | GroupedEntityType::AccountLockerEntities(..),
+                    )
+                    | None,
+                    _,
+                ) => false,
+            }
+        }
+        // Permitted Instructions
+        GroupedInstruction::TakeFromWorktopInstructions(..)
+        | GroupedInstruction::ReturnToWorktopInstructions(..)
+        | GroupedInstruction::AssertionInstructions(..)
+        | GroupedInstruction::ProofInstructions(..) => true,
+        // Disallowed Instructions
+        GroupedInstruction::SubintentInstructions(..)
+        | GroupedInstruction::BurnResourceInstructions(..)
+        | GroupedInstruction::AddressAllocationInstructions(..)
+        | GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallFunction(..)
+            | InvocationInstructions::CallRoyaltyMethod(..)
+            | InvocationInstructions::CallMetadataMethod(..)
+            | InvocationInstructions::CallDirectVaultMethod(..)
+            | InvocationInstructions::CallRoleAssignmentMethod(..),
+        ) => false,
+    }
+}
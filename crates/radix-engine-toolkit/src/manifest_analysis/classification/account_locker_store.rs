@@ -0,0 +1,266 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::locker::ResourceSpecifier as LockerResourceSpecifier;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AccountLockerStoreAnalyzer(AccountLockerStoreOutput);
+
+impl ManifestStaticAnalyzer for AccountLockerStoreAnalyzer {
+    type Initializer = ();
+    type Output = ();
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = AllOfRequirement<(
+        AccountLockerStoreInstructionPresentRequirement,
+        AccountLockerStaticAddressesRequirement,
+    )>;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {}
+
+    fn process_instruction(&mut self, _: InstructionContext<'_>) {
+        // No processing is done in the static analyzer. All of the processing
+        // for this transaction type is done in the dynamic analyzer since the
+        // resources in the stored buckets are only known after execution.
+    }
+}
+
+impl ManifestDynamicAnalyzer for AccountLockerStoreAnalyzer {
+    type Output = AccountLockerStoreOutput;
+
+    fn output(
+        self,
+    ) -> CombinedAnalysisOutput<
+        <Self as ManifestStaticAnalyzer>::Output,
+        <Self as ManifestDynamicAnalyzer>::Output,
+    > {
+        CombinedAnalysisOutput {
+            static_analyzer_output: (),
+            dynamic_analyzer_output: self.0,
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    receiver:
+                        ManifestInvocationReceiver::GlobalMethod(
+                            ResolvedManifestAddress::Static {
+                                static_address: locker_address,
+                            },
+                        ),
+                    invocation:
+                        TypedManifestNativeInvocation::AccountLockerBlueprintInvocation(
+                            AccountLockerBlueprintInvocation::Method(method),
+                        ),
+                }),
+            dynamic_analysis_invocation_io: Some(dynamic_analysis_invocation_io),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        let locker_address = ComponentAddress::try_from(*locker_address)
+            .expect(
+                "Must succeed since the typed invocation conversion succeeded",
+            );
+        let Some(stored_bucket) = dynamic_analysis_invocation_io.input.first()
+        else {
+            return;
+        };
+
+        match method {
+            AccountLockerBlueprintMethod::Store(
+                AccountLockerStoreManifestInput {
+                    claimant,
+                    try_direct_send,
+                    ..
+                },
+            ) => {
+                // Named claimants fail the requirement of the analyzer so the
+                // output is discarded in that case.
+                let ManifestComponentAddress::Static(claimant_address) =
+                    claimant.0
+                else {
+                    return;
+                };
+                self.0.store_operations.push(AccountLockerStoreOperation {
+                    locker_address,
+                    claimant_address,
+                    stored_resources: stored_bucket.clone().into(),
+                    try_direct_send: *try_direct_send,
+                });
+            }
+            AccountLockerBlueprintMethod::Airdrop(
+                AccountLockerAirdropManifestInput {
+                    claimants,
+                    try_direct_send,
+                    ..
+                },
+            ) => {
+                // The airdrop input only specifies the amount or the ids that
+                // each claimant gets. The resource address comes from the
+                // bucket passed to the airdrop.
+                let resource_address = *stored_bucket.resource_address();
+                for (claimant, resource_specifier) in claimants {
+                    let ManifestComponentAddress::Static(claimant_address) =
+                        claimant.0
+                    else {
+                        return;
+                    };
+                    let stored_resources = match resource_specifier {
+                        LockerResourceSpecifier::Fungible(amount) => {
+                            ResourceSpecifier::Amount(resource_address, *amount)
+                        }
+                        LockerResourceSpecifier::NonFungible(ids) => {
+                            ResourceSpecifier::Ids(
+                                resource_address,
+                                ids.clone(),
+                            )
+                        }
+                    };
+                    self.0.store_operations.push(AccountLockerStoreOperation {
+                        locker_address,
+                        claimant_address,
+                        stored_resources,
+                        try_direct_send: *try_direct_send,
+                    });
+                }
+            }
+            AccountLockerBlueprintMethod::Claim(..)
+            | AccountLockerBlueprintMethod::ClaimNonFungibles(..)
+            | AccountLockerBlueprintMethod::Recover(..)
+            | AccountLockerBlueprintMethod::RecoverNonFungibles(..)
+            | AccountLockerBlueprintMethod::GetAmount(..)
+            | AccountLockerBlueprintMethod::GetNonFungibleLocalIds(..) => {}
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AccountLockerStoreOutput {
+    pub store_operations: Vec<AccountLockerStoreOperation>,
+}
+
+/// This type represents the resources stored in an account locker for a single
+/// claimant, either through a store or an airdrop. An airdrop to several
+/// claimants is represented as one operation per claimant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountLockerStoreOperation {
+    pub locker_address: ComponentAddress,
+    pub claimant_address: ComponentAddress,
+    pub stored_resources: ResourceSpecifier,
+    pub try_direct_send: bool,
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address,
+                method_name,
+                ..
+            }),
+        ) => {
+            let grouped_entity_type = match address {
+                ManifestGlobalAddress::Static(static_address) => {
+                    static_address.as_node_id().entity_type()
+                }
+                ManifestGlobalAddress::Named(named_address) => context
+                    .named_address_store()
+                    .get(named_address)
+                    .and_then(BlueprintId::entity_type),
+            }
+            .map(GroupedEntityType::from);
+
+            match (grouped_entity_type, method_name.as_str()) {
+                // Selective Permissions
+                (
+                    Some(GroupedEntityType::AccountEntities(..)),
+                    ACCOUNT_WITHDRAW_IDENT
+                    | ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT
+                    | ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_DEPOSIT_IDENT
+                    | ACCOUNT_DEPOSIT_BATCH_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_IDENT
+                    | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccessControllerEntities(..)),
+                    ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccountLockerEntities(..)),
+                    ACCOUNT_LOCKER_STORE_IDENT | ACCOUNT_LOCKER_AIRDROP_IDENT,
+                ) => true,
+                // Disallowed Invocations
+                (
+                    Some(
+                        GroupedEntityType::IdentityEntities(..)
+                        | GroupedEntityType::PoolEntities(..)
+                        | GroupedEntityType::InternalEntities(..)
+                        | GroupedEntityType::SystemEntities(..)
+                        | GroupedEntityType::ResourceManagerEntities(..)
+                        | GroupedEntityType::PackageEntities(..)
+                        | GroupedEntityType::ValidatorEntities(..)
+                        | GroupedEntityType::AccountEntities(..)
+                        | GroupedEntityType::AccessControllerEntities(..)
+                        | GroupedEntityType::GenericComponentEntities(..)
+                        | GroupedEntityType::AccountLockerEntities(..),
+                    )
+                    | None,
+                    _,
+                ) => false,
+            }
+        }
+        // Permitted Instructions
+        GroupedInstruction::TakeFromWorktopInstructions(..)
+        | GroupedInstruction::ReturnToWorktopInstructions(..)
+        | GroupedInstruction::AssertionInstructions(..)
+        | GroupedInstruction::ProofInstructions(..) => true,
+        // Disallowed Instructions
+        GroupedInstruction::SubintentInstructions(..)
+        | GroupedInstruction::BurnResourceInstructions(..)
+        | GroupedInstruction::AddressAllocationInstructions(..)
+        | GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallFunction(..)
+            | InvocationInstructions::CallRoyaltyMethod(..)
+            | InvocationInstructions::CallMetadataMethod(..)
+            | InvocationInstructions::CallDirectVaultMethod(..)
+            | InvocationInstructions::CallRoleAssignmentMethod(..),
+        ) => false,
+    }
+}
@@ -15,22 +15,36 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod account_locker_claim;
+mod account_locker_store;
 mod account_settings_update;
 mod general;
+mod package_publish;
 mod pool_contribution;
 mod pool_redemption;
+mod resource_creation;
+mod security_shield_update;
 mod simple_transfer;
+mod swap;
 mod transfer;
 mod validator_claim;
+mod validator_management;
 mod validator_stake;
 mod validator_unstake;
 
+pub use account_locker_claim::*;
+pub use account_locker_store::*;
 pub use account_settings_update::*;
 pub use general::*;
+pub use package_publish::*;
 pub use pool_contribution::*;
 pub use pool_redemption::*;
+pub use resource_creation::*;
+pub use security_shield_update::*;
 pub use simple_transfer::*;
+pub use swap::*;
 pub use transfer::*;
 pub use validator_claim::*;
+pub use validator_management::*;
 pub use validator_stake::*;
 pub use validator_unstake::*;
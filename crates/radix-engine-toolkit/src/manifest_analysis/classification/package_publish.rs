@@ -0,0 +1,258 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct PackagePublishAnalyzer(PackagePublishOutput);
+
+impl ManifestStaticAnalyzer for PackagePublishAnalyzer {
+    type Initializer = ();
+    type Output = ();
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = PackagePublishInstructionPresentRequirement;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {}
+
+    fn process_instruction(&mut self, _: InstructionContext<'_>) {
+        // No processing is done in the static analyzer. All of the processing
+        // for this transaction type is done in the dynamic analyzer since the
+        // addresses of the published packages are only known after execution.
+    }
+}
+
+impl ManifestDynamicAnalyzer for PackagePublishAnalyzer {
+    type Output = PackagePublishOutput;
+
+    fn output(
+        self,
+    ) -> CombinedAnalysisOutput<
+        <Self as ManifestStaticAnalyzer>::Output,
+        <Self as ManifestDynamicAnalyzer>::Output,
+    > {
+        CombinedAnalysisOutput {
+            static_analyzer_output: (),
+            dynamic_analyzer_output: self.0,
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    invocation:
+                        TypedManifestNativeInvocation::PackageBlueprintInvocation(
+                            PackageBlueprintInvocation::Function(function),
+                        ),
+                    ..
+                }),
+            analysis_receipt: Some(analysis_receipt),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        let (code, definition, owner_role) = match function {
+            PackageBlueprintFunction::PublishWasm(
+                PackagePublishWasmManifestInput {
+                    code, definition, ..
+                },
+            ) => (code, definition, None),
+            PackageBlueprintFunction::PublishWasmAdvanced(
+                PackagePublishWasmAdvancedManifestInput {
+                    code,
+                    definition,
+                    owner_role,
+                    ..
+                },
+            ) => (code, definition, Some(owner_role.clone())),
+            _ => return,
+        };
+
+        // The definition is untyped in the manifest. A definition that can't be
+        // decoded as a package definition fails the publish and so there's no
+        // package to report.
+        let Ok(definition) = definition.clone().try_into_typed() else {
+            return;
+        };
+
+        // The package address isn't returned in a bucket so it can't be read
+        // from the invocation io. The package is paired with the first new
+        // package in the receipt that has not yet been attributed to an
+        // earlier invocation.
+        let Some(package_address) = analysis_receipt
+            .new_package_entities()
+            .into_iter()
+            .find(|package_address| {
+                !self.0.published_packages.iter().any(|published| {
+                    published.package_address == *package_address
+                })
+            })
+        else {
+            return;
+        };
+
+        self.0.published_packages.push(PublishedPackage {
+            package_address,
+            // The blob reference is the hash of the blob's content which is
+            // the same as the hash of the package's code.
+            code_hash: Hash(code.0),
+            blueprints: definition.blueprints.keys().cloned().collect(),
+            royalty_config: definition
+                .blueprints
+                .iter()
+                .map(|(blueprint_name, blueprint_definition)| {
+                    (
+                        blueprint_name.clone(),
+                        blueprint_definition.royalty_config.clone(),
+                    )
+                })
+                .collect(),
+            owner_role,
+        });
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct PackagePublishOutput {
+    pub published_packages: Vec<PublishedPackage>,
+}
+
+/// A package that was published in the manifest along with a summary of its
+/// definition. The owner role is [`None`] when the package was published
+/// through `publish_wasm` where the package blueprint mints an owner badge and
+/// returns it instead of taking an explicit owner role. The owner role is kept
+/// in its manifest form since it may reference named addresses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublishedPackage {
+    pub package_address: PackageAddress,
+    pub code_hash: Hash,
+    pub blueprints: IndexSet<String>,
+    pub royalty_config: IndexMap<String, PackageRoyaltyConfig>,
+    pub owner_role: Option<ManifestOwnerRole>,
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address,
+                method_name,
+                ..
+            }),
+        ) => {
+            let grouped_entity_type = match address {
+                ManifestGlobalAddress::Static(static_address) => {
+                    static_address.as_node_id().entity_type()
+                }
+                ManifestGlobalAddress::Named(named_address) => context
+                    .named_address_store()
+                    .get(named_address)
+                    .and_then(BlueprintId::entity_type),
+            }
+            .map(GroupedEntityType::from);
+
+            match (grouped_entity_type, method_name.as_str()) {
+                // Selective Permissions
+                (
+                    Some(GroupedEntityType::AccountEntities(..)),
+                    ACCOUNT_DEPOSIT_IDENT
+                    | ACCOUNT_DEPOSIT_BATCH_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_IDENT
+                    | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccessControllerEntities(..)),
+                    ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+                ) => true,
+                // Disallowed Invocations
+                (
+                    Some(
+                        GroupedEntityType::IdentityEntities(..)
+                        | GroupedEntityType::PoolEntities(..)
+                        | GroupedEntityType::InternalEntities(..)
+                        | GroupedEntityType::SystemEntities(..)
+                        | GroupedEntityType::ResourceManagerEntities(..)
+                        | GroupedEntityType::PackageEntities(..)
+                        | GroupedEntityType::ValidatorEntities(..)
+                        | GroupedEntityType::AccountEntities(..)
+                        | GroupedEntityType::AccessControllerEntities(..)
+                        | GroupedEntityType::GenericComponentEntities(..)
+                        | GroupedEntityType::AccountLockerEntities(..),
+                    )
+                    | None,
+                    _,
+                ) => false,
+            }
+        }
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallFunction(CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                ..
+            }),
+        ) => match package_address {
+            ManifestPackageAddress::Static(package_address) => {
+                *package_address == PACKAGE_PACKAGE
+                    && blueprint_name == PACKAGE_BLUEPRINT
+                    && matches!(
+                        function_name.as_str(),
+                        PACKAGE_PUBLISH_WASM_IDENT
+                            | PACKAGE_PUBLISH_WASM_ADVANCED_IDENT
+                    )
+            }
+            // Named package addresses can only be packages published in this
+            // very manifest. Calling functions on them is permitted so that
+            // blueprints of the package can be instantiated after publishing.
+            ManifestPackageAddress::Named(..) => true,
+        },
+        // Permitted Instructions
+        GroupedInstruction::TakeFromWorktopInstructions(..)
+        | GroupedInstruction::ReturnToWorktopInstructions(..)
+        | GroupedInstruction::AssertionInstructions(..)
+        | GroupedInstruction::ProofInstructions(..)
+        | GroupedInstruction::AddressAllocationInstructions(..) => true,
+        // Disallowed Instructions
+        GroupedInstruction::SubintentInstructions(..)
+        | GroupedInstruction::BurnResourceInstructions(..)
+        | GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallRoyaltyMethod(..)
+            | InvocationInstructions::CallMetadataMethod(..)
+            | InvocationInstructions::CallDirectVaultMethod(..)
+            | InvocationInstructions::CallRoleAssignmentMethod(..),
+        ) => false,
+    }
+}
@@ -0,0 +1,506 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ResourceCreationAnalyzer {
+    /// The resources created in the manifest in the order of the invocations
+    /// that created them along with where their address is to be read from.
+    created_resources: Vec<(CreatedResourceAddress, NewResourceDefinition)>,
+    /// The static addresses of the resource managers that the manifest refers
+    /// to through named addresses as seen in the output of the invocations made
+    /// on them.
+    named_resource_addresses: IndexMap<ManifestNamedAddress, ResourceAddress>,
+    /// The resources that the receipt reports as newly created.
+    new_resource_entities: IndexSet<ResourceAddress>,
+}
+
+/// Where the address of a created resource is read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CreatedResourceAddress {
+    /// The address was read from the bucket returned by the invocation that
+    /// created the resource.
+    Static(ResourceAddress),
+    /// The resource was created into an address reservation, so it's known by
+    /// its named address in the manifest.
+    Named(ManifestNamedAddress),
+    /// The resource was created with no initial supply and no reservation. The
+    /// address isn't seen anywhere in the manifest.
+    Unknown,
+}
+
+impl ManifestStaticAnalyzer for ResourceCreationAnalyzer {
+    type Initializer = ();
+    type Output = ();
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = ResourceManagerCreateInstructionPresentRequirement;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {}
+
+    fn process_instruction(&mut self, _: InstructionContext<'_>) {
+        // No processing is done in the static analyzer. All of the processing
+        // for this transaction type is done in the dynamic analyzer since the
+        // addresses of the created resources are only known after execution.
+    }
+}
+
+impl ManifestDynamicAnalyzer for ResourceCreationAnalyzer {
+    type Output = ResourceCreationOutput;
+
+    fn output(
+        self,
+    ) -> CombinedAnalysisOutput<
+        <Self as ManifestStaticAnalyzer>::Output,
+        <Self as ManifestDynamicAnalyzer>::Output,
+    > {
+        // Resolve the named addresses from the invocations made on them, then
+        // pair the remaining resources with the new resources in the receipt
+        // that are not accounted for. Resources are created in the order of the
+        // invocations that create them and so the new resources in the receipt
+        // are in the same order.
+        let created_resources = self
+            .created_resources
+            .into_iter()
+            .map(|(address, definition)| {
+                let address = match address {
+                    CreatedResourceAddress::Static(resource_address) => {
+                        Some(resource_address)
+                    }
+                    CreatedResourceAddress::Named(named_address) => self
+                        .named_resource_addresses
+                        .get(&named_address)
+                        .copied(),
+                    CreatedResourceAddress::Unknown => None,
+                };
+                (address, definition)
+            })
+            .collect::<Vec<_>>();
+        let mut unaccounted_resources = self
+            .new_resource_entities
+            .into_iter()
+            .filter(|resource_address| {
+                !created_resources
+                    .iter()
+                    .any(|(address, _)| *address == Some(*resource_address))
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+        let created_resources = created_resources
+            .into_iter()
+            .filter_map(|(address, definition)| {
+                address.or_else(|| unaccounted_resources.next()).map(
+                    |resource_address| CreatedResource {
+                        resource_address,
+                        definition,
+                    },
+                )
+            })
+            .collect();
+
+        CombinedAnalysisOutput {
+            static_analyzer_output: (),
+            dynamic_analyzer_output: ResourceCreationOutput {
+                created_resources,
+            },
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    receiver,
+                    invocation,
+                }),
+            dynamic_analysis_invocation_io: Some(dynamic_analysis_invocation_io),
+            analysis_receipt,
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        if let Some(analysis_receipt) = analysis_receipt {
+            if self.new_resource_entities.is_empty() {
+                self.new_resource_entities =
+                    analysis_receipt.new_resource_entities();
+            }
+        }
+
+        // The resource address of the resource returned by the invocation, if
+        // any. This is the bucket of the initial supply for creations and the
+        // bucket of minted resources for mints.
+        let output_resource_address = dynamic_analysis_invocation_io
+            .output
+            .resource_addresses()
+            .into_iter()
+            .next();
+
+        // The initial supply of non-fungible resources is read from the bucket
+        // returned by the invocation since the ids of RUID non-fungibles are
+        // not known until the transaction executes.
+        let minted_non_fungible_ids = || {
+            dynamic_analysis_invocation_io.output.items_iter().find_map(
+                |item| match item {
+                    InvocationIoItem::NonFungible(_, ids) => {
+                        Some((**ids).clone())
+                    }
+                    InvocationIoItem::Fungible(..) => None,
+                },
+            )
+        };
+
+        let (definition, address_reservation) = match invocation {
+            TypedManifestNativeInvocation::FungibleResourceManagerBlueprintInvocation(
+                invocation,
+            ) => match invocation {
+                FungibleResourceManagerBlueprintInvocation::Function(
+                    FungibleResourceManagerBlueprintFunction::Create(
+                        FungibleResourceManagerCreateManifestInput {
+                            owner_role,
+                            track_total_supply,
+                            divisibility,
+                            resource_roles,
+                            metadata,
+                            address_reservation,
+                            ..
+                        },
+                    ),
+                ) => (
+                    NewResourceDefinition::Fungible {
+                        owner_role: owner_role.clone(),
+                        track_total_supply: *track_total_supply,
+                        divisibility: *divisibility,
+                        initial_supply: None,
+                        resource_roles: resource_roles.clone(),
+                        metadata: metadata.clone(),
+                    },
+                    address_reservation,
+                ),
+                FungibleResourceManagerBlueprintInvocation::Function(
+                    FungibleResourceManagerBlueprintFunction::CreateWithInitialSupply(
+                        FungibleResourceManagerCreateWithInitialSupplyManifestInput {
+                            owner_role,
+                            track_total_supply,
+                            divisibility,
+                            initial_supply,
+                            resource_roles,
+                            metadata,
+                            address_reservation,
+                            ..
+                        },
+                    ),
+                ) => (
+                    NewResourceDefinition::Fungible {
+                        owner_role: owner_role.clone(),
+                        track_total_supply: *track_total_supply,
+                        divisibility: *divisibility,
+                        initial_supply: Some(*initial_supply),
+                        resource_roles: resource_roles.clone(),
+                        metadata: metadata.clone(),
+                    },
+                    address_reservation,
+                ),
+                FungibleResourceManagerBlueprintInvocation::Method(..) => {
+                    self.record_named_resource_address(
+                        receiver,
+                        output_resource_address,
+                    );
+                    return;
+                }
+                FungibleResourceManagerBlueprintInvocation::DirectMethod(..) => return,
+            },
+            TypedManifestNativeInvocation::NonFungibleResourceManagerBlueprintInvocation(
+                invocation,
+            ) => match invocation {
+                NonFungibleResourceManagerBlueprintInvocation::Function(
+                    NonFungibleResourceManagerBlueprintFunction::Create(
+                        NonFungibleResourceManagerCreateManifestInput {
+                            owner_role,
+                            id_type,
+                            track_total_supply,
+                            resource_roles,
+                            metadata,
+                            address_reservation,
+                            ..
+                        },
+                    ),
+                ) => (
+                    NewResourceDefinition::NonFungible {
+                        owner_role: owner_role.clone(),
+                        id_type: *id_type,
+                        track_total_supply: *track_total_supply,
+                        initial_supply: None,
+                        resource_roles: resource_roles.clone(),
+                        metadata: metadata.clone(),
+                    },
+                    address_reservation,
+                ),
+                NonFungibleResourceManagerBlueprintInvocation::Function(
+                    NonFungibleResourceManagerBlueprintFunction::CreateWithInitialSupply(
+                        NonFungibleResourceManagerCreateWithInitialSupplyManifestInput {
+                            owner_role,
+                            id_type,
+                            track_total_supply,
+                            resource_roles,
+                            metadata,
+                            address_reservation,
+                            ..
+                        },
+                    ),
+                ) => (
+                    NewResourceDefinition::NonFungible {
+                        owner_role: owner_role.clone(),
+                        id_type: *id_type,
+                        track_total_supply: *track_total_supply,
+                        initial_supply: minted_non_fungible_ids(),
+                        resource_roles: resource_roles.clone(),
+                        metadata: metadata.clone(),
+                    },
+                    address_reservation,
+                ),
+                NonFungibleResourceManagerBlueprintInvocation::Function(
+                    NonFungibleResourceManagerBlueprintFunction::CreateRuidNonFungibleWithInitialSupply(
+                        NonFungibleResourceManagerCreateRuidWithInitialSupplyManifestInput {
+                            owner_role,
+                            track_total_supply,
+                            resource_roles,
+                            metadata,
+                            address_reservation,
+                            ..
+                        },
+                    ),
+                ) => (
+                    NewResourceDefinition::NonFungible {
+                        owner_role: owner_role.clone(),
+                        id_type: NonFungibleIdType::RUID,
+                        track_total_supply: *track_total_supply,
+                        initial_supply: minted_non_fungible_ids(),
+                        resource_roles: resource_roles.clone(),
+                        metadata: metadata.clone(),
+                    },
+                    address_reservation,
+                ),
+                NonFungibleResourceManagerBlueprintInvocation::Method(..) => {
+                    self.record_named_resource_address(
+                        receiver,
+                        output_resource_address,
+                    );
+                    return;
+                }
+                NonFungibleResourceManagerBlueprintInvocation::DirectMethod(..) => return,
+            },
+            // Invocations of blueprints other than the resource manager ones
+            // don't create resources.
+            _ => return,
+        };
+
+        // A resource created with an initial supply is identified through the
+        // bucket returned by the invocation. Otherwise, it's identified by the
+        // named address of its address reservation if it has one. Allocating a
+        // global address creates a reservation and a named address together,
+        // so the two share the same id.
+        let address = match (output_resource_address, address_reservation) {
+            (Some(resource_address), _) => {
+                CreatedResourceAddress::Static(resource_address)
+            }
+            (None, Some(address_reservation)) => CreatedResourceAddress::Named(
+                ManifestNamedAddress(address_reservation.0),
+            ),
+            (None, None) => CreatedResourceAddress::Unknown,
+        };
+        self.created_resources.push((address, definition));
+    }
+}
+
+impl ResourceCreationAnalyzer {
+    fn record_named_resource_address(
+        &mut self,
+        receiver: &ManifestInvocationReceiver,
+        output_resource_address: Option<ResourceAddress>,
+    ) {
+        if let (
+            ManifestInvocationReceiver::GlobalMethod(
+                ResolvedManifestAddress::Named { named_address, .. },
+            ),
+            Some(resource_address),
+        ) = (receiver, output_resource_address)
+        {
+            self.named_resource_addresses
+                .insert(*named_address, resource_address);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ResourceCreationOutput {
+    pub created_resources: Vec<CreatedResource>,
+}
+
+/// This type represents a single resource that was created in the manifest. It
+/// contains the address of the resource as seen in the receipt and the
+/// definition that the resource was created with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatedResource {
+    pub resource_address: ResourceAddress,
+    pub definition: NewResourceDefinition,
+}
+
+/// The definition of a newly created resource as specified in the invocation
+/// to the resource manager blueprint. For non-fungible resources the initial
+/// supply is the set of ids that were minted when the resource was created.
+/// The roles and metadata are kept in their manifest form since they may
+/// reference named addresses, buckets, or proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NewResourceDefinition {
+    Fungible {
+        owner_role: ManifestOwnerRole,
+        track_total_supply: bool,
+        divisibility: u8,
+        initial_supply: Option<Decimal>,
+        resource_roles: ManifestFungibleResourceRoles,
+        metadata:
+            ModuleConfig<ManifestMetadataInit, ManifestRoleAssignmentInit>,
+    },
+    NonFungible {
+        owner_role: ManifestOwnerRole,
+        id_type: NonFungibleIdType,
+        track_total_supply: bool,
+        initial_supply: Option<IndexSet<NonFungibleLocalId>>,
+        resource_roles: ManifestNonFungibleResourceRoles,
+        metadata:
+            ModuleConfig<ManifestMetadataInit, ManifestRoleAssignmentInit>,
+    },
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address,
+                method_name,
+                ..
+            }),
+        ) => {
+            let grouped_entity_type = match address {
+                ManifestGlobalAddress::Static(static_address) => {
+                    static_address.as_node_id().entity_type()
+                }
+                ManifestGlobalAddress::Named(named_address) => context
+                    .named_address_store()
+                    .get(named_address)
+                    .and_then(BlueprintId::entity_type),
+            }
+            .map(GroupedEntityType::from);
+
+            match (grouped_entity_type, method_name.as_str()) {
+                // Selective Permissions
+                (
+                    Some(GroupedEntityType::AccountEntities(..)),
+                    ACCOUNT_DEPOSIT_IDENT
+                    | ACCOUNT_DEPOSIT_BATCH_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_IDENT
+                    | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccessControllerEntities(..)),
+                    ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::ResourceManagerEntities(..)),
+                    FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT
+                    | NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT,
+                ) => true,
+                // Disallowed Invocations
+                (
+                    Some(
+                        GroupedEntityType::IdentityEntities(..)
+                        | GroupedEntityType::PoolEntities(..)
+                        | GroupedEntityType::InternalEntities(..)
+                        | GroupedEntityType::SystemEntities(..)
+                        | GroupedEntityType::ResourceManagerEntities(..)
+                        | GroupedEntityType::PackageEntities(..)
+                        | GroupedEntityType::ValidatorEntities(..)
+                        | GroupedEntityType::AccountEntities(..)
+                        | GroupedEntityType::AccessControllerEntities(..)
+                        | GroupedEntityType::GenericComponentEntities(..)
+                        | GroupedEntityType::AccountLockerEntities(..),
+                    )
+                    | None,
+                    _,
+                ) => false,
+            }
+        }
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallFunction(CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                ..
+            }),
+        ) => {
+            *package_address == ManifestPackageAddress::Static(RESOURCE_PACKAGE)
+                && match blueprint_name.as_str() {
+                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT => matches!(
+                        function_name.as_str(),
+                        FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT
+                            | FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT
+                    ),
+                    NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT => matches!(
+                        function_name.as_str(),
+                        NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT
+                            | NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT
+                            | NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT
+                    ),
+                    _ => false,
+                }
+        }
+        // Permitted Instructions
+        GroupedInstruction::TakeFromWorktopInstructions(..)
+        | GroupedInstruction::ReturnToWorktopInstructions(..)
+        | GroupedInstruction::AssertionInstructions(..)
+        | GroupedInstruction::ProofInstructions(..)
+        | GroupedInstruction::AddressAllocationInstructions(..) => true,
+        // Disallowed Instructions
+        GroupedInstruction::SubintentInstructions(..)
+        | GroupedInstruction::BurnResourceInstructions(..)
+        | GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallRoyaltyMethod(..)
+            | InvocationInstructions::CallMetadataMethod(..)
+            | InvocationInstructions::CallDirectVaultMethod(..)
+            | InvocationInstructions::CallRoleAssignmentMethod(..),
+        ) => false,
+    }
+}
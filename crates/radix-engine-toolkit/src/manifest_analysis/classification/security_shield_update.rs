@@ -0,0 +1,349 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::identity::IDENTITY_SECURIFY_IDENT;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SecurityShieldUpdateAnalyzer(SecurityShieldUpdateOutput);
+
+impl ManifestStaticAnalyzer for SecurityShieldUpdateAnalyzer {
+    type Initializer = ();
+    type Output = SecurityShieldUpdateOutput;
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState =
+        AccessControllerSecurityUpdateInstructionPresentRequirement;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {
+        self.0
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    receiver,
+                    invocation:
+                        TypedManifestNativeInvocation::AccessControllerBlueprintInvocation(
+                            invocation,
+                        ),
+                }),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        let operation = match invocation {
+            AccessControllerBlueprintInvocation::Function(
+                AccessControllerBlueprintFunction::Create(
+                    AccessControllerCreateManifestInput {
+                        rule_set,
+                        timed_recovery_delay_in_minutes,
+                        ..
+                    },
+                ),
+            ) => SecurityShieldUpdateOperation::CreateAccessController {
+                rule_set: rule_set.clone(),
+                timed_recovery_delay_in_minutes:
+                    *timed_recovery_delay_in_minutes,
+            },
+            AccessControllerBlueprintInvocation::Method(method) => {
+                let access_controller_address = match receiver {
+                    ManifestInvocationReceiver::GlobalMethod(receiver) => {
+                        ManifestGlobalAddress::from(receiver)
+                    }
+                    ManifestInvocationReceiver::DirectAccess(..)
+                    | ManifestInvocationReceiver::BlueprintFunction(..) => {
+                        return
+                    }
+                };
+                match method {
+                    AccessControllerBlueprintMethod::InitiateRecoveryAsPrimary(
+                        AccessControllerInitiateRecoveryAsPrimaryManifestInput {
+                            rule_set,
+                            timed_recovery_delay_in_minutes,
+                        },
+                    ) => SecurityShieldUpdateOperation::InitiateRecovery {
+                        access_controller_address,
+                        proposer: AccessControllerProposer::Primary,
+                        rule_set: rule_set.clone(),
+                        timed_recovery_delay_in_minutes:
+                            *timed_recovery_delay_in_minutes,
+                    },
+                    AccessControllerBlueprintMethod::InitiateRecoveryAsRecovery(
+                        AccessControllerInitiateRecoveryAsRecoveryManifestInput {
+                            rule_set,
+                            timed_recovery_delay_in_minutes,
+                        },
+                    ) => SecurityShieldUpdateOperation::InitiateRecovery {
+                        access_controller_address,
+                        proposer: AccessControllerProposer::Recovery,
+                        rule_set: rule_set.clone(),
+                        timed_recovery_delay_in_minutes:
+                            *timed_recovery_delay_in_minutes,
+                    },
+                    AccessControllerBlueprintMethod::QuickConfirmPrimaryRoleRecoveryProposal(
+                        AccessControllerQuickConfirmPrimaryRoleRecoveryProposalManifestInput {
+                            rule_set,
+                            timed_recovery_delay_in_minutes,
+                        },
+                    ) => SecurityShieldUpdateOperation::QuickConfirmRecovery {
+                        access_controller_address,
+                        proposer: AccessControllerProposer::Primary,
+                        rule_set: rule_set.clone(),
+                        timed_recovery_delay_in_minutes:
+                            *timed_recovery_delay_in_minutes,
+                    },
+                    AccessControllerBlueprintMethod::QuickConfirmRecoveryRoleRecoveryProposal(
+                        AccessControllerQuickConfirmRecoveryRoleRecoveryProposalManifestInput {
+                            rule_set,
+                            timed_recovery_delay_in_minutes,
+                        },
+                    ) => SecurityShieldUpdateOperation::QuickConfirmRecovery {
+                        access_controller_address,
+                        proposer: AccessControllerProposer::Recovery,
+                        rule_set: rule_set.clone(),
+                        timed_recovery_delay_in_minutes:
+                            *timed_recovery_delay_in_minutes,
+                    },
+                    AccessControllerBlueprintMethod::TimedConfirmRecovery(
+                        AccessControllerTimedConfirmRecoveryManifestInput {
+                            rule_set,
+                            timed_recovery_delay_in_minutes,
+                        },
+                    ) => SecurityShieldUpdateOperation::TimedConfirmRecovery {
+                        access_controller_address,
+                        rule_set: rule_set.clone(),
+                        timed_recovery_delay_in_minutes:
+                            *timed_recovery_delay_in_minutes,
+                    },
+                    AccessControllerBlueprintMethod::LockPrimaryRole(..) => {
+                        SecurityShieldUpdateOperation::LockPrimaryRole {
+                            access_controller_address,
+                        }
+                    }
+                    AccessControllerBlueprintMethod::UnlockPrimaryRole(..) => {
+                        SecurityShieldUpdateOperation::UnlockPrimaryRole {
+                            access_controller_address,
+                        }
+                    }
+                    AccessControllerBlueprintMethod::InitiateBadgeWithdrawAttemptAsPrimary(..) => {
+                        SecurityShieldUpdateOperation::InitiateBadgeWithdrawAttempt {
+                            access_controller_address,
+                            proposer: AccessControllerProposer::Primary,
+                        }
+                    }
+                    AccessControllerBlueprintMethod::InitiateBadgeWithdrawAttemptAsRecovery(..) => {
+                        SecurityShieldUpdateOperation::InitiateBadgeWithdrawAttempt {
+                            access_controller_address,
+                            proposer: AccessControllerProposer::Recovery,
+                        }
+                    }
+                    AccessControllerBlueprintMethod::QuickConfirmPrimaryRoleBadgeWithdrawAttempt(..) => {
+                        SecurityShieldUpdateOperation::QuickConfirmBadgeWithdrawAttempt {
+                            access_controller_address,
+                            proposer: AccessControllerProposer::Primary,
+                        }
+                    }
+                    AccessControllerBlueprintMethod::QuickConfirmRecoveryRoleBadgeWithdrawAttempt(..) => {
+                        SecurityShieldUpdateOperation::QuickConfirmBadgeWithdrawAttempt {
+                            access_controller_address,
+                            proposer: AccessControllerProposer::Recovery,
+                        }
+                    }
+                    AccessControllerBlueprintMethod::CreateProof(..)
+                    | AccessControllerBlueprintMethod::CancelPrimaryRoleRecoveryProposal(..)
+                    | AccessControllerBlueprintMethod::CancelRecoveryRoleRecoveryProposal(..)
+                    | AccessControllerBlueprintMethod::StopTimedRecovery(..)
+                    | AccessControllerBlueprintMethod::CancelPrimaryRoleBadgeWithdrawAttempt(..)
+                    | AccessControllerBlueprintMethod::CancelRecoveryRoleBadgeWithdrawAttempt(..)
+                    | AccessControllerBlueprintMethod::MintRecoveryBadges(..)
+                    | AccessControllerBlueprintMethod::LockRecoveryFee(..)
+                    | AccessControllerBlueprintMethod::WithdrawRecoveryFee(..)
+                    | AccessControllerBlueprintMethod::ContributeRecoveryFee(..) => return,
+                }
+            }
+            AccessControllerBlueprintInvocation::DirectMethod(..) => return,
+        };
+        self.0.operations.push(operation);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SecurityShieldUpdateOutput {
+    pub operations: Vec<SecurityShieldUpdateOperation>,
+}
+
+/// The role that proposed the recovery or badge withdraw attempt that an
+/// operation either initiates or confirms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccessControllerProposer {
+    Primary,
+    Recovery,
+}
+
+/// This type represents a single operation on an access controller that has an
+/// effect on the security of the entity that it controls. The operations that
+/// propose or confirm a new set of rules include the proposed
+/// [`ManifestRuleSet`] and the timed recovery delay. The rule set is kept in
+/// its manifest form since it may reference named addresses or proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecurityShieldUpdateOperation {
+    /// A new access controller is created with the given rule set. This is
+    /// normally seen when an account or identity is securified.
+    CreateAccessController {
+        rule_set: ManifestRuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    InitiateRecovery {
+        access_controller_address: ManifestGlobalAddress,
+        proposer: AccessControllerProposer,
+        rule_set: ManifestRuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    QuickConfirmRecovery {
+        access_controller_address: ManifestGlobalAddress,
+        proposer: AccessControllerProposer,
+        rule_set: ManifestRuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    TimedConfirmRecovery {
+        access_controller_address: ManifestGlobalAddress,
+        rule_set: ManifestRuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    LockPrimaryRole {
+        access_controller_address: ManifestGlobalAddress,
+    },
+    UnlockPrimaryRole {
+        access_controller_address: ManifestGlobalAddress,
+    },
+    InitiateBadgeWithdrawAttempt {
+        access_controller_address: ManifestGlobalAddress,
+        proposer: AccessControllerProposer,
+    },
+    QuickConfirmBadgeWithdrawAttempt {
+        access_controller_address: ManifestGlobalAddress,
+        proposer: AccessControllerProposer,
+    },
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address,
+                method_name,
+                ..
+            }),
+        ) => {
+            let grouped_entity_type = match address {
+                ManifestGlobalAddress::Static(static_address) => {
+                    static_address.as_node_id().entity_type()
+                }
+                ManifestGlobalAddress::Named(named_address) => context
+                    .named_address_store()
+                    .get(named_address)
+                    .and_then(BlueprintId::entity_type),
+            }
+            .map(GroupedEntityType::from);
+
+            match (grouped_entity_type, method_name.as_str()) {
+                // Selective Permissions
+                (
+                    Some(GroupedEntityType::AccountEntities(..)),
+                    ACCOUNT_SECURIFY_IDENT
+                    | ACCOUNT_DEPOSIT_IDENT
+                    | ACCOUNT_DEPOSIT_BATCH_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_IDENT
+                    | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::IdentityEntities(..)),
+                    IDENTITY_SECURIFY_IDENT,
+                ) => true,
+                // Permitted Invocations
+                (Some(GroupedEntityType::AccessControllerEntities(..)), _) => {
+                    true
+                }
+                // Disallowed Invocations
+                (
+                    Some(
+                        GroupedEntityType::IdentityEntities(..)
+                        | GroupedEntityType::PoolEntities(..)
+                        | GroupedEntityType::InternalEntities(..)
+                        | GroupedEntityType::SystemEntities(..)
+                        | GroupedEntityType::ResourceManagerEntities(..)
+                        | GroupedEntityType::PackageEntities(..)
+                        | GroupedEntityType::ValidatorEntities(..)
+                        | GroupedEntityType::AccountEntities(..)
+                        | GroupedEntityType::GenericComponentEntities(..)
+                        | GroupedEntityType::AccountLockerEntities(..),
+                    )
+                    | None,
+                    _,
+                ) => false,
+            }
+        }
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallFunction(CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                ..
+            }),
+        ) => {
+            *package_address
+                == ManifestPackageAddress::Static(ACCESS_CONTROLLER_PACKAGE)
+                && blueprint_name == ACCESS_CONTROLLER_BLUEPRINT
+                && function_name == ACCESS_CONTROLLER_CREATE_IDENT
+        }
+        // Permitted Instructions
+        GroupedInstruction::TakeFromWorktopInstructions(..)
+        | GroupedInstruction::ReturnToWorktopInstructions(..)
+        | GroupedInstruction::AssertionInstructions(..)
+        | GroupedInstruction::ProofInstructions(..)
+        | GroupedInstruction::AddressAllocationInstructions(..) => true,
+        // Disallowed Instructions
+        GroupedInstruction::SubintentInstructions(..)
+        | GroupedInstruction::BurnResourceInstructions(..)
+        | GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallRoyaltyMethod(..)
+            | InvocationInstructions::CallMetadataMethod(..)
+            | InvocationInstructions::CallDirectVaultMethod(..)
+            | InvocationInstructions::CallRoleAssignmentMethod(..),
+        ) => false,
+    }
+}
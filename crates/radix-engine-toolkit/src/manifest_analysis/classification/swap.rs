@@ -0,0 +1,386 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SwapAnalyzer {
+    /// The account that resources were withdrawn from. This is captured when
+    /// the withdraw is encountered so that it can be included in the swap
+    /// operation once the component invocation is encountered.
+    account_withdrawn_from: Option<ComponentAddress>,
+    output: SwapOutput,
+}
+
+impl ManifestStaticAnalyzer for SwapAnalyzer {
+    type Initializer = ();
+    type Output = ();
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = AllOfRequirement<(
+        AccountWithdrawInstructionPresentRequirement,
+        AccountDepositInstructionPresentRequirement,
+        SwapStaticRequirementState,
+    )>;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {}
+
+    fn process_instruction(&mut self, _: InstructionContext<'_>) {
+        // No processing is done in the static analyzer. All of the processing
+        // for this transaction type is done in the dynamic analyzer since it
+        // requires us to monitor some invocations and resource movements.
+    }
+}
+
+impl ManifestDynamicAnalyzer for SwapAnalyzer {
+    type Output = SwapOutput;
+
+    fn output(
+        self,
+    ) -> CombinedAnalysisOutput<
+        <Self as ManifestStaticAnalyzer>::Output,
+        <Self as ManifestDynamicAnalyzer>::Output,
+    > {
+        CombinedAnalysisOutput {
+            static_analyzer_output: (),
+            dynamic_analyzer_output: self.output,
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation,
+            dynamic_analysis_invocation_io: Some(dynamic_analysis_invocation_io),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        // Capturing the account that the resources are withdrawn from.
+        if let Some(TypedNativeInvocation {
+            receiver:
+                ManifestInvocationReceiver::GlobalMethod(
+                    ResolvedManifestAddress::Static {
+                        static_address: account_address,
+                    },
+                ),
+            invocation:
+                TypedManifestNativeInvocation::AccountBlueprintInvocation(
+                    AccountBlueprintInvocation::Method(
+                        AccountBlueprintMethod::Withdraw(..)
+                        | AccountBlueprintMethod::WithdrawNonFungibles(..)
+                        | AccountBlueprintMethod::LockFeeAndWithdraw(..)
+                        | AccountBlueprintMethod::LockFeeAndWithdrawNonFungibles(
+                            ..,
+                        ),
+                    ),
+                ),
+        }) = typed_native_invocation
+        {
+            self.account_withdrawn_from =
+                ComponentAddress::try_from(*account_address).ok();
+            return;
+        }
+
+        // Capturing the invocation of the component that performs the swap.
+        let Some(ManifestGlobalAddress::Static(component_address)) =
+            generic_component_method_invocation(context)
+        else {
+            return;
+        };
+        let (Ok(component_address), Some(account_address)) = (
+            ComponentAddress::try_from(component_address),
+            self.account_withdrawn_from,
+        ) else {
+            return;
+        };
+        self.output.swap_operations.push(SwapOperation {
+            account_address,
+            component_address,
+            input_resources: dynamic_analysis_invocation_io.input.clone(),
+            output_resources: dynamic_analysis_invocation_io.output.clone(),
+        });
+    }
+}
+
+/// The requirement state that is specific to the swap classification. It
+/// requires that resources are withdrawn from exactly one account, that the
+/// resources are deposited back into that same account, and that exactly one
+/// invocation to a non-native component takes place which returns at least one
+/// resource that wasn't passed to it.
+#[derive(Clone, Debug, Default)]
+pub struct SwapStaticRequirementState {
+    accounts_withdrawn_from: IndexSet<ResolvedManifestAddress<GlobalAddress>>,
+    accounts_deposited_into: IndexSet<ResolvedManifestAddress<GlobalAddress>>,
+    component_invocations_count: usize,
+    /// Whether the component returned a resource that's different from the
+    /// ones passed to it. This is [`None`] when the returned resources aren't
+    /// known, which is the case in static analysis.
+    output_differs_from_input: Option<bool>,
+}
+
+impl ManifestAnalyzerRequirementState for SwapStaticRequirementState {
+    fn requirement_state(&self) -> RequirementState {
+        if self.accounts_withdrawn_from.len() > 1
+            || self.component_invocations_count > 1
+            || self.output_differs_from_input == Some(false)
+        {
+            RequirementState::PermanentlyUnfulfilled
+        } else if self.accounts_withdrawn_from.len() == 1
+            && self.accounts_deposited_into == self.accounts_withdrawn_from
+            && self.component_invocations_count == 1
+        {
+            RequirementState::Fulfilled
+        } else {
+            RequirementState::CurrentlyUnfulfilled
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        if generic_component_method_invocation(context).is_some() {
+            self.component_invocations_count += 1;
+            self.output_differs_from_input = output_differs_from_input(context);
+            return;
+        }
+
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    receiver: ManifestInvocationReceiver::GlobalMethod(account),
+                    invocation:
+                        TypedManifestNativeInvocation::AccountBlueprintInvocation(
+                            AccountBlueprintInvocation::Method(method),
+                        ),
+                }),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        match method {
+            AccountBlueprintMethod::Withdraw(..)
+            | AccountBlueprintMethod::WithdrawNonFungibles(..)
+            | AccountBlueprintMethod::LockFeeAndWithdraw(..)
+            | AccountBlueprintMethod::LockFeeAndWithdrawNonFungibles(..) => {
+                self.accounts_withdrawn_from.insert(account.clone());
+            }
+            AccountBlueprintMethod::Deposit(..)
+            | AccountBlueprintMethod::DepositBatch(..)
+            | AccountBlueprintMethod::TryDepositOrAbort(..)
+            | AccountBlueprintMethod::TryDepositBatchOrAbort(..) => {
+                self.accounts_deposited_into.insert(account.clone());
+            }
+            AccountBlueprintMethod::TryDepositOrRefund(..)
+            | AccountBlueprintMethod::TryDepositBatchOrRefund(..)
+            | AccountBlueprintMethod::Securify(..)
+            | AccountBlueprintMethod::LockFee(..)
+            | AccountBlueprintMethod::LockContingentFee(..)
+            | AccountBlueprintMethod::Burn(..)
+            | AccountBlueprintMethod::BurnNonFungibles(..)
+            | AccountBlueprintMethod::CreateProofOfAmount(..)
+            | AccountBlueprintMethod::CreateProofOfNonFungibles(..)
+            | AccountBlueprintMethod::SetDefaultDepositRule(..)
+            | AccountBlueprintMethod::SetResourcePreference(..)
+            | AccountBlueprintMethod::RemoveResourcePreference(..)
+            | AccountBlueprintMethod::AddAuthorizedDepositor(..)
+            | AccountBlueprintMethod::RemoveAuthorizedDepositor(..)
+            | AccountBlueprintMethod::Balance(..)
+            | AccountBlueprintMethod::NonFungibleLocalIds(..)
+            | AccountBlueprintMethod::HasNonFungible(..) => {}
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SwapOutput {
+    pub swap_operations: Vec<SwapOperation>,
+}
+
+/// This type represents a single swap operation. It contains the account that
+/// the resources were withdrawn from and deposited into, the address of the
+/// component that performed the swap, as well as the resources that went into
+/// the component and the resources that came out of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapOperation {
+    pub account_address: ComponentAddress,
+    pub component_address: ComponentAddress,
+    /* Input */
+    pub input_resources: InvocationIoItems,
+    /* Output */
+    pub output_resources: InvocationIoItems,
+}
+
+/// Returns the address of the component invoked by the instruction if it is a
+/// method invocation on a non-native (generic) component.
+fn generic_component_method_invocation(
+    context: InstructionContext<'_>,
+) -> Option<ManifestGlobalAddress> {
+    let GroupedInstruction::InvocationInstructions(
+        InvocationInstructions::CallMethod(CallMethod { address, .. }),
+    ) = context.instruction()
+    else {
+        return None;
+    };
+
+    let grouped_entity_type = match address {
+        ManifestGlobalAddress::Static(static_address) => {
+            static_address.as_node_id().entity_type()
+        }
+        ManifestGlobalAddress::Named(named_address) => context
+            .named_address_store()
+            .get(named_address)
+            .and_then(BlueprintId::entity_type),
+    }
+    .map(GroupedEntityType::from);
+
+    match grouped_entity_type {
+        Some(GroupedEntityType::GenericComponentEntities(..)) => Some(*address),
+        _ => None,
+    }
+}
+
+/// Checks if the invocation returned any resource that wasn't passed to it.
+/// The dynamic inputs and outputs are used when they're available, otherwise,
+/// the resources that are statically known to be passed and returned are used.
+/// [`None`] is returned if no resources are known to be returned.
+fn output_differs_from_input(context: InstructionContext<'_>) -> Option<bool> {
+    let InstructionContext::InvocationInstruction {
+        static_analysis_invocation_io,
+        dynamic_analysis_invocation_io,
+        ..
+    } = context
+    else {
+        return None;
+    };
+
+    let (input_resources, output_resources) =
+        match dynamic_analysis_invocation_io {
+            Some(InvocationIo { input, output }) => {
+                (input.resource_addresses(), output.resource_addresses())
+            }
+            None => {
+                let InvocationIo { input, output } =
+                    static_analysis_invocation_io;
+                (
+                    input.specified_resources().keys().copied().collect(),
+                    output.specified_resources().keys().copied().collect(),
+                )
+            }
+        };
+
+    if output_resources.is_empty() {
+        None
+    } else {
+        Some(!output_resources.is_subset(&input_resources))
+    }
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address,
+                method_name,
+                ..
+            }),
+        ) => {
+            let grouped_entity_type = match address {
+                ManifestGlobalAddress::Static(static_address) => {
+                    static_address.as_node_id().entity_type()
+                }
+                ManifestGlobalAddress::Named(named_address) => context
+                    .named_address_store()
+                    .get(named_address)
+                    .and_then(BlueprintId::entity_type),
+            }
+            .map(GroupedEntityType::from);
+
+            match (grouped_entity_type, method_name.as_str()) {
+                // Selective Permissions
+                (
+                    Some(GroupedEntityType::AccountEntities(..)),
+                    ACCOUNT_WITHDRAW_IDENT
+                    | ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_DEPOSIT_IDENT
+                    | ACCOUNT_DEPOSIT_BATCH_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_IDENT
+                    | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+                    | ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT
+                    | ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccessControllerEntities(..)),
+                    ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+                ) => true,
+                // Permitted Invocations
+                (Some(GroupedEntityType::GenericComponentEntities(..)), _) => {
+                    true
+                }
+                // Disallowed Invocations
+                (
+                    Some(
+                        GroupedEntityType::IdentityEntities(..)
+                        | GroupedEntityType::PoolEntities(..)
+                        | GroupedEntityType::InternalEntities(..)
+                        | GroupedEntityType::SystemEntities(..)
+                        | GroupedEntityType::ResourceManagerEntities(..)
+                        | GroupedEntityType::PackageEntities(..)
+                        | GroupedEntityType::ValidatorEntities(..)
+                        | GroupedEntityType::AccountEntities(..)
+                        | GroupedEntityType::AccessControllerEntities(..)
+                        | GroupedEntityType::AccountLockerEntities(..),
+                    )
+                    | None,
+                    _,
+                ) => false,
+            }
+        }
+        // Permitted Instructions
+        GroupedInstruction::TakeFromWorktopInstructions(..)
+        | GroupedInstruction::ReturnToWorktopInstructions(..)
+        | GroupedInstruction::AssertionInstructions(..)
+        | GroupedInstruction::ProofInstructions(..) => true,
+        // Disallowed Instructions
+        GroupedInstruction::SubintentInstructions(..)
+        | GroupedInstruction::BurnResourceInstructions(..)
+        | GroupedInstruction::AddressAllocationInstructions(..)
+        | GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallFunction(..)
+            | InvocationInstructions::CallRoyaltyMethod(..)
+            | InvocationInstructions::CallMetadataMethod(..)
+            | InvocationInstructions::CallDirectVaultMethod(..)
+            | InvocationInstructions::CallRoleAssignmentMethod(..),
+        ) => false,
+    }
+}
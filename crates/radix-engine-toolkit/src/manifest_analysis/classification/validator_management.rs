@@ -0,0 +1,278 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ValidatorManagementAnalyzer(ValidatorManagementOutput);
+
+impl ManifestStaticAnalyzer for ValidatorManagementAnalyzer {
+    type Initializer = ();
+    type Output = ();
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = ValidatorManagementInstructionPresentRequirement;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {}
+
+    fn process_instruction(&mut self, _: InstructionContext<'_>) {
+        // No processing is done in the static analyzer. All of the processing
+        // for this transaction type is done in the dynamic analyzer since the
+        // amount of stake units locked is only known after execution.
+    }
+}
+
+impl ManifestDynamicAnalyzer for ValidatorManagementAnalyzer {
+    type Output = ValidatorManagementOutput;
+
+    fn output(
+        self,
+    ) -> CombinedAnalysisOutput<
+        <Self as ManifestStaticAnalyzer>::Output,
+        <Self as ManifestDynamicAnalyzer>::Output,
+    > {
+        CombinedAnalysisOutput {
+            static_analyzer_output: (),
+            dynamic_analyzer_output: self.0,
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    receiver:
+                        ManifestInvocationReceiver::GlobalMethod(
+                            ResolvedManifestAddress::Static {
+                                static_address: validator_address,
+                            },
+                        ),
+                    invocation:
+                        TypedManifestNativeInvocation::ValidatorBlueprintInvocation(
+                            ValidatorBlueprintInvocation::Method(method),
+                        ),
+                }),
+            dynamic_analysis_invocation_io: Some(dynamic_analysis_invocation_io),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        let validator_address = ComponentAddress::try_from(*validator_address)
+            .expect(
+                "Must succeed since the typed invocation conversion succeeded",
+            );
+
+        let operation = match method {
+            ValidatorBlueprintMethod::Register(..) => {
+                ValidatorManagementOperation::Register { validator_address }
+            }
+            ValidatorBlueprintMethod::Unregister(..) => {
+                ValidatorManagementOperation::Unregister { validator_address }
+            }
+            ValidatorBlueprintMethod::UpdateFee(ValidatorUpdateFeeInput {
+                new_fee_factor,
+            }) => ValidatorManagementOperation::UpdateFee {
+                validator_address,
+                new_fee_factor: *new_fee_factor,
+            },
+            ValidatorBlueprintMethod::UpdateAcceptDelegatedStake(
+                ValidatorUpdateAcceptDelegatedStakeInput {
+                    accept_delegated_stake,
+                },
+            ) => ValidatorManagementOperation::UpdateAcceptDelegatedStake {
+                validator_address,
+                accept_delegated_stake: *accept_delegated_stake,
+            },
+            ValidatorBlueprintMethod::LockOwnerStakeUnits(..) => {
+                // The only input to the invocation is the bucket of stake
+                // units to lock.
+                let stake_unit_amount = dynamic_analysis_invocation_io
+                    .input
+                    .first()
+                    .map(|item| *item.amount())
+                    .unwrap_or_default();
+                ValidatorManagementOperation::LockOwnerStakeUnits {
+                    validator_address,
+                    stake_unit_amount,
+                }
+            }
+            ValidatorBlueprintMethod::StartUnlockOwnerStakeUnits(
+                ValidatorStartUnlockOwnerStakeUnitsInput {
+                    requested_stake_unit_amount,
+                },
+            ) => ValidatorManagementOperation::StartUnlockOwnerStakeUnits {
+                validator_address,
+                requested_stake_unit_amount: *requested_stake_unit_amount,
+            },
+            ValidatorBlueprintMethod::FinishUnlockOwnerStakeUnits(..) => {
+                ValidatorManagementOperation::FinishUnlockOwnerStakeUnits {
+                    validator_address,
+                }
+            }
+            ValidatorBlueprintMethod::SignalProtocolUpdateReadiness(
+                ValidatorSignalProtocolUpdateReadinessInput { vote },
+            ) => ValidatorManagementOperation::SignalProtocolUpdateReadiness {
+                validator_address,
+                protocol_version_name: vote.clone(),
+            },
+            _ => return,
+        };
+        self.0.operations.push(operation);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ValidatorManagementOutput {
+    pub operations: Vec<ValidatorManagementOperation>,
+}
+
+/// A single management operation performed on a validator by its owner. The
+/// operations are given in the order in which they appear in the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidatorManagementOperation {
+    Register {
+        validator_address: ComponentAddress,
+    },
+    Unregister {
+        validator_address: ComponentAddress,
+    },
+    UpdateFee {
+        validator_address: ComponentAddress,
+        new_fee_factor: Decimal,
+    },
+    UpdateAcceptDelegatedStake {
+        validator_address: ComponentAddress,
+        accept_delegated_stake: bool,
+    },
+    LockOwnerStakeUnits {
+        validator_address: ComponentAddress,
+        stake_unit_amount: Decimal,
+    },
+    StartUnlockOwnerStakeUnits {
+        validator_address: ComponentAddress,
+        requested_stake_unit_amount: Decimal,
+    },
+    FinishUnlockOwnerStakeUnits {
+        validator_address: ComponentAddress,
+    },
+    SignalProtocolUpdateReadiness {
+        validator_address: ComponentAddress,
+        protocol_version_name: String,
+    },
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        // Selective Permissions
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address,
+                method_name,
+                ..
+            }),
+        ) => {
+            let grouped_entity_type = match address {
+                ManifestGlobalAddress::Static(static_address) => {
+                    static_address.as_node_id().entity_type()
+                }
+                ManifestGlobalAddress::Named(named_address) => context
+                    .named_address_store()
+                    .get(named_address)
+                    .and_then(BlueprintId::entity_type),
+            }
+            .map(GroupedEntityType::from);
+
+            match (grouped_entity_type, method_name.as_str()) {
+                // Selective Permissions
+                (
+                    Some(GroupedEntityType::AccountEntities(..)),
+                    ACCOUNT_WITHDRAW_IDENT
+                    | ACCOUNT_DEPOSIT_IDENT
+                    | ACCOUNT_DEPOSIT_BATCH_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                    | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                    | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    | ACCOUNT_LOCK_FEE_IDENT
+                    | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+                    | ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::AccessControllerEntities(..)),
+                    ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+                )
+                | (
+                    Some(GroupedEntityType::ValidatorEntities(..)),
+                    VALIDATOR_REGISTER_IDENT
+                    | VALIDATOR_UNREGISTER_IDENT
+                    | VALIDATOR_UPDATE_FEE_IDENT
+                    | VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT
+                    | VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT
+                    | VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT
+                    | VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT
+                    | VALIDATOR_SIGNAL_PROTOCOL_UPDATE_READINESS_IDENT,
+                ) => true,
+                // Disallowed Invocations
+                (
+                    Some(
+                        GroupedEntityType::IdentityEntities(..)
+                        | GroupedEntityType::PoolEntities(..)
+                        | GroupedEntityType::InternalEntities(..)
+                        | GroupedEntityType::SystemEntities(..)
+                        | GroupedEntityType::ResourceManagerEntities(..)
+                        | GroupedEntityType::PackageEntities(..)
+                        | GroupedEntityType::ValidatorEntities(..)
+                        | GroupedEntityType::AccountEntities(..)
+                        | GroupedEntityType::AccessControllerEntities(..)
+                        | GroupedEntityType::GenericComponentEntities(..)
+                        | GroupedEntityType::AccountLockerEntities(..),
+                    )
+                    | None,
+                    _,
+                ) => false,
+            }
+        }
+        // Permitted Instructions
+        GroupedInstruction::TakeFromWorktopInstructions(..)
+        | GroupedInstruction::ReturnToWorktopInstructions(..)
+        | GroupedInstruction::AssertionInstructions(..)
+        | GroupedInstruction::ProofInstructions(..) => true,
+        // Disallowed Instructions
+        GroupedInstruction::SubintentInstructions(..)
+        | GroupedInstruction::BurnResourceInstructions(..)
+        | GroupedInstruction::AddressAllocationInstructions(..)
+        | GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallFunction(..)
+            | InvocationInstructions::CallRoyaltyMethod(..)
+            | InvocationInstructions::CallMetadataMethod(..)
+            | InvocationInstructions::CallDirectVaultMethod(..)
+            | InvocationInstructions::CallRoleAssignmentMethod(..),
+        ) => false,
+    }
+}
@@ -0,0 +1,397 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+/// An analyzer that produces a list of [`ManifestWarning`]s for the manifest
+/// which signing UIs are expected to surface to the user before signing. The
+/// analyzer builds on the [`ReservedInstructionsAnalyzer`], the
+/// [`AccountInteractionsAnalyzer`], and the [`EntitiesRequiringAuthAnalyzer`]
+/// and adds to them warnings for role assignment, metadata, and direct vault
+/// method invocations.
+///
+/// Withdrawn resources are reported as not deposited into accounts when the
+/// manifest doesn't deposit into any account, or when it burns resources or
+/// passes resources to an entity other than an account. The native exchanges
+/// of the validator and pool blueprints are not reported since they return
+/// resources of their own in exchange.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ManifestWarningsAnalyzer {
+    account_interactions: AccountInteractionsAnalyzer,
+    entities_requiring_auth: EntitiesRequiringAuthAnalyzer,
+    reserved_instructions: ReservedInstructionsAnalyzer,
+    invocation_warnings: Vec<ManifestWarning>,
+    is_any_resource_sent_to_non_account_entity: bool,
+}
+
+impl ManifestStaticAnalyzer for ManifestWarningsAnalyzer {
+    type Initializer = ();
+    type Output = ManifestWarningsOutput;
+    type PermissionState = ConstState<true>;
+    type RequirementState = ConstState<true>;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        Default::default()
+    }
+
+    fn output(self) -> Self::Output {
+        let AccountInteractionsOutput {
+            accounts_withdrawn_from,
+            accounts_deposited_into,
+            accounts_burned_from,
+            ..
+        } = ManifestStaticAnalyzer::output(self.account_interactions);
+        let EntitiesRequiringAuthOutput { accounts, .. } =
+            ManifestStaticAnalyzer::output(self.entities_requiring_auth);
+        let ReservedInstructionsOutput {
+            account_securify_invocations,
+            account_lock_owner_keys_metadata_field_invocations,
+            account_update_owner_keys_metadata_field_invocations,
+            identity_securify_invocations,
+            identity_lock_owner_keys_metadata_field_invocations,
+            identity_update_owner_keys_metadata_field_invocations,
+            ..
+        } = ManifestStaticAnalyzer::output(self.reserved_instructions);
+
+        let mut warnings = Vec::new();
+
+        // Reserved Instructions
+        warnings.extend(
+            account_securify_invocations
+                .into_iter()
+                .chain(identity_securify_invocations)
+                .map(|entity| ManifestWarning::EntitySecurified { entity }),
+        );
+        warnings.extend(
+            account_update_owner_keys_metadata_field_invocations
+                .into_iter()
+                .chain(identity_update_owner_keys_metadata_field_invocations)
+                .map(|entity| ManifestWarning::OwnerKeysUpdated { entity }),
+        );
+        warnings.extend(
+            account_lock_owner_keys_metadata_field_invocations
+                .into_iter()
+                .chain(identity_lock_owner_keys_metadata_field_invocations)
+                .map(|entity| ManifestWarning::OwnerKeysLocked { entity }),
+        );
+
+        // Account Interactions
+        warnings.extend(accounts_burned_from.into_iter().map(|account| {
+            ManifestWarning::ResourcesBurnedFromAccount { account }
+        }));
+        if !accounts_withdrawn_from.is_empty()
+            && (accounts_deposited_into.is_empty()
+                || self.is_any_resource_sent_to_non_account_entity)
+        {
+            warnings.push(
+                ManifestWarning::WithdrawnResourcesNotDepositedIntoAccounts {
+                    accounts_withdrawn_from,
+                },
+            );
+        }
+        warnings.extend(
+            accounts_deposited_into
+                .into_iter()
+                .filter(|account| !accounts.contains(account))
+                .map(|account| ManifestWarning::DepositIntoThirdPartyAccount {
+                    account,
+                }),
+        );
+
+        // Invocations
+        warnings.extend(self.invocation_warnings);
+
+        ManifestWarningsOutput { warnings }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        ManifestStaticAnalyzer::process_instruction(
+            &mut self.account_interactions,
+            context,
+        );
+        ManifestStaticAnalyzer::process_instruction(
+            &mut self.entities_requiring_auth,
+            context,
+        );
+        ManifestStaticAnalyzer::process_instruction(
+            &mut self.reserved_instructions,
+            context,
+        );
+
+        match context {
+            InstructionContext::NonInvocationInstruction {
+                instruction: GroupedInstruction::BurnResourceInstructions(..),
+                ..
+            } => self.is_any_resource_sent_to_non_account_entity = true,
+            InstructionContext::InvocationInstruction {
+                instruction:
+                    GroupedInstruction::InvocationInstructions(invocation),
+                named_address_store,
+                static_analysis_invocation_io: InvocationIo { input, .. },
+                ..
+            } => {
+                let is_any_resource_sent =
+                    !input.specified_resources().is_empty()
+                        || !input.unspecified_resources().none_are_present();
+                if is_any_resource_sent
+                    && !is_account_or_native_exchange_invocation(
+                        invocation,
+                        named_address_store,
+                    )
+                {
+                    self.is_any_resource_sent_to_non_account_entity = true;
+                }
+            }
+            _ => {}
+        }
+
+        let InstructionContext::InvocationInstruction {
+            instruction: GroupedInstruction::InvocationInstructions(invocation),
+            named_address_store,
+            typed_native_invocation,
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        let warning = match (invocation, typed_native_invocation) {
+            // Role Assignment
+            (
+                InvocationInstructions::CallRoleAssignmentMethod(
+                    CallRoleAssignmentMethod {
+                        address,
+                        method_name,
+                        ..
+                    },
+                ),
+                _,
+            ) => match method_name.as_str() {
+                ROLE_ASSIGNMENT_SET_OWNER_IDENT => {
+                    ManifestWarning::OwnerRoleUpdated { entity: *address }
+                }
+                ROLE_ASSIGNMENT_LOCK_OWNER_IDENT => {
+                    ManifestWarning::OwnerRoleLocked { entity: *address }
+                }
+                ROLE_ASSIGNMENT_SET_IDENT => match typed_native_invocation {
+                    Some(TypedNativeInvocation {
+                        invocation:
+                            TypedManifestNativeInvocation::RoleAssignmentBlueprintInvocation(
+                                RoleAssignmentBlueprintInvocation::Method(
+                                    RoleAssignmentBlueprintMethod::Set(
+                                        RoleAssignmentSetManifestInput {
+                                            role_key,
+                                            rule,
+                                            ..
+                                        },
+                                    ),
+                                ),
+                            ),
+                        ..
+                    }) if is_role_lock(role_key, rule) => {
+                        ManifestWarning::RoleLocked {
+                            entity: *address,
+                            role_key: role_key.key.clone(),
+                        }
+                    }
+                    _ => ManifestWarning::RoleUpdated { entity: *address },
+                },
+                _ => return,
+            },
+            // Metadata - updates to the owner keys of accounts and identities
+            // are reported through the reserved instructions. The invocations
+            // are decoded here since typed native invocations are only there
+            // for entities whose blueprint is known from their address.
+            (
+                InvocationInstructions::CallMetadataMethod(
+                    CallMetadataMethod {
+                        address,
+                        method_name,
+                        args,
+                    },
+                ),
+                _,
+            ) => {
+                let (Some(receiver), Ok(method)) = (
+                    ResolvedManifestAddress::from_manifest_global_address(
+                        address,
+                        named_address_store,
+                    ),
+                    MetadataBlueprintMethod::decode_invocation(
+                        method_name,
+                        args,
+                    ),
+                ) else {
+                    return;
+                };
+                let is_owner_keys_of_account_or_identity = |key: &str| {
+                    key == OWNER_KEYS_METADATA_KEY
+                        && (receiver.is_account() || receiver.is_identity())
+                };
+                match &method {
+                    MetadataBlueprintMethod::Set(
+                        MetadataSetManifestInput { key, .. },
+                    ) if !is_owner_keys_of_account_or_identity(key) => {
+                        ManifestWarning::MetadataUpdated {
+                            entity: *address,
+                            key: key.clone(),
+                        }
+                    }
+                    MetadataBlueprintMethod::Lock(MetadataLockInput {
+                        key,
+                        ..
+                    }) if !is_owner_keys_of_account_or_identity(key) => {
+                        ManifestWarning::MetadataLocked {
+                            entity: *address,
+                            key: key.clone(),
+                        }
+                    }
+                    MetadataBlueprintMethod::Remove(
+                        MetadataRemoveManifestInput { key },
+                    ) => ManifestWarning::MetadataRemoved {
+                        entity: *address,
+                        key: key.clone(),
+                    },
+                    _ => return,
+                }
+            }
+            // Direct Vault Methods
+            (
+                InvocationInstructions::CallDirectVaultMethod(
+                    CallDirectVaultMethod {
+                        address,
+                        method_name,
+                        ..
+                    },
+                ),
+                _,
+            ) => ManifestWarning::DirectVaultMethodInvoked {
+                vault_address: *address,
+                method_name: method_name.clone(),
+            },
+            _ => return,
+        };
+        self.invocation_warnings.push(warning);
+    }
+}
+
+/// Roles are locked by setting their updater role to deny all, after which the
+/// role can no longer be updated.
+fn is_role_lock(role_key: &RoleKey, rule: &ManifestAccessRule) -> bool {
+    role_key.key.ends_with("_updater")
+        && matches!(rule.clone().try_into_typed(), Ok(AccessRule::DenyAll))
+}
+
+/// Checks whether the invocation is to an account or to one of the native
+/// validator and pool methods which return resources in exchange for the ones
+/// that they're given.
+fn is_account_or_native_exchange_invocation(
+    invocation: &InvocationInstructions,
+    named_address_store: &NamedAddressStore,
+) -> bool {
+    let InvocationInstructions::CallMethod(CallMethod {
+        address,
+        method_name,
+        ..
+    }) = invocation
+    else {
+        return false;
+    };
+    // The contribute and redeem methods have the same name on all of the pool
+    // blueprints.
+    matches!(
+        (
+            address.resolve_grouped_entity_type(named_address_store),
+            method_name.as_str()
+        ),
+        (Some(GroupedEntityType::AccountEntities(..)), _)
+            | (
+                Some(GroupedEntityType::ValidatorEntities(..)),
+                VALIDATOR_STAKE_IDENT
+                    | VALIDATOR_UNSTAKE_IDENT
+                    | VALIDATOR_CLAIM_XRD_IDENT
+            )
+            | (
+                Some(GroupedEntityType::PoolEntities(..)),
+                ONE_RESOURCE_POOL_CONTRIBUTE_IDENT
+                    | ONE_RESOURCE_POOL_REDEEM_IDENT
+            )
+    )
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ManifestWarningsOutput {
+    pub warnings: Vec<ManifestWarning>,
+}
+
+/// A warning about a potentially risky operation performed by a manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestWarning {
+    /// Resources are withdrawn from accounts and some of them may end up
+    /// somewhere other than an account. This is the case when the manifest
+    /// doesn't deposit into any account, or when it burns resources or passes
+    /// them to an entity other than an account outside of the native validator
+    /// and pool exchanges.
+    WithdrawnResourcesNotDepositedIntoAccounts {
+        accounts_withdrawn_from: IndexSet<ManifestGlobalAddress>,
+    },
+    /// Resources are burned directly from an account.
+    ResourcesBurnedFromAccount { account: ManifestGlobalAddress },
+    /// Resources are deposited into an account that the manifest doesn't
+    /// require the auth of, which is typically an account of a third-party.
+    DepositIntoThirdPartyAccount { account: ManifestGlobalAddress },
+    /// An account or an identity is securified.
+    EntitySecurified { entity: ManifestGlobalAddress },
+    /// The owner keys metadata field of an account or an identity is updated.
+    OwnerKeysUpdated { entity: ManifestGlobalAddress },
+    /// The owner keys metadata field of an account or an identity is locked.
+    OwnerKeysLocked { entity: ManifestGlobalAddress },
+    /// The owner role of an entity is updated.
+    OwnerRoleUpdated { entity: ManifestGlobalAddress },
+    /// The owner role of an entity is locked and can no longer be updated.
+    OwnerRoleLocked { entity: ManifestGlobalAddress },
+    /// A role of an entity is updated.
+    RoleUpdated { entity: ManifestGlobalAddress },
+    /// A role of an entity is locked by setting its updater role to deny all.
+    /// The role key is that of the updater role.
+    RoleLocked {
+        entity: ManifestGlobalAddress,
+        role_key: String,
+    },
+    /// A metadata field of an entity is updated.
+    MetadataUpdated {
+        entity: ManifestGlobalAddress,
+        key: String,
+    },
+    /// A metadata field of an entity is locked and can no longer be updated.
+    MetadataLocked {
+        entity: ManifestGlobalAddress,
+        key: String,
+    },
+    /// A metadata field of an entity is removed.
+    MetadataRemoved {
+        entity: ManifestGlobalAddress,
+        key: String,
+    },
+    /// A method is invoked directly on a vault, such as a freeze or a recall.
+    DirectVaultMethodInvoked {
+        vault_address: InternalAddress,
+        method_name: String,
+    },
+}
@@ -19,6 +19,7 @@ mod account_dynamic_resource_movements;
 mod account_interactions;
 mod encountered_entities;
 mod entities_requiring_auth;
+mod manifest_warnings;
 mod presented_proofs;
 mod reserved_instructions;
 
@@ -26,5 +27,6 @@ pub use account_dynamic_resource_movements::*;
 pub use account_interactions::*;
 pub use encountered_entities::*;
 pub use entities_requiring_auth::*;
+pub use manifest_warnings::*;
 pub use presented_proofs::*;
 pub use reserved_instructions::*;
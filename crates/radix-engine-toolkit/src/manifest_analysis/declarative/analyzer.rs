@@ -0,0 +1,179 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+/// An analyzer whose permissions, requirements, and output are all described
+/// by a [`CompiledClassifierDefinition`] rather than by code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclarativeAnalyzer {
+    definition: Arc<CompiledClassifierDefinition>,
+    output: DeclarativeClassifierOutput,
+}
+
+impl ManifestStaticAnalyzer for DeclarativeAnalyzer {
+    type Initializer = Arc<CompiledClassifierDefinition>;
+    type Output = DeclarativeClassifierOutput;
+    type PermissionState = DeclarativePermissionState;
+    type RequirementState = DeclarativeRequirementState;
+
+    fn new(
+        initializer: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        let output = DeclarativeClassifierOutput {
+            fields: initializer
+                .outputs
+                .iter()
+                .map(|CompiledOutputRule { name, extract, .. }| {
+                    let value = match extract {
+                        OutputExtraction::Count => {
+                            DeclarativeOutputValue::Count(0)
+                        }
+                        OutputExtraction::Addresses => {
+                            DeclarativeOutputValue::Addresses(Vec::new())
+                        }
+                        OutputExtraction::Names => {
+                            DeclarativeOutputValue::Names(Vec::new())
+                        }
+                        OutputExtraction::Arguments => {
+                            DeclarativeOutputValue::Arguments(Vec::new())
+                        }
+                    };
+                    (name.clone(), value)
+                })
+                .collect(),
+        };
+        let requirement_state = DeclarativeRequirementState {
+            is_required_invocation_seen: vec![
+                false;
+                initializer
+                    .required_invocations
+                    .len()
+            ],
+            definition: initializer.clone(),
+        };
+        let permission_state = DeclarativePermissionState {
+            all_instructions_permitted: true,
+            definition: initializer.clone(),
+        };
+        (
+            Self {
+                definition: initializer,
+                output,
+            },
+            permission_state,
+            requirement_state,
+        )
+    }
+
+    fn output(self) -> Self::Output {
+        self.output
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let GroupedInstruction::InvocationInstructions(invocation) =
+            context.instruction()
+        else {
+            return;
+        };
+
+        for CompiledOutputRule {
+            name,
+            invocation: rule,
+            ..
+        } in self.definition.outputs.iter()
+        {
+            if !rule.matches(context) {
+                continue;
+            }
+            match self.output.fields.get_mut(name) {
+                Some(DeclarativeOutputValue::Count(count)) => *count += 1,
+                Some(DeclarativeOutputValue::Addresses(addresses)) => {
+                    addresses.extend(invocation.invoked_global_entity())
+                }
+                Some(DeclarativeOutputValue::Names(names)) => names
+                    .push(invocation.invoked_function_or_method().to_owned()),
+                Some(DeclarativeOutputValue::Arguments(arguments)) => {
+                    arguments.push(invocation.invocation_args().clone())
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+/// The output of the [`DeclarativeAnalyzer`]. There's a field in the output for
+/// each of the output rules in the definition, in the order they're defined in.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DeclarativeClassifierOutput {
+    pub fields: IndexMap<String, DeclarativeOutputValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeclarativeOutputValue {
+    Count(u64),
+    Addresses(Vec<ManifestGlobalAddress>),
+    Names(Vec<String>),
+    Arguments(Vec<ManifestValue>),
+}
+
+/// Permits an instruction if it's an invocation that matches any of the
+/// permitted or required invocation rules of the definition or if it's a
+/// non-invocation instruction in one of the permitted instruction groups.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclarativePermissionState {
+    definition: Arc<CompiledClassifierDefinition>,
+    all_instructions_permitted: bool,
+}
+
+impl ManifestAnalyzerPermissionState for DeclarativePermissionState {
+    fn all_instructions_permitted(&self) -> bool {
+        self.all_instructions_permitted
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        self.all_instructions_permitted &=
+            self.definition.is_instruction_permitted(context)
+    }
+}
+
+/// Fulfilled once each of the required invocation rules of the definition has
+/// been matched by at least one invocation in the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclarativeRequirementState {
+    definition: Arc<CompiledClassifierDefinition>,
+    is_required_invocation_seen: Vec<bool>,
+}
+
+impl ManifestAnalyzerRequirementState for DeclarativeRequirementState {
+    fn requirement_state(&self) -> RequirementState {
+        if self.is_required_invocation_seen.iter().all(|seen| *seen) {
+            RequirementState::Fulfilled
+        } else {
+            RequirementState::CurrentlyUnfulfilled
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        self.definition
+            .required_invocations
+            .iter()
+            .zip(self.is_required_invocation_seen.iter_mut())
+            .filter(|(rule, _)| rule.matches(context))
+            .for_each(|(_, seen)| *seen = true)
+    }
+}
@@ -0,0 +1,546 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The definition of a classification given as data rather than as code. The
+/// definition is deserialized from any of the formats supported by serde (e.g.,
+/// JSON or TOML) and then compiled into a [`CompiledClassifierDefinition`]
+/// which is used to initialize a [`DeclarativeAnalyzer`].
+///
+/// A manifest is given the classification if all of its instructions are
+/// permitted by the definition and if all of the required invocations of the
+/// definition are present in the manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClassifierDefinition {
+    /// The name of the classification.
+    pub name: String,
+    /// The invocations that are permitted to appear in the manifest. Any
+    /// invocation that doesn't match any of these rules or any of the rules in
+    /// the required invocations causes the manifest to not be classified.
+    #[serde(default)]
+    pub permitted_invocations: Vec<InvocationRule>,
+    /// The groups of non-invocation instructions that are permitted to appear
+    /// in the manifest.
+    #[serde(default)]
+    pub permitted_instructions: Vec<InstructionGroup>,
+    /// The invocations that must be present in the manifest. Each of the rules
+    /// must be matched by at least one invocation in the manifest. Invocations
+    /// matching these rules are implicitly permitted.
+    pub required_invocations: Vec<InvocationRule>,
+    /// The data to extract from the manifest into the output of the analyzer.
+    #[serde(default)]
+    pub outputs: Vec<OutputRule>,
+}
+
+/// A rule that matches invocation instructions in the manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum InvocationRule {
+    /// Matches calls to methods on the main module of global entities. The
+    /// receiver matches if it matches any of the entity groups, addresses, or
+    /// blueprints. If all three are empty then any receiver matches. If the
+    /// methods are empty then any method matches.
+    ///
+    /// The blueprint of an address that's statically known can't be determined
+    /// without access to the ledger state and therefore the blueprints only
+    /// apply to receivers that are named addresses allocated in the manifest.
+    /// A static receiver is matched by the entity groups and addresses alone
+    /// and is never matched or excluded by the blueprints. A rule that
+    /// restricts the receiver by blueprints alone is rejected when compiled
+    /// since it would never match an existing component.
+    Method {
+        #[serde(default)]
+        entity_groups: Vec<EntityGroup>,
+        #[serde(default)]
+        addresses: Vec<String>,
+        #[serde(default)]
+        blueprints: Vec<BlueprintRule>,
+        #[serde(default)]
+        methods: Vec<String>,
+    },
+    /// Matches calls to functions on a blueprint. If the functions are empty
+    /// then any function on the blueprint matches.
+    Function {
+        package_address: String,
+        blueprint_name: String,
+        #[serde(default)]
+        functions: Vec<String>,
+    },
+}
+
+/// Identifies a blueprint by the address of its package and its name.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlueprintRule {
+    pub package_address: String,
+    pub blueprint_name: String,
+}
+
+/// The entity groups of the [`GroupedEntityType`] as they appear in the
+/// classifier definitions.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+pub enum EntityGroup {
+    AccountEntities,
+    IdentityEntities,
+    PoolEntities,
+    InternalEntities,
+    SystemEntities,
+    ResourceManagerEntities,
+    AccessControllerEntities,
+    GenericComponentEntities,
+    AccountLockerEntities,
+    PackageEntities,
+    ValidatorEntities,
+}
+
+impl EntityGroup {
+    pub fn contains(&self, grouped_entity_type: &GroupedEntityType) -> bool {
+        matches!(
+            (self, grouped_entity_type),
+            (
+                Self::AccountEntities,
+                GroupedEntityType::AccountEntities(..)
+            ) | (
+                Self::IdentityEntities,
+                GroupedEntityType::IdentityEntities(..)
+            ) | (Self::PoolEntities, GroupedEntityType::PoolEntities(..))
+                | (
+                    Self::InternalEntities,
+                    GroupedEntityType::InternalEntities(..)
+                )
+                | (Self::SystemEntities, GroupedEntityType::SystemEntities(..))
+                | (
+                    Self::ResourceManagerEntities,
+                    GroupedEntityType::ResourceManagerEntities(..)
+                )
+                | (
+                    Self::AccessControllerEntities,
+                    GroupedEntityType::AccessControllerEntities(..)
+                )
+                | (
+                    Self::GenericComponentEntities,
+                    GroupedEntityType::GenericComponentEntities(..)
+                )
+                | (
+                    Self::AccountLockerEntities,
+                    GroupedEntityType::AccountLockerEntities(..)
+                )
+                | (
+                    Self::PackageEntities,
+                    GroupedEntityType::PackageEntities(..)
+                )
+                | (
+                    Self::ValidatorEntities,
+                    GroupedEntityType::ValidatorEntities(..)
+                )
+        )
+    }
+}
+
+/// The non-invocation groups of the [`GroupedInstruction`] as they appear in
+/// the classifier definitions. Invocations are permitted through the
+/// [`InvocationRule`]s instead.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+pub enum InstructionGroup {
+    TakeFromWorktopInstructions,
+    ReturnToWorktopInstructions,
+    AssertionInstructions,
+    ProofInstructions,
+    SubintentInstructions,
+    AddressAllocationInstructions,
+    BurnResourceInstructions,
+}
+
+impl InstructionGroup {
+    pub fn contains(&self, instruction: &GroupedInstruction) -> bool {
+        matches!(
+            (self, instruction),
+            (
+                Self::TakeFromWorktopInstructions,
+                GroupedInstruction::TakeFromWorktopInstructions(..)
+            ) | (
+                Self::ReturnToWorktopInstructions,
+                GroupedInstruction::ReturnToWorktopInstructions(..)
+            ) | (
+                Self::AssertionInstructions,
+                GroupedInstruction::AssertionInstructions(..)
+            ) | (
+                Self::ProofInstructions,
+                GroupedInstruction::ProofInstructions(..)
+            ) | (
+                Self::SubintentInstructions,
+                GroupedInstruction::SubintentInstructions(..)
+            ) | (
+                Self::AddressAllocationInstructions,
+                GroupedInstruction::AddressAllocationInstructions(..)
+            ) | (
+                Self::BurnResourceInstructions,
+                GroupedInstruction::BurnResourceInstructions(..)
+            )
+        )
+    }
+}
+
+/// A rule describing a field in the output of the analyzer and the data that
+/// it's extracted from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutputRule {
+    /// The name of the field in the output.
+    pub name: String,
+    /// The invocations that the data is extracted from.
+    pub invocation: InvocationRule,
+    /// The data to extract from the matching invocations.
+    pub extract: OutputExtraction,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputExtraction {
+    /// The number of matching invocations.
+    Count,
+    /// The addresses of the entities that the matching invocations were made
+    /// to. This is the package address in the case of function calls.
+    Addresses,
+    /// The names of the methods or functions of the matching invocations.
+    Names,
+    /// The arguments of the matching invocations.
+    Arguments,
+}
+
+impl ClassifierDefinition {
+    /// Compiles the definition by decoding and validating all of the addresses
+    /// in it against the given network.
+    pub fn compile(
+        &self,
+        network_definition: &NetworkDefinition,
+    ) -> Result<CompiledClassifierDefinition, ClassifierDefinitionError> {
+        let decoder = AddressBech32Decoder::new(network_definition);
+
+        if self.required_invocations.is_empty() {
+            return Err(ClassifierDefinitionError::NoRequiredInvocations);
+        }
+
+        let mut output_names = IndexSet::new();
+        for OutputRule { name, .. } in self.outputs.iter() {
+            if !output_names.insert(name.as_str()) {
+                return Err(ClassifierDefinitionError::DuplicateOutputName(
+                    name.clone(),
+                ));
+            }
+        }
+
+        let compile_rules = |rules: &[InvocationRule]| {
+            rules
+                .iter()
+                .map(|rule| rule.compile(&decoder))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(CompiledClassifierDefinition {
+            permitted_invocations: compile_rules(&self.permitted_invocations)?,
+            permitted_instructions: self
+                .permitted_instructions
+                .iter()
+                .copied()
+                .collect(),
+            required_invocations: compile_rules(&self.required_invocations)?,
+            outputs: self
+                .outputs
+                .iter()
+                .map(
+                    |OutputRule {
+                         name,
+                         invocation,
+                         extract,
+                     }| {
+                        invocation.compile(&decoder).map(|invocation| {
+                            CompiledOutputRule {
+                                name: name.clone(),
+                                invocation,
+                                extract: *extract,
+                            }
+                        })
+                    },
+                )
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl InvocationRule {
+    fn compile(
+        &self,
+        decoder: &AddressBech32Decoder,
+    ) -> Result<CompiledInvocationRule, ClassifierDefinitionError> {
+        match self {
+            Self::Method {
+                entity_groups,
+                addresses,
+                blueprints,
+                methods,
+            } => {
+                if !blueprints.is_empty()
+                    && entity_groups.is_empty()
+                    && addresses.is_empty()
+                {
+                    return Err(
+                        ClassifierDefinitionError::BlueprintsOnlyReceiverRule,
+                    );
+                }
+                Ok(CompiledInvocationRule::Method {
+                    entity_groups: entity_groups.clone(),
+                    addresses: addresses
+                        .iter()
+                        .map(|address| {
+                            GlobalAddress::try_from_bech32(decoder, address)
+                                .ok_or(
+                                    ClassifierDefinitionError::InvalidAddress(
+                                        address.clone(),
+                                    ),
+                                )
+                        })
+                        .collect::<Result<_, _>>()?,
+                    blueprints: blueprints
+                        .iter()
+                        .map(|blueprint| blueprint.compile(decoder))
+                        .collect::<Result<_, _>>()?,
+                    methods: methods.iter().cloned().collect(),
+                })
+            }
+            Self::Function {
+                package_address,
+                blueprint_name,
+                functions,
+            } => Ok(CompiledInvocationRule::Function {
+                blueprint_id: compile_blueprint_id(
+                    decoder,
+                    package_address,
+                    blueprint_name,
+                )?,
+                functions: functions.iter().cloned().collect(),
+            }),
+        }
+    }
+}
+
+impl BlueprintRule {
+    fn compile(
+        &self,
+        decoder: &AddressBech32Decoder,
+    ) -> Result<BlueprintId, ClassifierDefinitionError> {
+        compile_blueprint_id(
+            decoder,
+            &self.package_address,
+            &self.blueprint_name,
+        )
+    }
+}
+
+fn compile_blueprint_id(
+    decoder: &AddressBech32Decoder,
+    package_address: &str,
+    blueprint_name: &str,
+) -> Result<BlueprintId, ClassifierDefinitionError> {
+    let package_address =
+        PackageAddress::try_from_bech32(decoder, package_address).ok_or(
+            ClassifierDefinitionError::InvalidAddress(
+                package_address.to_owned(),
+            ),
+        )?;
+    Ok(BlueprintId::new(&package_address, blueprint_name))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClassifierDefinitionError {
+    /// An address in the definition is not a valid address of the expected
+    /// entity type on the network that the definition is compiled for.
+    InvalidAddress(String),
+    /// Two or more of the outputs in the definition have the same name.
+    DuplicateOutputName(String),
+    /// The definition has no required invocations and would therefore classify
+    /// manifests that do nothing.
+    NoRequiredInvocations,
+    /// A method rule restricts its receiver by blueprints alone. The blueprints
+    /// of statically known addresses can't be determined without the ledger
+    /// state and so the rule would only ever match addresses allocated in the
+    /// manifest.
+    BlueprintsOnlyReceiverRule,
+}
+
+/// A [`ClassifierDefinition`] whose addresses have been decoded and validated.
+/// This is the initializer of the [`DeclarativeAnalyzer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledClassifierDefinition {
+    pub(crate) permitted_invocations: Vec<CompiledInvocationRule>,
+    pub(crate) permitted_instructions: IndexSet<InstructionGroup>,
+    pub(crate) required_invocations: Vec<CompiledInvocationRule>,
+    pub(crate) outputs: Vec<CompiledOutputRule>,
+}
+
+impl CompiledClassifierDefinition {
+    pub(crate) fn is_instruction_permitted(
+        &self,
+        context: InstructionContext<'_>,
+    ) -> bool {
+        match context.instruction() {
+            GroupedInstruction::InvocationInstructions(..) => self
+                .permitted_invocations
+                .iter()
+                .chain(self.required_invocations.iter())
+                .any(|rule| rule.matches(context)),
+            instruction => self
+                .permitted_instructions
+                .iter()
+                .any(|group| group.contains(instruction)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CompiledOutputRule {
+    pub(crate) name: String,
+    pub(crate) invocation: CompiledInvocationRule,
+    pub(crate) extract: OutputExtraction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum CompiledInvocationRule {
+    Method {
+        entity_groups: Vec<EntityGroup>,
+        addresses: IndexSet<GlobalAddress>,
+        blueprints: IndexSet<BlueprintId>,
+        methods: IndexSet<String>,
+    },
+    Function {
+        blueprint_id: BlueprintId,
+        functions: IndexSet<String>,
+    },
+}
+
+impl CompiledInvocationRule {
+    pub(crate) fn matches(&self, context: InstructionContext<'_>) -> bool {
+        match (self, context.instruction()) {
+            (
+                Self::Method {
+                    entity_groups,
+                    addresses,
+                    blueprints,
+                    methods,
+                },
+                GroupedInstruction::InvocationInstructions(
+                    InvocationInstructions::CallMethod(CallMethod {
+                        address,
+                        method_name,
+                        ..
+                    }),
+                ),
+            ) => {
+                let is_method_matched =
+                    methods.is_empty() || methods.contains(method_name);
+                let is_any_receiver = entity_groups.is_empty()
+                    && addresses.is_empty()
+                    && blueprints.is_empty();
+                let is_entity_group_matched =
+                    |entity_type: Option<EntityType>| {
+                        entity_type.map(GroupedEntityType::from).is_some_and(
+                            |grouped_entity_type| {
+                                entity_groups.iter().any(|entity_group| {
+                                    entity_group.contains(&grouped_entity_type)
+                                })
+                            },
+                        )
+                    };
+                let is_receiver_matched = match address {
+                    ManifestGlobalAddress::Static(static_address) => {
+                        addresses.contains(static_address)
+                            || is_entity_group_matched(
+                                static_address.as_node_id().entity_type(),
+                            )
+                    }
+                    ManifestGlobalAddress::Named(named_address) => context
+                        .named_address_store()
+                        .get(named_address)
+                        .is_some_and(|blueprint_id| {
+                            blueprints.contains(blueprint_id)
+                                || is_entity_group_matched(
+                                    blueprint_id.entity_type(),
+                                )
+                        }),
+                };
+                is_method_matched && (is_any_receiver || is_receiver_matched)
+            }
+            (
+                Self::Function {
+                    blueprint_id,
+                    functions,
+                },
+                GroupedInstruction::InvocationInstructions(
+                    InvocationInstructions::CallFunction(CallFunction {
+                        package_address:
+                            ManifestPackageAddress::Static(package_address),
+                        blueprint_name,
+                        function_name,
+                        ..
+                    }),
+                ),
+            ) => {
+                *package_address == blueprint_id.package_address
+                    && *blueprint_name == blueprint_id.blueprint_name
+                    && (functions.is_empty()
+                        || functions.contains(function_name))
+            }
+            _ => false,
+        }
+    }
+}
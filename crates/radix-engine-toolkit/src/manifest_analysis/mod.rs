@@ -19,7 +19,9 @@ mod analysis;
 mod classification;
 mod composite;
 mod data_retrieval;
+mod declarative;
 mod error;
+mod registry;
 mod requirements;
 mod traits;
 mod traverser;
@@ -29,7 +31,9 @@ pub use analysis::*;
 pub use classification::*;
 pub use composite::*;
 pub use data_retrieval::*;
+pub use declarative::*;
 pub use error::*;
+pub use registry::*;
 pub use requirements::*;
 pub use traits::*;
 pub use traverser::*;
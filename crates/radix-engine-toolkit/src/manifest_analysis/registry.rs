@@ -0,0 +1,347 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+use std::any::Any;
+
+/// The type of the outputs of analyzers registered in the registry. Since the
+/// registry accepts analyzers of any type their outputs are type-erased and can
+/// be downcasted back into their concrete types by the caller.
+pub type RegisteredAnalyzerOutput = Box<dyn Any + Send + Sync>;
+
+type RegisteredAnalyzerFactory =
+    Box<dyn Fn() -> Box<dyn RegisteredAnalyzer> + Send + Sync>;
+
+/// A registry of additional analyzers that are not part of the toolkit and that
+/// are registered at runtime. This allows for the set of classifications that
+/// the toolkit can produce to be extended with dApp-specific classifications
+/// without needing to make any changes to the toolkit itself.
+///
+/// The analyzers registered in the registry are ran alongside the analyzers of
+/// the toolkit in the same traversal of the manifest and are subject to the
+/// same rules: an analyzer only produces an output if all of the instructions
+/// in the manifest were permitted by its permission state and if all of its
+/// requirements were fulfilled by the end of the manifest.
+#[derive(Default)]
+pub struct ManifestAnalyzerRegistry {
+    analyzer_factories: Vec<RegisteredAnalyzerFactory>,
+}
+
+impl ManifestAnalyzerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an analyzer that only implements [`ManifestStaticAnalyzer`].
+    /// The analyzer is instantiated with a clone of the initializer for each
+    /// manifest that is analyzed.
+    pub fn register_static_analyzer<A>(
+        &mut self,
+        name: impl Into<String>,
+        initializer: A::Initializer,
+    ) -> &mut Self
+    where
+        A: ManifestStaticAnalyzer + 'static,
+        A::Initializer: Clone + Send + Sync + 'static,
+        A::Output: Send + Sync + 'static,
+    {
+        self.register_dynamic_analyzer::<DynamicAnalyzerWrapper<A>>(
+            name,
+            initializer,
+        )
+    }
+
+    /// Registers an analyzer that implements [`ManifestDynamicAnalyzer`]. The
+    /// analyzer is instantiated with a clone of the initializer for each
+    /// manifest that is analyzed.
+    pub fn register_dynamic_analyzer<A>(
+        &mut self,
+        name: impl Into<String>,
+        initializer: A::Initializer,
+    ) -> &mut Self
+    where
+        A: ManifestDynamicAnalyzer + 'static,
+        A::Initializer: Clone + Send + Sync + 'static,
+        <A as ManifestStaticAnalyzer>::Output: Send + Sync + 'static,
+        <A as ManifestDynamicAnalyzer>::Output: Send + Sync + 'static,
+    {
+        let name = name.into();
+        self.analyzer_factories.push(Box::new(move || {
+            Box::new(RegisteredAnalyzerState::<A>::new(
+                name.clone(),
+                initializer.clone(),
+            ))
+        }));
+        self
+    }
+
+    /// Compiles the given [`ClassifierDefinition`] and registers a
+    /// [`DeclarativeAnalyzer`] for it under the name of the definition.
+    pub fn register_classifier_definition(
+        &mut self,
+        definition: &ClassifierDefinition,
+        network_definition: &NetworkDefinition,
+    ) -> Result<&mut Self, ClassifierDefinitionError> {
+        let compiled_definition = definition.compile(network_definition)?;
+        Ok(self.register_static_analyzer::<DeclarativeAnalyzer>(
+            definition.name.clone(),
+            Arc::new(compiled_definition),
+        ))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.analyzer_factories.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.analyzer_factories.len()
+    }
+
+    /// Creates new instances of all of the analyzers in the registry in the
+    /// order in which they were registered.
+    pub(crate) fn instantiate(&self) -> Vec<Box<dyn RegisteredAnalyzer>> {
+        self.analyzer_factories
+            .iter()
+            .map(|factory| factory())
+            .collect()
+    }
+}
+
+/// The classification produced by an analyzer registered in the registry as
+/// seen by the static analyzer.
+pub struct RegisteredClassification {
+    /// The name that the analyzer was registered under.
+    pub name: String,
+    /// The output of the [`ManifestStaticAnalyzer`].
+    pub static_output: RegisteredAnalyzerOutput,
+}
+
+impl RegisteredClassification {
+    pub fn static_output<T: Any>(&self) -> Option<&T> {
+        self.static_output.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for RegisteredClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisteredClassification")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The classification produced by an analyzer registered in the registry as
+/// seen by the dynamic analyzer.
+pub struct RegisteredDetailedClassification {
+    /// The name that the analyzer was registered under.
+    pub name: String,
+    /// The output of the [`ManifestStaticAnalyzer`].
+    pub static_output: RegisteredAnalyzerOutput,
+    /// The output of the [`ManifestDynamicAnalyzer`].
+    pub dynamic_output: RegisteredAnalyzerOutput,
+}
+
+impl RegisteredDetailedClassification {
+    pub fn static_output<T: Any>(&self) -> Option<&T> {
+        self.static_output.downcast_ref()
+    }
+
+    pub fn dynamic_output<T: Any>(&self) -> Option<&T> {
+        self.dynamic_output.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for RegisteredDetailedClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisteredDetailedClassification")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An object-safe interface over an analyzer along with its permission and
+/// requirement states. The [`ManifestStaticAnalyzer`] and the
+/// [`ManifestDynamicAnalyzer`] traits are not object-safe due to their
+/// associated types, so this trait is what allows the registry to store the
+/// analyzers as trait objects.
+pub(crate) trait RegisteredAnalyzer {
+    fn process_static_instruction(&mut self, context: InstructionContext<'_>);
+
+    fn process_dynamic_instruction(&mut self, context: InstructionContext<'_>);
+
+    fn static_output(self: Box<Self>) -> Option<RegisteredClassification>;
+
+    fn dynamic_output(
+        self: Box<Self>,
+    ) -> Option<RegisteredDetailedClassification>;
+}
+
+struct RegisteredAnalyzerState<A: ManifestDynamicAnalyzer> {
+    name: String,
+    analyzer: A,
+    permission_state: <A as ManifestStaticAnalyzer>::PermissionState,
+    requirement_state: <A as ManifestStaticAnalyzer>::RequirementState,
+    /// Mirrors the early termination of the traverser. Once an instruction is
+    /// not permitted or the requirements are permanently unfulfilled, then the
+    /// analyzer stops seeing any further instructions.
+    is_terminated: bool,
+}
+
+impl<A: ManifestDynamicAnalyzer> RegisteredAnalyzerState<A> {
+    fn new(name: String, initializer: A::Initializer) -> Self {
+        let (analyzer, permission_state, requirement_state) =
+            <A as ManifestStaticAnalyzer>::new(initializer);
+        Self {
+            name,
+            analyzer,
+            permission_state,
+            requirement_state,
+            is_terminated: false,
+        }
+    }
+
+    fn is_resolved(&self) -> bool {
+        self.permission_state.all_instructions_permitted()
+            && self.requirement_state.requirement_state().is_fulfilled()
+    }
+}
+
+impl<A> RegisteredAnalyzer for RegisteredAnalyzerState<A>
+where
+    A: ManifestDynamicAnalyzer,
+    <A as ManifestStaticAnalyzer>::Output: Send + Sync + 'static,
+    <A as ManifestDynamicAnalyzer>::Output: Send + Sync + 'static,
+{
+    fn process_static_instruction(&mut self, context: InstructionContext<'_>) {
+        if self.is_terminated {
+            return;
+        }
+
+        self.permission_state.process_instruction(context);
+        if !self.permission_state.all_instructions_permitted()
+            || matches!(
+                self.requirement_state.requirement_state(),
+                RequirementState::PermanentlyUnfulfilled
+            )
+        {
+            self.is_terminated = true;
+            return;
+        }
+        self.requirement_state.process_instruction(context);
+        ManifestStaticAnalyzer::process_instruction(
+            &mut self.analyzer,
+            context,
+        );
+    }
+
+    fn process_dynamic_instruction(&mut self, context: InstructionContext<'_>) {
+        if self.is_terminated {
+            return;
+        }
+        ManifestDynamicAnalyzer::process_instruction(
+            &mut self.analyzer,
+            context,
+        );
+    }
+
+    fn static_output(self: Box<Self>) -> Option<RegisteredClassification> {
+        self.is_resolved().then(|| RegisteredClassification {
+            name: self.name,
+            static_output: Box::new(ManifestStaticAnalyzer::output(
+                self.analyzer,
+            )),
+        })
+    }
+
+    fn dynamic_output(
+        self: Box<Self>,
+    ) -> Option<RegisteredDetailedClassification> {
+        self.is_resolved().then(|| {
+            let CombinedAnalysisOutput {
+                static_analyzer_output,
+                dynamic_analyzer_output,
+            } = ManifestDynamicAnalyzer::output(self.analyzer);
+            RegisteredDetailedClassification {
+                name: self.name,
+                static_output: Box::new(static_analyzer_output),
+                dynamic_output: Box::new(dynamic_analyzer_output),
+            }
+        })
+    }
+}
+
+/// An analyzer that drives all of the analyzers instantiated from the registry.
+/// This analyzer is a member of the composite analyzer used in the analysis and
+/// is therefore always permitted and always has its requirements fulfilled. The
+/// permission and requirement states of each of the registered analyzers are
+/// tracked separately and used to determine which of them produce an output.
+pub(crate) struct RegisteredAnalyzersAnalyzer(Vec<Box<dyn RegisteredAnalyzer>>);
+
+impl ManifestStaticAnalyzer for RegisteredAnalyzersAnalyzer {
+    type Initializer = Vec<Box<dyn RegisteredAnalyzer>>;
+    type Output = Vec<RegisteredClassification>;
+    type PermissionState = ConstState<true>;
+    type RequirementState = ConstState<true>;
+
+    fn new(
+        initializer: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (Self(initializer), ConstState, ConstState)
+    }
+
+    fn output(self) -> Self::Output {
+        self.0
+            .into_iter()
+            .filter_map(|analyzer| analyzer.static_output())
+            .collect()
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        self.0
+            .iter_mut()
+            .for_each(|analyzer| analyzer.process_static_instruction(context))
+    }
+}
+
+impl ManifestDynamicAnalyzer for RegisteredAnalyzersAnalyzer {
+    type Output = Vec<RegisteredDetailedClassification>;
+
+    fn output(
+        self,
+    ) -> CombinedAnalysisOutput<
+        <Self as ManifestStaticAnalyzer>::Output,
+        <Self as ManifestDynamicAnalyzer>::Output,
+    > {
+        // The detailed classifications carry the output of both the static and
+        // the dynamic analyzers and thus there is no need for the outputs to be
+        // duplicated in the static analyzer output.
+        CombinedAnalysisOutput {
+            static_analyzer_output: Vec::new(),
+            dynamic_analyzer_output: self
+                .0
+                .into_iter()
+                .filter_map(|analyzer| analyzer.dynamic_output())
+                .collect(),
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        self.0
+            .iter_mut()
+            .for_each(|analyzer| analyzer.process_dynamic_instruction(context))
+    }
+}
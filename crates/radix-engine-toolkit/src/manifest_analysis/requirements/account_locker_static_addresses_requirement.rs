@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+/// A requirement that the account locker claims, stores and airdrops in the
+/// manifest only use static addresses for the locker and the claimants. The
+/// analyzers of the account locker classifications report these addresses in
+/// their output, which can't be done for named addresses since they're only
+/// known after the manifest is executed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AccountLockerStaticAddressesRequirement {
+    is_all_addresses_static: bool,
+}
+
+impl Default for AccountLockerStaticAddressesRequirement {
+    fn default() -> Self {
+        Self {
+            is_all_addresses_static: true,
+        }
+    }
+}
+
+impl ManifestAnalyzerRequirementState
+    for AccountLockerStaticAddressesRequirement
+{
+    fn requirement_state(&self) -> RequirementState {
+        match self.is_all_addresses_static {
+            true => RequirementState::Fulfilled,
+            false => RequirementState::PermanentlyUnfulfilled,
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        let InstructionContext::InvocationInstruction {
+            typed_native_invocation:
+                Some(TypedNativeInvocation {
+                    receiver,
+                    invocation:
+                        TypedManifestNativeInvocation::AccountLockerBlueprintInvocation(
+                            AccountLockerBlueprintInvocation::Method(method),
+                        ),
+                }),
+            ..
+        } = context
+        else {
+            return;
+        };
+
+        let is_claimants_static = match method {
+            AccountLockerBlueprintMethod::Claim(
+                AccountLockerClaimManifestInput { claimant, .. },
+            )
+            | AccountLockerBlueprintMethod::ClaimNonFungibles(
+                AccountLockerClaimNonFungiblesManifestInput {
+                    claimant, ..
+                },
+            )
+            | AccountLockerBlueprintMethod::Store(
+                AccountLockerStoreManifestInput { claimant, .. },
+            ) => matches!(claimant.0, ManifestComponentAddress::Static(..)),
+            AccountLockerBlueprintMethod::Airdrop(
+                AccountLockerAirdropManifestInput { claimants, .. },
+            ) => claimants.keys().all(|claimant| {
+                matches!(claimant.0, ManifestComponentAddress::Static(..))
+            }),
+            AccountLockerBlueprintMethod::Recover(..)
+            | AccountLockerBlueprintMethod::RecoverNonFungibles(..)
+            | AccountLockerBlueprintMethod::GetAmount(..)
+            | AccountLockerBlueprintMethod::GetNonFungibleLocalIds(..) => {
+                return;
+            }
+        };
+        let is_locker_static = matches!(
+            receiver,
+            ManifestInvocationReceiver::GlobalMethod(
+                ResolvedManifestAddress::Static { .. }
+            )
+        );
+
+        self.is_all_addresses_static &= is_claimants_static && is_locker_static;
+    }
+}
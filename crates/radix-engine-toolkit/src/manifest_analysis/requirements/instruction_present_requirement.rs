@@ -247,6 +247,33 @@ impl<F: FnMut(InstructionContext<'_>) -> bool>
         })
     }
 
+    pub fn validator_management() -> DefaultInstructionPresentRequirement {
+        InstructionPresentRequirement::new(|context| {
+            matches!(
+                context,
+                InstructionContext::InvocationInstruction {
+                    typed_native_invocation: Some(TypedNativeInvocation {
+                        invocation:
+                            TypedManifestNativeInvocation::ValidatorBlueprintInvocation(
+                                ValidatorBlueprintInvocation::Method(
+                                    ValidatorBlueprintMethod::Register(..)
+                                        | ValidatorBlueprintMethod::Unregister(..)
+                                        | ValidatorBlueprintMethod::UpdateFee(..)
+                                        | ValidatorBlueprintMethod::UpdateAcceptDelegatedStake(..)
+                                        | ValidatorBlueprintMethod::LockOwnerStakeUnits(..)
+                                        | ValidatorBlueprintMethod::StartUnlockOwnerStakeUnits(..)
+                                        | ValidatorBlueprintMethod::FinishUnlockOwnerStakeUnits(..)
+                                        | ValidatorBlueprintMethod::SignalProtocolUpdateReadiness(..)
+                                )
+                            ),
+                        ..
+                    }),
+                    ..
+                }
+            )
+        })
+    }
+
     pub fn pool_contribute() -> DefaultInstructionPresentRequirement {
         InstructionPresentRequirement::new(|context| {
             matches!(
@@ -302,6 +329,129 @@ impl<F: FnMut(InstructionContext<'_>) -> bool>
             )
         })
     }
+
+    pub fn account_locker_claim() -> DefaultInstructionPresentRequirement {
+        InstructionPresentRequirement::new(|context| {
+            matches!(
+                context,
+                InstructionContext::InvocationInstruction {
+                    typed_native_invocation: Some(TypedNativeInvocation {
+                        invocation:
+                            TypedManifestNativeInvocation::AccountLockerBlueprintInvocation(
+                                AccountLockerBlueprintInvocation::Method(
+                                    AccountLockerBlueprintMethod::Claim(..)
+                                        | AccountLockerBlueprintMethod::ClaimNonFungibles(..)
+                                )
+                            ),
+                        ..
+                    }),
+                    ..
+                }
+            )
+        })
+    }
+
+    pub fn account_locker_store() -> DefaultInstructionPresentRequirement {
+        InstructionPresentRequirement::new(|context| {
+            matches!(
+                context,
+                InstructionContext::InvocationInstruction {
+                    typed_native_invocation: Some(TypedNativeInvocation {
+                        invocation:
+                            TypedManifestNativeInvocation::AccountLockerBlueprintInvocation(
+                                AccountLockerBlueprintInvocation::Method(
+                                    AccountLockerBlueprintMethod::Store(..)
+                                        | AccountLockerBlueprintMethod::Airdrop(..)
+                                )
+                            ),
+                        ..
+                    }),
+                    ..
+                }
+            )
+        })
+    }
+
+    pub fn access_controller_security_update(
+    ) -> DefaultInstructionPresentRequirement {
+        InstructionPresentRequirement::new(|context| {
+            matches!(
+                context,
+                InstructionContext::InvocationInstruction {
+                    typed_native_invocation: Some(TypedNativeInvocation {
+                        invocation:
+                            TypedManifestNativeInvocation::AccessControllerBlueprintInvocation(
+                                AccessControllerBlueprintInvocation::Function(
+                                    AccessControllerBlueprintFunction::Create(..)
+                                ) | AccessControllerBlueprintInvocation::Method(
+                                    AccessControllerBlueprintMethod::InitiateRecoveryAsPrimary(..)
+                                        | AccessControllerBlueprintMethod::InitiateRecoveryAsRecovery(..)
+                                        | AccessControllerBlueprintMethod::QuickConfirmPrimaryRoleRecoveryProposal(..)
+                                        | AccessControllerBlueprintMethod::QuickConfirmRecoveryRoleRecoveryProposal(..)
+                                        | AccessControllerBlueprintMethod::TimedConfirmRecovery(..)
+                                        | AccessControllerBlueprintMethod::LockPrimaryRole(..)
+                                        | AccessControllerBlueprintMethod::UnlockPrimaryRole(..)
+                                        | AccessControllerBlueprintMethod::InitiateBadgeWithdrawAttemptAsPrimary(..)
+                                        | AccessControllerBlueprintMethod::InitiateBadgeWithdrawAttemptAsRecovery(..)
+                                        | AccessControllerBlueprintMethod::QuickConfirmPrimaryRoleBadgeWithdrawAttempt(..)
+                                        | AccessControllerBlueprintMethod::QuickConfirmRecoveryRoleBadgeWithdrawAttempt(..)
+                                )
+                            ),
+                        ..
+                    }),
+                    ..
+                }
+            )
+        })
+    }
+
+    pub fn resource_manager_create() -> DefaultInstructionPresentRequirement {
+        InstructionPresentRequirement::new(|context| {
+            matches!(
+                context,
+                InstructionContext::InvocationInstruction {
+                    typed_native_invocation: Some(TypedNativeInvocation {
+                        invocation:
+                            TypedManifestNativeInvocation::FungibleResourceManagerBlueprintInvocation(
+                                FungibleResourceManagerBlueprintInvocation::Function(
+                                    FungibleResourceManagerBlueprintFunction::Create(..)
+                                        | FungibleResourceManagerBlueprintFunction::CreateWithInitialSupply(..)
+                                )
+                            ) | TypedManifestNativeInvocation::NonFungibleResourceManagerBlueprintInvocation(
+                                NonFungibleResourceManagerBlueprintInvocation::Function(
+                                    NonFungibleResourceManagerBlueprintFunction::Create(..)
+                                        | NonFungibleResourceManagerBlueprintFunction::CreateWithInitialSupply(..)
+                                        | NonFungibleResourceManagerBlueprintFunction::CreateRuidNonFungibleWithInitialSupply(..)
+                                )
+                            ),
+                        ..
+                    }),
+                    ..
+                }
+            )
+        })
+    }
+
+    pub fn package_publish() -> DefaultInstructionPresentRequirement {
+        InstructionPresentRequirement::new(|context| {
+            matches!(
+                context,
+                InstructionContext::InvocationInstruction {
+                    typed_native_invocation: Some(TypedNativeInvocation {
+                        invocation:
+                            TypedManifestNativeInvocation::PackageBlueprintInvocation(
+                                PackageBlueprintInvocation::Function(
+                                    PackageBlueprintFunction::PublishWasm(..)
+                                        | PackageBlueprintFunction::PublishWasmAdvanced(..)
+                                )
+                            ),
+                        ..
+                    }),
+                    ..
+                }
+            )
+        })
+    }
 }
 
 impl<F: FnMut(InstructionContext<'_>) -> bool> ManifestAnalyzerRequirementState
@@ -371,7 +521,18 @@ define_instruction_present_type! {
     ValidatorStakeInstructionPresentRequirement => validator_stake,
     ValidatorUnstakeInstructionPresentRequirement => validator_unstake,
     ValidatorClaimXrdInstructionPresentRequirement => validator_claim_xrd,
+    ValidatorManagementInstructionPresentRequirement => validator_management,
     /* Pools */
     PoolContributeInstructionPresentRequirement => pool_contribute,
     PoolRedeemInstructionPresentRequirement => pool_redeem,
+    /* Account Locker */
+    AccountLockerClaimInstructionPresentRequirement => account_locker_claim,
+    AccountLockerStoreInstructionPresentRequirement => account_locker_store,
+    /* Access Controller */
+    AccessControllerSecurityUpdateInstructionPresentRequirement
+        => access_controller_security_update,
+    /* Resource Manager */
+    ResourceManagerCreateInstructionPresentRequirement => resource_manager_create,
+    /* Package */
+    PackagePublishInstructionPresentRequirement => package_publish,
 }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod account_locker_static_addresses_requirement;
 mod account_only_fungible_withdraws_requirement;
 mod account_only_non_fungible_withdraws_requirement;
 mod account_only_xrd_withdraws_requirement;
@@ -24,6 +25,7 @@ mod all;
 mod any;
 mod instruction_present_requirement;
 
+pub use account_locker_static_addresses_requirement::*;
 pub use account_only_fungible_withdraws_requirement::*;
 pub use account_only_non_fungible_withdraws_requirement::*;
 pub use account_only_xrd_withdraws_requirement::*;
@@ -71,6 +71,10 @@ pub struct DynamicAnalysis {
     /// manifest.
     pub reserved_instructions_summary: ReservedInstructionsOutput,
 
+    /// A summary of the warnings about potentially risky operations performed
+    /// by the manifest which should be surfaced to the user before signing.
+    pub manifest_warnings_summary: ManifestWarningsOutput,
+
     /// A summary of the fees locked in the transaction that splits them up into
     /// regular and contingent fee locks.
     pub fee_locks_summary: FeeLocks,
@@ -82,4 +86,10 @@ pub struct DynamicAnalysis {
     /// A vector of the manifest's detailed classification. We maintain the
     /// invariant that this field has no duplicates.
     pub detailed_manifest_classification: Vec<DetailedManifestClassification>,
+
+    /// The detailed classification(s) of the manifest produced by the analyzers
+    /// in the [`ManifestAnalyzerRegistry`] used in the analysis. This is empty
+    /// if no registry was used or none of the registered analyzers matched.
+    pub registered_detailed_classifications:
+        Vec<RegisteredDetailedClassification>,
 }
@@ -56,4 +56,35 @@ pub enum ManifestClassification {
     /// A manifest where account deposit settings get updated. In this manifest
     /// class one of the account deposit settings methods are called.
     AccountDepositSettingsUpdate,
+    /// A manifest where resources are swapped through a non-native component
+    /// such as a DEX. In this class resources are withdrawn from a single
+    /// account, a single non-native component is invoked, and the resources
+    /// returned by the component are deposited back into the same account.
+    Swap,
+    /// A manifest where resources are claimed from one or more account
+    /// locker(s). In this class resources are claimed from the locker(s) on
+    /// behalf of one or more account(s) and then deposited into account(s).
+    AccountLockerClaim,
+    /// A manifest where resources are stored in one or more account locker(s)
+    /// either through a store or an airdrop. In this class resources are
+    /// withdrawn from account(s) and stored in the locker(s) for claimants.
+    AccountLockerStore,
+    /// A manifest where the security of an entity controlled by an access
+    /// controller is updated. This includes securifying an entity through the
+    /// creation of an access controller, initiating and confirming recoveries,
+    /// locking and unlocking the primary role, and badge withdraw attempts.
+    SecurityShieldUpdate,
+    /// A manifest where one or more fungible or non-fungible resources are
+    /// created. In this class the resources may be created with an initial
+    /// supply or minted, and the resources are deposited into account(s).
+    ResourceCreation,
+    /// A manifest where one or more packages are published. In this class the
+    /// packages may be published with or without an explicit owner role and
+    /// the blueprints of the newly published packages may be instantiated.
+    PackagePublish,
+    /// A manifest where the owner of one or more validator(s) manages them.
+    /// This includes registering and unregistering validators, updating their
+    /// fee and delegation settings, locking and unlocking the owner's stake
+    /// units, and signaling readiness for protocol updates.
+    ValidatorManagement,
 }
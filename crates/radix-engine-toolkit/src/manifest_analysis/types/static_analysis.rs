@@ -62,7 +62,16 @@ pub struct StaticAnalysis {
     /// manifest.
     pub reserved_instructions_summary: ReservedInstructionsOutput,
 
+    /// A summary of the warnings about potentially risky operations performed
+    /// by the manifest which should be surfaced to the user before signing.
+    pub manifest_warnings_summary: ManifestWarningsOutput,
+
     /// An ordered set of the classification(s) of the manifest based on the
     /// static analysis.
     pub manifest_classification: Vec<ManifestClassification>,
+
+    /// The classification(s) of the manifest produced by the analyzers in the
+    /// [`ManifestAnalyzerRegistry`] used in the analysis. This is empty if no
+    /// registry was used or none of the registered analyzers matched.
+    pub registered_classifications: Vec<RegisteredClassification>,
 }
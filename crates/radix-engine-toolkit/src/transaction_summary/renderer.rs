@@ -0,0 +1,549 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+/// A trait implemented by types that render the [`TransactionSummaryLine`]s
+/// into text. Integrators that need the summary in a language other than
+/// English implement this trait, typically looking up the template of the line
+/// through its [`message_key`].
+///
+/// [`message_key`]: TransactionSummaryLine::message_key
+pub trait TransactionSummaryRenderer {
+    fn render_line(&self, line: &TransactionSummaryLine) -> String;
+}
+
+/// Renders the [`TransactionSummaryLine`]s into English text. Addresses are
+/// rendered as Bech32m addresses of the network the renderer was created for,
+/// except for XRD which is rendered by its symbol. Named addresses allocated in
+/// the manifest have no address yet and are rendered as such. Amounts that are
+/// predicted rather than guaranteed are prefixed with a `~`.
+pub struct EnglishTransactionSummaryRenderer {
+    address_encoder: AddressBech32Encoder,
+}
+
+impl EnglishTransactionSummaryRenderer {
+    pub fn new(network_definition: &NetworkDefinition) -> Self {
+        Self {
+            address_encoder: AddressBech32Encoder::new(network_definition),
+        }
+    }
+
+    fn render_address(&self, address: impl Into<GlobalAddress>) -> String {
+        self.address_encoder
+            .encode(address.into().as_node_id().as_bytes())
+            .expect("Global addresses can always be Bech32m encoded")
+    }
+
+    fn render_manifest_address(
+        &self,
+        address: &ManifestGlobalAddress,
+    ) -> String {
+        match address {
+            ManifestGlobalAddress::Static(address) => {
+                self.render_address(*address)
+            }
+            ManifestGlobalAddress::Named(..) => {
+                "an address allocated in the manifest".to_owned()
+            }
+        }
+    }
+
+    fn render_manifest_resource_address(
+        &self,
+        address: &ManifestResourceAddress,
+    ) -> String {
+        match address {
+            ManifestResourceAddress::Static(address) => {
+                self.render_address(*address)
+            }
+            ManifestResourceAddress::Named(..) => {
+                "a resource allocated in the manifest".to_owned()
+            }
+        }
+    }
+
+    fn render_badge(&self, badge: &ManifestResourceOrNonFungible) -> String {
+        match badge {
+            ManifestResourceOrNonFungible::Resource(address) => {
+                self.render_manifest_resource_address(address)
+            }
+            ManifestResourceOrNonFungible::NonFungible(id) => format!(
+                "{} of {}",
+                id.local_id(),
+                self.render_address(id.resource_address())
+            ),
+        }
+    }
+
+    fn render_entity(&self, entity: &SummaryEntity) -> String {
+        self.render_address(entity.address)
+    }
+
+    fn render_resource(&self, resource: &SummaryResource) -> String {
+        let resource_name = if resource.resource_address == XRD {
+            "XRD".to_owned()
+        } else {
+            self.render_address(resource.resource_address)
+        };
+        let prefix = if resource.is_guaranteed { "" } else { "~" };
+        match &resource.amount {
+            SummaryResourceAmount::Fungible(amount) => {
+                format!("{prefix}{amount} {resource_name}")
+            }
+            SummaryResourceAmount::NonFungible(ids) => format!(
+                "{prefix}{} of {resource_name}",
+                ids.iter().map(ToString::to_string).join(", ")
+            ),
+        }
+    }
+
+    fn render_resources(&self, resources: &[SummaryResource]) -> String {
+        resources
+            .iter()
+            .map(|resource| self.render_resource(resource))
+            .join(", ")
+    }
+}
+
+impl TransactionSummaryRenderer for EnglishTransactionSummaryRenderer {
+    fn render_line(&self, line: &TransactionSummaryLine) -> String {
+        match line {
+            TransactionSummaryLine::Withdraw { account, resources } => format!(
+                "Withdraw {} from {}",
+                self.render_resources(resources),
+                self.render_entity(account)
+            ),
+            TransactionSummaryLine::Deposit { account, resources } => format!(
+                "Deposit {} into {}",
+                self.render_resources(resources),
+                self.render_entity(account)
+            ),
+            TransactionSummaryLine::Stake {
+                validator,
+                staked,
+                received,
+            } => format!(
+                "Stake {} to {} for {}",
+                self.render_resource(staked),
+                self.render_entity(validator),
+                self.render_resource(received)
+            ),
+            TransactionSummaryLine::Unstake {
+                validator,
+                unstaked,
+                received,
+            } => format!(
+                "Unstake {} from {} for {}",
+                self.render_resource(unstaked),
+                self.render_entity(validator),
+                self.render_resource(received)
+            ),
+            TransactionSummaryLine::ClaimXrd {
+                validator,
+                claimed,
+                received,
+            } => format!(
+                "Claim {} from {} with {}",
+                self.render_resource(received),
+                self.render_entity(validator),
+                self.render_resource(claimed)
+            ),
+            TransactionSummaryLine::PoolContribution {
+                pool,
+                contributed,
+                received,
+            } => format!(
+                "Contribute {} to {} for {}",
+                self.render_resources(contributed),
+                self.render_entity(pool),
+                self.render_resource(received)
+            ),
+            TransactionSummaryLine::PoolRedemption {
+                pool,
+                redeemed,
+                received,
+            } => format!(
+                "Redeem {} from {} for {}",
+                self.render_resource(redeemed),
+                self.render_entity(pool),
+                self.render_resources(received)
+            ),
+            TransactionSummaryLine::Swap {
+                component,
+                input,
+                output,
+            } => format!(
+                "Swap {} for {} through {}",
+                self.render_resources(input),
+                self.render_resources(output),
+                self.render_entity(component)
+            ),
+            TransactionSummaryLine::Fee { amount } => {
+                format!("Fee: {amount} XRD")
+            }
+            TransactionSummaryLine::General {
+                is_subintent: false,
+            } => "Interact with components".to_owned(),
+            TransactionSummaryLine::General { is_subintent: true } => {
+                "Interact with components as part of a subintent".to_owned()
+            }
+            TransactionSummaryLine::Transfer {
+                is_one_to_one_transfer: true,
+            } => "Transfer resources from one account to another".to_owned(),
+            TransactionSummaryLine::Transfer {
+                is_one_to_one_transfer: false,
+            } => "Transfer resources between accounts".to_owned(),
+            TransactionSummaryLine::AccountDepositSettingsUpdate {
+                account,
+                default_deposit_rule,
+                resource_preference_updates,
+                authorized_depositor_updates,
+            } => {
+                let default_deposit_rule =
+                    default_deposit_rule.map(|rule| match rule {
+                        DefaultDepositRule::Accept => {
+                            "accept all deposits".to_owned()
+                        }
+                        DefaultDepositRule::Reject => {
+                            "reject all deposits".to_owned()
+                        }
+                        DefaultDepositRule::AllowExisting => {
+                            "only accept deposits of existing resources"
+                                .to_owned()
+                        }
+                    });
+                let resource_preference_updates = resource_preference_updates
+                    .iter()
+                    .map(|(resource_address, update)| {
+                        let resource_address = self
+                            .render_manifest_resource_address(resource_address);
+                        match update {
+                            Update::Set(ResourcePreference::Allowed) => {
+                                format!("always allow {resource_address}")
+                            }
+                            Update::Set(ResourcePreference::Disallowed) => {
+                                format!("always disallow {resource_address}")
+                            }
+                            Update::Remove => format!(
+                                "remove the preference of {resource_address}"
+                            ),
+                        }
+                    });
+                let authorized_depositor_updates = authorized_depositor_updates
+                    .iter()
+                    .map(|(badge, operation)| {
+                        let badge = self.render_badge(badge);
+                        match operation {
+                            Operation::Added => {
+                                format!("authorize depositor {badge}")
+                            }
+                            Operation::Removed => {
+                                format!("deauthorize depositor {badge}")
+                            }
+                        }
+                    });
+                format!(
+                    "Update the deposit settings of {}: {}",
+                    self.render_manifest_address(account),
+                    default_deposit_rule
+                        .into_iter()
+                        .chain(resource_preference_updates)
+                        .chain(authorized_depositor_updates)
+                        .join(", ")
+                )
+            }
+            TransactionSummaryLine::AccountLockerClaim {
+                locker,
+                claimant,
+                claimed,
+            } => format!(
+                "Claim {} from {} for {}",
+                self.render_resource(claimed),
+                self.render_entity(locker),
+                self.render_entity(claimant)
+            ),
+            TransactionSummaryLine::AccountLockerStore {
+                locker,
+                claimant,
+                stored,
+                try_direct_send,
+            } => {
+                let suffix = if *try_direct_send {
+                    ", sending them directly if possible"
+                } else {
+                    ""
+                };
+                format!(
+                    "Store {} in {} for {}{suffix}",
+                    self.render_resource(stored),
+                    self.render_entity(locker),
+                    self.render_entity(claimant)
+                )
+            }
+            TransactionSummaryLine::SecurityShieldUpdate { operation } => {
+                self.render_security_shield_update(operation)
+            }
+            TransactionSummaryLine::ResourceCreation {
+                resource,
+                initial_supply: Some(initial_supply),
+            } => format!(
+                "Create {} with an initial supply of {}",
+                self.render_entity(resource),
+                self.render_resource(initial_supply)
+            ),
+            TransactionSummaryLine::ResourceCreation {
+                resource,
+                initial_supply: None,
+            } => format!("Create {}", self.render_entity(resource)),
+            TransactionSummaryLine::PackagePublish {
+                package,
+                blueprints,
+            } => format!(
+                "Publish {} with the blueprints {}",
+                self.render_entity(package),
+                blueprints.iter().join(", ")
+            ),
+            TransactionSummaryLine::ValidatorManagement { operation } => {
+                self.render_validator_management(operation)
+            }
+            TransactionSummaryLine::Warning { warning } => {
+                format!("Warning: {}", self.render_warning(warning))
+            }
+        }
+    }
+}
+
+impl EnglishTransactionSummaryRenderer {
+    fn render_security_shield_update(
+        &self,
+        operation: &SecurityShieldUpdateOperation,
+    ) -> String {
+        let proposer = |proposer: &AccessControllerProposer| match proposer {
+            AccessControllerProposer::Primary => "primary",
+            AccessControllerProposer::Recovery => "recovery",
+        };
+        match operation {
+            SecurityShieldUpdateOperation::CreateAccessController {
+                timed_recovery_delay_in_minutes: Some(delay),
+                ..
+            } => format!(
+                "Create an access controller with a timed recovery delay of \
+                {delay} minutes"
+            ),
+            SecurityShieldUpdateOperation::CreateAccessController {
+                timed_recovery_delay_in_minutes: None,
+                ..
+            } => "Create an access controller".to_owned(),
+            SecurityShieldUpdateOperation::InitiateRecovery {
+                access_controller_address,
+                proposer: role,
+                ..
+            } => format!(
+                "Propose a new security shield for {} as the {} role",
+                self.render_manifest_address(access_controller_address),
+                proposer(role)
+            ),
+            SecurityShieldUpdateOperation::QuickConfirmRecovery {
+                access_controller_address,
+                proposer: role,
+                ..
+            } => format!(
+                "Confirm the security shield proposed by the {} role for {}",
+                proposer(role),
+                self.render_manifest_address(access_controller_address)
+            ),
+            SecurityShieldUpdateOperation::TimedConfirmRecovery {
+                access_controller_address,
+                ..
+            } => format!(
+                "Confirm the security shield proposed for {} after the timed \
+                recovery delay",
+                self.render_manifest_address(access_controller_address)
+            ),
+            SecurityShieldUpdateOperation::LockPrimaryRole {
+                access_controller_address,
+            } => format!(
+                "Lock the primary role of {}",
+                self.render_manifest_address(access_controller_address)
+            ),
+            SecurityShieldUpdateOperation::UnlockPrimaryRole {
+                access_controller_address,
+            } => format!(
+                "Unlock the primary role of {}",
+                self.render_manifest_address(access_controller_address)
+            ),
+            SecurityShieldUpdateOperation::InitiateBadgeWithdrawAttempt {
+                access_controller_address,
+                proposer: role,
+            } => format!(
+                "Propose withdrawing the badge of {} as the {} role",
+                self.render_manifest_address(access_controller_address),
+                proposer(role)
+            ),
+            SecurityShieldUpdateOperation::QuickConfirmBadgeWithdrawAttempt {
+                access_controller_address,
+                proposer: role,
+            } => format!(
+                "Confirm withdrawing the badge of {} as proposed by the {} role",
+                self.render_manifest_address(access_controller_address),
+                proposer(role)
+            ),
+        }
+    }
+
+    fn render_validator_management(
+        &self,
+        operation: &ValidatorManagementOperation,
+    ) -> String {
+        match operation {
+            ValidatorManagementOperation::Register { validator_address } => {
+                format!("Register {}", self.render_address(*validator_address))
+            }
+            ValidatorManagementOperation::Unregister { validator_address } => {
+                format!(
+                    "Unregister {}",
+                    self.render_address(*validator_address)
+                )
+            }
+            ValidatorManagementOperation::UpdateFee {
+                validator_address,
+                new_fee_factor,
+            } => format!(
+                "Update the fee factor of {} to {new_fee_factor}",
+                self.render_address(*validator_address)
+            ),
+            ValidatorManagementOperation::UpdateAcceptDelegatedStake {
+                validator_address,
+                accept_delegated_stake: true,
+            } => format!(
+                "Accept delegated stake on {}",
+                self.render_address(*validator_address)
+            ),
+            ValidatorManagementOperation::UpdateAcceptDelegatedStake {
+                validator_address,
+                accept_delegated_stake: false,
+            } => format!(
+                "Stop accepting delegated stake on {}",
+                self.render_address(*validator_address)
+            ),
+            ValidatorManagementOperation::LockOwnerStakeUnits {
+                validator_address,
+                stake_unit_amount,
+            } => format!(
+                "Lock {stake_unit_amount} owner stake units in {}",
+                self.render_address(*validator_address)
+            ),
+            ValidatorManagementOperation::StartUnlockOwnerStakeUnits {
+                validator_address,
+                requested_stake_unit_amount,
+            } => format!(
+                "Start unlocking {requested_stake_unit_amount} owner stake \
+                units from {}",
+                self.render_address(*validator_address)
+            ),
+            ValidatorManagementOperation::FinishUnlockOwnerStakeUnits {
+                validator_address,
+            } => format!(
+                "Finish unlocking the owner stake units from {}",
+                self.render_address(*validator_address)
+            ),
+            ValidatorManagementOperation::SignalProtocolUpdateReadiness {
+                validator_address,
+                protocol_version_name,
+            } => format!(
+                "Signal the readiness of {} for the protocol update \
+                {protocol_version_name}",
+                self.render_address(*validator_address)
+            ),
+        }
+    }
+
+    fn render_warning(&self, warning: &ManifestWarning) -> String {
+        match warning {
+            ManifestWarning::WithdrawnResourcesNotDepositedIntoAccounts {
+                accounts_withdrawn_from,
+            } => format!(
+                "resources withdrawn from {} aren't deposited into any account",
+                accounts_withdrawn_from
+                    .iter()
+                    .map(|account| self.render_manifest_address(account))
+                    .join(", ")
+            ),
+            ManifestWarning::ResourcesBurnedFromAccount { account } => format!(
+                "resources are burned from {}",
+                self.render_manifest_address(account)
+            ),
+            ManifestWarning::DepositIntoThirdPartyAccount { account } => {
+                format!(
+                    "resources are deposited into the third-party account {}",
+                    self.render_manifest_address(account)
+                )
+            }
+            ManifestWarning::EntitySecurified { entity } => format!(
+                "{} is securified",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::OwnerKeysUpdated { entity } => format!(
+                "the owner keys of {} are updated",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::OwnerKeysLocked { entity } => format!(
+                "the owner keys of {} are locked",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::OwnerRoleUpdated { entity } => format!(
+                "the owner role of {} is updated",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::OwnerRoleLocked { entity } => format!(
+                "the owner role of {} is locked",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::RoleUpdated { entity } => format!(
+                "a role of {} is updated",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::RoleLocked { entity, role_key } => format!(
+                "the {role_key} role of {} is set to deny all, locking the \
+                role it updates",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::MetadataUpdated { entity, key } => format!(
+                "the {key} metadata of {} is updated",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::MetadataLocked { entity, key } => format!(
+                "the {key} metadata of {} is locked",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::MetadataRemoved { entity, key } => format!(
+                "the {key} metadata of {} is removed",
+                self.render_manifest_address(entity)
+            ),
+            ManifestWarning::DirectVaultMethodInvoked {
+                vault_address,
+                method_name,
+            } => format!(
+                "the {method_name} method is invoked directly on the vault {}",
+                self.address_encoder
+                    .encode(vault_address.as_node_id().as_bytes())
+                    .expect("Internal addresses can always be Bech32m encoded")
+            ),
+        }
+    }
+}
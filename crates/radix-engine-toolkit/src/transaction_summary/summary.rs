@@ -0,0 +1,700 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+use crate::manifest_analysis::{
+    AccountLockerClaimOutput, AccountLockerStoreOutput,
+};
+
+/// A summary of what a transaction does made up of structured lines. The lines
+/// carry typed data rather than text so that they can be rendered in any
+/// language through a [`TransactionSummaryRenderer`].
+///
+/// The lines are ordered as follows: the warnings about the manifest, the
+/// withdraws from accounts, the lines specific to the detailed classification
+/// of the manifest (e.g., stakes, swaps, or pool contributions), the deposits
+/// into accounts, and finally the fees paid by the transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct TransactionSummary {
+    pub lines: Vec<TransactionSummaryLine>,
+}
+
+impl TransactionSummary {
+    pub fn from_dynamic_analysis(analysis: &DynamicAnalysis) -> Self {
+        let AccountDynamicResourceMovementsOutput {
+            account_withdraws,
+            account_deposits,
+        } = &analysis.account_dynamic_resource_movements_summary;
+
+        let mut lines = Vec::new();
+
+        lines.extend(
+            analysis
+                .manifest_warnings_summary
+                .warnings
+                .iter()
+                .cloned()
+                .map(|warning| TransactionSummaryLine::Warning { warning }),
+        );
+
+        lines.extend(account_withdraws.iter().map(|(account, withdraws)| {
+            TransactionSummaryLine::Withdraw {
+                account: SummaryEntity::from(*account),
+                resources: withdraws
+                    .iter()
+                    .map(SummaryResource::from)
+                    .collect(),
+            }
+        }));
+
+        // Manifests that have a more specific classification are typically
+        // also classified as general, so the general line is only included
+        // when no more specific classification applies.
+        let has_specific_classification = analysis
+            .detailed_manifest_classification
+            .iter()
+            .any(|classification| !is_general(classification));
+        lines.extend(
+            analysis
+                .detailed_manifest_classification
+                .iter()
+                .filter(|classification| {
+                    !(has_specific_classification && is_general(classification))
+                })
+                .flat_map(classification_lines),
+        );
+
+        lines.extend(account_deposits.iter().map(|(account, deposits)| {
+            TransactionSummaryLine::Deposit {
+                account: SummaryEntity::from(*account),
+                resources: deposits.iter().map(SummaryResource::from).collect(),
+            }
+        }));
+
+        let FeeSummary {
+            execution_cost,
+            finalization_cost,
+            storage_expansion_cost,
+            royalty_cost,
+        } = &analysis.fee_consumption_summary;
+        lines.push(TransactionSummaryLine::Fee {
+            amount: *execution_cost
+                + *finalization_cost
+                + *storage_expansion_cost
+                + *royalty_cost,
+        });
+
+        Self { lines }
+    }
+
+    /// Renders all of the lines of the summary through the given renderer.
+    pub fn render(
+        &self,
+        renderer: &impl TransactionSummaryRenderer,
+    ) -> Vec<String> {
+        self.lines
+            .iter()
+            .map(|line| renderer.render_line(line))
+            .collect()
+    }
+}
+
+/// A single line of the [`TransactionSummary`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionSummaryLine {
+    /// Resources withdrawn from an account.
+    Withdraw {
+        account: SummaryEntity,
+        resources: Vec<SummaryResource>,
+    },
+    /// Resources deposited into an account.
+    Deposit {
+        account: SummaryEntity,
+        resources: Vec<SummaryResource>,
+    },
+    /// XRD staked to a validator in exchange for liquid stake units.
+    Stake {
+        validator: SummaryEntity,
+        staked: SummaryResource,
+        received: SummaryResource,
+    },
+    /// Liquid stake units unstaked from a validator in exchange for claim NFTs.
+    Unstake {
+        validator: SummaryEntity,
+        unstaked: SummaryResource,
+        received: SummaryResource,
+    },
+    /// Claim NFTs claimed from a validator in exchange for XRD.
+    ClaimXrd {
+        validator: SummaryEntity,
+        claimed: SummaryResource,
+        received: SummaryResource,
+    },
+    /// Resources contributed to a pool in exchange for pool units.
+    PoolContribution {
+        pool: SummaryEntity,
+        contributed: Vec<SummaryResource>,
+        received: SummaryResource,
+    },
+    /// Pool units redeemed from a pool in exchange for the pool's resources.
+    PoolRedemption {
+        pool: SummaryEntity,
+        redeemed: SummaryResource,
+        received: Vec<SummaryResource>,
+    },
+    /// Resources swapped through a component.
+    Swap {
+        component: SummaryEntity,
+        input: Vec<SummaryResource>,
+        output: Vec<SummaryResource>,
+    },
+    /// The total fee paid by the transaction in XRD.
+    Fee { amount: Decimal },
+    /// A general interaction with components that isn't described by a more
+    /// specific classification.
+    General { is_subintent: bool },
+    /// Resources transferred between accounts. The resources transferred are
+    /// described by the withdraw and deposit lines.
+    Transfer { is_one_to_one_transfer: bool },
+    /// The deposit settings of an account are updated. The account and the
+    /// resources are kept in their manifest form since they may be named
+    /// addresses allocated in the manifest.
+    AccountDepositSettingsUpdate {
+        account: ManifestGlobalAddress,
+        default_deposit_rule: Option<DefaultDepositRule>,
+        resource_preference_updates:
+            IndexMap<ManifestResourceAddress, Update<ResourcePreference>>,
+        authorized_depositor_updates:
+            IndexMap<ManifestResourceOrNonFungible, Operation>,
+    },
+    /// Resources claimed from an account locker on behalf of an account.
+    AccountLockerClaim {
+        locker: SummaryEntity,
+        claimant: SummaryEntity,
+        claimed: SummaryResource,
+    },
+    /// Resources stored in an account locker for an account to claim.
+    AccountLockerStore {
+        locker: SummaryEntity,
+        claimant: SummaryEntity,
+        stored: SummaryResource,
+        try_direct_send: bool,
+    },
+    /// An operation on an access controller that affects the security of the
+    /// entity that it controls.
+    SecurityShieldUpdate {
+        operation: SecurityShieldUpdateOperation,
+    },
+    /// A new resource is created, optionally with an initial supply.
+    ResourceCreation {
+        resource: SummaryEntity,
+        initial_supply: Option<SummaryResource>,
+    },
+    /// A new package is published with the given blueprints.
+    PackagePublish {
+        package: SummaryEntity,
+        blueprints: IndexSet<String>,
+    },
+    /// A management operation performed on a validator by its owner.
+    ValidatorManagement {
+        operation: ValidatorManagementOperation,
+    },
+    /// A warning about a potentially risky operation performed by the
+    /// manifest.
+    Warning { warning: ManifestWarning },
+}
+
+impl TransactionSummaryLine {
+    /// A stable key identifying the kind of the line which can be used to look
+    /// up the localised template of the line.
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            Self::Withdraw { .. } => "transaction_summary.withdraw",
+            Self::Deposit { .. } => "transaction_summary.deposit",
+            Self::Stake { .. } => "transaction_summary.stake",
+            Self::Unstake { .. } => "transaction_summary.unstake",
+            Self::ClaimXrd { .. } => "transaction_summary.claim_xrd",
+            Self::PoolContribution { .. } => {
+                "transaction_summary.pool_contribution"
+            }
+            Self::PoolRedemption { .. } => {
+                "transaction_summary.pool_redemption"
+            }
+            Self::Swap { .. } => "transaction_summary.swap",
+            Self::Fee { .. } => "transaction_summary.fee",
+            Self::General { .. } => "transaction_summary.general",
+            Self::Transfer { .. } => "transaction_summary.transfer",
+            Self::AccountDepositSettingsUpdate { .. } => {
+                "transaction_summary.account_deposit_settings_update"
+            }
+            Self::AccountLockerClaim { .. } => {
+                "transaction_summary.account_locker_claim"
+            }
+            Self::AccountLockerStore { .. } => {
+                "transaction_summary.account_locker_store"
+            }
+            Self::SecurityShieldUpdate { .. } => {
+                "transaction_summary.security_shield_update"
+            }
+            Self::ResourceCreation { .. } => {
+                "transaction_summary.resource_creation"
+            }
+            Self::PackagePublish { .. } => {
+                "transaction_summary.package_publish"
+            }
+            Self::ValidatorManagement { .. } => {
+                "transaction_summary.validator_management"
+            }
+            Self::Warning { .. } => "transaction_summary.warning",
+        }
+    }
+}
+
+/// An entity referenced in a [`TransactionSummaryLine`] along with its kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SummaryEntity {
+    pub kind: SummaryEntityKind,
+    pub address: GlobalAddress,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SummaryEntityKind {
+    Account,
+    Identity,
+    Validator,
+    Pool,
+    Resource,
+    Package,
+    AccessController,
+    AccountLocker,
+    Component,
+}
+
+impl From<GlobalAddress> for SummaryEntity {
+    fn from(address: GlobalAddress) -> Self {
+        let kind = match address
+            .as_node_id()
+            .entity_type()
+            .map(GroupedEntityType::from)
+        {
+            Some(GroupedEntityType::AccountEntities(..)) => {
+                SummaryEntityKind::Account
+            }
+            Some(GroupedEntityType::IdentityEntities(..)) => {
+                SummaryEntityKind::Identity
+            }
+            Some(GroupedEntityType::ValidatorEntities(..)) => {
+                SummaryEntityKind::Validator
+            }
+            Some(GroupedEntityType::PoolEntities(..)) => {
+                SummaryEntityKind::Pool
+            }
+            Some(GroupedEntityType::ResourceManagerEntities(..)) => {
+                SummaryEntityKind::Resource
+            }
+            Some(GroupedEntityType::PackageEntities(..)) => {
+                SummaryEntityKind::Package
+            }
+            Some(GroupedEntityType::AccessControllerEntities(..)) => {
+                SummaryEntityKind::AccessController
+            }
+            Some(GroupedEntityType::AccountLockerEntities(..)) => {
+                SummaryEntityKind::AccountLocker
+            }
+            Some(
+                GroupedEntityType::GenericComponentEntities(..)
+                | GroupedEntityType::SystemEntities(..)
+                | GroupedEntityType::InternalEntities(..),
+            )
+            | None => SummaryEntityKind::Component,
+        };
+        Self { kind, address }
+    }
+}
+
+impl From<ComponentAddress> for SummaryEntity {
+    fn from(address: ComponentAddress) -> Self {
+        GlobalAddress::from(address).into()
+    }
+}
+
+impl From<ResourceAddress> for SummaryEntity {
+    fn from(address: ResourceAddress) -> Self {
+        GlobalAddress::from(address).into()
+    }
+}
+
+impl From<PackageAddress> for SummaryEntity {
+    fn from(address: PackageAddress) -> Self {
+        GlobalAddress::from(address).into()
+    }
+}
+
+/// A resource referenced in a [`TransactionSummaryLine`] along with its amount.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SummaryResource {
+    pub resource_address: ResourceAddress,
+    pub amount: SummaryResourceAmount,
+    /// Whether the amount is guaranteed or only predicted from the preview of
+    /// the transaction and could therefore change when it's executed.
+    pub is_guaranteed: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SummaryResourceAmount {
+    Fungible(Decimal),
+    NonFungible(IndexSet<NonFungibleLocalId>),
+}
+
+impl SummaryResource {
+    pub fn guaranteed_fungible(
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            resource_address,
+            amount: SummaryResourceAmount::Fungible(amount),
+            is_guaranteed: true,
+        }
+    }
+
+    pub fn guaranteed_non_fungible(
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+    ) -> Self {
+        Self {
+            resource_address,
+            amount: SummaryResourceAmount::NonFungible(ids),
+            is_guaranteed: true,
+        }
+    }
+}
+
+impl From<&InvocationIoItem> for SummaryResource {
+    fn from(item: &InvocationIoItem) -> Self {
+        match item {
+            InvocationIoItem::Fungible(resource_address, amount) => Self {
+                resource_address: *resource_address,
+                amount: SummaryResourceAmount::Fungible(**amount),
+                is_guaranteed: matches!(
+                    amount,
+                    EitherGuaranteedOrPredicted::Guaranteed(..)
+                ),
+            },
+            InvocationIoItem::NonFungible(resource_address, ids) => Self {
+                resource_address: *resource_address,
+                amount: SummaryResourceAmount::NonFungible((**ids).clone()),
+                is_guaranteed: matches!(
+                    ids,
+                    EitherGuaranteedOrPredicted::Guaranteed(..)
+                ),
+            },
+        }
+    }
+}
+
+impl From<&ResourceSpecifier> for SummaryResource {
+    fn from(specifier: &ResourceSpecifier) -> Self {
+        match specifier {
+            ResourceSpecifier::Amount(resource_address, amount) => {
+                Self::guaranteed_fungible(*resource_address, *amount)
+            }
+            ResourceSpecifier::Ids(resource_address, ids) => {
+                Self::guaranteed_non_fungible(*resource_address, ids.clone())
+            }
+        }
+    }
+}
+
+fn is_general(classification: &DetailedManifestClassification) -> bool {
+    matches!(
+        classification,
+        DetailedManifestClassification::General
+            | DetailedManifestClassification::GeneralSubintent
+    )
+}
+
+/// The lines that describe the operations performed in a manifest of the given
+/// classification.
+fn classification_lines(
+    classification: &DetailedManifestClassification,
+) -> Vec<TransactionSummaryLine> {
+    match classification {
+        DetailedManifestClassification::ValidatorStake(
+            ValidatorStakingOutput { stake_operations },
+        ) => stake_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::Stake {
+                validator: operation.validator_address.into(),
+                staked: SummaryResource::guaranteed_fungible(
+                    XRD,
+                    operation.staked_xrd_amount,
+                ),
+                received: SummaryResource::guaranteed_fungible(
+                    operation.liquid_stake_unit_resource_address,
+                    operation.liquid_stake_unit_amount,
+                ),
+            })
+            .collect(),
+        DetailedManifestClassification::ValidatorUnstake(
+            ValidatorUnstakingOutput { unstake_operations },
+        ) => unstake_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::Unstake {
+                validator: operation.validator_address.into(),
+                unstaked: SummaryResource::guaranteed_fungible(
+                    operation.liquid_stake_unit_address,
+                    operation.liquid_stake_unit_amount,
+                ),
+                received: SummaryResource::guaranteed_non_fungible(
+                    operation.claim_nft_address,
+                    operation.claim_nfts.keys().cloned().collect(),
+                ),
+            })
+            .collect(),
+        DetailedManifestClassification::ValidatorClaimXrd(
+            ValidatorClaimingXrdOutput { claim_operations },
+        ) => claim_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::ClaimXrd {
+                validator: operation.validator_address.into(),
+                claimed: SummaryResource::guaranteed_non_fungible(
+                    operation.claim_nft_address,
+                    operation.claim_nft_ids.clone(),
+                ),
+                received: SummaryResource::guaranteed_fungible(
+                    XRD,
+                    operation.xrd_amount,
+                ),
+            })
+            .collect(),
+        DetailedManifestClassification::PoolContribution(
+            PoolContributionOutput {
+                contribution_operations,
+            },
+        ) => contribution_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::PoolContribution {
+                pool: operation.pool_address.into(),
+                contributed: operation
+                    .contributed_resources
+                    .iter()
+                    .map(|(resource_address, amount)| {
+                        SummaryResource::guaranteed_fungible(
+                            *resource_address,
+                            *amount,
+                        )
+                    })
+                    .collect(),
+                received: SummaryResource::guaranteed_fungible(
+                    operation.pool_units_resource_address,
+                    operation.pool_units_amount,
+                ),
+            })
+            .collect(),
+        DetailedManifestClassification::PoolRedemption(
+            PoolRedemptionOutput {
+                redemption_operations,
+            },
+        ) => redemption_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::PoolRedemption {
+                pool: operation.pool_address.into(),
+                redeemed: SummaryResource::guaranteed_fungible(
+                    operation.pool_units_resource_address,
+                    operation.pool_units_amount,
+                ),
+                received: operation
+                    .redeemed_resources
+                    .iter()
+                    .map(|(resource_address, amount)| {
+                        SummaryResource::guaranteed_fungible(
+                            *resource_address,
+                            *amount,
+                        )
+                    })
+                    .collect(),
+            })
+            .collect(),
+        DetailedManifestClassification::Swap(SwapOutput {
+            swap_operations,
+        }) => swap_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::Swap {
+                component: operation.component_address.into(),
+                input: operation
+                    .input_resources
+                    .items_iter()
+                    .map(SummaryResource::from)
+                    .collect(),
+                output: operation
+                    .output_resources
+                    .items_iter()
+                    .map(SummaryResource::from)
+                    .collect(),
+            })
+            .collect(),
+        DetailedManifestClassification::General => {
+            vec![TransactionSummaryLine::General {
+                is_subintent: false,
+            }]
+        }
+        DetailedManifestClassification::GeneralSubintent => {
+            vec![TransactionSummaryLine::General { is_subintent: true }]
+        }
+        DetailedManifestClassification::Transfer {
+            is_one_to_one_transfer,
+        } => vec![TransactionSummaryLine::Transfer {
+            is_one_to_one_transfer: *is_one_to_one_transfer,
+        }],
+        DetailedManifestClassification::AccountDepositSettingsUpdate(
+            AccountSettingsUpdateOutput {
+                resource_preference_updates,
+                default_deposit_rule_updates,
+                authorized_depositor_updates,
+            },
+        ) => {
+            // The updates are grouped by account so that each account that
+            // had its deposit settings updated gets a single line.
+            let accounts = resource_preference_updates
+                .keys()
+                .map(|(account, _)| *account)
+                .chain(default_deposit_rule_updates.keys().copied())
+                .chain(
+                    authorized_depositor_updates
+                        .keys()
+                        .map(|(account, _)| *account),
+                )
+                .collect::<IndexSet<_>>();
+            accounts
+                .into_iter()
+                .map(|account| {
+                    TransactionSummaryLine::AccountDepositSettingsUpdate {
+                        account,
+                        default_deposit_rule: default_deposit_rule_updates
+                            .get(&account)
+                            .copied(),
+                        resource_preference_updates:
+                            resource_preference_updates
+                                .iter()
+                                .filter(|((updated_account, _), _)| {
+                                    *updated_account == account
+                                })
+                                .map(|((_, resource_address), update)| {
+                                    (*resource_address, update.clone())
+                                })
+                                .collect(),
+                        authorized_depositor_updates:
+                            authorized_depositor_updates
+                                .iter()
+                                .filter(|((updated_account, _), _)| {
+                                    *updated_account == account
+                                })
+                                .map(|((_, badge), operation)| {
+                                    (badge.clone(), operation.clone())
+                                })
+                                .collect(),
+                    }
+                })
+                .collect()
+        }
+        DetailedManifestClassification::AccountLockerClaim(
+            AccountLockerClaimOutput { claim_operations },
+        ) => claim_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::AccountLockerClaim {
+                locker: operation.locker_address.into(),
+                claimant: operation.claimant_address.into(),
+                claimed: SummaryResource::from(&operation.claimed_resources),
+            })
+            .collect(),
+        DetailedManifestClassification::AccountLockerStore(
+            AccountLockerStoreOutput { store_operations },
+        ) => store_operations
+            .iter()
+            .map(|operation| TransactionSummaryLine::AccountLockerStore {
+                locker: operation.locker_address.into(),
+                claimant: operation.claimant_address.into(),
+                stored: SummaryResource::from(&operation.stored_resources),
+                try_direct_send: operation.try_direct_send,
+            })
+            .collect(),
+        DetailedManifestClassification::SecurityShieldUpdate(
+            SecurityShieldUpdateOutput { operations },
+        ) => operations
+            .iter()
+            .cloned()
+            .map(|operation| TransactionSummaryLine::SecurityShieldUpdate {
+                operation,
+            })
+            .collect(),
+        DetailedManifestClassification::ResourceCreation(
+            ResourceCreationOutput { created_resources },
+        ) => created_resources
+            .iter()
+            .map(
+                |CreatedResource {
+                     resource_address,
+                     definition,
+                 }| {
+                    let initial_supply = match definition {
+                        NewResourceDefinition::Fungible {
+                            initial_supply,
+                            ..
+                        } => initial_supply.map(|amount| {
+                            SummaryResource::guaranteed_fungible(
+                                *resource_address,
+                                amount,
+                            )
+                        }),
+                        NewResourceDefinition::NonFungible {
+                            initial_supply,
+                            ..
+                        } => initial_supply.clone().map(|ids| {
+                            SummaryResource::guaranteed_non_fungible(
+                                *resource_address,
+                                ids,
+                            )
+                        }),
+                    };
+                    TransactionSummaryLine::ResourceCreation {
+                        resource: (*resource_address).into(),
+                        initial_supply,
+                    }
+                },
+            )
+            .collect(),
+        DetailedManifestClassification::PackagePublish(
+            PackagePublishOutput { published_packages },
+        ) => published_packages
+            .iter()
+            .map(|package| TransactionSummaryLine::PackagePublish {
+                package: package.package_address.into(),
+                blueprints: package.blueprints.clone(),
+            })
+            .collect(),
+        DetailedManifestClassification::ValidatorManagement(
+            ValidatorManagementOutput { operations },
+        ) => operations
+            .iter()
+            .cloned()
+            .map(|operation| TransactionSummaryLine::ValidatorManagement {
+                operation,
+            })
+            .collect(),
+    }
+}
@@ -25,6 +25,7 @@ pub struct IndexedManifestValue {
     static_addresses: Vec<NodeId>,
     named_addresses: Vec<ManifestNamedAddress>,
     buckets: Vec<ManifestBucket>,
+    proofs: Vec<ManifestProof>,
     expressions: Vec<ManifestExpression>,
 }
 
@@ -41,6 +42,7 @@ impl IndexedManifestValue {
         let mut static_addresses = Vec::new();
         let mut named_addresses = Vec::new();
         let mut buckets = Vec::new();
+        let mut proofs = Vec::new();
         let mut expressions = Vec::new();
         loop {
             let event = traverser.next_event();
@@ -63,11 +65,13 @@ impl IndexedManifestValue {
                             ManifestCustomValue::Bucket(bucket) => {
                                 buckets.push(bucket)
                             }
+                            ManifestCustomValue::Proof(proof) => {
+                                proofs.push(proof)
+                            }
                             ManifestCustomValue::Expression(expression) => {
                                 expressions.push(expression)
                             }
-                            ManifestCustomValue::Proof(_)
-                            | ManifestCustomValue::Blob(_)
+                            ManifestCustomValue::Blob(_)
                             | ManifestCustomValue::Decimal(_)
                             | ManifestCustomValue::PreciseDecimal(_)
                             | ManifestCustomValue::NonFungibleLocalId(_)
@@ -90,6 +94,7 @@ impl IndexedManifestValue {
             static_addresses,
             named_addresses,
             buckets,
+            proofs,
             expressions,
             manifest_value: RefCell::new(None),
         })
@@ -163,4 +168,8 @@ impl IndexedManifestValue {
     pub fn buckets(&self) -> &Vec<ManifestBucket> {
         &self.buckets
     }
+
+    pub fn proofs(&self) -> &Vec<ManifestProof> {
+        &self.proofs
+    }
 }
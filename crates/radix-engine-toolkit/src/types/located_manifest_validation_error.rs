@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+/// A [`ManifestValidationError`] along with the index of the instruction that
+/// the interpreter was processing when the error was encountered. The index is
+/// [`None`] for errors that are not caused by a single instruction, such as the
+/// ones encountered when registering the blobs or at the end of the manifest.
+#[derive(Clone, Debug)]
+pub struct LocatedManifestValidationError {
+    pub instruction_index: Option<InstructionIndex>,
+    pub error: ManifestValidationError,
+}
+
+impl LocatedManifestValidationError {
+    /// Statically validates the manifest against the ruleset and returns the
+    /// first error encountered along with the index of the instruction that
+    /// caused it.
+    pub fn validate(
+        manifest: &impl ReadableManifest,
+        ruleset: ValidationRuleset,
+    ) -> Result<(), Self> {
+        let mut visitor = InstructionIndexVisitor::default();
+        StaticManifestInterpreter::new(ruleset, manifest)
+            .validate_and_apply_visitor(&mut visitor)
+            .map_err(|ValidationErrorOutput(error)| Self {
+                instruction_index: visitor.instruction_index,
+                error,
+            })
+    }
+
+    /// Locates an error that was reported for the manifest by the validator
+    /// of the engine, which doesn't report the index of the instruction that
+    /// caused it. The manifest is validated again against the ruleset of the
+    /// validator and the index is only reported if the same error is
+    /// encountered.
+    pub fn locate(
+        manifest: &impl ReadableManifest,
+        ruleset: ValidationRuleset,
+        error: ManifestValidationError,
+    ) -> Self {
+        let instruction_index = match Self::validate(manifest, ruleset) {
+            Err(located_error) if located_error.error == error => {
+                located_error.instruction_index
+            }
+            _ => None,
+        };
+        Self {
+            instruction_index,
+            error,
+        }
+    }
+}
+
+struct ValidationErrorOutput(ManifestValidationError);
+
+impl From<ManifestValidationError> for ValidationErrorOutput {
+    fn from(value: ManifestValidationError) -> Self {
+        Self(value)
+    }
+}
+
+/// A visitor that keeps track of the index of the instruction that the
+/// interpreter is processing.
+#[derive(Default)]
+struct InstructionIndexVisitor {
+    instruction_index: Option<InstructionIndex>,
+}
+
+impl ManifestInterpretationVisitor for InstructionIndexVisitor {
+    type Output = ValidationErrorOutput;
+
+    fn on_start_instruction(
+        &mut self,
+        OnStartInstruction { index, .. }: OnStartInstruction,
+    ) -> ControlFlow<Self::Output> {
+        self.instruction_index = Some(InstructionIndex::of(index));
+        ControlFlow::Continue(())
+    }
+
+    fn on_end_instruction(
+        &mut self,
+        _: OnEndInstruction,
+    ) -> ControlFlow<Self::Output> {
+        self.instruction_index = None;
+        ControlFlow::Continue(())
+    }
+}
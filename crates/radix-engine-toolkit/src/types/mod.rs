@@ -21,6 +21,7 @@ mod grouped_instruction;
 mod indexed_manifest_value;
 mod instruction_index;
 mod invocation_io;
+mod located_manifest_validation_error;
 mod manifest_invocation_receiver;
 mod manifest_resource_specifier;
 mod named_address_store;
@@ -28,7 +29,9 @@ mod node_id;
 mod olympia_network;
 mod operation;
 mod resolved_manifest_address;
+mod sbor_diff;
 mod sbor_string_representation;
+mod signature_verification;
 mod transaction_hash;
 mod update;
 mod worktop_changes;
@@ -39,6 +42,7 @@ pub use grouped_instruction::*;
 pub use indexed_manifest_value::*;
 pub use instruction_index::*;
 pub use invocation_io::*;
+pub use located_manifest_validation_error::*;
 pub use manifest_invocation_receiver::*;
 pub use manifest_resource_specifier::*;
 pub use named_address_store::*;
@@ -46,7 +50,9 @@ pub use node_id::*;
 pub use olympia_network::*;
 pub use operation::*;
 pub use resolved_manifest_address::*;
+pub use sbor_diff::*;
 pub use sbor_string_representation::*;
+pub use signature_verification::*;
 pub use transaction_hash::*;
 pub use update::*;
 pub use worktop_changes::*;
@@ -0,0 +1,40 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+/// A single difference between two SBOR values, the values are given in the
+/// representation chosen by the caller, typically a programmatic JSON value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SborChange<V> {
+    /// The path from the root of the compared values to the changed value.
+    pub path: Vec<SborPathElement<V>>,
+    /// The value before the change or [`None`] if the value was added.
+    pub old_value: Option<V>,
+    /// The value after the change or [`None`] if the value was removed.
+    pub new_value: Option<V>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SborPathElement<V> {
+    /// A field of a tuple or an enum variant without a name in the schema.
+    FieldIndex(usize),
+    /// A field of a tuple or an enum variant with a name in the schema.
+    FieldName(String),
+    ArrayIndex(usize),
+    MapKey(V),
+}
@@ -26,4 +26,9 @@ pub enum ScryptoSborStringRepresentation {
 pub enum ManifestSborStringRepresentation {
     ManifestString,
     JSON(SerializationMode),
+    /// The [`ProgrammaticManifestValue`] JSON representation with the static
+    /// addresses encoded for the given network.
+    ProgrammaticJson {
+        network_id: u8,
+    },
 }
@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::internal_prelude::*;
+
+/// The report of the verification of a single signature of a transaction
+/// payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureVerification {
+    /// The kind of the signature and the intent that it's on.
+    pub kind: SignatureKind,
+    /// The public key of the signer. This is [`None`] for Secp256k1 signatures
+    /// that the public key couldn't be recovered from.
+    pub signer: Option<PublicKey>,
+    /// The hash that the signature is expected to be over.
+    pub signed_hash: Hash,
+    /// Whether the signature is a valid signature by the [`signer`] over the
+    /// hash. The signer of a Secp256k1 signature is recovered from it, so any
+    /// well-formed signature over the hash is valid for the key it recovers
+    /// to. Callers must compare the [`signer`] against the signers that they
+    /// expect to learn whether the expected party signed.
+    ///
+    /// [`signer`]: SignatureVerification::signer
+    pub is_valid_for_signer: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureKind {
+    /// A signature over the hash of the transaction intent.
+    TransactionIntent,
+    /// A signature over the hash of the root subintent of a partial
+    /// transaction.
+    RootSubintent,
+    /// A signature over the hash of the non-root subintent at the index.
+    NonRootSubintent { index: usize },
+    /// The notary signature over the hash of the signed transaction intent.
+    Notary,
+}
+
+impl SignatureVerification {
+    /// Verifies a signature over an intent. The signer of Secp256k1 signatures
+    /// is recovered from the signature and the hash, and thus the signature is
+    /// valid for the recovered signer if it can be recovered.
+    pub fn of_intent_signature(
+        kind: SignatureKind,
+        signed_hash: Hash,
+        signature: &SignatureWithPublicKeyV1,
+    ) -> Self {
+        let (signer, is_valid_for_signer) = match signature {
+            SignatureWithPublicKeyV1::Secp256k1 { signature } => {
+                let signer =
+                    verify_and_recover_secp256k1(&signed_hash, signature);
+                (signer.map(PublicKey::Secp256k1), signer.is_some())
+            }
+            SignatureWithPublicKeyV1::Ed25519 {
+                public_key,
+                signature,
+            } => (
+                Some(PublicKey::Ed25519(*public_key)),
+                verify_ed25519(signed_hash, public_key, signature),
+            ),
+        };
+        Self {
+            kind,
+            signer,
+            signed_hash,
+            is_valid_for_signer,
+        }
+    }
+
+    /// Verifies the notary signature against the notary public key of the
+    /// transaction header.
+    pub fn of_notary_signature(
+        signed_hash: Hash,
+        notary_public_key: PublicKey,
+        signature: &SignatureV1,
+    ) -> Self {
+        Self {
+            kind: SignatureKind::Notary,
+            signer: Some(notary_public_key),
+            signed_hash,
+            is_valid_for_signer: match (notary_public_key, signature) {
+                (
+                    PublicKey::Secp256k1(public_key),
+                    SignatureV1::Secp256k1(signature),
+                ) => verify_secp256k1(&signed_hash, &public_key, signature),
+                (
+                    PublicKey::Ed25519(public_key),
+                    SignatureV1::Ed25519(signature),
+                ) => verify_ed25519(signed_hash, &public_key, signature),
+                _ => false,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureVerificationError {
+    PrepareError(PrepareError),
+    /// The number of lists of non-root subintent signatures doesn't match the
+    /// number of non-root subintents.
+    IncorrectNumberOfSubintentSignatureBatches {
+        non_root_subintents: usize,
+        signature_batches: usize,
+    },
+}
+
+impl From<PrepareError> for SignatureVerificationError {
+    fn from(value: PrepareError) -> Self {
+        Self::PrepareError(value)
+    }
+}
@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[test]
+fn declarative_classifier_definition_produces_a_registered_classification() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account1(), None)
+        .build();
+    let definition = faucet_classifier_definition();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry
+        .register_classifier_definition(
+            &definition,
+            &NetworkDefinition::simulator(),
+        )
+        .unwrap();
+
+    // Act
+    let StaticAnalysis {
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(&manifest, &registry).unwrap();
+
+    // Assert
+    let [registered_classification] = registered_classifications.as_slice()
+    else {
+        panic!("Expected a single registered classification")
+    };
+    assert_eq!(registered_classification.name, "faucet");
+    let output = registered_classification
+        .static_output::<DeclarativeClassifierOutput>()
+        .unwrap();
+    assert_eq!(
+        output.fields.get("faucet_invocations"),
+        Some(&DeclarativeOutputValue::Count(1))
+    );
+    assert_eq!(
+        output.fields.get("deposited_into"),
+        Some(&DeclarativeOutputValue::Addresses(vec![
+            ManifestGlobalAddress::Static(account1().into())
+        ]))
+    );
+}
+
+#[test]
+fn declarative_classifier_definition_with_disallowed_instructions_produces_no_classification(
+) {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .withdraw_from_account(account1(), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account2(), None)
+        .build();
+    let definition = faucet_classifier_definition();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry
+        .register_classifier_definition(
+            &definition,
+            &NetworkDefinition::simulator(),
+        )
+        .unwrap();
+
+    // Act
+    let StaticAnalysis {
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(&manifest, &registry).unwrap();
+
+    // Assert
+    assert!(registered_classifications.is_empty());
+}
+
+#[test]
+fn declarative_classifier_definition_with_addresses_of_another_network_fails_to_compile(
+) {
+    // Arrange
+    let definition = faucet_classifier_definition();
+
+    // Act
+    let result = definition.compile(&NetworkDefinition::mainnet());
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ClassifierDefinitionError::InvalidAddress(..))
+    ));
+}
+
+#[test]
+fn declarative_classifier_definition_with_blueprints_only_receiver_fails_to_compile(
+) {
+    // Arrange
+    let faucet_package_address = AddressBech32Encoder::for_simulator()
+        .encode(FAUCET_PACKAGE.as_node_id().as_bytes())
+        .unwrap();
+    let definition =
+        serde_json::from_value::<ClassifierDefinition>(serde_json::json!({
+            "name": "faucet",
+            "required_invocations": [
+                {
+                    "kind": "method",
+                    "blueprints": [
+                        {
+                            "package_address": faucet_package_address,
+                            "blueprint_name": FAUCET_BLUEPRINT
+                        }
+                    ],
+                    "methods": ["free"]
+                }
+            ]
+        }))
+        .unwrap();
+
+    // Act
+    let result = definition.compile(&NetworkDefinition::simulator());
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ClassifierDefinitionError::BlueprintsOnlyReceiverRule)
+    ));
+}
+
+#[test]
+fn declarative_classifier_blueprints_only_match_named_receivers() {
+    // Arrange
+    let encoder = AddressBech32Encoder::for_simulator();
+    let faucet_address =
+        encoder.encode(FAUCET.as_node_id().as_bytes()).unwrap();
+    let account_package_address = encoder
+        .encode(ACCOUNT_PACKAGE.as_node_id().as_bytes())
+        .unwrap();
+    let definition =
+        serde_json::from_value::<ClassifierDefinition>(serde_json::json!({
+            "name": "faucet",
+            "permitted_invocations": [
+                {
+                    "kind": "method",
+                    "entity_groups": ["AccountEntities"],
+                    "methods": ["try_deposit_batch_or_abort", "lock_fee"]
+                }
+            ],
+            "permitted_instructions": [
+                "TakeFromWorktopInstructions",
+                "AddressAllocationInstructions"
+            ],
+            "required_invocations": [
+                {
+                    "kind": "method",
+                    "addresses": [faucet_address],
+                    "methods": ["free"]
+                }
+            ],
+            "outputs": [
+                {
+                    "name": "matched_invocations",
+                    "invocation": {
+                        "kind": "method",
+                        "addresses": [faucet_address],
+                        "blueprints": [
+                            {
+                                "package_address": account_package_address,
+                                "blueprint_name": ACCOUNT_BLUEPRINT
+                            }
+                        ]
+                    },
+                    "extract": "count"
+                }
+            ]
+        }))
+        .unwrap();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry
+        .register_classifier_definition(
+            &definition,
+            &NetworkDefinition::simulator(),
+        )
+        .unwrap();
+
+    // The static account is an account but it's not matched by the account
+    // blueprint since the blueprint of a static address isn't known. The
+    // named account is matched since its blueprint is known from allocation.
+    let manifest = ManifestBuilder::new()
+        .allocate_global_address(
+            ACCOUNT_PACKAGE,
+            ACCOUNT_BLUEPRINT,
+            "reservation",
+            "account",
+        )
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account1(), None)
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                namer.named_address("account"),
+                ACCOUNT_LOCK_FEE_IDENT,
+                AccountLockFeeManifestInput { amount: dec!(1) },
+            )
+        })
+        .build_no_validate();
+
+    // Act
+    let StaticAnalysis {
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(&manifest, &registry).unwrap();
+
+    // Assert
+    let [registered_classification] = registered_classifications.as_slice()
+    else {
+        panic!("Expected a single registered classification")
+    };
+    let output = registered_classification
+        .static_output::<DeclarativeClassifierOutput>()
+        .unwrap();
+    assert_eq!(
+        output.fields.get("matched_invocations"),
+        Some(&DeclarativeOutputValue::Count(2))
+    );
+}
+
+fn faucet_classifier_definition() -> ClassifierDefinition {
+    let faucet_address = AddressBech32Encoder::for_simulator()
+        .encode(FAUCET.as_node_id().as_bytes())
+        .unwrap();
+    serde_json::from_value(serde_json::json!({
+        "name": "faucet",
+        "permitted_invocations": [
+            {
+                "kind": "method",
+                "entity_groups": ["AccountEntities"],
+                "methods": ["try_deposit_batch_or_abort"]
+            }
+        ],
+        "permitted_instructions": ["TakeFromWorktopInstructions"],
+        "required_invocations": [
+            {
+                "kind": "method",
+                "addresses": [faucet_address],
+                "methods": ["free", "lock_fee"]
+            }
+        ],
+        "outputs": [
+            {
+                "name": "faucet_invocations",
+                "invocation": {
+                    "kind": "method",
+                    "addresses": [faucet_address]
+                },
+                "extract": "count"
+            },
+            {
+                "name": "deposited_into",
+                "invocation": {
+                    "kind": "method",
+                    "entity_groups": ["AccountEntities"]
+                },
+                "extract": "addresses"
+            }
+        ]
+    }))
+    .unwrap()
+}
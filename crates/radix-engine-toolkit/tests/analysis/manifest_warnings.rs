@@ -0,0 +1,309 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[test]
+fn transfer_to_a_third_party_account_produces_a_third_party_deposit_warning() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1(), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account2(), None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert_eq!(
+        warnings,
+        vec![ManifestWarning::DepositIntoThirdPartyAccount {
+            account: ManifestGlobalAddress::Static(account2().into())
+        }]
+    );
+}
+
+#[test]
+fn transfer_between_accounts_of_the_signer_produces_no_warnings() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1(), XRD, 10)
+        .deposit_entire_worktop(account2())
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn burn_from_account_produces_a_burn_warning() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .burn_in_account(account1(), XRD, 10)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert_eq!(
+        warnings,
+        vec![ManifestWarning::ResourcesBurnedFromAccount {
+            account: ManifestGlobalAddress::Static(account1().into())
+        }]
+    );
+}
+
+#[test]
+fn withdraw_without_deposit_produces_a_withdrawn_not_deposited_warning() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1(), XRD, 10)
+        .burn_all_from_worktop(XRD)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert_eq!(
+        warnings,
+        vec![
+            ManifestWarning::WithdrawnResourcesNotDepositedIntoAccounts {
+                accounts_withdrawn_from: indexset![
+                    ManifestGlobalAddress::Static(account1().into())
+                ]
+            }
+        ]
+    );
+}
+
+#[test]
+fn owner_role_and_metadata_changes_produce_warnings() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let component = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalGenericComponent);
+    let manifest = ManifestBuilder::new()
+        .set_metadata(component, "name", "not important")
+        .lock_owner_role(component)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert_eq!(
+        warnings,
+        vec![
+            ManifestWarning::MetadataUpdated {
+                entity: ManifestGlobalAddress::Static(component.into()),
+                key: "name".to_owned()
+            },
+            ManifestWarning::OwnerRoleLocked {
+                entity: ManifestGlobalAddress::Static(component.into())
+            }
+        ]
+    );
+}
+
+#[test]
+fn account_securify_produces_an_entity_securified_warning() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(account1(), ACCOUNT_SECURIFY_IDENT, ())
+        .try_deposit_entire_worktop_or_abort(account1(), None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(warnings.contains(&ManifestWarning::EntitySecurified {
+        entity: ManifestGlobalAddress::Static(account1().into())
+    }));
+}
+
+#[test]
+fn recall_from_a_vault_produces_a_direct_vault_method_warning() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let vault = allocator
+        .next_address::<InternalAddress>(EntityType::InternalFungibleVault);
+    let manifest = ManifestBuilder::new()
+        .recall(vault, 10)
+        .try_deposit_entire_worktop_or_abort(account1(), None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(
+        warnings.contains(&ManifestWarning::DirectVaultMethodInvoked {
+            vault_address: vault,
+            method_name: VAULT_RECALL_IDENT.to_owned()
+        })
+    );
+}
+
+#[test]
+fn sending_withdrawn_resources_to_a_component_produces_a_withdrawn_not_deposited_warning(
+) {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let component = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalGenericComponent);
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1(), XRD, 10)
+        .take_from_worktop(XRD, 5, "bucket")
+        .call_method_with_name_lookup(component, "donate", |lookup| {
+            (lookup.bucket("bucket"),)
+        })
+        .deposit_entire_worktop(account1())
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert_eq!(
+        warnings,
+        vec![
+            ManifestWarning::WithdrawnResourcesNotDepositedIntoAccounts {
+                accounts_withdrawn_from: indexset![
+                    ManifestGlobalAddress::Static(account1().into())
+                ]
+            }
+        ]
+    );
+}
+
+#[test]
+fn staking_withdrawn_resources_produces_no_warnings() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let validator =
+        allocator.next_address::<ComponentAddress>(EntityType::GlobalValidator);
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1(), XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .stake_validator(validator, "bucket")
+        .deposit_entire_worktop(account1())
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn locking_and_updating_roles_produce_warnings() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let resource = allocator.next_address::<ResourceAddress>(
+        EntityType::GlobalFungibleResourceManager,
+    );
+    let manifest = ManifestBuilder::new()
+        .set_main_role(resource, MINTER_UPDATER_ROLE, rule!(deny_all))
+        .set_main_role(resource, MINTER_ROLE, rule!(allow_all))
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert_eq!(
+        warnings,
+        vec![
+            ManifestWarning::RoleLocked {
+                entity: ManifestGlobalAddress::Static(resource.into()),
+                role_key: MINTER_UPDATER_ROLE.to_owned()
+            },
+            ManifestWarning::RoleUpdated {
+                entity: ManifestGlobalAddress::Static(resource.into())
+            }
+        ]
+    );
+}
+
+#[test]
+fn removing_metadata_produces_a_metadata_removed_warning() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let component = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalGenericComponent);
+    let manifest = ManifestBuilder::new()
+        .call_metadata_method(
+            component,
+            METADATA_REMOVE_IDENT,
+            MetadataRemoveInput {
+                key: "name".to_owned(),
+            },
+        )
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_warnings_summary: ManifestWarningsOutput { warnings },
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert_eq!(
+        warnings,
+        vec![ManifestWarning::MetadataRemoved {
+            entity: ManifestGlobalAddress::Static(component.into()),
+            key: "name".to_owned()
+        }]
+    );
+}
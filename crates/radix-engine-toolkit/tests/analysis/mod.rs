@@ -17,10 +17,14 @@
 
 mod account_interactions;
 mod classification;
+mod declarative_classification;
 mod dynamic_resource_movements;
 mod encountered_entities;
+mod manifest_warnings;
 mod non_fungible_dynamic_analysis;
 mod presented_proofs;
+mod registry;
 mod requiring_auth;
 mod reserved_instructions;
+mod transaction_summary;
 mod troublesome_manifests;
@@ -0,0 +1,206 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine_toolkit_common::receipt::RuntimeToolkitTransactionReceipt;
+use radix_transactions::manifest::CallMethod;
+
+#[test]
+fn registered_analyzer_produces_a_registered_classification() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account1(), None)
+        .build();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry.register_static_analyzer::<FaucetAnalyzer>("faucet", ());
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(&manifest, &registry).unwrap();
+
+    // Assert
+    assert_eq!(
+        manifest_classification,
+        statically_analyze(&manifest)
+            .unwrap()
+            .manifest_classification
+    );
+    let [registered_classification] = registered_classifications.as_slice()
+    else {
+        panic!("Expected a single registered classification")
+    };
+    assert_eq!(registered_classification.name, "faucet");
+    assert_eq!(
+        registered_classification.static_output::<FaucetOutput>(),
+        Some(&FaucetOutput {
+            faucet_invocations: 1
+        })
+    );
+}
+
+#[test]
+fn registered_analyzer_produces_a_registered_detailed_classification() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let manifest = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry.register_static_analyzer::<FaucetAnalyzer>("faucet", ());
+
+    // Act
+    let receipt = LedgerSimulatorEDExt::preview(&mut ledger, manifest.clone());
+    let DynamicAnalysis {
+        registered_detailed_classifications,
+        ..
+    } = dynamically_analyze_with_registry(
+        &manifest,
+        RuntimeToolkitTransactionReceipt::try_from(receipt).unwrap(),
+        &registry,
+    )
+    .unwrap();
+
+    // Assert
+    let [registered_classification] =
+        registered_detailed_classifications.as_slice()
+    else {
+        panic!("Expected a single registered detailed classification")
+    };
+    assert_eq!(registered_classification.name, "faucet");
+    assert_eq!(
+        registered_classification.static_output::<FaucetOutput>(),
+        Some(&FaucetOutput {
+            faucet_invocations: 1
+        })
+    );
+    assert_eq!(registered_classification.dynamic_output::<()>(), Some(&()));
+}
+
+#[test]
+fn registered_analyzer_with_disallowed_instructions_produces_no_classification()
+{
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .withdraw_from_account(account1(), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account2(), None)
+        .build();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry.register_static_analyzer::<FaucetAnalyzer>("faucet", ());
+
+    // Act
+    let StaticAnalysis {
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(&manifest, &registry).unwrap();
+
+    // Assert
+    assert!(registered_classifications.is_empty());
+}
+
+/// A third-party analyzer that classifies manifests that get XRD from the
+/// faucet and deposit it into accounts.
+#[derive(Default)]
+struct FaucetAnalyzer(FaucetOutput);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct FaucetOutput {
+    faucet_invocations: usize,
+}
+
+#[derive(Default)]
+struct FaucetRequirementState {
+    is_faucet_invoked: bool,
+}
+
+impl ManifestStaticAnalyzer for FaucetAnalyzer {
+    type Initializer = ();
+    type Output = FaucetOutput;
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = FaucetRequirementState;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Default::default(),
+            CallbackPermissionState::new(is_instruction_permitted),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {
+        self.0
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        if is_faucet_invocation(context) {
+            self.0.faucet_invocations += 1;
+        }
+    }
+}
+
+impl ManifestAnalyzerRequirementState for FaucetRequirementState {
+    fn requirement_state(&self) -> RequirementState {
+        match self.is_faucet_invoked {
+            true => RequirementState::Fulfilled,
+            false => RequirementState::CurrentlyUnfulfilled,
+        }
+    }
+
+    fn process_instruction(&mut self, context: InstructionContext<'_>) {
+        self.is_faucet_invoked |= is_faucet_invocation(context)
+    }
+}
+
+fn is_faucet_invocation(context: InstructionContext<'_>) -> bool {
+    matches!(
+        context.instruction(),
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address: ManifestGlobalAddress::Static(address),
+                ..
+            }),
+        ) if *address == GlobalAddress::from(FAUCET)
+    )
+}
+
+fn is_instruction_permitted(context: InstructionContext<'_>) -> bool {
+    match context.instruction() {
+        GroupedInstruction::InvocationInstructions(
+            InvocationInstructions::CallMethod(CallMethod {
+                address: ManifestGlobalAddress::Static(address),
+                method_name,
+                ..
+            }),
+        ) => {
+            *address == GlobalAddress::from(FAUCET)
+                || (address.as_node_id().is_global_account()
+                    && method_name == ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT)
+        }
+        GroupedInstruction::InvocationInstructions(..) => false,
+        _ => true,
+    }
+}
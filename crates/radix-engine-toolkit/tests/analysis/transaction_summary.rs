@@ -0,0 +1,570 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine_toolkit_common::receipt::RuntimeToolkitTransactionReceipt;
+
+#[test]
+fn validator_stake_summary_contains_withdraw_stake_deposit_and_fee_lines() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (pk, _, account) = ledger.new_account(true);
+    let (validator, lsu, _) = ledger.new_validator(pk, account);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .stake_validator(validator, "bucket")
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    let [TransactionSummaryLine::Withdraw {
+        account: withdrawn_from,
+        resources: withdrawn,
+    }, TransactionSummaryLine::Stake {
+        validator: staked_to,
+        staked,
+        received,
+    }, TransactionSummaryLine::Deposit {
+        account: deposited_into,
+        ..
+    }, TransactionSummaryLine::Fee { .. }] = lines.as_slice()
+    else {
+        panic!("Unexpected summary lines: {lines:?}")
+    };
+    assert_eq!(withdrawn_from.kind, SummaryEntityKind::Account);
+    assert_eq!(withdrawn_from.address, GlobalAddress::from(account));
+    assert_eq!(
+        withdrawn.as_slice(),
+        &[SummaryResource::guaranteed_fungible(XRD, 10.into())]
+    );
+    assert_eq!(staked_to.kind, SummaryEntityKind::Validator);
+    assert_eq!(staked_to.address, GlobalAddress::from(validator));
+    assert_eq!(
+        staked,
+        &SummaryResource::guaranteed_fungible(XRD, 10.into())
+    );
+    assert_eq!(
+        received,
+        &SummaryResource::guaranteed_fungible(lsu, 10.into())
+    );
+    assert_eq!(deposited_into.address, GlobalAddress::from(account));
+}
+
+#[test]
+fn general_summary_contains_a_general_line() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(lines.contains(&TransactionSummaryLine::General {
+        is_subintent: false
+    }));
+}
+
+#[test]
+fn general_subintent_summary_contains_a_general_subintent_line() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let manifest = ManifestBuilder::new_subintent_v2()
+        .yield_to_parent(())
+        .build();
+    let receipt = LedgerSimulatorEDExt::preview(
+        &mut ledger,
+        ManifestBuilder::new().lock_fee_from_faucet().build(),
+    );
+    let dynamic_analysis = dynamically_analyze(
+        &manifest,
+        RuntimeToolkitTransactionReceipt::try_from(receipt).unwrap(),
+    )
+    .unwrap();
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(
+        lines.contains(&TransactionSummaryLine::General { is_subintent: true })
+    );
+}
+
+#[test]
+fn transfer_summary_contains_a_transfer_line() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account1) = ledger.new_account(false);
+    let (_, _, account2) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .try_deposit_or_abort(account2, None, "bucket")
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(lines.contains(&TransactionSummaryLine::Transfer {
+        is_one_to_one_transfer: true
+    }));
+    assert!(!lines.contains(&TransactionSummaryLine::General {
+        is_subintent: false
+    }));
+}
+
+#[test]
+fn account_deposit_settings_update_summary_contains_a_line_per_account() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(true);
+
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            account,
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountSetDefaultDepositRuleManifestInput {
+                default: DefaultDepositRule::Reject,
+            },
+        )
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(lines.contains(
+        &TransactionSummaryLine::AccountDepositSettingsUpdate {
+            account: ManifestGlobalAddress::Static(account.into()),
+            default_deposit_rule: Some(DefaultDepositRule::Reject),
+            resource_preference_updates: Default::default(),
+            authorized_depositor_updates: Default::default(),
+        }
+    ));
+}
+
+#[test]
+fn account_locker_store_summary_contains_a_store_line() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let locker = ledger.new_account_locker(OwnerRole::None);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                locker,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                AccountLockerStoreManifestInput {
+                    claimant: account.into(),
+                    bucket: namer.bucket("bucket"),
+                    try_direct_send: false,
+                },
+            )
+        })
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(lines.contains(&TransactionSummaryLine::AccountLockerStore {
+        locker: locker.into(),
+        claimant: account.into(),
+        stored: SummaryResource::guaranteed_fungible(XRD, 10.into()),
+        try_direct_send: false,
+    }));
+}
+
+#[test]
+fn account_locker_claim_summary_contains_a_claim_line() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let locker = ledger.new_account_locker(OwnerRole::None);
+    ledger
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .get_free_xrd_from_faucet()
+                .take_all_from_worktop(XRD, "bucket")
+                .with_name_lookup(|builder, namer| {
+                    builder.call_method(
+                        locker,
+                        ACCOUNT_LOCKER_STORE_IDENT,
+                        AccountLockerStoreManifestInput {
+                            claimant: account.into(),
+                            bucket: namer.bucket("bucket"),
+                            try_direct_send: false,
+                        },
+                    )
+                })
+                .build(),
+            [],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            locker,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            AccountLockerClaimManifestInput {
+                claimant: account.into(),
+                resource_address: XRD.into(),
+                amount: dec!(10),
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(lines.contains(&TransactionSummaryLine::AccountLockerClaim {
+        locker: locker.into(),
+        claimant: account.into(),
+        claimed: SummaryResource::guaranteed_fungible(XRD, 10.into()),
+    }));
+}
+
+#[test]
+fn security_shield_update_summary_contains_a_line_per_operation() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(true);
+    let rule_set = RuleSet {
+        primary_role: rule!(allow_all),
+        recovery_role: rule!(allow_all),
+        confirmation_role: rule!(deny_all),
+    };
+
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            account,
+            ACCOUNT_SECURIFY_IDENT,
+            AccountSecurifyManifestInput {},
+        )
+        .take_all_from_worktop(ACCOUNT_OWNER_BADGE, "bucket")
+        .then(|builder| {
+            let bucket = builder.bucket("bucket");
+            builder.call_function(
+                ACCESS_CONTROLLER_PACKAGE,
+                ACCESS_CONTROLLER_BLUEPRINT,
+                ACCESS_CONTROLLER_CREATE_IDENT,
+                AccessControllerCreateManifestInput {
+                    controlled_asset: bucket,
+                    rule_set: rule_set.clone().into(),
+                    timed_recovery_delay_in_minutes: Some(100),
+                    address_reservation: None,
+                },
+            )
+        })
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(
+        lines.contains(&TransactionSummaryLine::SecurityShieldUpdate {
+            operation: SecurityShieldUpdateOperation::CreateAccessController {
+                rule_set: rule_set.into(),
+                timed_recovery_delay_in_minutes: Some(100),
+            }
+        })
+    );
+}
+
+#[test]
+fn resource_creation_summary_contains_the_initial_supply() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            6,
+            FungibleResourceRoles::default(),
+            metadata! {},
+            Some(dec!(1000)),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    let Some(TransactionSummaryLine::ResourceCreation {
+        resource,
+        initial_supply: Some(initial_supply),
+    }) = lines.iter().find(|line| {
+        matches!(line, TransactionSummaryLine::ResourceCreation { .. })
+    })
+    else {
+        panic!("Unexpected summary lines: {lines:?}")
+    };
+    assert_eq!(resource.kind, SummaryEntityKind::Resource);
+    assert_eq!(
+        initial_supply,
+        &SummaryResource::guaranteed_fungible(
+            ResourceAddress::try_from(resource.address).unwrap(),
+            dec!(1000)
+        )
+    );
+}
+
+#[test]
+fn package_publish_summary_contains_the_published_blueprints() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let (code, definition) = package_code_and_definition();
+
+    let manifest = ManifestBuilder::new()
+        .publish_package(code, definition.clone())
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    let Some(TransactionSummaryLine::PackagePublish {
+        package,
+        blueprints,
+    }) = lines.iter().find(|line| {
+        matches!(line, TransactionSummaryLine::PackagePublish { .. })
+    })
+    else {
+        panic!("Unexpected summary lines: {lines:?}")
+    };
+    assert_eq!(package.kind, SummaryEntityKind::Package);
+    assert_eq!(
+        blueprints,
+        &definition
+            .blueprints
+            .keys()
+            .cloned()
+            .collect::<IndexSet<_>>()
+    );
+}
+
+#[test]
+fn validator_management_summary_contains_a_line_per_operation() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (pk, _, account) = ledger.new_account(true);
+    let (validator, ..) = ledger.new_validator(pk, account);
+
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator.as_node_id().0).unwrap()],
+        )
+        .call_method(
+            validator,
+            VALIDATOR_UPDATE_FEE_IDENT,
+            ValidatorUpdateFeeInput {
+                new_fee_factor: dec!("0.05"),
+            },
+        )
+        .call_method(validator, VALIDATOR_REGISTER_IDENT, ())
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert!(
+        lines.contains(&TransactionSummaryLine::ValidatorManagement {
+            operation: ValidatorManagementOperation::UpdateFee {
+                validator_address: validator,
+                new_fee_factor: dec!("0.05"),
+            }
+        })
+    );
+    assert!(
+        lines.contains(&TransactionSummaryLine::ValidatorManagement {
+            operation: ValidatorManagementOperation::Register {
+                validator_address: validator,
+            }
+        })
+    );
+}
+
+#[test]
+fn manifest_warnings_are_the_first_lines_of_the_summary() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .burn_all_from_worktop(XRD)
+        .build();
+    let (_, dynamic_analysis) = ledger.analyze(manifest);
+
+    // Act
+    let TransactionSummary { lines } =
+        TransactionSummary::from_dynamic_analysis(&dynamic_analysis);
+
+    // Assert
+    assert_eq!(
+        lines.first(),
+        Some(&TransactionSummaryLine::Warning {
+            warning:
+                ManifestWarning::WithdrawnResourcesNotDepositedIntoAccounts {
+                    accounts_withdrawn_from: indexset![
+                        ManifestGlobalAddress::Static(account.into())
+                    ]
+                }
+        })
+    );
+}
+
+#[test]
+fn english_renderer_renders_summary_lines() {
+    // Arrange
+    let network_definition = NetworkDefinition::simulator();
+    let encoder = AddressBech32Encoder::new(&network_definition);
+    let renderer = EnglishTransactionSummaryRenderer::new(&network_definition);
+    let summary = TransactionSummary {
+        lines: vec![
+            TransactionSummaryLine::Withdraw {
+                account: account1().into(),
+                resources: vec![SummaryResource::guaranteed_fungible(
+                    XRD,
+                    10.into(),
+                )],
+            },
+            TransactionSummaryLine::ValidatorManagement {
+                operation: ValidatorManagementOperation::Register {
+                    validator_address: validator_address(),
+                },
+            },
+            TransactionSummaryLine::Fee {
+                amount: dec!("0.25"),
+            },
+        ],
+    };
+
+    // Act
+    let rendered = summary.render(&renderer);
+
+    // Assert
+    assert_eq!(
+        rendered,
+        vec![
+            format!(
+                "Withdraw 10 XRD from {}",
+                encoder.encode(account1().as_node_id().as_bytes()).unwrap()
+            ),
+            format!(
+                "Register {}",
+                encoder
+                    .encode(validator_address().as_node_id().as_bytes())
+                    .unwrap()
+            ),
+            "Fee: 0.25 XRD".to_owned()
+        ]
+    );
+}
+
+fn validator_address() -> ComponentAddress {
+    ComponentAddress::new_or_panic([EntityType::GlobalValidator as u8; 30])
+}
+
+/// The code and definition of a package with a single `Test` blueprint whose
+/// `f` function returns a unit.
+fn package_code_and_definition() -> (Vec<u8>, PackageDefinition) {
+    (
+        wat2wasm(PACKAGE_WAT),
+        single_function_package_definition("Test", "f"),
+    )
+}
+
+const PACKAGE_WAT: &str = r#"
+(module
+    (func $Test_f (param $0 i64) (result i64)
+        ;; Encode () in SBOR at address 0x0
+        (i32.store8 (i32.const 0) (i32.const 92))
+        (i32.store8 (i32.const 1) (i32.const 33))
+        (i32.store8 (i32.const 2) (i32.const 0))
+
+        ;; Return slice (ptr = 0, len = 3)
+        (i64.const 3)
+    )
+
+    (memory $0 1)
+    (export "memory" (memory $0))
+    (export "Test_f" (func $Test_f))
+)
+"#;
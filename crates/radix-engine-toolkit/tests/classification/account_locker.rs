@@ -0,0 +1,429 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
+use radix_engine_interface::blueprints::locker::ResourceSpecifier as LockerResourceSpecifier;
+use radix_engine_toolkit::prelude::{
+    AccountLockerClaimOutput, AccountLockerStoreOutput,
+};
+
+#[test]
+fn storing_resources_in_locker_classifies_as_account_locker_store() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let locker = ledger.new_account_locker(OwnerRole::None);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                locker,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                AccountLockerStoreManifestInput {
+                    claimant: account.into(),
+                    bucket: namer.bucket("bucket"),
+                    try_direct_send: false,
+                },
+            )
+        })
+        .build();
+
+    // Act
+    let (
+        StaticAnalysis {
+            manifest_classification,
+            ..
+        },
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::AccountLockerStore));
+    let Some(DetailedManifestClassification::AccountLockerStore(
+        AccountLockerStoreOutput { store_operations },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::AccountLockerStore(..)
+            )
+        })
+    else {
+        panic!("Not an account locker store transaction")
+    };
+    assert_eq!(
+        store_operations.as_slice(),
+        [AccountLockerStoreOperation {
+            locker_address: locker,
+            claimant_address: account,
+            stored_resources: ResourceSpecifier::Amount(XRD, dec!(10)),
+            try_direct_send: false,
+        }]
+    );
+}
+
+#[test]
+fn airdropping_resources_produces_an_operation_per_claimant() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let locker = ledger.new_account_locker(OwnerRole::None);
+    let (_, _, claimant1) = ledger.new_account(false);
+    let (_, _, claimant2) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                locker,
+                ACCOUNT_LOCKER_AIRDROP_IDENT,
+                AccountLockerAirdropManifestInput {
+                    claimants: indexmap! {
+                        claimant1.into() => LockerResourceSpecifier::Fungible(dec!(4)),
+                        claimant2.into() => LockerResourceSpecifier::Fungible(dec!(6)),
+                    },
+                    bucket: namer.bucket("bucket"),
+                    try_direct_send: false,
+                },
+            )
+        })
+        .build();
+
+    // Act
+    let (
+        _,
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    let Some(DetailedManifestClassification::AccountLockerStore(
+        AccountLockerStoreOutput { store_operations },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::AccountLockerStore(..)
+            )
+        })
+    else {
+        panic!("Not an account locker store transaction")
+    };
+    assert_eq!(
+        store_operations.as_slice(),
+        [
+            AccountLockerStoreOperation {
+                locker_address: locker,
+                claimant_address: claimant1,
+                stored_resources: ResourceSpecifier::Amount(XRD, dec!(4)),
+                try_direct_send: false,
+            },
+            AccountLockerStoreOperation {
+                locker_address: locker,
+                claimant_address: claimant2,
+                stored_resources: ResourceSpecifier::Amount(XRD, dec!(6)),
+                try_direct_send: false,
+            }
+        ]
+    );
+}
+
+#[test]
+fn claiming_from_locker_classifies_as_account_locker_claim() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let locker = ledger.new_account_locker(OwnerRole::None);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_all_from_worktop(XRD, "bucket")
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                locker,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                AccountLockerStoreManifestInput {
+                    claimant: account.into(),
+                    bucket: namer.bucket("bucket"),
+                    try_direct_send: false,
+                },
+            )
+        })
+        .build();
+    ledger
+        .execute_manifest(manifest, [])
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            locker,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            AccountLockerClaimManifestInput {
+                claimant: account.into(),
+                resource_address: XRD.into(),
+                amount: dec!(10),
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let (
+        StaticAnalysis {
+            manifest_classification,
+            ..
+        },
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::AccountLockerClaim));
+    assert!(detailed_manifest_classification.contains(
+        &DetailedManifestClassification::AccountLockerClaim(
+            AccountLockerClaimOutput {
+                claim_operations: vec![AccountLockerClaimOperation {
+                    locker_address: locker,
+                    claimant_address: account,
+                    claimed_resources: ResourceSpecifier::Amount(XRD, dec!(10)),
+                }]
+            }
+        )
+    ));
+}
+
+#[test]
+fn claiming_from_locker_and_calling_a_component_is_not_a_claim() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let account = allocator.new_account_address();
+    let locker = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalAccountLocker);
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            locker,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            AccountLockerClaimManifestInput {
+                claimant: account.into(),
+                resource_address: XRD.into(),
+                amount: dec!(10),
+            },
+        )
+        .call_method(FAUCET, "free", ())
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(!manifest_classification
+        .contains(&ManifestClassification::AccountLockerClaim));
+}
+
+#[test]
+fn claiming_for_a_named_claimant_is_not_a_claim() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let account = allocator.new_account_address();
+    let locker = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalAccountLocker);
+    let manifest = ManifestBuilder::new()
+        .allocate_global_address(
+            ACCOUNT_PACKAGE,
+            ACCOUNT_BLUEPRINT,
+            "reservation",
+            "claimant",
+        )
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                locker,
+                ACCOUNT_LOCKER_CLAIM_IDENT,
+                AccountLockerClaimManifestInput {
+                    claimant: ManifestComponentAddress::Named(
+                        namer.named_address("claimant"),
+                    )
+                    .into(),
+                    resource_address: XRD.into(),
+                    amount: dec!(10),
+                },
+            )
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build_no_validate();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(
+        &manifest,
+        &static_addresses_requirement_registry(),
+    )
+    .unwrap();
+
+    // Assert
+    assert!(!manifest_classification
+        .contains(&ManifestClassification::AccountLockerClaim));
+    assert!(registered_classifications.is_empty());
+}
+
+#[test]
+fn storing_for_a_named_claimant_is_not_a_store() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let account = allocator.new_account_address();
+    let locker = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalAccountLocker);
+    let manifest = ManifestBuilder::new()
+        .allocate_global_address(
+            ACCOUNT_PACKAGE,
+            ACCOUNT_BLUEPRINT,
+            "reservation",
+            "claimant",
+        )
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                locker,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                AccountLockerStoreManifestInput {
+                    claimant: ManifestComponentAddress::Named(
+                        namer.named_address("claimant"),
+                    )
+                    .into(),
+                    bucket: namer.bucket("bucket"),
+                    try_direct_send: false,
+                },
+            )
+        })
+        .build_no_validate();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(
+        &manifest,
+        &static_addresses_requirement_registry(),
+    )
+    .unwrap();
+
+    // Assert
+    assert!(!manifest_classification
+        .contains(&ManifestClassification::AccountLockerStore));
+    assert!(registered_classifications.is_empty());
+}
+
+#[test]
+fn storing_for_a_static_claimant_fulfills_the_static_addresses_requirement() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let account = allocator.new_account_address();
+    let locker = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalAccountLocker);
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                locker,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                AccountLockerStoreManifestInput {
+                    claimant: account.into(),
+                    bucket: namer.bucket("bucket"),
+                    try_direct_send: false,
+                },
+            )
+        })
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        registered_classifications,
+        ..
+    } = statically_analyze_with_registry(
+        &manifest,
+        &static_addresses_requirement_registry(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(registered_classifications.len(), 1);
+}
+
+/// A registry with an analyzer that permits every instruction and whose only
+/// requirement is that of the static addresses of the locker invocations, so
+/// that the requirement can be observed on manifests with instructions that
+/// the locker classifications don't permit, such as address allocations.
+fn static_addresses_requirement_registry() -> ManifestAnalyzerRegistry {
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry.register_static_analyzer::<StaticAddressesRequirementAnalyzer>(
+        "static_addresses_requirement",
+        (),
+    );
+    registry
+}
+
+struct StaticAddressesRequirementAnalyzer;
+
+impl ManifestStaticAnalyzer for StaticAddressesRequirementAnalyzer {
+    type Initializer = ();
+    type Output = ();
+    type PermissionState =
+        CallbackPermissionState<PermissionStateStaticCallback>;
+    type RequirementState = AccountLockerStaticAddressesRequirement;
+
+    fn new(
+        _: Self::Initializer,
+    ) -> (Self, Self::PermissionState, Self::RequirementState) {
+        (
+            Self,
+            CallbackPermissionState::new(|_| true),
+            Default::default(),
+        )
+    }
+
+    fn output(self) -> Self::Output {}
+
+    fn process_instruction(&mut self, _: InstructionContext<'_>) {}
+}
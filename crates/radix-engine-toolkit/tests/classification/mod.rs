@@ -16,12 +16,18 @@
 // under the License.
 
 mod account_deposit_settings;
+mod account_locker;
 mod general;
 mod general_subintent;
+mod package_publish;
 mod pool_contribution;
 mod pool_redemption;
+mod resource_creation;
+mod security_shield_update;
+mod swap;
 mod transfer;
 mod validator_claim;
+mod validator_management;
 mod validator_stake;
 mod validator_unstake;
 
@@ -0,0 +1,217 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[test]
+fn publishing_a_package_classifies_as_package_publish() {
+    // Arrange
+    let account = account1();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 100)
+        .publish_package(vec![0], PackageDefinition::default())
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::PackagePublish));
+}
+
+#[test]
+fn publishing_a_package_and_withdrawing_from_account_is_not_package_publish() {
+    // Arrange
+    let account = account1();
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .publish_package(vec![0], PackageDefinition::default())
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(!manifest_classification
+        .contains(&ManifestClassification::PackagePublish));
+}
+
+#[test]
+fn publishing_a_package_is_reported_in_the_detailed_classification() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let (code, definition) = package_code_and_definition();
+
+    let manifest = ManifestBuilder::new()
+        .publish_package(code.clone(), definition.clone())
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let (
+        StaticAnalysis {
+            manifest_classification,
+            ..
+        },
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            entities_newly_created_summary,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::PackagePublish));
+    let Some(DetailedManifestClassification::PackagePublish(
+        PackagePublishOutput { published_packages },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::PackagePublish(..)
+            )
+        })
+    else {
+        panic!("Not a package publish transaction")
+    };
+    let [PublishedPackage {
+        package_address,
+        code_hash,
+        blueprints,
+        royalty_config,
+        owner_role,
+    }] = published_packages.as_slice()
+    else {
+        panic!("Expected a single package to be published")
+    };
+    assert_eq!(
+        entities_newly_created_summary.new_package_entities,
+        indexset![*package_address]
+    );
+    assert_eq!(*code_hash, hash(&code));
+    assert_eq!(
+        *blueprints,
+        definition
+            .blueprints
+            .keys()
+            .cloned()
+            .collect::<IndexSet<_>>()
+    );
+    assert_eq!(
+        *royalty_config,
+        definition
+            .blueprints
+            .iter()
+            .map(|(blueprint_name, blueprint_definition)| (
+                blueprint_name.clone(),
+                blueprint_definition.royalty_config.clone()
+            ))
+            .collect::<IndexMap<_, _>>()
+    );
+    assert_eq!(*owner_role, None);
+}
+
+#[test]
+fn publishing_a_package_with_an_owner_role_reports_the_owner_role() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (code, definition) = package_code_and_definition();
+    let owner_role = OwnerRole::Fixed(rule!(allow_all));
+
+    let manifest = ManifestBuilder::new()
+        .publish_package_advanced(
+            None,
+            code,
+            definition,
+            metadata_init!(),
+            owner_role.clone(),
+        )
+        .build();
+
+    // Act
+    let (
+        _,
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    let Some(DetailedManifestClassification::PackagePublish(
+        PackagePublishOutput { published_packages },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::PackagePublish(..)
+            )
+        })
+    else {
+        panic!("Not a package publish transaction")
+    };
+    let [PublishedPackage {
+        owner_role: published_owner_role,
+        ..
+    }] = published_packages.as_slice()
+    else {
+        panic!("Expected a single package to be published")
+    };
+    assert_eq!(*published_owner_role, Some(owner_role.into()));
+}
+
+/// The code and definition of a package with a single `Test` blueprint whose
+/// `f` function returns a unit.
+fn package_code_and_definition() -> (Vec<u8>, PackageDefinition) {
+    (
+        wat2wasm(PACKAGE_WAT),
+        single_function_package_definition("Test", "f"),
+    )
+}
+
+const PACKAGE_WAT: &str = r#"
+(module
+    (func $Test_f (param $0 i64) (result i64)
+        ;; Encode () in SBOR at address 0x0
+        (i32.store8 (i32.const 0) (i32.const 92))
+        (i32.store8 (i32.const 1) (i32.const 33))
+        (i32.store8 (i32.const 2) (i32.const 0))
+
+        ;; Return slice (ptr = 0, len = 3)
+        (i64.const 3)
+    )
+
+    (memory $0 1)
+    (export "memory" (memory $0))
+    (export "Test_f" (func $Test_f))
+)
+"#;
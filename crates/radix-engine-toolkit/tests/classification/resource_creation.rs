@@ -0,0 +1,277 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[test]
+fn creating_a_fungible_resource_classifies_as_resource_creation() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            6,
+            FungibleResourceRoles::default(),
+            metadata! {
+                init {
+                    "symbol" => "TKN", locked;
+                }
+            },
+            Some(dec!(1000)),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let (
+        StaticAnalysis {
+            manifest_classification,
+            ..
+        },
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            entities_newly_created_summary,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::ResourceCreation));
+    let Some(DetailedManifestClassification::ResourceCreation(
+        ResourceCreationOutput { created_resources },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::ResourceCreation(..)
+            )
+        })
+    else {
+        panic!("Not a resource creation transaction")
+    };
+    let [CreatedResource {
+        resource_address,
+        definition:
+            NewResourceDefinition::Fungible {
+                divisibility,
+                initial_supply,
+                metadata,
+                ..
+            },
+    }] = created_resources.as_slice()
+    else {
+        panic!("Expected a single fungible resource to be created")
+    };
+    assert!(entities_newly_created_summary
+        .new_resource_entities
+        .contains(resource_address));
+    assert_eq!(*divisibility, 6);
+    assert_eq!(*initial_supply, Some(dec!(1000)));
+    assert!(metadata.init.data.contains_key("symbol"));
+}
+
+#[test]
+fn resources_created_in_a_single_manifest_are_matched_to_their_definitions() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .with_name_lookup(|builder, lookup| {
+            builder
+                .allocate_global_address(
+                    RESOURCE_PACKAGE,
+                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    "reservation1",
+                    "address1",
+                )
+                .allocate_global_address(
+                    RESOURCE_PACKAGE,
+                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    "reservation2",
+                    "address2",
+                )
+                .create_fungible_resource(
+                    OwnerRole::None,
+                    true,
+                    18,
+                    FungibleResourceRoles::default(),
+                    metadata! {
+                        init {
+                            "symbol" => "NOSUPPLY", locked;
+                        }
+                    },
+                    None,
+                )
+                // Created into the reservation that was allocated last.
+                .call_function(
+                    RESOURCE_PACKAGE,
+                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
+                    FungibleResourceManagerCreateManifestInput {
+                        owner_role: OwnerRole::None.into(),
+                        track_total_supply: true,
+                        divisibility: 18,
+                        resource_roles:
+                            FungibleResourceRoles::single_locked_rule(rule!(
+                                allow_all
+                            ))
+                            .into(),
+                        metadata: metadata! {
+                            init {
+                                "symbol" => "RESERVED2", locked;
+                            }
+                        }
+                        .into(),
+                        address_reservation: Some(
+                            lookup.address_reservation("reservation2"),
+                        ),
+                    },
+                )
+                .create_fungible_resource(
+                    OwnerRole::None,
+                    true,
+                    18,
+                    FungibleResourceRoles::default(),
+                    metadata! {
+                        init {
+                            "symbol" => "SUPPLY", locked;
+                        }
+                    },
+                    Some(dec!(1000)),
+                )
+                .call_function(
+                    RESOURCE_PACKAGE,
+                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
+                    FungibleResourceManagerCreateManifestInput {
+                        owner_role: OwnerRole::None.into(),
+                        track_total_supply: true,
+                        divisibility: 18,
+                        resource_roles:
+                            FungibleResourceRoles::single_locked_rule(rule!(
+                                allow_all
+                            ))
+                            .into(),
+                        metadata: metadata! {
+                            init {
+                                "symbol" => "RESERVED1", locked;
+                            }
+                        }
+                        .into(),
+                        address_reservation: Some(
+                            lookup.address_reservation("reservation1"),
+                        ),
+                    },
+                )
+                .mint_fungible(
+                    ManifestResourceAddress::Named(ManifestNamedAddress(0)),
+                    dec!(10),
+                )
+                .mint_fungible(
+                    ManifestResourceAddress::Named(ManifestNamedAddress(1)),
+                    dec!(10),
+                )
+                .try_deposit_entire_worktop_or_abort(account, None)
+        })
+        .build();
+
+    // Act
+    let (
+        _,
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            entities_newly_created_summary,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    let Some(DetailedManifestClassification::ResourceCreation(
+        ResourceCreationOutput { created_resources },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::ResourceCreation(..)
+            )
+        })
+    else {
+        panic!("Not a resource creation transaction")
+    };
+    assert_eq!(created_resources.len(), 4);
+    for CreatedResource {
+        resource_address,
+        definition,
+    } in created_resources
+    {
+        let NewResourceDefinition::Fungible { metadata, .. } = definition
+        else {
+            panic!("Expected only fungible resources to be created")
+        };
+        let symbol = metadata
+            .init
+            .data
+            .get("symbol")
+            .and_then(|entry| entry.value.clone())
+            .map(|value| value.try_into_typed().unwrap());
+        let symbol_in_receipt = entities_newly_created_summary
+            .global_entities_metadata
+            .get(&GlobalAddress::from(*resource_address))
+            .and_then(|metadata| metadata.get("symbol"))
+            .cloned()
+            .flatten();
+        assert!(symbol.is_some());
+        assert_eq!(symbol, symbol_in_receipt);
+    }
+}
+
+#[test]
+fn creating_a_resource_and_withdrawing_from_account_is_not_resource_creation() {
+    // Arrange
+    let account = account1();
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles::default(),
+            Default::default(),
+            Some(dec!(1000)),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(!manifest_classification
+        .contains(&ManifestClassification::ResourceCreation));
+}
@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[test]
+fn securifying_an_account_classifies_as_security_shield_update() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(true);
+    let rule_set = RuleSet {
+        primary_role: rule!(allow_all),
+        recovery_role: rule!(allow_all),
+        confirmation_role: rule!(deny_all),
+    };
+
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            account,
+            ACCOUNT_SECURIFY_IDENT,
+            AccountSecurifyManifestInput {},
+        )
+        .take_all_from_worktop(ACCOUNT_OWNER_BADGE, "bucket")
+        .then(|builder| {
+            let bucket = builder.bucket("bucket");
+            builder.call_function(
+                ACCESS_CONTROLLER_PACKAGE,
+                ACCESS_CONTROLLER_BLUEPRINT,
+                ACCESS_CONTROLLER_CREATE_IDENT,
+                AccessControllerCreateManifestInput {
+                    controlled_asset: bucket,
+                    rule_set: rule_set.clone().into(),
+                    timed_recovery_delay_in_minutes: Some(100),
+                    address_reservation: None,
+                },
+            )
+        })
+        .build();
+
+    // Act
+    let (
+        StaticAnalysis {
+            manifest_classification,
+            ..
+        },
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::SecurityShieldUpdate));
+    assert!(detailed_manifest_classification.contains(
+        &DetailedManifestClassification::SecurityShieldUpdate(
+            SecurityShieldUpdateOutput {
+                operations: vec![
+                    SecurityShieldUpdateOperation::CreateAccessController {
+                        rule_set: rule_set.into(),
+                        timed_recovery_delay_in_minutes: Some(100),
+                    }
+                ]
+            }
+        )
+    ));
+}
+
+#[test]
+fn initiating_recovery_classifies_as_security_shield_update() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let access_controller = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalAccessController);
+    let rule_set = RuleSet {
+        primary_role: rule!(deny_all),
+        recovery_role: rule!(allow_all),
+        confirmation_role: rule!(allow_all),
+    };
+
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            AccessControllerInitiateRecoveryAsRecoveryInput {
+                rule_set: rule_set.clone(),
+                timed_recovery_delay_in_minutes: Some(10),
+            },
+        )
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::SecurityShieldUpdate));
+}
+
+#[test]
+fn withdrawing_from_an_account_disqualifies_security_shield_update() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let account = allocator.new_account_address();
+    let access_controller = allocator
+        .next_address::<ComponentAddress>(EntityType::GlobalAccessController);
+
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT,
+            AccessControllerLockPrimaryRoleInput {},
+        )
+        .withdraw_from_account(account, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(!manifest_classification
+        .contains(&ManifestClassification::SecurityShieldUpdate));
+}
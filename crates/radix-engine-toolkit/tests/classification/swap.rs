@@ -0,0 +1,314 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine::errors::RuntimeError;
+use radix_engine::vm::{OverridePackageCode, VmApi, VmInvoke};
+use radix_engine_interface::api::{AttachedModuleId, FieldValue, SystemApi};
+
+#[test]
+fn withdraw_component_call_and_deposit_classifies_as_swap() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new()
+        .with_custom_extension(OverridePackageCode::new(
+            SWAP_PACKAGE_CODE_ID,
+            SwapBlueprint,
+        ))
+        .without_kernel_trace()
+        .build();
+    let (_, _, account) = ledger.new_account(false);
+    let swap_component = ledger.new_swap_component();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .call_method_with_name_lookup(swap_component, "swap", |lookup| {
+            (lookup.bucket("bucket"),)
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let (
+        StaticAnalysis {
+            manifest_classification,
+            ..
+        },
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(manifest_classification.contains(&ManifestClassification::Swap));
+    let Some(DetailedManifestClassification::Swap(SwapOutput {
+        swap_operations,
+    })) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(classification, DetailedManifestClassification::Swap(..))
+        })
+    else {
+        panic!("Not a swap transaction")
+    };
+    assert_eq!(swap_operations.len(), 1);
+    let SwapOperation {
+        account_address,
+        component_address,
+        input_resources,
+        output_resources,
+    } = swap_operations.first().unwrap();
+    assert_eq!(*account_address, account);
+    assert_eq!(*component_address, swap_component);
+    assert_eq!(input_resources.resource_addresses(), indexset![XRD]);
+    assert_eq!(input_resources.resource_amount(&XRD), dec!(10));
+    let output_resource_addresses = output_resources.resource_addresses();
+    assert_eq!(output_resource_addresses.len(), 1);
+    let output_resource = *output_resource_addresses.first().unwrap();
+    assert_ne!(output_resource, XRD);
+    assert_eq!(output_resources.resource_amount(&output_resource), dec!(10));
+}
+
+#[test]
+fn getting_back_the_same_resource_disqualifies_swap_classification() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new()
+        .with_custom_extension(OverridePackageCode::new(
+            SWAP_PACKAGE_CODE_ID,
+            SwapBlueprint,
+        ))
+        .without_kernel_trace()
+        .build();
+    let (_, _, account) = ledger.new_account(false);
+    let swap_component = ledger.new_swap_component();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .call_method_with_name_lookup(swap_component, "echo", |lookup| {
+            (lookup.bucket("bucket"),)
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let (
+        _,
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(!detailed_manifest_classification
+        .iter()
+        .any(|classification| {
+            matches!(classification, DetailedManifestClassification::Swap(..))
+        }));
+}
+
+#[test]
+fn calling_two_components_disqualifies_swap_classification() {
+    // Arrange
+    let account = account1();
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .call_method(FAUCET, "free", ())
+        .call_method(FAUCET, "free", ())
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(!manifest_classification.contains(&ManifestClassification::Swap));
+}
+
+#[test]
+fn depositing_into_another_account_disqualifies_swap_classification() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1(), XRD, 10)
+        .call_method(FAUCET, "free", ())
+        .try_deposit_entire_worktop_or_abort(account2(), None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(!manifest_classification.contains(&ManifestClassification::Swap));
+}
+
+const SWAP_PACKAGE_CODE_ID: u64 = 1024;
+const SWAP_BLUEPRINT_NAME: &str = "Swap";
+
+/// A native test blueprint standing in for a DEX: its `swap` method keeps the
+/// XRD bucket that it's given in a vault and returns a bucket of a newly
+/// created resource of the same amount. Its `echo` method returns the bucket
+/// that it's given as is.
+#[derive(Clone)]
+struct SwapBlueprint;
+
+impl VmInvoke for SwapBlueprint {
+    fn invoke<Y: SystemApi<RuntimeError>, V: VmApi>(
+        &mut self,
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+        _: &V,
+    ) -> Result<IndexedScryptoValue, RuntimeError> {
+        match export_name {
+            "new" => {
+                let metadata = api.call_function(
+                    METADATA_MODULE_PACKAGE,
+                    METADATA_BLUEPRINT,
+                    METADATA_CREATE_IDENT,
+                    scrypto_encode(&MetadataCreateInput {}).unwrap(),
+                )?;
+                let metadata = scrypto_decode::<Own>(&metadata).unwrap();
+
+                let role_assignment = api.call_function(
+                    ROLE_ASSIGNMENT_MODULE_PACKAGE,
+                    ROLE_ASSIGNMENT_BLUEPRINT,
+                    ROLE_ASSIGNMENT_CREATE_IDENT,
+                    scrypto_encode(&RoleAssignmentCreateInput {
+                        owner_role: OwnerRole::None.into(),
+                        roles: Default::default(),
+                    })
+                    .unwrap(),
+                )?;
+                let role_assignment =
+                    scrypto_decode::<Own>(&role_assignment).unwrap();
+
+                let vault = api.call_method(
+                    XRD.as_node_id(),
+                    RESOURCE_MANAGER_CREATE_EMPTY_VAULT_IDENT,
+                    scrypto_encode(
+                        &FungibleResourceManagerCreateEmptyVaultInput {},
+                    )
+                    .unwrap(),
+                )?;
+                let vault = scrypto_decode::<Own>(&vault).unwrap();
+
+                let node_id = api.new_simple_object(
+                    SWAP_BLUEPRINT_NAME,
+                    indexmap!(0u8 => FieldValue::new(vault)),
+                )?;
+                let address = api.globalize(
+                    node_id,
+                    indexmap!(
+                        AttachedModuleId::Metadata => metadata.0,
+                        AttachedModuleId::RoleAssignment => role_assignment.0,
+                    ),
+                    None,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(
+                    &ComponentAddress::new_or_panic(address.as_node_id().0),
+                ))
+            }
+            "swap" => {
+                let (bucket,) = input.as_typed::<(Bucket,)>().unwrap();
+
+                let amount = api.call_method(
+                    bucket.0.as_node_id(),
+                    BUCKET_GET_AMOUNT_IDENT,
+                    scrypto_encode(&BucketGetAmountInput {}).unwrap(),
+                )?;
+                let amount = scrypto_decode::<Decimal>(&amount).unwrap();
+
+                let handle = api.actor_open_field(
+                    ACTOR_STATE_SELF,
+                    0,
+                    LockFlags::read_only(),
+                )?;
+                let vault = api.field_read_typed::<Own>(handle)?;
+                api.call_method(
+                    vault.as_node_id(),
+                    VAULT_PUT_IDENT,
+                    scrypto_encode(&VaultPutInput { bucket }).unwrap(),
+                )?;
+                api.field_close(handle)?;
+
+                let output = api.call_function(
+                    RESOURCE_PACKAGE,
+                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
+                    scrypto_encode(
+                        &FungibleResourceManagerCreateWithInitialSupplyInput {
+                            owner_role: OwnerRole::None,
+                            track_total_supply: true,
+                            divisibility: 18,
+                            initial_supply: amount,
+                            resource_roles: Default::default(),
+                            metadata: Default::default(),
+                            address_reservation: None,
+                        },
+                    )
+                    .unwrap(),
+                )?;
+                let (_, bucket) =
+                    scrypto_decode::<(ResourceAddress, Bucket)>(&output)
+                        .unwrap();
+
+                Ok(IndexedScryptoValue::from_typed(&(bucket,)))
+            }
+            // The input is a single-element tuple of the bucket and so is the
+            // output, so returning the input hands the bucket back.
+            "echo" => Ok(input.clone()),
+            _ => Ok(IndexedScryptoValue::from_typed(&())),
+        }
+    }
+}
+
+#[extend::ext]
+impl<D> LedgerSimulator<OverridePackageCode<SwapBlueprint>, D>
+where
+    D: TestDatabase,
+{
+    fn new_swap_component(&mut self) -> ComponentAddress {
+        let package_address = self.publish_native_package(
+            SWAP_PACKAGE_CODE_ID,
+            PackageDefinition::new_with_field_test_definition(
+                SWAP_BLUEPRINT_NAME,
+                vec![
+                    ("new", "new", false),
+                    ("swap", "swap", true),
+                    ("echo", "echo", true),
+                ],
+            ),
+        );
+        let receipt = self.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(package_address, SWAP_BLUEPRINT_NAME, "new", ())
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit_success().new_component_addresses()[0]
+    }
+}
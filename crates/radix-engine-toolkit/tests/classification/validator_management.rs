@@ -0,0 +1,192 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[test]
+fn validator_owner_updating_validator_classifies_as_validator_management() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (pk, _, account) = ledger.new_account(true);
+    let (validator, ..) = ledger.new_validator(pk, account);
+
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator.as_node_id().0).unwrap()],
+        )
+        .call_method(
+            validator,
+            VALIDATOR_UPDATE_FEE_IDENT,
+            ValidatorUpdateFeeInput {
+                new_fee_factor: dec!("0.05"),
+            },
+        )
+        .call_method(
+            validator,
+            VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
+            ValidatorUpdateAcceptDelegatedStakeInput {
+                accept_delegated_stake: false,
+            },
+        )
+        .call_method(validator, VALIDATOR_REGISTER_IDENT, ())
+        .build();
+
+    // Act
+    let (
+        StaticAnalysis {
+            manifest_classification,
+            ..
+        },
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    assert!(manifest_classification
+        .contains(&ManifestClassification::ValidatorManagement));
+    let Some(DetailedManifestClassification::ValidatorManagement(
+        ValidatorManagementOutput { operations },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::ValidatorManagement(..)
+            )
+        })
+    else {
+        panic!("Not a validator management transaction")
+    };
+    assert_eq!(
+        operations,
+        &vec![
+            ValidatorManagementOperation::UpdateFee {
+                validator_address: validator,
+                new_fee_factor: dec!("0.05"),
+            },
+            ValidatorManagementOperation::UpdateAcceptDelegatedStake {
+                validator_address: validator,
+                accept_delegated_stake: false,
+            },
+            ValidatorManagementOperation::Register {
+                validator_address: validator,
+            },
+        ]
+    );
+}
+
+#[test]
+fn locking_owner_stake_units_reports_the_locked_amount() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (pk, _, account) = ledger.new_account(true);
+    let (validator, stake_units, _) = ledger.new_validator(pk, account);
+    ledger
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .get_free_xrd_from_faucet()
+                .take_all_from_worktop(XRD, "xrd")
+                .stake_validator(validator, "xrd")
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator.as_node_id().0).unwrap()],
+        )
+        .withdraw_from_account(account, stake_units, 10)
+        .take_all_from_worktop(stake_units, "stake_units")
+        .with_name_lookup(|builder, namer| {
+            builder.call_method(
+                validator,
+                VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT,
+                ValidatorLockOwnerStakeUnitsManifestInput {
+                    stake_unit_bucket: namer.bucket("stake_units"),
+                },
+            )
+        })
+        .build();
+
+    // Act
+    let (
+        _,
+        DynamicAnalysis {
+            detailed_manifest_classification,
+            ..
+        },
+    ) = ledger.analyze(manifest);
+
+    // Assert
+    let Some(DetailedManifestClassification::ValidatorManagement(
+        ValidatorManagementOutput { operations },
+    )) = detailed_manifest_classification
+        .iter()
+        .find(|classification| {
+            matches!(
+                classification,
+                DetailedManifestClassification::ValidatorManagement(..)
+            )
+        })
+    else {
+        panic!("Not a validator management transaction")
+    };
+    assert_eq!(
+        operations,
+        &vec![ValidatorManagementOperation::LockOwnerStakeUnits {
+            validator_address: validator,
+            stake_unit_amount: dec!(10),
+        }]
+    );
+}
+
+#[test]
+fn registering_a_validator_and_staking_to_it_is_not_validator_management() {
+    // Arrange
+    let mut allocator = TestAddressAllocator::new();
+    let account = account1();
+    let validator =
+        allocator.next_address::<ComponentAddress>(EntityType::GlobalValidator);
+    let manifest = ManifestBuilder::new()
+        .call_method(validator, VALIDATOR_REGISTER_IDENT, ())
+        .withdraw_from_account(account, XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .stake_validator(validator, "bucket")
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let StaticAnalysis {
+        manifest_classification,
+        ..
+    } = statically_analyze(&manifest).unwrap();
+
+    // Assert
+    assert!(!manifest_classification
+        .contains(&ManifestClassification::ValidatorManagement));
+}
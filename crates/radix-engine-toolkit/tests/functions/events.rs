@@ -0,0 +1,322 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_blueprint_schema_init::TypeRef;
+use radix_engine::object_modules::metadata::SetMetadataEvent;
+use radix_engine_toolkit::functions::events::*;
+use sbor::representations::SerializationMode;
+
+#[test]
+fn events_of_non_native_blueprints_can_be_decoded_with_the_package_schema() {
+    // Arrange
+    let package_definition = package_definition();
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Method(XRD.into_node_id(), ModuleId::Main),
+        "SwapEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&SwapEvent {
+        input_amount: dec!(10),
+        output_amount: dec!(20),
+    })
+    .unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        None,
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(decoded_event.blueprint_name, "Swap");
+    assert_eq!(decoded_event.event_name, "SwapEvent");
+    assert!(decoded_event.event.contains("input_amount"));
+    assert!(decoded_event.event.contains("output_amount"));
+}
+
+#[test]
+fn events_not_defined_by_the_package_can_not_be_decoded() {
+    // Arrange
+    let package_definition = package_definition();
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Method(XRD.into_node_id(), ModuleId::Main),
+        "DepositEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&()).unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        None,
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    );
+
+    // Assert
+    assert!(matches!(
+        decoded_event,
+        Err(EventDecodingError::EventNotFound(event_name)) if event_name == "DepositEvent"
+    ));
+}
+
+#[test]
+fn events_valid_against_the_schemas_of_several_blueprints_are_ambiguous() {
+    // Arrange
+    let package_definition =
+        package_definition_with_blueprints(&["Swap", "Pool"]);
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Method(XRD.into_node_id(), ModuleId::Main),
+        "SwapEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&SwapEvent {
+        input_amount: dec!(10),
+        output_amount: dec!(20),
+    })
+    .unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        None,
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    );
+
+    // Assert
+    assert!(matches!(
+        decoded_event,
+        Err(EventDecodingError::AmbiguousEmitterBlueprint(blueprint_names))
+            if blueprint_names == ["Swap", "Pool"]
+    ));
+}
+
+#[test]
+fn events_of_methods_are_decoded_with_the_schema_of_the_given_blueprint() {
+    // Arrange
+    let package_definition =
+        package_definition_with_blueprints(&["Swap", "Pool"]);
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Method(XRD.into_node_id(), ModuleId::Main),
+        "SwapEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&SwapEvent {
+        input_amount: dec!(10),
+        output_amount: dec!(20),
+    })
+    .unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        Some("Pool"),
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(decoded_event.blueprint_name, "Pool");
+    assert_eq!(decoded_event.event_name, "SwapEvent");
+}
+
+#[test]
+fn events_of_methods_of_a_blueprint_missing_from_the_package_are_rejected() {
+    // Arrange
+    let package_definition = package_definition();
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Method(XRD.into_node_id(), ModuleId::Main),
+        "SwapEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&SwapEvent {
+        input_amount: dec!(10),
+        output_amount: dec!(20),
+    })
+    .unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        Some("Pool"),
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    );
+
+    // Assert
+    assert!(matches!(
+        decoded_event,
+        Err(EventDecodingError::BlueprintNotFound(blueprint_name))
+            if blueprint_name == "Pool"
+    ));
+}
+
+#[test]
+fn events_of_functions_are_decoded_with_the_schema_of_their_blueprint() {
+    // Arrange
+    let package_definition =
+        package_definition_with_blueprints(&["Swap", "Pool"]);
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Function(BlueprintId::new(&package_address(), "Pool")),
+        "SwapEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&SwapEvent {
+        input_amount: dec!(10),
+        output_amount: dec!(20),
+    })
+    .unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        None,
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(decoded_event.blueprint_name, "Pool");
+    assert_eq!(decoded_event.event_name, "SwapEvent");
+}
+
+#[test]
+fn events_of_functions_of_blueprints_of_another_package_are_rejected() {
+    // Arrange
+    let package_definition = package_definition();
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Function(BlueprintId::new(&ACCOUNT_PACKAGE, "Swap")),
+        "SwapEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&SwapEvent {
+        input_amount: dec!(10),
+        output_amount: dec!(20),
+    })
+    .unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        None,
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    );
+
+    // Assert
+    assert!(matches!(
+        decoded_event,
+        Err(EventDecodingError::PackageAddressMismatch { expected, actual })
+            if expected == package_address() && actual == ACCOUNT_PACKAGE
+    ));
+}
+
+#[test]
+fn events_of_the_metadata_module_are_decoded_with_the_native_module_schema() {
+    // Arrange
+    let package_definition = package_definition();
+    let event_type_identifier = EventTypeIdentifier(
+        Emitter::Method(XRD.into_node_id(), ModuleId::Metadata),
+        "SetMetadataEvent".to_owned(),
+    );
+    let event_data = scrypto_encode(&SetMetadataEvent {
+        key: "name".to_owned(),
+        value: MetadataValue::String("Swap Component".to_owned()),
+    })
+    .unwrap();
+
+    // Act
+    let decoded_event = scrypto_sbor_decode_event(
+        &event_type_identifier,
+        &event_data,
+        &package_address(),
+        &package_definition,
+        None,
+        SerializationMode::Natural,
+        &AddressBech32Encoder::for_simulator(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(decoded_event.blueprint_name, METADATA_BLUEPRINT);
+    assert_eq!(decoded_event.event_name, "SetMetadataEvent");
+    assert!(decoded_event.event.contains("Swap Component"));
+}
+
+fn package_address() -> PackageAddress {
+    PackageAddress::new_or_panic(
+        [EntityType::GlobalPackage as u8; NodeId::LENGTH],
+    )
+}
+
+fn package_definition() -> PackageDefinition {
+    package_definition_with_blueprints(&["Swap"])
+}
+
+/// A package definition where each of the blueprints defines a `SwapEvent`
+/// with the same schema.
+fn package_definition_with_blueprints(
+    blueprint_names: &[&str],
+) -> PackageDefinition {
+    let mut package_definition = PackageDefinition::default();
+    for blueprint_name in blueprint_names {
+        let (local_type_id, schema) = generate_full_schema_from_single_type::<
+            SwapEvent,
+            ScryptoCustomSchema,
+        >();
+
+        let mut blueprint_definition = BlueprintDefinitionInit::default();
+        blueprint_definition.schema.schema = schema;
+        blueprint_definition
+            .schema
+            .events
+            .event_schema
+            .insert("SwapEvent".to_owned(), TypeRef::Static(local_type_id));
+
+        package_definition
+            .blueprints
+            .insert(blueprint_name.to_string(), blueprint_definition);
+    }
+    package_definition
+}
+
+#[derive(ScryptoSbor)]
+struct SwapEvent {
+    input_amount: Decimal,
+    output_amount: Decimal,
+}
@@ -0,0 +1,352 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine_toolkit::functions::fee_estimation::*;
+use radix_engine_toolkit_common::receipt::RuntimeToolkitTransactionReceipt;
+
+#[test]
+fn fee_estimate_covers_the_fees_of_the_notarized_transaction() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, private_key, account) = ledger.new_allocated_account();
+    let notary_private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let manifest = |lock_fee: Decimal| {
+        ManifestBuilder::new()
+            .lock_fee(account, lock_fee)
+            .withdraw_from_account(account, XRD, 10)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build()
+    };
+    let preview_manifest = manifest(dec!(100));
+    let receipt = preview_with_auth(
+        &mut ledger,
+        preview_manifest.clone(),
+        private_key.public_key().into(),
+    );
+
+    // Act
+    let estimate =
+        radix_engine_toolkit::functions::transaction_v1::manifest::estimate_fee(
+            &preview_manifest,
+            RuntimeToolkitTransactionReceipt::try_from(receipt).unwrap(),
+            &FeeEstimationParameters {
+                signer_count: 1,
+                notary_is_signatory: false,
+                tip_proportion: dec!("0.05"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let current_epoch = ledger.get_current_epoch();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10).unwrap(),
+            nonce: 0,
+            notary_public_key: notary_private_key.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+        })
+        .manifest(manifest(estimate.lock_fee))
+        .sign(&private_key)
+        .notarize(&notary_private_key)
+        .build();
+    let receipt =
+        ledger.execute_notarized_transaction(transaction.to_raw().unwrap());
+    receipt.expect_commit_success();
+    assert!(receipt.fee_summary.total_tipping_cost_in_xrd.is_positive());
+    assert!(estimate.tip_cost.is_positive());
+    assert!(estimate.lock_fee >= receipt.fee_summary.total_cost());
+    assert_eq!(
+        estimate.lock_fee,
+        estimate.fee_summary.execution_cost
+            + estimate.fee_summary.finalization_cost
+            + estimate.fee_summary.storage_expansion_cost
+            + estimate.fee_summary.royalty_cost
+            + estimate.signature_verification_cost
+            + estimate.payload_validation_cost
+            + estimate.tip_cost
+            + estimate.margin
+    );
+}
+
+#[test]
+fn fee_estimate_without_margin_is_close_to_the_fees_of_the_transaction() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, private_key, account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 100)
+        .withdraw_from_account(account, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = preview_with_auth(
+        &mut ledger,
+        manifest.clone(),
+        private_key.public_key().into(),
+    );
+
+    // Act
+    let estimate =
+        radix_engine_toolkit::functions::transaction_v1::manifest::estimate_fee(
+            &manifest,
+            RuntimeToolkitTransactionReceipt::try_from(receipt).unwrap(),
+            &FeeEstimationParameters {
+                signer_count: 1,
+                notary_is_signatory: true,
+                margin: Decimal::ZERO,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let current_epoch = ledger.get_current_epoch();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10).unwrap(),
+            nonce: 0,
+            notary_public_key: private_key.public_key().into(),
+            notary_is_signatory: true,
+            tip_percentage: 0,
+        })
+        .manifest(manifest)
+        .notarize(&private_key)
+        .build();
+    let receipt =
+        ledger.execute_notarized_transaction(transaction.to_raw().unwrap());
+    receipt.expect_commit_success();
+    let total_cost = receipt.fee_summary.total_cost();
+    assert!(
+        (estimate.lock_fee - total_cost).checked_abs().unwrap()
+            < total_cost * dec!("0.05")
+    );
+}
+
+#[test]
+fn fee_estimate_grows_with_the_number_of_signers() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+    let manifest = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = LedgerSimulatorEDExt::preview(&mut ledger, manifest.clone());
+
+    // Act
+    let [one_signer, five_signers] = [1, 5].map(|signer_count| {
+        radix_engine_toolkit::functions::transaction_v1::manifest::estimate_fee(
+            &manifest,
+            RuntimeToolkitTransactionReceipt::try_from(receipt.clone())
+                .unwrap(),
+            &FeeEstimationParameters {
+                signer_count,
+                notary_is_signatory: true,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    });
+
+    // Assert
+    assert!(
+        five_signers.signature_verification_cost
+            > one_signer.signature_verification_cost
+    );
+    assert!(
+        five_signers.payload_validation_cost
+            > one_signer.payload_validation_cost
+    );
+    assert!(five_signers.lock_fee > one_signer.lock_fee);
+}
+
+#[test]
+fn v2_fee_estimate_covers_the_fees_of_a_transaction_with_a_subintent() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let notary_private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let child_private_key = Ed25519PrivateKey::from_u64(2).unwrap();
+    let (mut preview_builder, preview_manifest) = transaction_v2_with_a_child(
+        &mut ledger,
+        &notary_private_key,
+        &child_private_key,
+        dec!(100),
+    );
+    let subintents = preview_builder
+        .create_intent_and_subintent_info()
+        .non_root_subintents
+        .0
+        .clone();
+    let receipt = ledger.preview_v2(
+        preview_builder.build_preview_transaction(vec![]),
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: false,
+            skip_epoch_check: false,
+            disable_auth: false,
+        },
+    );
+
+    // Act
+    let estimate =
+        radix_engine_toolkit::functions::transaction_v2::transaction_manifest::estimate_fee(
+            &preview_manifest,
+            &subintents,
+            RuntimeToolkitTransactionReceipt::try_from(receipt).unwrap(),
+            &FeeEstimationParameters {
+                signer_count: 0,
+                notary_is_signatory: false,
+                subintent_signer_counts: vec![1],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let (builder, _) = transaction_v2_with_a_child(
+        &mut ledger,
+        &notary_private_key,
+        &child_private_key,
+        estimate.lock_fee,
+    );
+    let transaction = builder.notarize(&notary_private_key).build();
+    let receipt = ledger.execute_notarized_transaction(&transaction.raw);
+    receipt.expect_commit_success();
+    assert_eq!(estimate.signature_verification_cost, Decimal::ZERO);
+    assert!(estimate.lock_fee >= receipt.fee_summary.total_cost());
+}
+
+#[test]
+fn v2_fee_estimate_rejects_signer_counts_not_matching_the_subintents() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let notary_private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let child_private_key = Ed25519PrivateKey::from_u64(2).unwrap();
+    let (mut builder, manifest) = transaction_v2_with_a_child(
+        &mut ledger,
+        &notary_private_key,
+        &child_private_key,
+        dec!(100),
+    );
+    let subintents = builder
+        .create_intent_and_subintent_info()
+        .non_root_subintents
+        .0
+        .clone();
+    let receipt = ledger.preview_v2(
+        builder.build_preview_transaction(vec![]),
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: false,
+            skip_epoch_check: false,
+            disable_auth: false,
+        },
+    );
+
+    // Act
+    let estimate =
+        radix_engine_toolkit::functions::transaction_v2::transaction_manifest::estimate_fee(
+            &manifest,
+            &subintents,
+            RuntimeToolkitTransactionReceipt::try_from(receipt).unwrap(),
+            &FeeEstimationParameters {
+                subintent_signer_counts: vec![],
+                ..Default::default()
+            },
+        );
+
+    // Assert
+    assert!(matches!(
+        estimate,
+        Err(FeeEstimationError::SubintentSignerCountMismatch {
+            subintents: 1,
+            subintent_signer_counts: 0
+        })
+    ));
+}
+
+/// Previews a V1 manifest signed by the given key with the auth checks and
+/// their costs in place, as they are when the transaction is committed.
+fn preview_with_auth(
+    ledger: &mut DefaultLedgerSimulator,
+    manifest: TransactionManifestV1,
+    signer_public_key: PublicKey,
+) -> TransactionReceiptV1 {
+    ledger.preview_manifest(
+        manifest,
+        vec![signer_public_key],
+        0,
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: false,
+            skip_epoch_check: false,
+            disable_auth: false,
+        },
+    )
+}
+
+/// Builds a V2 transaction whose root intent locks the given fee from the
+/// faucet and yields to a single child subintent signed by the given key.
+fn transaction_v2_with_a_child(
+    ledger: &mut DefaultLedgerSimulator,
+    notary_private_key: &Secp256k1PrivateKey,
+    child_private_key: &Ed25519PrivateKey,
+    lock_fee: Decimal,
+) -> (TransactionV2Builder, TransactionManifestV2) {
+    let current_epoch = ledger.get_current_epoch();
+    let intent_header = |intent_discriminator| IntentHeaderV2 {
+        network_id: NetworkDefinition::simulator().id,
+        start_epoch_inclusive: current_epoch,
+        end_epoch_exclusive: current_epoch.after(10).unwrap(),
+        min_proposer_timestamp_inclusive: None,
+        max_proposer_timestamp_exclusive: None,
+        intent_discriminator,
+    };
+
+    let child = TransactionBuilder::new_partial_v2()
+        .intent_header(intent_header(1))
+        .manifest_builder(|builder| builder.yield_to_parent(()))
+        .sign(child_private_key)
+        .build();
+    let manifest = ManifestBuilder::new_v2()
+        .use_child("child", child.root_subintent_hash)
+        .lock_fee(FAUCET, lock_fee)
+        .yield_to_child("child", ())
+        .build();
+    let builder = TransactionBuilder::new_v2()
+        .add_signed_child("child", child)
+        .manifest(manifest.clone())
+        .intent_header(intent_header(2))
+        .transaction_header(TransactionHeaderV2 {
+            notary_public_key: notary_private_key.public_key().into(),
+            notary_is_signatory: false,
+            tip_basis_points: 0,
+        });
+    (builder, manifest)
+}
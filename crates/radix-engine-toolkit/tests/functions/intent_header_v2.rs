@@ -0,0 +1,216 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine_toolkit::functions::transaction_v2::intent_header::*;
+use radix_engine_toolkit::functions::transaction_v2::partial_transaction;
+use radix_transactions::validation::TransactionValidationConfigV1;
+
+#[test]
+fn intent_header_can_be_constructed_from_the_current_epoch() {
+    // Arrange
+    let current_epoch = Epoch::of(100);
+
+    // Act
+    let header = new(
+        0x01,
+        current_epoch,
+        10,
+        None,
+        None,
+        1,
+        &TransactionValidationConfigV1::cuttlefish(),
+    );
+
+    // Assert
+    let header = header.unwrap();
+    assert_eq!(header.start_epoch_inclusive, Epoch::of(100));
+    assert_eq!(header.end_epoch_exclusive, Epoch::of(110));
+}
+
+#[test]
+fn intent_header_with_a_window_that_is_too_long_is_rejected() {
+    // Arrange
+    let validation_config = TransactionValidationConfigV1::cuttlefish();
+    let epoch_count = validation_config.max_epoch_range + 1;
+
+    // Act
+    let header = new(
+        0x01,
+        Epoch::of(100),
+        epoch_count,
+        None,
+        None,
+        1,
+        &validation_config,
+    );
+
+    // Assert
+    assert_eq!(
+        header,
+        Err(IntentHeaderValidationError::EpochRangeTooLong {
+            epoch_range: epoch_count,
+            max_epoch_range: validation_config.max_epoch_range,
+        })
+    );
+}
+
+#[test]
+fn intent_header_with_an_empty_timestamp_range_is_rejected() {
+    // Arrange
+    let min_inclusive = Instant::new(1_000);
+    let max_exclusive = Instant::new(1_000);
+
+    // Act
+    let header = new(
+        0x01,
+        Epoch::of(100),
+        10,
+        Some(min_inclusive),
+        Some(max_exclusive),
+        1,
+        &TransactionValidationConfigV1::cuttlefish(),
+    );
+
+    // Assert
+    assert_eq!(
+        header,
+        Err(IntentHeaderValidationError::TimestampRangeIsEmpty {
+            min_inclusive,
+            max_exclusive,
+        })
+    );
+}
+
+#[test]
+fn subintents_that_overlap_the_root_intent_and_each_other_are_accepted() {
+    // Arrange
+    let partial_transaction = partial_transaction(
+        header(100, 120, None, None),
+        vec![header(105, 115, None, None), header(110, 130, None, None)],
+    );
+
+    // Act
+    let validation_result = partial_transaction::validate_intent_headers(
+        &partial_transaction,
+        None,
+        &TransactionValidationConfigV1::cuttlefish(),
+    );
+
+    // Assert
+    assert_eq!(validation_result, Ok(()));
+}
+
+#[test]
+fn subintents_with_epoch_ranges_that_do_not_overlap_each_other_are_rejected() {
+    // Arrange
+    let partial_transaction = partial_transaction(
+        header(100, 120, None, None),
+        vec![header(100, 110, None, None), header(110, 120, None, None)],
+    );
+
+    // Act
+    let validation_result = partial_transaction::validate_intent_headers(
+        &partial_transaction,
+        None,
+        &TransactionValidationConfigV1::cuttlefish(),
+    );
+
+    // Assert
+    assert_eq!(
+        validation_result,
+        Err(
+            IntentHeaderValidationError::SubintentEpochRangeDoesNotOverlap {
+                subintent_index: 1
+            }
+        )
+    );
+}
+
+#[test]
+fn subintents_with_timestamp_ranges_that_do_not_overlap_each_other_are_rejected(
+) {
+    // Arrange
+    let partial_transaction = partial_transaction(
+        header(100, 120, None, None),
+        vec![
+            header(100, 120, None, Some(1_000)),
+            header(100, 120, Some(1_000), None),
+        ],
+    );
+
+    // Act
+    let validation_result = partial_transaction::validate_intent_headers(
+        &partial_transaction,
+        None,
+        &TransactionValidationConfigV1::cuttlefish(),
+    );
+
+    // Assert
+    assert_eq!(
+        validation_result,
+        Err(
+            IntentHeaderValidationError::SubintentTimestampRangeDoesNotOverlap {
+                subintent_index: 1
+            }
+        )
+    );
+}
+
+fn partial_transaction(
+    root_header: IntentHeaderV2,
+    non_root_headers: Vec<IntentHeaderV2>,
+) -> PartialTransactionV2 {
+    PartialTransactionV2 {
+        root_subintent: subintent(root_header),
+        non_root_subintents: NonRootSubintentsV2(
+            non_root_headers.into_iter().map(subintent).collect(),
+        ),
+    }
+}
+
+fn subintent(header: IntentHeaderV2) -> SubintentV2 {
+    SubintentV2 {
+        intent_core: IntentCoreV2 {
+            header,
+            blobs: BlobsV1 { blobs: vec![] },
+            message: MessageV2::None,
+            children: ChildSubintentSpecifiersV2 {
+                children: Default::default(),
+            },
+            instructions: InstructionsV2(vec![]),
+        },
+    }
+}
+
+fn header(
+    start_epoch_inclusive: u64,
+    end_epoch_exclusive: u64,
+    min_proposer_timestamp_inclusive: Option<i64>,
+    max_proposer_timestamp_exclusive: Option<i64>,
+) -> IntentHeaderV2 {
+    IntentHeaderV2 {
+        network_id: 0x01,
+        start_epoch_inclusive: Epoch::of(start_epoch_inclusive),
+        end_epoch_exclusive: Epoch::of(end_epoch_exclusive),
+        min_proposer_timestamp_inclusive: min_proposer_timestamp_inclusive
+            .map(Instant::new),
+        max_proposer_timestamp_exclusive: max_proposer_timestamp_exclusive
+            .map(Instant::new),
+        intent_discriminator: 1,
+    }
+}
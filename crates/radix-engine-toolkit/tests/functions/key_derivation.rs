@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine_toolkit::functions::key_derivation::*;
+
+#[test]
+fn mnemonic_is_converted_to_the_expected_seed() {
+    // Arrange
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    // Act
+    let seed = mnemonic_to_seed(mnemonic, "TREZOR").unwrap();
+
+    // Assert
+    assert_eq!(
+        hex::encode(seed),
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+}
+
+#[test]
+fn ed25519_keys_are_derived_according_to_the_slip10_test_vectors() {
+    // Arrange
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let path = "m/0H/1H".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let private_key =
+        derive_private_key(&seed, CurveType::Ed25519, &path).unwrap();
+
+    // Assert
+    assert_eq!(
+        hex::encode(private_key_bytes(&private_key)),
+        "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+    );
+}
+
+#[test]
+fn secp256k1_keys_are_derived_according_to_the_slip10_test_vectors() {
+    // Arrange
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let path = "m/0'/1".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let private_key =
+        derive_private_key(&seed, CurveType::Secp256k1, &path).unwrap();
+
+    // Assert
+    assert_eq!(
+        hex::encode(private_key_bytes(&private_key)),
+        "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368"
+    );
+}
+
+#[test]
+fn ed25519_keys_cant_be_derived_along_non_hardened_paths() {
+    // Arrange
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let path = "m/0H/1".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let result = derive_private_key(&seed, CurveType::Ed25519, &path);
+
+    // Assert
+    assert_eq!(
+        result.err(),
+        Some(KeyDerivationError::NonHardenedEd25519Derivation)
+    );
+}
+
+#[test]
+fn cap26_path_is_converted_to_a_fully_hardened_derivation_path() {
+    // Arrange
+    let path = Cap26Path {
+        network_id: 1,
+        entity_kind: Cap26EntityKind::Account,
+        key_kind: Cap26KeyKind::TransactionSigning,
+        index: 5,
+    };
+
+    // Act
+    let derivation_path = DerivationPath::try_from(path).unwrap();
+
+    // Assert
+    assert_eq!(derivation_path.to_string(), "m/44H/1022H/1H/525H/1460H/5H");
+    assert_eq!(
+        "m/44'/1022'/1'/525'/1460'/5'".parse::<DerivationPath>(),
+        Ok(derivation_path)
+    );
+}
+
+#[test]
+fn cap26_path_with_an_index_beyond_the_hardened_offset_is_rejected() {
+    // Arrange
+    let path = Cap26Path {
+        network_id: 1,
+        entity_kind: Cap26EntityKind::Account,
+        key_kind: Cap26KeyKind::TransactionSigning,
+        index: HARDENED_OFFSET,
+    };
+
+    // Act
+    let derivation_path = DerivationPath::try_from(path);
+
+    // Assert
+    assert_eq!(
+        derivation_path,
+        Err(KeyDerivationError::InvalidDerivationPath)
+    );
+}
+
+/// The expected key and address were computed independently of the toolkit
+/// by following BIP-39, SLIP-10 and the preallocated account address
+/// derivation.
+#[test]
+fn first_mainnet_account_is_derived_from_the_mnemonic_along_its_cap26_path() {
+    // Arrange
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let path = DerivationPath::try_from(Cap26Path {
+        network_id: NetworkDefinition::mainnet().id,
+        entity_kind: Cap26EntityKind::Account,
+        key_kind: Cap26KeyKind::TransactionSigning,
+        index: 0,
+    })
+    .unwrap();
+
+    // Act
+    let seed = mnemonic_to_seed(mnemonic, "").unwrap();
+    let private_key =
+        derive_private_key(&seed, CurveType::Ed25519, &path).unwrap();
+
+    // Assert
+    let PublicKey::Ed25519(public_key) = private_key.public_key() else {
+        panic!("Not an Ed25519 public key")
+    };
+    assert_eq!(
+        hex::encode(public_key.to_vec()),
+        "a5f40a306cd319049700e1dbbe8e446d17146926534e389ee9365f506549678d"
+    );
+    let account_address =
+        ComponentAddress::preallocated_account_from_public_key(&public_key);
+    assert_eq!(
+        AddressBech32Encoder::new(&NetworkDefinition::mainnet())
+            .encode(account_address.as_bytes())
+            .unwrap(),
+        "account_rdx129xas2sw4fn5amyce4zun43amw73d6lu7htcxlvghd3egalnf2uv66"
+    );
+}
+
+fn private_key_bytes(private_key: &PrivateKey) -> Vec<u8> {
+    match private_key {
+        PrivateKey::Secp256k1(private_key) => private_key.to_bytes(),
+        PrivateKey::Ed25519(private_key) => private_key.to_bytes(),
+    }
+}
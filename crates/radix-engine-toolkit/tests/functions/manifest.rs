@@ -16,6 +16,10 @@
 // under the License.
 
 use crate::prelude::*;
+use radix_engine_toolkit::functions::transaction_v1::instructions::InstructionValidationError;
+use radix_transactions::manifest::{
+    DropAllProofs, ManifestValidationError, ReturnToWorktop,
+};
 
 #[test]
 fn manifest_can_be_compiled() {
@@ -68,3 +72,42 @@ fn manifest_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn manifest_validation_error_is_located_at_the_failing_instruction() {
+    // Arrange
+    let manifest = TransactionManifestV1 {
+        instructions: vec![
+            InstructionV1::DropAllProofs(DropAllProofs),
+            InstructionV1::ReturnToWorktop(ReturnToWorktop {
+                bucket_id: ManifestBucket(0),
+            }),
+        ],
+        blobs: Default::default(),
+        object_names: Default::default(),
+    };
+
+    // Act
+    let validation_result =
+        radix_engine_toolkit::functions::transaction_v1::instructions::statically_validate(
+            &manifest.instructions,
+            &manifest.blobs,
+            &NetworkDefinition::mainnet(),
+        );
+
+    // Assert
+    let Err(InstructionValidationError::LocatedManifestValidationError(
+        LocatedManifestValidationError {
+            instruction_index,
+            error,
+        },
+    )) = validation_result
+    else {
+        panic!("Expected a located manifest validation error: {validation_result:?}")
+    };
+    assert_eq!(instruction_index, Some(InstructionIndex::of(1)));
+    assert_eq!(
+        error,
+        ManifestValidationError::BucketNotYetCreated(ManifestBucket(0))
+    );
+}
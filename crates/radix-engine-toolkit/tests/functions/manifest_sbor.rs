@@ -17,6 +17,7 @@
 
 use crate::prelude::*;
 use sbor::representations::*;
+use sbor_json::scrypto::programmatic::value::ProgrammaticScryptoValue;
 
 #[test]
 fn manifest_value_can_be_encoded() {
@@ -61,6 +62,7 @@ fn manifest_value_can_be_represented_as_a_string() {
         ManifestSborStringRepresentation::JSON(SerializationMode::Model),
         ManifestSborStringRepresentation::JSON(SerializationMode::Natural),
         ManifestSborStringRepresentation::JSON(SerializationMode::Programmatic),
+        ManifestSborStringRepresentation::ProgrammaticJson { network_id: 0xF2 },
     ];
     let schema_params = [None, Some((local_type_id, schema.v1().clone()))];
     let bech32_encoder = AddressBech32Encoder::for_simulator();
@@ -82,7 +84,217 @@ fn manifest_value_can_be_represented_as_a_string() {
     }
 }
 
+#[test]
+fn manifest_value_programmatic_json_can_be_encoded_back_to_manifest_sbor() {
+    // Arrange
+    let value = (
+        ManifestBucket(1),
+        ManifestExpression::EntireWorktop,
+        ManifestAddress::Static(XRD.into_node_id()),
+    );
+    let encoded_value = manifest_encode(&value).unwrap();
+    let programmatic_json =
+        radix_engine_toolkit::functions::manifest_sbor::decode_to_string_representation(
+            &encoded_value,
+            ManifestSborStringRepresentation::ProgrammaticJson {
+                network_id: 0xF2,
+            },
+            &AddressBech32Encoder::for_simulator(),
+            None,
+        )
+        .unwrap();
+
+    // Act
+    let encoded =
+        radix_engine_toolkit::functions::manifest_sbor::encode_string_representation(
+            &programmatic_json,
+        );
+
+    // Assert
+    assert_eq!(encoded.unwrap(), encoded_value);
+}
+
+#[test]
+fn natural_json_can_be_encoded_to_manifest_sbor_with_a_schema() {
+    // Arrange
+    let value = Deposit {
+        amount: dec!("10.5"),
+        notes: vec![0xca, 0xfe],
+    };
+    let (local_type_id, schema) =
+        generate_full_schema_from_single_type::<Deposit, ScryptoCustomSchema>();
+    let json = r#"{ "amount": "10.5", "notes": "cafe" }"#;
+
+    // Act
+    let encoded =
+        radix_engine_toolkit::functions::manifest_sbor::encode_json_with_schema(
+            json,
+            local_type_id,
+            schema.v1(),
+        );
+
+    // Assert
+    assert_eq!(encoded.unwrap(), manifest_encode(&value).unwrap());
+}
+
+#[test]
+fn diff_of_manifest_payloads_reports_changed_addresses_as_references() {
+    // Arrange
+    let old_value = manifest_encode(&(
+        ManifestBucket(0),
+        ManifestAddress::Named(ManifestNamedAddress(0)),
+        ManifestAddress::Static(XRD.into_node_id()),
+    ))
+    .unwrap();
+    let new_value = manifest_encode(&(
+        ManifestBucket(0),
+        ManifestAddress::Named(ManifestNamedAddress(0)),
+        ManifestAddress::Static(ACCOUNT_OWNER_BADGE.into_node_id()),
+    ))
+    .unwrap();
+
+    // Act
+    let changes = radix_engine_toolkit::functions::manifest_sbor::diff(
+        old_value, new_value, 0xF2, None,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        changes,
+        vec![SborChange {
+            path: vec![SborPathElement::FieldIndex(2)],
+            old_value: Some(programmatic(&Reference(XRD.into_node_id()))),
+            new_value: Some(programmatic(&Reference(
+                ACCOUNT_OWNER_BADGE.into_node_id()
+            ))),
+        }]
+    );
+}
+
+#[test]
+fn diff_of_manifest_payloads_with_changed_buckets_can_not_be_represented() {
+    // Arrange
+    let old_value = manifest_encode(&(ManifestBucket(0),)).unwrap();
+    let new_value = manifest_encode(&(ManifestBucket(1),)).unwrap();
+
+    // Act
+    let changes = radix_engine_toolkit::functions::manifest_sbor::diff(
+        old_value, new_value, 0xF2, None,
+    );
+
+    // Assert
+    assert!(matches!(
+        changes,
+        Err(radix_engine_toolkit::functions::manifest_sbor::ManifestSborError::ValueNotRepresentableInScrypto)
+    ));
+}
+
+#[test]
+fn diff_of_manifest_payloads_reports_added_and_removed_map_entries() {
+    // Arrange
+    let old_value = manifest_encode(&indexmap!(
+        1u8 => "one".to_owned(),
+        2u8 => "two".to_owned(),
+    ))
+    .unwrap();
+    let new_value = manifest_encode(&indexmap!(
+        2u8 => "two".to_owned(),
+        3u8 => "three".to_owned(),
+    ))
+    .unwrap();
+
+    // Act
+    let changes = radix_engine_toolkit::functions::manifest_sbor::diff(
+        old_value, new_value, 0xF2, None,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        changes,
+        vec![
+            SborChange {
+                path: vec![SborPathElement::MapKey(programmatic(&1u8))],
+                old_value: Some(programmatic(&"one".to_owned())),
+                new_value: None,
+            },
+            SborChange {
+                path: vec![SborPathElement::MapKey(programmatic(&3u8))],
+                old_value: None,
+                new_value: Some(programmatic(&"three".to_owned())),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_of_manifest_payloads_reports_a_change_of_enum_variant_as_a_whole() {
+    // Arrange
+    let old_value = manifest_encode(&Withdrawal {
+        kind: WithdrawalKind::Amount(dec!("10")),
+        amount_limit: dec!("100"),
+    })
+    .unwrap();
+    let new_value = manifest_encode(&Withdrawal {
+        kind: WithdrawalKind::All,
+        amount_limit: dec!("100"),
+    })
+    .unwrap();
+    let (local_type_id, schema) = generate_full_schema_from_single_type::<
+        Withdrawal,
+        ScryptoCustomSchema,
+    >();
+
+    // Act
+    let changes = radix_engine_toolkit::functions::manifest_sbor::diff(
+        old_value,
+        new_value,
+        0xF2,
+        Some((local_type_id, schema.v1().clone())),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        changes,
+        vec![SborChange {
+            path: vec![SborPathElement::FieldName("kind".to_owned())],
+            old_value: Some(programmatic(&WithdrawalKind::Amount(dec!("10")))),
+            new_value: Some(programmatic(&WithdrawalKind::All)),
+        }]
+    );
+}
+
+/// Converts the value into the representation that the diff reports it in.
+fn programmatic<T>(value: &T) -> ProgrammaticScryptoValue
+where
+    T: ScryptoEncode + ?Sized,
+{
+    let value = scrypto_decode::<ScryptoValue>(&scrypto_encode(value).unwrap())
+        .unwrap();
+    ProgrammaticScryptoValue::from_scrypto_value(&value, 0xF2)
+}
+
 #[derive(ManifestSbor, ScryptoSbor)]
 struct MyStruct {
     value: bool,
 }
+
+#[derive(ManifestSbor, ScryptoSbor)]
+struct Deposit {
+    amount: Decimal,
+    notes: Vec<u8>,
+}
+
+#[derive(ManifestSbor, ScryptoSbor)]
+struct Withdrawal {
+    kind: WithdrawalKind,
+    amount_limit: Decimal,
+}
+
+#[derive(ManifestSbor, ScryptoSbor)]
+enum WithdrawalKind {
+    All,
+    Amount(Decimal),
+}
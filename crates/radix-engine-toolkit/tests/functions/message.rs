@@ -0,0 +1,239 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::message;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn encrypted_message_v2_can_be_decrypted_by_all_recipients() {
+    // Arrange
+    let plaintext = plaintext();
+    let recipients = [
+        PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(1).unwrap()),
+        PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(2).unwrap()),
+        PrivateKey::Ed25519(Ed25519PrivateKey::from_u64(1).unwrap()),
+        PrivateKey::Ed25519(Ed25519PrivateKey::from_u64(2).unwrap()),
+    ];
+    let public_keys = recipients
+        .iter()
+        .map(|private_key| private_key.public_key())
+        .collect::<Vec<_>>();
+
+    // Act
+    let encrypted =
+        message::encrypt_message_v2(&plaintext, &public_keys).unwrap();
+
+    // Assert
+    assert_eq!(encrypted.decryptors_by_curve.len(), 2);
+    for private_key in recipients.iter() {
+        let decrypted =
+            message::decrypt_message_v2(&encrypted, private_key).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+}
+
+#[test]
+fn encrypted_message_v1_can_be_decrypted_by_all_recipients() {
+    // Arrange
+    let plaintext = plaintext();
+    let recipients = [
+        PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(1).unwrap()),
+        PrivateKey::Ed25519(Ed25519PrivateKey::from_u64(1).unwrap()),
+    ];
+    let public_keys = recipients
+        .iter()
+        .map(|private_key| private_key.public_key())
+        .collect::<Vec<_>>();
+
+    // Act
+    let encrypted =
+        message::encrypt_message_v1(&plaintext, &public_keys).unwrap();
+
+    // Assert
+    for private_key in recipients.iter() {
+        let decrypted =
+            message::decrypt_message_v1(&encrypted, private_key).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+}
+
+#[test]
+fn encrypted_message_can_not_be_decrypted_by_a_non_recipient() {
+    // Arrange
+    let plaintext = plaintext();
+    let recipient = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let non_recipient =
+        PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(2).unwrap());
+    let encrypted =
+        message::encrypt_message_v2(&plaintext, &[recipient.into()]).unwrap();
+
+    // Act
+    let decrypted = message::decrypt_message_v2(&encrypted, &non_recipient);
+
+    // Assert
+    assert!(matches!(
+        decrypted,
+        Err(message::MessageDecryptionError::NotARecipient)
+    ));
+}
+
+// The known-answer vectors below were produced outside of the toolkit by
+// following the scheme documented on `EncryptedMessageV1` with Python's
+// `cryptography` and `hashlib`: ECDH with the ephemeral secp256k1 key `0x22`
+// or X25519 with the ephemeral ed25519 key `[0x22; 32]`, an HKDF-Blake2b-256
+// key-encryption key with no salt and no info, AES-256-KeyWrap of a message key
+// of repeated `0x33` bytes and AES-GCM with the nonce `000102..0b`. The
+// plaintext is a `text/plain` "Hello Radix" message.
+
+#[test]
+fn secp256k1_known_answer_vector_decrypts_as_message_v1() {
+    // Arrange
+    let private_key =
+        PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(0x11).unwrap());
+    let encrypted = EncryptedMessageV1 {
+        encrypted: AesGcmPayload(hex::decode("000102030405060708090a0bb6b1848c502c0699bbe7f126e34d59b633ea9fa2d09ee2d1d8f89313b1d561fe0754490a7e48ca9a1a33b231dac835").unwrap()),
+        decryptors_by_curve: indexmap! {
+            CurveType::Secp256k1 => DecryptorsByCurve::Secp256k1 {
+                dh_ephemeral_public_key: secp256k1_public_key("031be68a5a028f2601d0e80d468c344ba331d611b96c358b6032e8b4da0547fc11"),
+                decryptors: indexmap! {
+                    fingerprint("adad0387c7ce300e") => AesWrapped128BitKey(bytes("fd2228bad69ede6ec393bbb4b10c874555b6693c3868e971")),
+                },
+            },
+        },
+    };
+
+    // Act
+    let decrypted = message::decrypt_message_v1(&encrypted, &private_key);
+
+    // Assert
+    assert_eq!(
+        private_key.public_key(),
+        PublicKey::Secp256k1(secp256k1_public_key("03defdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34"))
+    );
+    assert_eq!(decrypted.unwrap(), known_answer_plaintext());
+}
+
+#[test]
+fn secp256k1_known_answer_vector_decrypts_as_message_v2() {
+    // Arrange
+    let private_key =
+        PrivateKey::Secp256k1(Secp256k1PrivateKey::from_u64(0x11).unwrap());
+    let encrypted = EncryptedMessageV2 {
+        encrypted: AesGcmPayload(hex::decode("000102030405060708090a0b55860c36e1146b836f218e082502dcbed74ac64fcd4ab34f5d2daf299cc7382f336447e8b09fc75c98132c46350b57").unwrap()),
+        decryptors_by_curve: indexmap! {
+            CurveType::Secp256k1 => DecryptorsByCurveV2::Secp256k1 {
+                dh_ephemeral_public_key: secp256k1_public_key("031be68a5a028f2601d0e80d468c344ba331d611b96c358b6032e8b4da0547fc11"),
+                decryptors: indexmap! {
+                    fingerprint("adad0387c7ce300e") => AesWrapped256BitKey(bytes("c2a1c1cd9035c69e18515e4d22c9040457e41ba8bdeb8e6fa61adecaa0537ae9cce98b648ab7b8bb")),
+                },
+            },
+        },
+    };
+
+    // Act
+    let decrypted = message::decrypt_message_v2(&encrypted, &private_key);
+
+    // Assert
+    assert_eq!(decrypted.unwrap(), known_answer_plaintext());
+}
+
+#[test]
+fn ed25519_known_answer_vector_decrypts_as_message_v1() {
+    // Arrange
+    let private_key = PrivateKey::Ed25519(
+        Ed25519PrivateKey::from_bytes(&[0x11; 32]).unwrap(),
+    );
+    let encrypted = EncryptedMessageV1 {
+        encrypted: AesGcmPayload(hex::decode("000102030405060708090a0bb6b1848c502c0699bbe7f126e34d59b633ea9fa2d09ee2d1d8f89313b1d561fe0754490a7e48ca9a1a33b231dac835").unwrap()),
+        decryptors_by_curve: indexmap! {
+            CurveType::Ed25519 => DecryptorsByCurve::Ed25519 {
+                dh_ephemeral_public_key: ed25519_public_key("a09aa5f47a6759802ff955f8dc2d2a14a5c99d23be97f864127ff9383455a4f0"),
+                decryptors: indexmap! {
+                    fingerprint("568e1a32ce6a7886") => AesWrapped128BitKey(bytes("99c541beabd1dc6fef8c23fb5e8e1ac43a227d9d8bbdee22")),
+                },
+            },
+        },
+    };
+
+    // Act
+    let decrypted = message::decrypt_message_v1(&encrypted, &private_key);
+
+    // Assert
+    assert_eq!(
+        private_key.public_key(),
+        PublicKey::Ed25519(ed25519_public_key(
+            "d04ab232742bb4ab3a1368bd4615e4e6d0224ab71a016baf8520a332c9778737"
+        ))
+    );
+    assert_eq!(decrypted.unwrap(), known_answer_plaintext());
+}
+
+#[test]
+fn ed25519_known_answer_vector_decrypts_as_message_v2() {
+    // Arrange
+    let private_key = PrivateKey::Ed25519(
+        Ed25519PrivateKey::from_bytes(&[0x11; 32]).unwrap(),
+    );
+    let encrypted = EncryptedMessageV2 {
+        encrypted: AesGcmPayload(hex::decode("000102030405060708090a0b55860c36e1146b836f218e082502dcbed74ac64fcd4ab34f5d2daf299cc7382f336447e8b09fc75c98132c46350b57").unwrap()),
+        decryptors_by_curve: indexmap! {
+            CurveType::Ed25519 => DecryptorsByCurveV2::Ed25519 {
+                dh_ephemeral_public_key: ed25519_public_key("a09aa5f47a6759802ff955f8dc2d2a14a5c99d23be97f864127ff9383455a4f0"),
+                decryptors: indexmap! {
+                    fingerprint("568e1a32ce6a7886") => AesWrapped256BitKey(bytes("c6d92ed54e7690e61d4f06d427baa850b0a5f9f9af2ea52197559795e60fce8d5c047a4a0151955e")),
+                },
+            },
+        },
+    };
+
+    // Act
+    let decrypted = message::decrypt_message_v2(&encrypted, &private_key);
+
+    // Assert
+    assert_eq!(decrypted.unwrap(), known_answer_plaintext());
+}
+
+fn known_answer_plaintext() -> PlaintextMessageV1 {
+    PlaintextMessageV1 {
+        mime_type: "text/plain".to_owned(),
+        message: MessageContentsV1::String("Hello Radix".to_owned()),
+    }
+}
+
+fn bytes<const N: usize>(hex: &str) -> [u8; N] {
+    hex::decode(hex).unwrap().try_into().unwrap()
+}
+
+fn fingerprint(hex: &str) -> PublicKeyFingerprint {
+    PublicKeyFingerprint(bytes(hex))
+}
+
+fn secp256k1_public_key(hex: &str) -> Secp256k1PublicKey {
+    Secp256k1PublicKey(bytes(hex))
+}
+
+fn ed25519_public_key(hex: &str) -> Ed25519PublicKey {
+    Ed25519PublicKey(bytes(hex))
+}
+
+fn plaintext() -> PlaintextMessageV1 {
+    PlaintextMessageV1 {
+        mime_type: "text/plain".to_owned(),
+        message: MessageContentsV1::String("Hello World!".to_owned()),
+    }
+}
@@ -18,10 +18,17 @@
 //! This module tests the toolkit's pure function that it exposes to its clients
 
 mod derive;
+mod events;
+mod fee_estimation;
 mod information;
 mod intent;
+mod intent_header_v2;
+mod key_derivation;
 mod manifest;
 mod manifest_sbor;
+mod message;
 mod notarized_transaction;
+mod schema_codegen;
 mod scrypto_sbor;
+mod signed_partial_transaction_v2;
 mod subintent_manifest_v2;
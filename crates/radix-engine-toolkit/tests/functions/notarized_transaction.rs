@@ -83,3 +83,64 @@ fn notarized_transaction_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn notarized_transaction_signatures_can_be_verified() {
+    // Arrange
+    let transaction = notarized_transaction();
+
+    // Act
+    let verifications =
+        radix_engine_toolkit::functions::transaction_v1::notarized_transaction::verify_signatures(
+            &transaction,
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(verifications.len(), 4);
+    assert!(verifications
+        .iter()
+        .all(|verification| verification.is_valid_for_signer));
+    assert_eq!(
+        verifications
+            .iter()
+            .map(|verification| verification.signer)
+            .collect::<Vec<_>>(),
+        vec![
+            Some(private_key2().public_key().into()),
+            Some(private_key3().public_key().into()),
+            Some(private_key4().public_key().into()),
+            Some(private_key1().public_key().into()),
+        ]
+    );
+    assert_eq!(
+        verifications.last().map(|verification| verification.kind),
+        Some(SignatureKind::Notary)
+    );
+}
+
+#[test]
+fn notarized_transaction_signature_over_another_hash_is_invalid() {
+    // Arrange
+    let mut transaction = notarized_transaction();
+    transaction.notary_signature =
+        NotarySignatureV1(private_key1().sign(&hash([0u8])).into());
+
+    // Act
+    let verifications =
+        radix_engine_toolkit::functions::transaction_v1::notarized_transaction::verify_signatures(
+            &transaction,
+        )
+        .unwrap();
+
+    // Assert
+    let [intent_verifications @ .., notary_verification] =
+        verifications.as_slice()
+    else {
+        panic!("Expected the notary signature to be verified")
+    };
+    assert!(intent_verifications
+        .iter()
+        .all(|verification| verification.is_valid_for_signer));
+    assert!(!notary_verification.is_valid_for_signer);
+}
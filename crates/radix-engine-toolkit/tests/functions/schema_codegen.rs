@@ -0,0 +1,409 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_blueprint_schema_init::{FunctionSchemaInit, ReceiverInfo, TypeRef};
+use radix_engine::blueprints::account::AccountNativePackage;
+use radix_engine_toolkit::functions::schema_codegen::*;
+use sbor::basic_well_known_types;
+
+#[test]
+fn rust_bindings_can_be_generated_for_the_account_blueprint() {
+    // Arrange
+    let package_definition = AccountNativePackage::definition();
+
+    // Act
+    let bindings =
+        generate(&package_definition, SchemaCodegenLanguage::Rust).unwrap();
+
+    // Assert
+    let account = bindings.get(ACCOUNT_BLUEPRINT).unwrap();
+    assert!(account.contains("pub struct AccountWithdrawInput {"));
+    assert!(account.contains("    pub resource_address: ResourceAddress,"));
+    assert!(account.contains("    pub amount: Decimal,"));
+    assert!(account.contains("pub trait AccountManifestBuilderExt: Sized {"));
+    assert!(account.contains("    fn account_withdraw("));
+}
+
+#[test]
+fn typescript_bindings_can_be_generated_for_the_account_blueprint() {
+    // Arrange
+    let package_definition = AccountNativePackage::definition();
+
+    // Act
+    let bindings =
+        generate(&package_definition, SchemaCodegenLanguage::TypeScript)
+            .unwrap();
+
+    // Assert
+    let account = bindings.get(ACCOUNT_BLUEPRINT).unwrap();
+    assert!(account.contains("export interface AccountWithdrawInput {"));
+    assert!(account.contains("    amount: string;"));
+}
+
+#[test]
+fn kotlin_bindings_include_typed_manifest_builder_methods() {
+    // Arrange
+    let package_definition = AccountNativePackage::definition();
+
+    // Act
+    let bindings =
+        generate(&package_definition, SchemaCodegenLanguage::Kotlin).unwrap();
+
+    // Assert
+    let account = bindings.get(ACCOUNT_BLUEPRINT).unwrap();
+    assert!(account.contains("data class AccountWithdrawInput("));
+    assert!(account.contains("fun ManifestV2Builder.accountWithdraw("));
+    assert!(account.contains("    \"withdraw\",\n"));
+}
+
+#[test]
+fn rust_bindings_of_the_forest_blueprint_match_the_snapshot() {
+    // Arrange
+    let blueprint_definition = forest_blueprint_definition();
+
+    // Act
+    let bindings = generate_blueprint(
+        "Forest",
+        &blueprint_definition,
+        SchemaCodegenLanguage::Rust,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        bindings,
+        r#"// Generated from the schema of the Forest blueprint.
+
+use radix_transactions::prelude::{
+    BuildableManifest, InstructionV1, ManifestBuilder,
+    ReferencedManifestGlobalAddress,
+};
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct PlantInput {
+    pub tree: Tree,
+    pub count: u32,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct Tree {
+    pub value: u32,
+    pub children: Vec<Tree>,
+    pub shape: Shape,
+    pub bounds: TreeBounds,
+    pub parent: Option<Vec<Tree>>,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Empty,
+    Circle(u32),
+    Rectangle {
+        width: u32,
+        height: u32,
+    },
+    #[sbor(discriminator(5))]
+    Subtree(Vec<Tree>),
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct TreeBounds(pub u32, pub TreeBoundsField1);
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct TreeBoundsField1(pub String, pub Vec<Tree>);
+
+pub trait ForestManifestBuilderExt: Sized {
+    fn forest_plant(
+        self,
+        address: impl ReferencedManifestGlobalAddress,
+        tree: Tree,
+        count: u32,
+    ) -> Self;
+}
+
+impl<M: BuildableManifest> ForestManifestBuilderExt for ManifestBuilder<M>
+where
+    M::Instruction: From<InstructionV1>,
+{
+    fn forest_plant(
+        self,
+        address: impl ReferencedManifestGlobalAddress,
+        tree: Tree,
+        count: u32,
+    ) -> Self {
+        self.call_method(address, "plant", (tree, count))
+    }
+}
+"#
+    );
+}
+
+#[test]
+fn typescript_bindings_of_the_forest_blueprint_match_the_snapshot() {
+    // Arrange
+    let blueprint_definition = forest_blueprint_definition();
+
+    // Act
+    let bindings = generate_blueprint(
+        "Forest",
+        &blueprint_definition,
+        SchemaCodegenLanguage::TypeScript,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        bindings,
+        r#"// Generated from the schema of the Forest blueprint.
+
+export interface PlantInput {
+    tree: Tree;
+    count: number;
+}
+
+export interface Tree {
+    value: number;
+    children: Tree[];
+    shape: Shape;
+    bounds: TreeBounds;
+    parent: Tree[] | null;
+}
+
+export type Shape =
+    | { variant: "Empty" }
+    | { variant: "Circle"; fields: [number] }
+    | { variant: "Rectangle"; fields: { width: number; height: number } }
+    | { variant: "Subtree"; fields: [Tree[]] };
+
+export type TreeBounds = [number, TreeBoundsField1];
+
+export type TreeBoundsField1 = [string, Tree[]];
+"#
+    );
+}
+
+#[test]
+fn kotlin_bindings_of_the_forest_blueprint_match_the_snapshot() {
+    // Arrange
+    let blueprint_definition = forest_blueprint_definition();
+
+    // Act
+    let bindings = generate_blueprint(
+        "Forest",
+        &blueprint_definition,
+        SchemaCodegenLanguage::Kotlin,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        bindings,
+        r#"// Generated from the schema of the Forest blueprint.
+
+import com.radixdlt.ret.*
+import java.math.BigInteger
+
+data class PlantInput(
+    val tree: Tree,
+    val count: UInt,
+) {
+    fun toManifestBuilderValue(): ManifestBuilderValue =
+        ManifestBuilderValue.TupleValue(listOf(tree.toManifestBuilderValue(), ManifestBuilderValue.U32Value(count)))
+}
+
+data class Tree(
+    val value: UInt,
+    val children: List<Tree>,
+    val shape: Shape,
+    val bounds: TreeBounds,
+    val parent: List<Tree>?,
+) {
+    fun toManifestBuilderValue(): ManifestBuilderValue =
+        ManifestBuilderValue.TupleValue(listOf(ManifestBuilderValue.U32Value(value), ManifestBuilderValue.ArrayValue(ManifestBuilderValueKind.TUPLE_VALUE, children.map { element0 -> element0.toManifestBuilderValue() }), shape.toManifestBuilderValue(), bounds.toManifestBuilderValue(), parent?.let { value0 -> ManifestBuilderValue.EnumValue(1u, listOf(ManifestBuilderValue.ArrayValue(ManifestBuilderValueKind.TUPLE_VALUE, value0.map { element1 -> element1.toManifestBuilderValue() }))) } ?: ManifestBuilderValue.EnumValue(0u, listOf())))
+}
+
+sealed class Shape {
+    abstract fun toManifestBuilderValue(): ManifestBuilderValue
+
+    object Empty : Shape() {
+        override fun toManifestBuilderValue(): ManifestBuilderValue =
+            ManifestBuilderValue.EnumValue(0u, listOf())
+    }
+
+    data class Circle(val field0: UInt) : Shape() {
+        override fun toManifestBuilderValue(): ManifestBuilderValue =
+            ManifestBuilderValue.EnumValue(1u, listOf(ManifestBuilderValue.U32Value(field0)))
+    }
+
+    data class Rectangle(val width: UInt, val height: UInt) : Shape() {
+        override fun toManifestBuilderValue(): ManifestBuilderValue =
+            ManifestBuilderValue.EnumValue(2u, listOf(ManifestBuilderValue.U32Value(width), ManifestBuilderValue.U32Value(height)))
+    }
+
+    data class Subtree(val field0: List<Tree>) : Shape() {
+        override fun toManifestBuilderValue(): ManifestBuilderValue =
+            ManifestBuilderValue.EnumValue(5u, listOf(ManifestBuilderValue.ArrayValue(ManifestBuilderValueKind.TUPLE_VALUE, field0.map { element0 -> element0.toManifestBuilderValue() })))
+    }
+}
+
+data class TreeBounds(
+    val field0: UInt,
+    val field1: TreeBoundsField1,
+) {
+    fun toManifestBuilderValue(): ManifestBuilderValue =
+        ManifestBuilderValue.TupleValue(listOf(ManifestBuilderValue.U32Value(field0), field1.toManifestBuilderValue()))
+}
+
+data class TreeBoundsField1(
+    val field0: String,
+    val field1: List<Tree>,
+) {
+    fun toManifestBuilderValue(): ManifestBuilderValue =
+        ManifestBuilderValue.TupleValue(listOf(ManifestBuilderValue.StringValue(field0), ManifestBuilderValue.ArrayValue(ManifestBuilderValueKind.TUPLE_VALUE, field1.map { element0 -> element0.toManifestBuilderValue() })))
+}
+
+fun ManifestV2Builder.forestPlant(
+    address: ManifestBuilderAddress,
+    tree: Tree,
+    count: UInt,
+): ManifestV2Builder = callMethod(
+    address,
+    "plant",
+    listOf(
+        tree.toManifestBuilderValue(),
+        ManifestBuilderValue.U32Value(count),
+    ),
+)
+"#
+    );
+}
+
+/// A blueprint with a registered type which is a tree that references itself
+/// through arrays and options, an enum with a variant of a custom discriminator
+/// and tuples with unnamed fields nested in one another, and with a `plant`
+/// method taking a tree and a count.
+fn forest_blueprint_definition() -> BlueprintDefinitionInit {
+    let local = LocalTypeId::SchemaLocalIndex;
+    let u32_type = LocalTypeId::WellKnown(basic_well_known_types::U32_TYPE);
+    let string_type =
+        LocalTypeId::WellKnown(basic_well_known_types::STRING_TYPE);
+
+    let schema = SchemaV1::<ScryptoCustomSchema> {
+        type_kinds: vec![
+            // 0: Tree
+            TypeKind::Tuple {
+                field_types: vec![
+                    u32_type,
+                    local(1),
+                    local(2),
+                    local(3),
+                    local(5),
+                ],
+            },
+            // 1: Vec<Tree>
+            TypeKind::Array {
+                element_type: local(0),
+            },
+            // 2: Shape
+            TypeKind::Enum {
+                variants: indexmap! {
+                    0 => vec![],
+                    1 => vec![u32_type],
+                    2 => vec![u32_type, u32_type],
+                    5 => vec![local(1)],
+                },
+            },
+            // 3: (u32, (String, Vec<Tree>))
+            TypeKind::Tuple {
+                field_types: vec![u32_type, local(4)],
+            },
+            // 4: (String, Vec<Tree>)
+            TypeKind::Tuple {
+                field_types: vec![string_type, local(1)],
+            },
+            // 5: Option<Vec<Tree>>
+            TypeKind::Enum {
+                variants: indexmap! {
+                    0 => vec![],
+                    1 => vec![local(1)],
+                },
+            },
+            // 6: The input of the plant method, (Tree, u32)
+            TypeKind::Tuple {
+                field_types: vec![local(0), u32_type],
+            },
+        ],
+        type_metadata: vec![
+            TypeMetadata::struct_fields(
+                "Tree",
+                &["value", "children", "shape", "bounds", "parent"],
+            ),
+            TypeMetadata::unnamed(),
+            TypeMetadata::enum_variants(
+                "Shape",
+                indexmap! {
+                    0 => TypeMetadata::no_child_names("Empty"),
+                    1 => TypeMetadata::no_child_names("Circle"),
+                    2 => TypeMetadata::struct_fields(
+                        "Rectangle",
+                        &["width", "height"],
+                    ),
+                    5 => TypeMetadata::no_child_names("Subtree"),
+                },
+            ),
+            TypeMetadata::unnamed(),
+            TypeMetadata::unnamed(),
+            TypeMetadata::enum_variants(
+                "Option",
+                indexmap! {
+                    0 => TypeMetadata::no_child_names("None"),
+                    1 => TypeMetadata::no_child_names("Some"),
+                },
+            ),
+            TypeMetadata {
+                type_name: None,
+                child_names: Some(ChildNames::NamedFields(vec![
+                    "tree".into(),
+                    "count".into(),
+                ])),
+            },
+        ],
+        type_validations: vec![TypeValidation::None; 7],
+    };
+
+    let mut blueprint_definition = BlueprintDefinitionInit::default();
+    blueprint_definition.schema.schema = VersionedScryptoSchema::from(schema);
+    blueprint_definition
+        .schema
+        .types
+        .type_schema
+        .insert("Tree".to_owned(), local(0));
+    blueprint_definition.schema.functions.functions.insert(
+        "plant".to_owned(),
+        FunctionSchemaInit {
+            receiver: Some(ReceiverInfo::normal_ref_mut()),
+            input: TypeRef::Static(local(6)),
+            output: TypeRef::Static(LocalTypeId::WellKnown(
+                basic_well_known_types::UNIT_TYPE,
+            )),
+            export: "Forest_plant".to_owned(),
+        },
+    );
+    blueprint_definition
+}
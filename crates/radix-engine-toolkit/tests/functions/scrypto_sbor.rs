@@ -16,11 +16,16 @@
 // under the License.
 
 use radix_common::prelude::{
-    scrypto_encode, AddressBech32Encoder, ScryptoCustomSchema, ScryptoValue,
+    scrypto_encode, AddressBech32Encoder, ManifestAddress, ManifestCustomValue,
+    ManifestValue, Own, Reference, ScryptoCustomSchema, ScryptoCustomValue,
+    ScryptoValue, XRD,
 };
 use radix_common::ScryptoSbor;
+use radix_engine_toolkit::prelude::{SborChange, SborPathElement};
 use sbor::generate_full_schema_from_single_type;
 use sbor::representations::SerializationMode;
+use sbor_json::scrypto::programmatic::value::ProgrammaticScryptoValue;
+use scrypto::prelude::dec;
 
 #[test]
 fn scrypto_value_can_be_encoded() {
@@ -85,7 +90,234 @@ fn scrypto_value_can_be_represented_as_a_string() {
     }
 }
 
+#[test]
+fn scrypto_value_can_be_converted_to_a_manifest_value() {
+    // Arrange
+    let value = ScryptoValue::Tuple {
+        fields: vec![
+            ScryptoValue::String {
+                value: "Hello World".to_owned(),
+            },
+            ScryptoValue::Custom {
+                value: ScryptoCustomValue::Reference(Reference(
+                    XRD.into_node_id(),
+                )),
+            },
+            ScryptoValue::Custom {
+                value: ScryptoCustomValue::Decimal(dec!(10)),
+            },
+        ],
+    };
+
+    // Act
+    let manifest_value = radix_engine_toolkit::functions::scrypto_sbor::scrypto_value_to_manifest_value(&value);
+
+    // Assert
+    let Ok(ManifestValue::Tuple { fields }) = manifest_value else {
+        panic!("Conversion must succeed into a tuple")
+    };
+    assert_eq!(fields.len(), 3);
+    assert_eq!(
+        fields[0],
+        ManifestValue::String {
+            value: "Hello World".to_owned()
+        }
+    );
+    assert_eq!(
+        fields[1],
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Address(ManifestAddress::Static(
+                XRD.into_node_id()
+            ))
+        }
+    );
+}
+
+#[test]
+fn owned_scrypto_values_can_not_be_converted_to_manifest_values() {
+    // Arrange
+    let value = ScryptoValue::Custom {
+        value: ScryptoCustomValue::Own(Own(XRD.into_node_id())),
+    };
+
+    // Act
+    let manifest_value = radix_engine_toolkit::functions::scrypto_sbor::scrypto_value_to_manifest_value(&value);
+
+    // Assert
+    assert!(matches!(
+        manifest_value,
+        Err(radix_engine_toolkit::functions::scrypto_sbor::ScryptoSborError::ValueNotRepresentableInManifest)
+    ));
+}
+
+#[test]
+fn natural_json_with_named_fields_can_be_encoded_with_a_schema() {
+    // Arrange
+    let value = Order {
+        side: Side::Sell { price: 10 },
+        quantity: 5,
+        tags: vec!["limit".to_owned()],
+    };
+    let (local_type_id, schema) =
+        generate_full_schema_from_single_type::<Order, ScryptoCustomSchema>();
+    let json = r#"{
+        "side": { "variant_name": "Sell", "fields": { "price": 10 } },
+        "quantity": "5",
+        "tags": ["limit"]
+    }"#;
+
+    // Act
+    let encoded =
+        radix_engine_toolkit::functions::scrypto_sbor::encode_json_with_schema(
+            json,
+            local_type_id,
+            schema.v1(),
+        );
+
+    // Assert
+    assert_eq!(encoded.unwrap(), scrypto_encode(&value).unwrap());
+}
+
+#[test]
+fn json_with_unknown_fields_can_not_be_encoded_with_a_schema() {
+    // Arrange
+    let (local_type_id, schema) =
+        generate_full_schema_from_single_type::<Order, ScryptoCustomSchema>();
+    let json = r#"{
+        "side": "Buy",
+        "quantity": 5,
+        "tags": [],
+        "price": 10
+    }"#;
+
+    // Act
+    let encoded =
+        radix_engine_toolkit::functions::scrypto_sbor::encode_json_with_schema(
+            json,
+            local_type_id,
+            schema.v1(),
+        );
+
+    // Assert
+    assert!(matches!(
+        encoded,
+        Err(radix_engine_toolkit::functions::scrypto_sbor::ScryptoSborError::SchemaJsonError(
+            radix_engine_toolkit::functions::schema_json::SchemaJsonError::UnknownField(field)
+        )) if field == "price"
+    ));
+}
+
+#[test]
+fn diff_of_scrypto_payloads_reports_changes_by_field_name() {
+    // Arrange
+    let old_value = scrypto_encode(&Order {
+        side: Side::Sell { price: 10 },
+        quantity: 5,
+        tags: vec!["limit".to_owned()],
+    })
+    .unwrap();
+    let new_value = scrypto_encode(&Order {
+        side: Side::Sell { price: 12 },
+        quantity: 5,
+        tags: vec!["limit".to_owned(), "post-only".to_owned()],
+    })
+    .unwrap();
+    let (local_type_id, schema) =
+        generate_full_schema_from_single_type::<Order, ScryptoCustomSchema>();
+
+    // Act
+    let changes = radix_engine_toolkit::functions::scrypto_sbor::diff(
+        old_value,
+        new_value,
+        0xF2,
+        Some((local_type_id, schema.v1().clone())),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        changes,
+        vec![
+            SborChange {
+                path: vec![
+                    SborPathElement::FieldName("side".to_owned()),
+                    SborPathElement::FieldName("price".to_owned()),
+                ],
+                old_value: Some(ProgrammaticScryptoValue::U32 { value: 10 }),
+                new_value: Some(ProgrammaticScryptoValue::U32 { value: 12 }),
+            },
+            SborChange {
+                path: vec![
+                    SborPathElement::FieldName("tags".to_owned()),
+                    SborPathElement::ArrayIndex(1),
+                ],
+                old_value: None,
+                new_value: Some(ProgrammaticScryptoValue::String {
+                    value: "post-only".to_owned()
+                }),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_of_scrypto_payloads_reports_a_change_of_enum_variant_as_a_whole() {
+    // Arrange
+    let old_value = scrypto_encode(&Order {
+        side: Side::Buy,
+        quantity: 5,
+        tags: vec![],
+    })
+    .unwrap();
+    let new_value = scrypto_encode(&Order {
+        side: Side::Sell { price: 12 },
+        quantity: 5,
+        tags: vec![],
+    })
+    .unwrap();
+    let (local_type_id, schema) =
+        generate_full_schema_from_single_type::<Order, ScryptoCustomSchema>();
+
+    // Act
+    let changes = radix_engine_toolkit::functions::scrypto_sbor::diff(
+        old_value,
+        new_value,
+        0xF2,
+        Some((local_type_id, schema.v1().clone())),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        changes,
+        vec![SborChange {
+            path: vec![SborPathElement::FieldName("side".to_owned())],
+            old_value: Some(ProgrammaticScryptoValue::Enum {
+                discriminator: 0,
+                fields: vec![],
+            }),
+            new_value: Some(ProgrammaticScryptoValue::Enum {
+                discriminator: 1,
+                fields: vec![ProgrammaticScryptoValue::U32 { value: 12 }],
+            }),
+        }]
+    );
+}
+
 #[derive(ScryptoSbor)]
 struct MyStruct {
     value: bool,
 }
+
+#[derive(ScryptoSbor)]
+struct Order {
+    side: Side,
+    quantity: u64,
+    tags: Vec<String>,
+}
+
+#[derive(ScryptoSbor)]
+enum Side {
+    Buy,
+    Sell { price: u32 },
+}
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+use radix_engine_toolkit::functions::transaction_v2::signed_partial_transaction::*;
+use radix_transactions::manifest::YieldToParent;
+
+#[test]
+fn signed_partial_transaction_signatures_can_be_verified() {
+    // Arrange
+    let transaction = signed_partial_transaction(1, 1);
+
+    // Act
+    let verifications = verify_signatures(&transaction);
+
+    // Assert
+    let verifications = verifications.unwrap();
+    assert_eq!(
+        verifications
+            .iter()
+            .map(|verification| verification.kind)
+            .collect::<Vec<_>>(),
+        vec![
+            SignatureKind::RootSubintent,
+            SignatureKind::NonRootSubintent { index: 0 }
+        ]
+    );
+    assert!(verifications
+        .iter()
+        .all(|verification| verification.is_valid_for_signer));
+}
+
+#[test]
+fn secp256k1_signatures_are_valid_for_the_signer_they_recover_to() {
+    // Arrange
+    let mut transaction = signed_partial_transaction(0, 0);
+    let subintent_hash =
+        radix_engine_toolkit::functions::transaction_v2::subintent::hash(
+            &transaction.partial_transaction.root_subintent,
+        )
+        .unwrap()
+        .hash;
+    transaction.root_subintent_signatures = IntentSignaturesV2 {
+        signatures: vec![IntentSignatureV1(
+            private_key3().sign_with_public_key(&subintent_hash),
+        )],
+    };
+
+    // Act
+    let verifications = verify_signatures(&transaction);
+
+    // Assert
+    let [verification] = verifications.unwrap().try_into().unwrap();
+    assert!(verification.is_valid_for_signer);
+    assert_eq!(
+        verification.signer,
+        Some(private_key3().public_key().into())
+    );
+    assert_ne!(
+        verification.signer,
+        Some(
+            Secp256k1PrivateKey::from_u64(3)
+                .unwrap()
+                .public_key()
+                .into()
+        )
+    );
+}
+
+#[test]
+fn signed_partial_transaction_with_a_missing_signature_list_is_rejected() {
+    // Arrange
+    let transaction = signed_partial_transaction(2, 1);
+
+    // Act
+    let verifications = verify_signatures(&transaction);
+
+    // Assert
+    assert_eq!(
+        verifications,
+        Err(
+            SignatureVerificationError::IncorrectNumberOfSubintentSignatureBatches {
+                non_root_subintents: 2,
+                signature_batches: 1,
+            }
+        )
+    );
+}
+
+#[test]
+fn signed_partial_transaction_with_an_extra_signature_list_is_rejected() {
+    // Arrange
+    let transaction = signed_partial_transaction(1, 2);
+
+    // Act
+    let verifications = verify_signatures(&transaction);
+
+    // Assert
+    assert_eq!(
+        verifications,
+        Err(
+            SignatureVerificationError::IncorrectNumberOfSubintentSignatureBatches {
+                non_root_subintents: 1,
+                signature_batches: 2,
+            }
+        )
+    );
+}
+
+/// Constructs a signed partial transaction with the given number of non-root
+/// subintents and lists of non-root subintent signatures, where each list has
+/// a signature over the subintent at the same index, if any.
+fn signed_partial_transaction(
+    non_root_subintent_count: u64,
+    signature_list_count: u64,
+) -> SignedPartialTransactionV2 {
+    let root_subintent = subintent(0);
+    let non_root_subintents = (1..=non_root_subintent_count)
+        .map(subintent)
+        .collect::<Vec<_>>();
+    let by_subintent = (1..=signature_list_count)
+        .map(|intent_discriminator| IntentSignaturesV2 {
+            signatures: vec![sign(&subintent(intent_discriminator))],
+        })
+        .collect::<Vec<_>>();
+
+    SignedPartialTransactionV2 {
+        root_subintent_signatures: IntentSignaturesV2 {
+            signatures: vec![sign(&root_subintent)],
+        },
+        partial_transaction: PartialTransactionV2 {
+            root_subintent,
+            non_root_subintents: NonRootSubintentsV2(non_root_subintents),
+        },
+        non_root_subintent_signatures: NonRootSubintentSignaturesV2 {
+            by_subintent,
+        },
+    }
+}
+
+fn subintent(intent_discriminator: u64) -> SubintentV2 {
+    SubintentV2 {
+        intent_core: IntentCoreV2 {
+            header: IntentHeaderV2 {
+                network_id: 0x01,
+                start_epoch_inclusive: Epoch::of(100),
+                end_epoch_exclusive: Epoch::of(110),
+                min_proposer_timestamp_inclusive: None,
+                max_proposer_timestamp_exclusive: None,
+                intent_discriminator,
+            },
+            blobs: BlobsV1 { blobs: vec![] },
+            message: MessageV2::None,
+            children: ChildSubintentSpecifiersV2 {
+                children: Default::default(),
+            },
+            instructions: InstructionsV2(vec![InstructionV2::YieldToParent(
+                YieldToParent {
+                    args: ManifestValue::Tuple { fields: vec![] },
+                },
+            )]),
+        },
+    }
+}
+
+fn sign(subintent: &SubintentV2) -> IntentSignatureV1 {
+    let subintent_hash =
+        radix_engine_toolkit::functions::transaction_v2::subintent::hash(
+            subintent,
+        )
+        .unwrap()
+        .hash;
+    IntentSignatureV1(private_key2().sign_with_public_key(&subintent_hash))
+}
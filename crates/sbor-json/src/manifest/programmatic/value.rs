@@ -0,0 +1,563 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+use radix_transactions::data::{
+    from_decimal, from_non_fungible_local_id, from_precise_decimal, to_decimal,
+    to_non_fungible_local_id, to_precise_decimal,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::common::address::SerializableNodeId;
+use crate::common::map_entry::MapEntry;
+
+#[serde_as]
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(tag = "kind")]
+pub enum ProgrammaticManifestValue {
+    Bool {
+        value: bool,
+    },
+    I8 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: i8,
+    },
+    I16 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: i16,
+    },
+    I32 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: i32,
+    },
+    I64 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: i64,
+    },
+    I128 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: i128,
+    },
+    U8 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u8,
+    },
+    U16 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u16,
+    },
+    U32 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u32,
+    },
+    U64 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u64,
+    },
+    U128 {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u128,
+    },
+    String {
+        value: String,
+    },
+    Enum {
+        #[serde(rename = "variant_id")]
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        discriminator: u8,
+        fields: Vec<ProgrammaticManifestValue>,
+    },
+    Array {
+        #[serde(rename = "element_kind")]
+        element_value_kind: ProgrammaticManifestValueKind,
+        elements: Vec<ProgrammaticManifestValue>,
+    },
+    Tuple {
+        fields: Vec<ProgrammaticManifestValue>,
+    },
+    Map {
+        #[serde(rename = "key_kind")]
+        key_value_kind: ProgrammaticManifestValueKind,
+        #[serde(rename = "value_kind")]
+        value_value_kind: ProgrammaticManifestValueKind,
+        #[serde_as(
+            as = "Vec<serde_with::FromInto<MapEntry<ProgrammaticManifestValue>>>"
+        )]
+        entries: Vec<(ProgrammaticManifestValue, ProgrammaticManifestValue)>,
+    },
+    Address {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: SerializableNodeId,
+    },
+    NamedAddress {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u32,
+    },
+    Bucket {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u32,
+    },
+    Proof {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u32,
+    },
+    Expression {
+        value: ProgrammaticManifestExpression,
+    },
+    Blob {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: Hash,
+    },
+    Decimal {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: Decimal,
+    },
+    PreciseDecimal {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: PreciseDecimal,
+    },
+    NonFungibleLocalId {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: NonFungibleLocalId,
+    },
+    AddressReservation {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        value: u32,
+    },
+    Bytes {
+        #[serde(rename = "element_kind")]
+        element_value_kind: ProgrammaticManifestValueKind,
+
+        #[serde_as(as = "serde_with::hex::Hex")]
+        #[serde(rename = "hex")]
+        value: Vec<u8>,
+    },
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+pub enum ProgrammaticManifestValueKind {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    String,
+    Enum,
+    Array,
+    Tuple,
+    Map,
+    Address,
+    Bucket,
+    Proof,
+    Expression,
+    Blob,
+    Decimal,
+    PreciseDecimal,
+    NonFungibleLocalId,
+    AddressReservation,
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+pub enum ProgrammaticManifestExpression {
+    EntireWorktop,
+    EntireAuthZone,
+}
+
+impl From<ProgrammaticManifestExpression> for ManifestExpression {
+    fn from(value: ProgrammaticManifestExpression) -> Self {
+        match value {
+            ProgrammaticManifestExpression::EntireWorktop => {
+                Self::EntireWorktop
+            }
+            ProgrammaticManifestExpression::EntireAuthZone => {
+                Self::EntireAuthZone
+            }
+        }
+    }
+}
+
+impl From<ManifestExpression> for ProgrammaticManifestExpression {
+    fn from(value: ManifestExpression) -> Self {
+        match value {
+            ManifestExpression::EntireWorktop => Self::EntireWorktop,
+            ManifestExpression::EntireAuthZone => Self::EntireAuthZone,
+        }
+    }
+}
+
+impl From<ProgrammaticManifestValueKind> for ManifestValueKind {
+    fn from(value: ProgrammaticManifestValueKind) -> Self {
+        match value {
+            ProgrammaticManifestValueKind::Bool => Self::Bool,
+            ProgrammaticManifestValueKind::I8 => Self::I8,
+            ProgrammaticManifestValueKind::I16 => Self::I16,
+            ProgrammaticManifestValueKind::I32 => Self::I32,
+            ProgrammaticManifestValueKind::I64 => Self::I64,
+            ProgrammaticManifestValueKind::I128 => Self::I128,
+            ProgrammaticManifestValueKind::U8 => Self::U8,
+            ProgrammaticManifestValueKind::U16 => Self::U16,
+            ProgrammaticManifestValueKind::U32 => Self::U32,
+            ProgrammaticManifestValueKind::U64 => Self::U64,
+            ProgrammaticManifestValueKind::U128 => Self::U128,
+            ProgrammaticManifestValueKind::String => Self::String,
+            ProgrammaticManifestValueKind::Enum => Self::Enum,
+            ProgrammaticManifestValueKind::Array => Self::Array,
+            ProgrammaticManifestValueKind::Tuple => Self::Tuple,
+            ProgrammaticManifestValueKind::Map => Self::Map,
+            ProgrammaticManifestValueKind::Address => {
+                Self::Custom(ManifestCustomValueKind::Address)
+            }
+            ProgrammaticManifestValueKind::Bucket => {
+                Self::Custom(ManifestCustomValueKind::Bucket)
+            }
+            ProgrammaticManifestValueKind::Proof => {
+                Self::Custom(ManifestCustomValueKind::Proof)
+            }
+            ProgrammaticManifestValueKind::Expression => {
+                Self::Custom(ManifestCustomValueKind::Expression)
+            }
+            ProgrammaticManifestValueKind::Blob => {
+                Self::Custom(ManifestCustomValueKind::Blob)
+            }
+            ProgrammaticManifestValueKind::Decimal => {
+                Self::Custom(ManifestCustomValueKind::Decimal)
+            }
+            ProgrammaticManifestValueKind::PreciseDecimal => {
+                Self::Custom(ManifestCustomValueKind::PreciseDecimal)
+            }
+            ProgrammaticManifestValueKind::NonFungibleLocalId => {
+                Self::Custom(ManifestCustomValueKind::NonFungibleLocalId)
+            }
+            ProgrammaticManifestValueKind::AddressReservation => {
+                Self::Custom(ManifestCustomValueKind::AddressReservation)
+            }
+        }
+    }
+}
+
+impl From<ManifestValueKind> for ProgrammaticManifestValueKind {
+    fn from(value: ManifestValueKind) -> Self {
+        match value {
+            ManifestValueKind::Bool => Self::Bool,
+            ManifestValueKind::I8 => Self::I8,
+            ManifestValueKind::I16 => Self::I16,
+            ManifestValueKind::I32 => Self::I32,
+            ManifestValueKind::I64 => Self::I64,
+            ManifestValueKind::I128 => Self::I128,
+            ManifestValueKind::U8 => Self::U8,
+            ManifestValueKind::U16 => Self::U16,
+            ManifestValueKind::U32 => Self::U32,
+            ManifestValueKind::U64 => Self::U64,
+            ManifestValueKind::U128 => Self::U128,
+            ManifestValueKind::String => Self::String,
+            ManifestValueKind::Enum => Self::Enum,
+            ManifestValueKind::Array => Self::Array,
+            ManifestValueKind::Tuple => Self::Tuple,
+            ManifestValueKind::Map => Self::Map,
+            ManifestValueKind::Custom(ManifestCustomValueKind::Address) => {
+                Self::Address
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Bucket) => {
+                Self::Bucket
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Proof) => {
+                Self::Proof
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Expression) => {
+                Self::Expression
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Blob) => {
+                Self::Blob
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Decimal) => {
+                Self::Decimal
+            }
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::PreciseDecimal,
+            ) => Self::PreciseDecimal,
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::NonFungibleLocalId,
+            ) => Self::NonFungibleLocalId,
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::AddressReservation,
+            ) => Self::AddressReservation,
+        }
+    }
+}
+
+impl ProgrammaticManifestValue {
+    pub fn to_manifest_value(&self) -> ManifestValue {
+        match self {
+            Self::Bool { value } => ManifestValue::Bool { value: *value },
+            Self::I8 { value } => ManifestValue::I8 { value: *value },
+            Self::I16 { value } => ManifestValue::I16 { value: *value },
+            Self::I32 { value } => ManifestValue::I32 { value: *value },
+            Self::I64 { value } => ManifestValue::I64 { value: *value },
+            Self::I128 { value } => ManifestValue::I128 { value: *value },
+            Self::U8 { value } => ManifestValue::U8 { value: *value },
+            Self::U16 { value } => ManifestValue::U16 { value: *value },
+            Self::U32 { value } => ManifestValue::U32 { value: *value },
+            Self::U64 { value } => ManifestValue::U64 { value: *value },
+            Self::U128 { value } => ManifestValue::U128 { value: *value },
+            Self::String { value } => ManifestValue::String {
+                value: value.clone(),
+            },
+            Self::Enum {
+                discriminator,
+                fields,
+            } => ManifestValue::Enum {
+                discriminator: *discriminator,
+                fields: fields.iter().map(Self::to_manifest_value).collect(),
+            },
+            Self::Array {
+                element_value_kind,
+                elements,
+            } => ManifestValue::Array {
+                element_value_kind: (*element_value_kind).into(),
+                elements: elements
+                    .iter()
+                    .map(Self::to_manifest_value)
+                    .collect(),
+            },
+            Self::Tuple { fields } => ManifestValue::Tuple {
+                fields: fields.iter().map(Self::to_manifest_value).collect(),
+            },
+            Self::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => ManifestValue::Map {
+                key_value_kind: (*key_value_kind).into(),
+                value_value_kind: (*value_value_kind).into(),
+                entries: entries
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            Self::to_manifest_value(key),
+                            Self::to_manifest_value(value),
+                        )
+                    })
+                    .collect(),
+            },
+            Self::Address { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::Address(ManifestAddress::Static(
+                    value.0,
+                )),
+            },
+            Self::NamedAddress { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::Address(ManifestAddress::Named(
+                    ManifestNamedAddress(*value),
+                )),
+            },
+            Self::Bucket { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::Bucket(ManifestBucket(*value)),
+            },
+            Self::Proof { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::Proof(ManifestProof(*value)),
+            },
+            Self::Expression { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::Expression((*value).into()),
+            },
+            Self::Blob { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::Blob(ManifestBlobRef(value.0)),
+            },
+            Self::Decimal { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::Decimal(from_decimal(*value)),
+            },
+            Self::PreciseDecimal { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::PreciseDecimal(
+                    from_precise_decimal(*value),
+                ),
+            },
+            Self::NonFungibleLocalId { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::NonFungibleLocalId(
+                    from_non_fungible_local_id(value.clone()),
+                ),
+            },
+            Self::AddressReservation { value } => ManifestValue::Custom {
+                value: ManifestCustomValue::AddressReservation(
+                    ManifestAddressReservation(*value),
+                ),
+            },
+            Self::Bytes {
+                element_value_kind,
+                value,
+            } => ManifestValue::Array {
+                element_value_kind: (*element_value_kind).into(),
+                elements: value
+                    .iter()
+                    .map(|value| ManifestValue::U8 { value: *value })
+                    .collect(),
+            },
+        }
+    }
+
+    pub fn from_manifest_value(value: &ManifestValue, network_id: u8) -> Self {
+        match value {
+            SborValue::Bool { value } => Self::Bool { value: *value },
+            SborValue::I8 { value } => Self::I8 { value: *value },
+            SborValue::I16 { value } => Self::I16 { value: *value },
+            SborValue::I32 { value } => Self::I32 { value: *value },
+            SborValue::I64 { value } => Self::I64 { value: *value },
+            SborValue::I128 { value } => Self::I128 { value: *value },
+            SborValue::U8 { value } => Self::U8 { value: *value },
+            SborValue::U16 { value } => Self::U16 { value: *value },
+            SborValue::U32 { value } => Self::U32 { value: *value },
+            SborValue::U64 { value } => Self::U64 { value: *value },
+            SborValue::U128 { value } => Self::U128 { value: *value },
+            SborValue::String { value } => Self::String {
+                value: value.to_owned(),
+            },
+            SborValue::Enum {
+                discriminator,
+                fields,
+            } => Self::Enum {
+                discriminator: *discriminator,
+                fields: fields
+                    .iter()
+                    .map(|value| Self::from_manifest_value(value, network_id))
+                    .collect(),
+            },
+            SborValue::Array {
+                element_value_kind,
+                elements,
+            } if elements
+                .iter()
+                .all(|element| matches!(element, ManifestValue::U8 { .. })) =>
+            {
+                Self::Bytes {
+                    element_value_kind: (*element_value_kind).into(),
+                    value: elements
+                        .iter()
+                        .map_while(|value| match value {
+                            ManifestValue::U8 { value } => Some(*value),
+                            _ => None,
+                        })
+                        .collect::<Vec<u8>>(),
+                }
+            }
+            SborValue::Array {
+                element_value_kind,
+                elements,
+            } => Self::Array {
+                element_value_kind: (*element_value_kind).into(),
+                elements: elements
+                    .iter()
+                    .map(|value| Self::from_manifest_value(value, network_id))
+                    .collect(),
+            },
+            SborValue::Tuple { fields } => Self::Tuple {
+                fields: fields
+                    .iter()
+                    .map(|value| Self::from_manifest_value(value, network_id))
+                    .collect(),
+            },
+            SborValue::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => Self::Map {
+                key_value_kind: (*key_value_kind).into(),
+                value_value_kind: (*value_value_kind).into(),
+                entries: entries
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            Self::from_manifest_value(key, network_id),
+                            Self::from_manifest_value(value, network_id),
+                        )
+                    })
+                    .collect(),
+            },
+            SborValue::Custom {
+                value:
+                    ManifestCustomValue::Address(ManifestAddress::Static(value)),
+            } => Self::Address {
+                value: SerializableNodeId(*value, network_id),
+            },
+            SborValue::Custom {
+                value:
+                    ManifestCustomValue::Address(ManifestAddress::Named(value)),
+            } => Self::NamedAddress { value: value.0 },
+            SborValue::Custom {
+                value: ManifestCustomValue::Bucket(value),
+            } => Self::Bucket { value: value.0 },
+            SborValue::Custom {
+                value: ManifestCustomValue::Proof(value),
+            } => Self::Proof { value: value.0 },
+            SborValue::Custom {
+                value: ManifestCustomValue::Expression(value),
+            } => Self::Expression {
+                value: (*value).into(),
+            },
+            SborValue::Custom {
+                value: ManifestCustomValue::Blob(value),
+            } => Self::Blob {
+                value: Hash(value.0),
+            },
+            SborValue::Custom {
+                value: ManifestCustomValue::Decimal(value),
+            } => Self::Decimal {
+                value: to_decimal(value.clone()),
+            },
+            SborValue::Custom {
+                value: ManifestCustomValue::PreciseDecimal(value),
+            } => Self::PreciseDecimal {
+                value: to_precise_decimal(value.clone()),
+            },
+            SborValue::Custom {
+                value: ManifestCustomValue::NonFungibleLocalId(value),
+            } => Self::NonFungibleLocalId {
+                value: to_non_fungible_local_id(value.clone()),
+            },
+            SborValue::Custom {
+                value: ManifestCustomValue::AddressReservation(value),
+            } => Self::AddressReservation { value: value.0 },
+        }
+    }
+}
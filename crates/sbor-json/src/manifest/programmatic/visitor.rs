@@ -0,0 +1,260 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+
+use super::value::*;
+use crate::common::address::*;
+
+#[allow(unused_variables)]
+pub trait ProgrammaticManifestValueVisitor {
+    #[inline]
+    fn visit_bool(&mut self, value: &bool) {}
+
+    #[inline]
+    fn visit_i8(&mut self, value: &i8) {}
+
+    #[inline]
+    fn visit_i16(&mut self, value: &i16) {}
+
+    #[inline]
+    fn visit_i32(&mut self, value: &i32) {}
+
+    #[inline]
+    fn visit_i64(&mut self, value: &i64) {}
+
+    #[inline]
+    fn visit_i128(&mut self, value: &i128) {}
+
+    #[inline]
+    fn visit_u8(&mut self, value: &u8) {}
+
+    #[inline]
+    fn visit_u16(&mut self, value: &u16) {}
+
+    #[inline]
+    fn visit_u32(&mut self, value: &u32) {}
+
+    #[inline]
+    fn visit_u64(&mut self, value: &u64) {}
+
+    #[inline]
+    fn visit_u128(&mut self, value: &u128) {}
+
+    #[inline]
+    fn visit_string(&mut self, value: &str) {}
+
+    #[inline]
+    fn visit_enum(
+        &mut self,
+        discriminator: &u8,
+        fields: &[ProgrammaticManifestValue],
+    ) {
+    }
+
+    #[inline]
+    fn visit_array(
+        &mut self,
+        element_value_kind: &ProgrammaticManifestValueKind,
+        elements: &[ProgrammaticManifestValue],
+    ) {
+    }
+
+    #[inline]
+    fn visit_tuple(&mut self, fields: &[ProgrammaticManifestValue]) {}
+
+    #[inline]
+    fn visit_map(
+        &mut self,
+        key_value_kind: &ProgrammaticManifestValueKind,
+        value_value_kind: &ProgrammaticManifestValueKind,
+        entries: &[(ProgrammaticManifestValue, ProgrammaticManifestValue)],
+    ) {
+    }
+
+    #[inline]
+    fn visit_address(&mut self, value: &SerializableNodeId) {}
+
+    #[inline]
+    fn visit_named_address(&mut self, value: &u32) {}
+
+    #[inline]
+    fn visit_bucket(&mut self, value: &u32) {}
+
+    #[inline]
+    fn visit_proof(&mut self, value: &u32) {}
+
+    #[inline]
+    fn visit_expression(&mut self, value: &ProgrammaticManifestExpression) {}
+
+    #[inline]
+    fn visit_blob(&mut self, value: &Hash) {}
+
+    #[inline]
+    fn visit_decimal(&mut self, value: &Decimal) {}
+
+    #[inline]
+    fn visit_precise_decimal(&mut self, value: &PreciseDecimal) {}
+
+    #[inline]
+    fn visit_non_fungible_local_id(&mut self, value: &NonFungibleLocalId) {}
+
+    #[inline]
+    fn visit_address_reservation(&mut self, value: &u32) {}
+
+    #[inline]
+    fn visit_bytes(
+        &mut self,
+        element_value_kind: &ProgrammaticManifestValueKind,
+        value: &[u8],
+    ) {
+    }
+}
+
+pub fn traverse(
+    value: &ProgrammaticManifestValue,
+    visitors: &mut [&mut dyn ProgrammaticManifestValueVisitor],
+) {
+    match value {
+        ProgrammaticManifestValue::Bool { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_bool(value)),
+        ProgrammaticManifestValue::I8 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_i8(value)),
+        ProgrammaticManifestValue::I16 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_i16(value)),
+        ProgrammaticManifestValue::I32 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_i32(value)),
+        ProgrammaticManifestValue::I64 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_i64(value)),
+        ProgrammaticManifestValue::I128 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_i128(value)),
+        ProgrammaticManifestValue::U8 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_u8(value)),
+        ProgrammaticManifestValue::U16 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_u16(value)),
+        ProgrammaticManifestValue::U32 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_u32(value)),
+        ProgrammaticManifestValue::U64 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_u64(value)),
+        ProgrammaticManifestValue::U128 { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_u128(value)),
+        ProgrammaticManifestValue::String { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_string(value)),
+        ProgrammaticManifestValue::Enum {
+            discriminator,
+            fields,
+        } => {
+            visitors
+                .iter_mut()
+                .for_each(|visitor| visitor.visit_enum(discriminator, fields));
+            fields.iter().for_each(|field| traverse(field, visitors));
+        }
+        ProgrammaticManifestValue::Array {
+            element_value_kind,
+            elements,
+        } => {
+            visitors.iter_mut().for_each(|visitor| {
+                visitor.visit_array(element_value_kind, elements)
+            });
+            elements
+                .iter()
+                .for_each(|element| traverse(element, visitors));
+        }
+        ProgrammaticManifestValue::Tuple { fields } => {
+            visitors
+                .iter_mut()
+                .for_each(|visitor| visitor.visit_tuple(fields));
+            fields.iter().for_each(|field| traverse(field, visitors));
+        }
+        ProgrammaticManifestValue::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        } => {
+            visitors.iter_mut().for_each(|visitor| {
+                visitor.visit_map(key_value_kind, value_value_kind, entries)
+            });
+            entries.iter().for_each(|(key, value)| {
+                traverse(key, visitors);
+                traverse(value, visitors);
+            });
+        }
+        ProgrammaticManifestValue::Address { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_address(value)),
+        ProgrammaticManifestValue::NamedAddress { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_named_address(value)),
+        ProgrammaticManifestValue::Bucket { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_bucket(value)),
+        ProgrammaticManifestValue::Proof { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_proof(value)),
+        ProgrammaticManifestValue::Expression { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_expression(value)),
+        ProgrammaticManifestValue::Blob { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_blob(value)),
+        ProgrammaticManifestValue::Decimal { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_decimal(value)),
+        ProgrammaticManifestValue::PreciseDecimal { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_precise_decimal(value)),
+        ProgrammaticManifestValue::NonFungibleLocalId { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_non_fungible_local_id(value)),
+        ProgrammaticManifestValue::AddressReservation { value } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_address_reservation(value)),
+        ProgrammaticManifestValue::Bytes {
+            element_value_kind,
+            value,
+        } => visitors
+            .iter_mut()
+            .for_each(|visitor| visitor.visit_bytes(element_value_kind, value)),
+    };
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AddressNetworkMismatchVisitor(HashSet<u8>);
+
+impl AddressNetworkMismatchVisitor {
+    pub fn is_network_mismatch(&self) -> bool {
+        self.0.len() > 1
+    }
+}
+
+impl ProgrammaticManifestValueVisitor for AddressNetworkMismatchVisitor {
+    fn visit_address(&mut self, value: &SerializableNodeId) {
+        self.0.insert(value.1);
+    }
+}
@@ -0,0 +1,140 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+use radix_engine_interface::macros::{dec, pdec};
+use radix_transactions::data::{
+    from_decimal, from_non_fungible_local_id, from_precise_decimal,
+};
+use sbor_json::common::address::SerializableNodeId;
+use sbor_json::manifest::programmatic::utils::manifest_value_contains_network_mismatch;
+use sbor_json::manifest::programmatic::value::{
+    ProgrammaticManifestValue, ProgrammaticManifestValueKind,
+};
+use std::fmt::Debug;
+
+round_trip_tests! {
+    programmatic_json_round_trip_of_bool: true,
+    programmatic_json_round_trip_of_u8: 1u8,
+    programmatic_json_round_trip_of_i128: -1i128,
+    programmatic_json_round_trip_of_string: "Hello World!",
+    programmatic_json_round_trip_of_tuple: (1u8, "String"),
+    programmatic_json_round_trip_of_bytes: vec![0u8, 1u8, 2u8],
+    programmatic_json_round_trip_of_map: hashmap!(
+        "A" => 0u8,
+        "B" => 1u8,
+    ),
+
+    programmatic_json_round_trip_of_static_address: ManifestAddress::Static(XRD.into_node_id()),
+    programmatic_json_round_trip_of_named_address: ManifestAddress::Named(ManifestNamedAddress(1)),
+    programmatic_json_round_trip_of_bucket: ManifestBucket(1),
+    programmatic_json_round_trip_of_proof: ManifestProof(1),
+    programmatic_json_round_trip_of_expression: ManifestExpression::EntireWorktop,
+    programmatic_json_round_trip_of_blob: ManifestBlobRef([1; 32]),
+    programmatic_json_round_trip_of_address_reservation: ManifestAddressReservation(1),
+    programmatic_json_round_trip_of_decimal: from_decimal(dec!(100)),
+    programmatic_json_round_trip_of_precise_decimal: from_precise_decimal(pdec!(100)),
+    programmatic_json_round_trip_of_non_fungible_local_id: from_non_fungible_local_id(NonFungibleLocalId::integer(1)),
+}
+
+#[test]
+pub fn manifest_value_with_two_address_of_the_differing_networks_has_a_network_mismatch(
+) {
+    // Arrange
+    let value = ProgrammaticManifestValue::Array {
+        element_value_kind: ProgrammaticManifestValueKind::Address,
+        elements: vec![
+            ProgrammaticManifestValue::Address {
+                value: SerializableNodeId(XRD.into_node_id(), 1),
+            },
+            ProgrammaticManifestValue::Address {
+                value: SerializableNodeId(
+                    ACCESS_CONTROLLER_PACKAGE.into_node_id(),
+                    2,
+                ),
+            },
+        ],
+    };
+
+    // Act
+    let contains_network_mismatch =
+        manifest_value_contains_network_mismatch(&value);
+
+    // Assert
+    assert!(contains_network_mismatch)
+}
+
+#[test]
+pub fn bucket_is_serialized_with_its_kind_and_id() {
+    // Arrange
+    let value = ProgrammaticManifestValue::Bucket { value: 10 };
+
+    // Act
+    let value = serde_json::to_value(&value).unwrap();
+
+    // Assert
+    assert_eq!(
+        value,
+        serde_json::json!({ "kind": "Bucket", "value": "10" })
+    )
+}
+
+/// Tests that a manifest value survives the conversion into the programmatic
+/// JSON representation and back.
+pub fn programmatic_json_round_trip_is_lossless<T>(object: &T)
+where
+    T: ManifestEncode + Debug,
+{
+    // Arrange
+    let payload = manifest_encode(&object).unwrap();
+    let manifest_value = manifest_decode::<ManifestValue>(&payload).unwrap();
+
+    // Act
+    let round_tripped = {
+        let value = ProgrammaticManifestValue::from_manifest_value(
+            &manifest_value,
+            0xF2,
+        );
+        let json = serde_json::to_string(&value).unwrap();
+        serde_json::from_str::<ProgrammaticManifestValue>(&json)
+            .unwrap()
+            .to_manifest_value()
+    };
+
+    // Assert
+    assert_eq!(
+        manifest_encode(&round_tripped).unwrap(),
+        payload,
+        "The round trip of \"{object:?}\" through programmatic JSON is lossy"
+    )
+}
+
+macro_rules! round_trip_tests {
+    (
+        $(
+            $fn_ident: ident: $value: expr
+        ),* $(,)?
+    ) => {
+        $(
+            #[test]
+            fn $fn_ident() {
+                programmatic_json_round_trip_is_lossless(&$value)
+            }
+        )*
+    };
+}
+use round_trip_tests;